@@ -0,0 +1,267 @@
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use clap::Parser;
+use mrml::mjml::Mjml;
+use mrml::prelude::parser::loader::{IncludeLoader, IncludeLoaderError};
+use mrml::prelude::parser::local_loader::LocalIncludeLoader;
+use mrml::prelude::parser::multi_loader::MultiIncludeLoader;
+use mrml::prelude::parser::ParserOptions;
+use mrml::prelude::render::{html_to_plain_text, RenderOptions};
+
+use crate::{format_parser_error, Options, Render};
+
+const EXTENSION: &str = "mjml";
+
+#[derive(Clone, Debug, Parser)]
+pub(crate) struct Build {
+    /// Directory containing the templates to render
+    pub source: PathBuf,
+    /// Directory where the rendered files are written
+    #[clap(short, long, default_value = "dist")]
+    pub output: PathBuf,
+    /// Also write a plain text version of each template
+    #[clap(long)]
+    pub text: bool,
+    #[clap(flatten)]
+    pub render: Render,
+}
+
+/// Loader resolving the paths without scheme relatively to the directory of
+/// the template being rendered, without leaving the source directory.
+#[derive(Debug)]
+struct RelativeIncludeLoader {
+    root: PathBuf,
+    directory: PathBuf,
+}
+
+impl IncludeLoader for RelativeIncludeLoader {
+    fn resolve(&self, path: &str) -> Result<String, IncludeLoaderError> {
+        let target = self
+            .directory
+            .join(path)
+            .canonicalize()
+            .map_err(|err| IncludeLoaderError::new(path, err.kind()))?;
+        if !target.starts_with(&self.root) {
+            return Err(IncludeLoaderError::new(path, ErrorKind::NotFound)
+                .with_message("the path should stay in the source directory"));
+        }
+        std::fs::read_to_string(target).map_err(|err| {
+            IncludeLoaderError::new(path, ErrorKind::InvalidData)
+                .with_message("unable to load the template file")
+                .with_cause(Arc::new(err))
+        })
+    }
+}
+
+/// Partials don't have an `mjml` root element, they are only rendered through
+/// `mj-include`.
+fn is_template(content: &str) -> bool {
+    let mut content = content.trim_start();
+    while let Some(rest) = content.strip_prefix("<!--") {
+        content = match rest.find("-->") {
+            Some(end) => rest[end + 3..].trim_start(),
+            None => return false,
+        };
+    }
+    content.starts_with("<mjml")
+}
+
+fn collect_files(dir: &Path, result: &mut Vec<PathBuf>) -> Result<(), String> {
+    let entries = std::fs::read_dir(dir).map_err(|err| format!("couldn't read {dir:?}: {err}"))?;
+    for entry in entries {
+        let path = entry
+            .map_err(|err| format!("couldn't read {dir:?}: {err}"))?
+            .path();
+        if path.is_dir() {
+            collect_files(&path, result)?;
+        } else if path.extension().is_some_and(|ext| ext == EXTENSION) {
+            result.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn write_file(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|err| format!("couldn't create directory {parent:?}: {err}"))?;
+    }
+    std::fs::write(path, content).map_err(|err| format!("couldn't write {path:?}: {err}"))
+}
+
+impl Build {
+    fn parser_options(
+        &self,
+        options: &Options,
+        root: &Path,
+        file: &Path,
+    ) -> Result<ParserOptions, String> {
+        let local = options
+            .local_include_loader()?
+            .unwrap_or_else(|| Box::new(LocalIncludeLoader::new(root.to_path_buf())));
+        let relative = RelativeIncludeLoader {
+            root: root.to_path_buf(),
+            directory: file.parent().unwrap_or(root).to_path_buf(),
+        };
+        Ok(ParserOptions {
+            include_loader: Box::new(
+                MultiIncludeLoader::new()
+                    .with_starts_with("file://", local)
+                    .with_starts_with("http://", options.http_include_loader())
+                    .with_starts_with("https://", options.http_include_loader())
+                    .with_any(Box::new(relative)),
+            ),
+            ..Default::default()
+        })
+    }
+
+    fn build_file(
+        &self,
+        options: &Options,
+        render_opts: &RenderOptions,
+        root: &Path,
+        file: &Path,
+    ) -> Result<bool, String> {
+        let content = options.read_file(&file.to_string_lossy())?;
+        if !is_template(&content) {
+            log::debug!("skipping partial {file:?}");
+            return Ok(false);
+        }
+        let parser_options = self.parser_options(options, root, file)?;
+        let template = Mjml::parse_with_options(content, &parser_options)
+            .map_err(|err| format!("couldn't parse {file:?}: {}", format_parser_error(err)))?;
+        for warning in template.warnings.iter() {
+            log::warn!("{file:?}: {warning}");
+        }
+        let html = template
+            .element
+            .render(render_opts)
+            .map_err(|err| format!("couldn't render {file:?}: {err}"))?;
+
+        let target = self.output.join(file.strip_prefix(root).unwrap_or(file));
+        if self.text {
+            write_file(&target.with_extension("txt"), &html_to_plain_text(&html))?;
+        }
+        write_file(&target.with_extension("html"), &html)?;
+        Ok(true)
+    }
+}
+
+pub(crate) fn execute(options: &Options, args: &Build) -> Result<(), String> {
+    let root = args
+        .source
+        .canonicalize()
+        .map_err(|err| format!("couldn't open {:?}: {err}", args.source))?;
+    let mut files = Vec::new();
+    collect_files(&root, &mut files)?;
+    files.sort();
+
+    let render_opts = RenderOptions::from(args.render.clone());
+    let mut count = 0;
+    let mut errors = Vec::new();
+    for file in files {
+        match args.build_file(options, &render_opts, &root, &file) {
+            Ok(true) => count += 1,
+            Ok(false) => {}
+            Err(error) => {
+                log::error!("{error}");
+                errors.push(error);
+            }
+        }
+    }
+    log::info!("rendered {count} template(s) in {:?}", args.output);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use clap::Parser;
+
+    use super::{execute, is_template};
+    use crate::{Options, SubCommand};
+
+    fn create_source(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("mrml-cli-build-{name}"));
+        let _ = std::fs::remove_dir_all(&root);
+        let source = root.join("src");
+        std::fs::create_dir_all(source.join("emails").join("partials")).unwrap();
+        std::fs::write(
+            source.join("emails").join("welcome.mjml"),
+            r#"<mjml><mj-body><mj-include path="./partials/button.mjml" /></mj-body></mjml>"#,
+        )
+        .unwrap();
+        std::fs::write(
+            source.join("emails").join("partials").join("button.mjml"),
+            r#"<mj-button href="https://mrml.io">Hello</mj-button>"#,
+        )
+        .unwrap();
+        root
+    }
+
+    fn build(root: &std::path::Path, extra: &[&str]) -> Result<(), String> {
+        let source = root.join("src");
+        let output = root.join("dist");
+        let mut args = vec![
+            "mrml-cli",
+            "build",
+            source.to_str().unwrap(),
+            "--output",
+            output.to_str().unwrap(),
+        ];
+        args.extend_from_slice(extra);
+        let options = Options::parse_from(args);
+        let SubCommand::Build(ref args) = options.subcmd else {
+            panic!("expected build subcommand");
+        };
+        execute(&options, args)
+    }
+
+    #[test]
+    fn should_detect_templates() {
+        assert!(is_template("<!-- header -->\n<mjml></mjml>"));
+        assert!(!is_template("<mj-button>Hello</mj-button>"));
+    }
+
+    #[test]
+    fn should_build_directory() {
+        let root = create_source("directory");
+        build(&root, &["--text"]).unwrap();
+        let dist = root.join("dist").join("emails");
+        let html = std::fs::read_to_string(dist.join("welcome.html")).unwrap();
+        assert!(html.starts_with("<!doctype html>"));
+        assert!(html.contains("Hello"));
+        let text = std::fs::read_to_string(dist.join("welcome.txt")).unwrap();
+        assert!(text.contains("Hello (https://mrml.io)"));
+        assert!(!dist.join("partials").join("button.html").exists());
+    }
+
+    #[test]
+    fn should_not_write_text_by_default() {
+        let root = create_source("no-text");
+        build(&root, &[]).unwrap();
+        let dist = root.join("dist").join("emails");
+        assert!(dist.join("welcome.html").exists());
+        assert!(!dist.join("welcome.txt").exists());
+    }
+
+    #[test]
+    fn should_not_include_outside_of_source() {
+        let root = create_source("outside");
+        std::fs::write(root.join("secret.mjml"), "<mj-text>Secret</mj-text>").unwrap();
+        std::fs::write(
+            root.join("src").join("leak.mjml"),
+            r#"<mjml><mj-body><mj-include path="../secret.mjml" /></mj-body></mjml>"#,
+        )
+        .unwrap();
+        assert!(build(&root, &[]).is_err());
+        assert!(!root.join("dist").join("leak.html").exists());
+    }
+}
@@ -0,0 +1,480 @@
+use clap::{Parser, ValueEnum};
+use mrml::lint::{Linter, Severity};
+use mrml::mjml::Mjml;
+use mrml::prelude::parser::{Error as ParserError, Origin, Warning};
+
+use crate::{format_parser_error, Options};
+
+#[derive(ValueEnum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LintFormat {
+    Text,
+    Json,
+    Sarif,
+}
+
+#[derive(Clone, Debug, Parser)]
+pub(crate) struct Lint {
+    /// Output format of the diagnostics
+    #[clap(long, value_enum, default_value_t = LintFormat::Text)]
+    pub format: LintFormat,
+    /// Exit with an error when a warning is found
+    #[clap(long)]
+    pub deny_warnings: bool,
+    /// Run the accessibility rules on the template
+    #[clap(long)]
+    pub a11y: bool,
+    /// Run the deliverability rules on the template
+    #[clap(long)]
+    pub spam: bool,
+    /// Run the unsubscribe link and postal address rules on the template
+    #[clap(long)]
+    pub compliance: bool,
+    /// Override the severity of a rule, like `a11y/html-lang=off`
+    #[clap(long = "rule", value_parser = parse_rule_severity)]
+    pub rules: Vec<(String, Severity)>,
+}
+
+fn parse_rule_severity(value: &str) -> Result<(String, Severity), String> {
+    let (name, severity) = value
+        .split_once('=')
+        .ok_or_else(|| format!("invalid rule {value:?}, expected name=severity"))?;
+    let severity = severity
+        .parse::<Severity>()
+        .map_err(|err| err.to_string())?;
+    Ok((name.to_string(), severity))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Level {
+    Error,
+    Warning,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+struct Location {
+    start: usize,
+    end: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    column: Option<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+struct Diagnostic {
+    level: Level,
+    code: &'static str,
+    message: String,
+    origin: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<Location>,
+    /// Path of the element, like `/mjml/mj-body/mj-section[1]`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+}
+
+/// Computes the line and column, starting at 1, of a byte offset.
+fn line_column(input: &str, offset: usize) -> Option<(usize, usize)> {
+    let before = input.get(..offset)?;
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rfind('\n')
+        .map(|index| before[index + 1..].chars().count())
+        .unwrap_or_else(|| before.chars().count())
+        + 1;
+    Some((line, column))
+}
+
+fn location(input: &str, origin: &Origin, start: usize, end: usize) -> Location {
+    // the position can only be resolved in the root template
+    let line_column = match origin {
+        Origin::Root => line_column(input, start),
+        Origin::Include { .. } => None,
+    };
+    Location {
+        start,
+        end,
+        line: line_column.map(|(line, _)| line),
+        column: line_column.map(|(_, column)| column),
+    }
+}
+
+fn origin_name(origin: &Origin, filename: &str) -> String {
+    match origin {
+        Origin::Root => filename.to_string(),
+        Origin::Include { path } => path.clone(),
+    }
+}
+
+impl Diagnostic {
+    fn from_warning(input: &str, filename: &str, warning: &Warning) -> Self {
+        Self {
+            level: Level::Warning,
+            code: warning.kind.as_str(),
+            message: warning.kind.to_string(),
+            origin: origin_name(&warning.origin, filename),
+            location: Some(location(
+                input,
+                &warning.origin,
+                warning.span.start,
+                warning.span.end,
+            )),
+            path: None,
+        }
+    }
+
+    fn from_lint(filename: &str, item: mrml::lint::Diagnostic) -> Self {
+        Self {
+            level: match item.severity {
+                Severity::Error => Level::Error,
+                _ => Level::Warning,
+            },
+            code: item.rule,
+            message: if item.score > 0 {
+                format!("{} ({}, score {})", item.message, item.tag, item.score)
+            } else {
+                format!("{} ({})", item.message, item.tag)
+            },
+            origin: filename.to_string(),
+            location: None,
+            path: item.path.map(|path| path.to_string()),
+        }
+    }
+
+    fn from_error(input: &str, filename: &str, error: ParserError) -> Self {
+        let origin = error.origin().cloned().unwrap_or(Origin::Root);
+        Self {
+            level: Level::Error,
+            code: "parser-error",
+            location: error
+                .position()
+                .map(|span| location(input, &origin, span.start, span.end)),
+            origin: origin_name(&origin, filename),
+            message: format_parser_error(error),
+            path: None,
+        }
+    }
+}
+
+fn build_linter(args: &Lint) -> Option<Linter> {
+    if !args.a11y && !args.spam && !args.compliance {
+        return None;
+    }
+    let mut linter = Linter::default();
+    if args.a11y {
+        linter = linter.with_a11y_rules();
+    }
+    if args.spam {
+        linter = linter.with_spam_rules();
+    }
+    if args.compliance {
+        linter = linter.with_compliance_rules();
+    }
+    for (name, severity) in args.rules.iter() {
+        linter.set_severity(name.as_str(), *severity);
+    }
+    Some(linter)
+}
+
+fn collect(
+    options: &Options,
+    filename: &str,
+    linter: Option<&Linter>,
+) -> Result<Vec<Diagnostic>, String> {
+    let input = options.read_input()?;
+    if filename.ends_with(".json") {
+        let diagnostics = match options.parse_json(&input) {
+            Ok(_) => Vec::new(),
+            Err(message) => vec![Diagnostic {
+                level: Level::Error,
+                code: "json-error",
+                message,
+                origin: filename.to_string(),
+                location: None,
+                path: None,
+            }],
+        };
+        return Ok(diagnostics);
+    }
+    let parser_options = options.parser_options()?;
+    let diagnostics = match Mjml::parse_with_options(&input, &parser_options) {
+        Ok(output) => {
+            let mut diagnostics = output
+                .warnings
+                .iter()
+                .map(|warning| Diagnostic::from_warning(&input, filename, warning))
+                .collect::<Vec<_>>();
+            if let Some(linter) = linter {
+                diagnostics.extend(
+                    linter
+                        .check(&output.element)
+                        .into_iter()
+                        .map(|item| Diagnostic::from_lint(filename, item)),
+                );
+            }
+            diagnostics
+        }
+        Err(error) => vec![Diagnostic::from_error(&input, filename, error)],
+    };
+    Ok(diagnostics)
+}
+
+fn format_text(diagnostics: &[Diagnostic]) -> String {
+    diagnostics
+        .iter()
+        .map(|item| {
+            let level = match item.level {
+                Level::Error => "error",
+                Level::Warning => "warning",
+            };
+            match item.location {
+                Some(Location {
+                    line: Some(line),
+                    column: Some(column),
+                    ..
+                }) => format!(
+                    "{}:{line}:{column}: {level}[{}]: {}",
+                    item.origin, item.code, item.message
+                ),
+                Some(Location { start, end, .. }) => format!(
+                    "{}@{start}:{end}: {level}[{}]: {}",
+                    item.origin, item.code, item.message
+                ),
+                None => format!("{}: {level}[{}]: {}", item.origin, item.code, item.message),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_sarif(diagnostics: &[Diagnostic]) -> serde_json::Value {
+    let mut codes = diagnostics.iter().map(|item| item.code).collect::<Vec<_>>();
+    codes.sort_unstable();
+    codes.dedup();
+    let rules = codes
+        .into_iter()
+        .map(|code| serde_json::json!({ "id": code }))
+        .collect::<Vec<_>>();
+    let results = diagnostics
+        .iter()
+        .map(|item| {
+            let mut region = serde_json::Map::new();
+            if let Some(ref location) = item.location {
+                region.insert("charOffset".into(), location.start.into());
+                region.insert(
+                    "charLength".into(),
+                    location.end.saturating_sub(location.start).into(),
+                );
+                if let (Some(line), Some(column)) = (location.line, location.column) {
+                    region.insert("startLine".into(), line.into());
+                    region.insert("startColumn".into(), column.into());
+                }
+            }
+            let mut location = serde_json::json!({
+                "physicalLocation": {
+                    "artifactLocation": { "uri": item.origin },
+                    "region": region,
+                }
+            });
+            if let Some(ref path) = item.path {
+                location["logicalLocations"] = serde_json::json!([{ "fullyQualifiedName": path }]);
+            }
+            serde_json::json!({
+                "ruleId": item.code,
+                "level": item.level,
+                "message": { "text": item.message },
+                "locations": [location],
+            })
+        })
+        .collect::<Vec<_>>();
+    serde_json::json!({
+        "version": "2.1.0",
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "mrml",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/jdrouet/mrml",
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }],
+    })
+}
+
+fn format(format: LintFormat, diagnostics: &[Diagnostic]) -> String {
+    match format {
+        LintFormat::Text => format_text(diagnostics),
+        LintFormat::Json => {
+            serde_json::to_string_pretty(diagnostics).expect("couldn't format to JSON")
+        }
+        LintFormat::Sarif => serde_json::to_string_pretty(&format_sarif(diagnostics))
+            .expect("couldn't format to SARIF"),
+    }
+}
+
+pub(crate) fn execute(options: &Options, args: &Lint) -> Result<(), String> {
+    let filename = options.input.as_deref().unwrap_or("stdin");
+    let linter = build_linter(args);
+    let diagnostics = collect(options, filename, linter.as_ref())?;
+    let output = format(args.format, &diagnostics);
+    if !output.is_empty() {
+        println!("{output}");
+    }
+    let errors = diagnostics
+        .iter()
+        .filter(|item| item.level == Level::Error)
+        .count();
+    let warnings = diagnostics.len() - errors;
+    if errors > 0 || (args.deny_warnings && warnings > 0) {
+        Err(format!("found {errors} error(s) and {warnings} warning(s)"))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::{build_linter, collect, format, line_column, Level, LintFormat};
+    use crate::{Options, SubCommand};
+
+    fn lint_with(path: &str, args: &[&str]) -> Vec<super::Diagnostic> {
+        let options = Options::parse_from(
+            ["mrml-cli", path, "lint"]
+                .iter()
+                .chain(args.iter())
+                .copied(),
+        );
+        let linter = match options.subcmd {
+            SubCommand::Lint(ref args) => build_linter(args),
+            _ => unreachable!(),
+        };
+        collect(&options, path, linter.as_ref()).unwrap()
+    }
+
+    fn lint(path: &str) -> Vec<super::Diagnostic> {
+        lint_with(path, &[])
+    }
+
+    #[test]
+    fn should_compute_line_column() {
+        assert_eq!(line_column("<mjml>", 0), Some((1, 1)));
+        assert_eq!(line_column("<mjml>\n  <mj-body>", 9), Some((2, 3)));
+        assert_eq!(line_column("<mjml>", 42), None);
+    }
+
+    #[test]
+    fn should_report_nothing() {
+        assert!(lint("./resources/amario.mjml").is_empty());
+    }
+
+    #[test]
+    fn should_report_warnings() {
+        let diagnostics = lint("./resources/invalid.mjml");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].level, Level::Warning);
+        assert_eq!(diagnostics[0].code, "unexpected-attribute");
+        let location = diagnostics[0].location.as_ref().unwrap();
+        assert_eq!(location.line, Some(1));
+        assert_eq!(location.column, Some(7));
+    }
+
+    #[test]
+    fn should_format_warnings() {
+        let diagnostics = lint("./resources/invalid.mjml");
+        assert_eq!(
+            format(LintFormat::Text, &diagnostics),
+            "./resources/invalid.mjml:1:7: warning[unexpected-attribute]: unexpected attribute"
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&format(LintFormat::Json, &diagnostics)).unwrap();
+        assert_eq!(json[0]["level"], "warning");
+        let sarif: serde_json::Value =
+            serde_json::from_str(&format(LintFormat::Sarif, &diagnostics)).unwrap();
+        assert_eq!(sarif["version"], "2.1.0");
+        assert_eq!(
+            sarif["runs"][0]["results"][0]["ruleId"],
+            "unexpected-attribute"
+        );
+        assert_eq!(
+            sarif["runs"][0]["results"][0]["locations"][0]["physicalLocation"]["region"]
+                ["startLine"],
+            1
+        );
+    }
+
+    #[test]
+    fn should_fail_with_warnings_when_denied() {
+        let options = Options::parse_from([
+            "mrml-cli",
+            "./resources/invalid.mjml",
+            "lint",
+            "--deny-warnings",
+        ]);
+        assert!(options.execute().is_err());
+        let options = Options::parse_from(["mrml-cli", "./resources/invalid.mjml", "lint"]);
+        assert!(options.execute().is_ok());
+    }
+
+    #[test]
+    fn should_report_accessibility_issues() {
+        let diagnostics = lint_with("./resources/inaccessible.mjml", &["--a11y"]);
+        let codes = diagnostics.iter().map(|item| item.code).collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            vec!["a11y/image-alt", "a11y/html-lang", "a11y/link-text"]
+        );
+        assert_eq!(diagnostics[0].level, Level::Error);
+        assert!(diagnostics[0]
+            .path
+            .as_deref()
+            .unwrap()
+            .starts_with("/mjml/mj-body/"));
+        assert!(lint("./resources/inaccessible.mjml").is_empty());
+    }
+
+    #[test]
+    fn should_override_rule_severity() {
+        let diagnostics = lint_with(
+            "./resources/inaccessible.mjml",
+            &[
+                "--a11y",
+                "--rule",
+                "a11y/html-lang=off",
+                "--rule",
+                "a11y/link-text=error",
+            ],
+        );
+        let codes = diagnostics
+            .iter()
+            .map(|item| (item.code, item.level))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            vec![
+                ("a11y/image-alt", Level::Error),
+                ("a11y/link-text", Level::Error)
+            ]
+        );
+    }
+
+    #[test]
+    fn should_report_spam_issues() {
+        let diagnostics = lint_with("./resources/inaccessible.mjml", &["--spam"]);
+        let codes = diagnostics.iter().map(|item| item.code).collect::<Vec<_>>();
+        assert_eq!(codes, vec!["spam/image-ratio"]);
+        assert!(diagnostics[0].message.contains("score 2"));
+    }
+
+    #[test]
+    fn should_report_compliance_issues() {
+        let diagnostics = lint_with("./resources/inaccessible.mjml", &["--compliance"]);
+        let codes = diagnostics.iter().map(|item| item.code).collect::<Vec<_>>();
+        assert_eq!(codes, vec!["compliance/unsubscribe", "compliance/address"]);
+    }
+}
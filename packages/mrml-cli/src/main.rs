@@ -5,8 +5,10 @@ use std::fs::File;
 use std::io::prelude::*;
 use std::iter::FromIterator;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use clap::{Parser, Subcommand, ValueEnum};
+use mrml::mj_trans::{Catalog, MemoryCatalog};
 use mrml::mjml::Mjml;
 use mrml::prelude::parser::http_loader::{HttpIncludeLoader, UreqFetcher};
 use mrml::prelude::parser::loader::IncludeLoader;
@@ -15,7 +17,11 @@ use mrml::prelude::parser::multi_loader::MultiIncludeLoader;
 use mrml::prelude::parser::noop_loader::NoopIncludeLoader;
 use mrml::prelude::parser::{Error as ParserError, ParseOutput, ParserOptions};
 use mrml::prelude::print::Printable;
-use mrml::prelude::render::RenderOptions;
+use mrml::prelude::render::{default_fonts, AriaOptions, EntityStrategy, RenderOptions, WebUrl};
+
+mod build;
+mod lint;
+mod watch;
 
 fn format_parser_error(error: ParserError) -> String {
     if let Some(src) = error.source() {
@@ -31,6 +37,23 @@ pub enum HttpLoaderMode {
     Deny,
 }
 
+#[derive(ValueEnum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EntityMode {
+    Raw,
+    Numeric,
+    Named,
+}
+
+impl From<EntityMode> for EntityStrategy {
+    fn from(value: EntityMode) -> Self {
+        match value {
+            EntityMode::Raw => Self::Raw,
+            EntityMode::Numeric => Self::Numeric,
+            EntityMode::Named => Self::Named,
+        }
+    }
+}
+
 #[derive(Debug, Parser)]
 #[clap(author, version, about, long_about = None)]
 struct Options {
@@ -114,15 +137,32 @@ impl Options {
         })
     }
 
-    fn parse_mjml(&self, input: &str) -> Result<ParseOutput<Mjml>, String> {
-        log::debug!("parsing mjml input");
-        let options = ParserOptions {
+    fn parser_options(&self) -> Result<ParserOptions, String> {
+        Ok(ParserOptions {
             include_loader: self.include_loader()?,
-        };
-        Mjml::parse_with_options(input, &options).map_err(format_parser_error)
+            ..Default::default()
+        })
+    }
+
+    fn parse_mjml(
+        &self,
+        input: &str,
+        options: &ParserOptions,
+    ) -> Result<ParseOutput<Mjml>, String> {
+        log::debug!("parsing mjml input");
+        Mjml::parse_with_options(input, options).map_err(format_parser_error)
     }
 
     fn parse_input(&self, input: String) -> Result<ParseOutput<Mjml>, String> {
+        let options = self.parser_options()?;
+        self.parse_input_with_options(input, &options)
+    }
+
+    fn parse_input_with_options(
+        &self,
+        input: String,
+        options: &ParserOptions,
+    ) -> Result<ParseOutput<Mjml>, String> {
         if let Some(ref filename) = self.input {
             if filename.ends_with(".json") {
                 self.parse_json(&input).map(|element| ParseOutput {
@@ -130,12 +170,12 @@ impl Options {
                     warnings: Vec::new(),
                 })
             } else if filename.ends_with(".mjml") {
-                self.parse_mjml(&input)
+                self.parse_mjml(&input, options)
             } else {
                 Err(format!("unable to detect file type for {filename:?}"))
             }
         } else {
-            self.parse_mjml(&input).or_else(|_| {
+            self.parse_mjml(&input, options).or_else(|_| {
                 self.parse_json(&input).map(|element| ParseOutput {
                     element,
                     warnings: Vec::new(),
@@ -153,6 +193,17 @@ impl Options {
     }
 
     pub fn execute(self) -> Result<(), String> {
+        if let SubCommand::Watch(ref args) = self.subcmd {
+            let args = args.clone();
+            return watch::serve(self, args);
+        }
+        if let SubCommand::Lint(ref args) = self.subcmd {
+            return lint::execute(&self, args);
+        }
+        if let SubCommand::Build(ref args) = self.subcmd {
+            return build::execute(&self, args);
+        }
+
         let root = self.read_input()?;
         let root = self.parse_input(root)?;
 
@@ -162,14 +213,20 @@ impl Options {
 
 #[derive(Debug, Subcommand)]
 enum SubCommand {
+    /// Render all the templates of a directory
+    Build(build::Build),
     /// Format template to JSON
     FormatJSON(Format),
     /// Format template to Mjml
     FormatMjml(Format),
     /// Render template to HTML
     Render(Render),
+    /// Read input file and report the diagnostics
+    Lint(lint::Lint),
     /// Read input file and validate its structure
     Validate,
+    /// Render the input file on every change and serve it with live reload
+    Watch(watch::Watch),
 }
 
 impl SubCommand {
@@ -209,6 +266,9 @@ impl SubCommand {
                     log::warn!("{warning}");
                 }
             }
+            Self::Build(_) | Self::Lint(_) | Self::Watch(_) => {
+                return Err(String::from("this subcommand reads the input by itself"));
+            }
         };
         Ok(())
     }
@@ -221,7 +281,7 @@ struct Format {
     pub pretty: bool,
 }
 
-#[derive(Debug, Parser)]
+#[derive(Clone, Debug, Parser)]
 struct Render {
     /// Remove comments from html output
     #[clap(short, long)]
@@ -229,13 +289,99 @@ struct Render {
     /// Base url for social icons
     #[clap(short, long)]
     pub social_icon_origin: Option<String>,
+    /// Font that can be imported when used in the template, with the format
+    /// `name=url`
+    #[clap(long, action = clap::ArgAction::Append, value_parser = parse_font)]
+    pub fonts: Vec<(String, String)>,
+    /// Remove the whitespaces between the html tags
+    #[clap(short, long)]
+    pub minify: bool,
+    /// Break the lines longer than this value, 998 keeps the output under the
+    /// SMTP limit
+    #[clap(long)]
+    pub max_line_length: Option<usize>,
+    /// Use CRLF line endings in the html output
+    #[clap(long)]
+    pub crlf: bool,
+    /// Use html entities for the non ascii characters when it reduces the
+    /// size of the output encoded in quoted-printable
+    #[clap(long)]
+    pub quoted_printable: bool,
+    /// Encoding of the non ascii characters of the html output
+    #[clap(long, value_enum, default_value = "raw")]
+    pub entities: EntityMode,
+    /// Add `role="presentation"` on all the layout tables
+    #[clap(long)]
+    pub presentation_tables: bool,
+    /// Url of an image added at the end of the body to track the opening of
+    /// the email
+    #[clap(long)]
+    pub tracking_pixel: Option<String>,
+    /// Url of the online version of the email, replacing the
+    /// `{{__weburl__}}` placeholder
+    #[clap(long, conflicts_with = "strip_web_url")]
+    pub web_url: Option<String>,
+    /// Remove the links to the `{{__weburl__}}` placeholder
+    #[clap(long)]
+    pub strip_web_url: bool,
+    /// Locale used to translate the `mj-trans` elements
+    #[clap(long, requires = "catalog")]
+    pub locale: Option<String>,
+    /// Json file with the translations of the `mj-trans` elements, by locale
+    /// and key, like `{"fr": {"welcome.title": "Bienvenue"}}`
+    #[clap(long, value_parser = parse_catalog)]
+    pub catalog: Option<MemoryCatalog>,
+}
+
+fn parse_font(value: &str) -> Result<(String, String), String> {
+    match value.split_once('=') {
+        Some((name, url)) if !name.trim().is_empty() && !url.trim().is_empty() => {
+            Ok((name.trim().to_string(), url.trim().to_string()))
+        }
+        _ => Err(format!(
+            "invalid font {value:?}, expected format is name=url"
+        )),
+    }
+}
+
+fn parse_catalog(value: &str) -> Result<MemoryCatalog, String> {
+    let content = std::fs::read_to_string(value)
+        .map_err(|err| format!("unable to read catalog {value:?}: {err}"))?;
+    serde_json::from_str(&content).map_err(|err| format!("invalid catalog {value:?}: {err}"))
 }
 
 impl From<Render> for RenderOptions {
     fn from(value: Render) -> Self {
+        let mut fonts = default_fonts();
+        fonts.extend(
+            value
+                .fonts
+                .into_iter()
+                .map(|(name, url)| (name, Cow::Owned(url))),
+        );
         Self {
             disable_comments: value.disable_comments,
             social_icon_origin: value.social_icon_origin.map(Cow::Owned),
+            fonts,
+            max_line_length: value.max_line_length,
+            minify: value.minify,
+            crlf_line_endings: value.crlf,
+            quoted_printable_friendly: value.quoted_printable,
+            entity_strategy: value.entities.into(),
+            aria: AriaOptions {
+                presentation_tables: value.presentation_tables,
+                ..Default::default()
+            },
+            tracking_pixel: value.tracking_pixel,
+            web_url: match value.web_url {
+                Some(url) => WebUrl::Replace(url),
+                None if value.strip_web_url => WebUrl::Strip,
+                None => WebUrl::Keep,
+            },
+            locale: value.locale,
+            catalog: value
+                .catalog
+                .map(|catalog| Arc::new(catalog) as Arc<dyn Catalog>),
             ..Default::default()
         }
     }
@@ -252,11 +398,12 @@ fn main() {
 #[cfg(test)]
 mod tests {
     use clap::Parser;
-
-    use crate::format_parser_error;
+    use mrml::mj_trans::Catalog;
+    use mrml::prelude::parser::loader::IncludeLoaderError;
+    use mrml::prelude::parser::{Error as ParserError, Origin, Span};
 
     use super::Options;
-    use mrml::prelude::parser::{loader::IncludeLoaderError, Error as ParserError, Origin, Span};
+    use crate::{format_parser_error, parse_catalog, parse_font};
 
     fn origin_include() -> Origin {
         Origin::Include {
@@ -501,6 +648,56 @@ mod tests {
         execute(["mrml-cli", "./resources/amario.mjml", "render"]);
     }
 
+    #[test]
+    fn render_amario_minified_with_fonts() {
+        execute([
+            "mrml-cli",
+            "./resources/amario.mjml",
+            "render",
+            "--minify",
+            "--fonts",
+            "Comic Sans=https://example.com/comic-sans.css",
+        ]);
+    }
+
+    #[test]
+    fn render_amario_smtp_safe() {
+        execute([
+            "mrml-cli",
+            "./resources/amario.mjml",
+            "render",
+            "--minify",
+            "--max-line-length",
+            "76",
+            "--crlf",
+            "--quoted-printable",
+        ]);
+    }
+
+    #[test]
+    fn parse_font_argument() {
+        assert_eq!(
+            parse_font("Comic Sans=https://example.com/font.css").unwrap(),
+            (
+                String::from("Comic Sans"),
+                String::from("https://example.com/font.css")
+            )
+        );
+        assert!(parse_font("Comic Sans").is_err());
+        assert!(parse_font("=https://example.com/font.css").is_err());
+    }
+
+    #[test]
+    fn parse_catalog_argument() {
+        let catalog = parse_catalog("./resources/catalog.json").unwrap();
+        assert_eq!(
+            catalog.translate("fr-CA", "welcome.title").unwrap(),
+            "Bienvenue"
+        );
+        assert!(parse_catalog("./resources/missing.json").is_err());
+        assert!(parse_catalog("./resources/amario.mjml").is_err());
+    }
+
     #[test]
     fn validate_amario_json() {
         execute(["mrml-cli", "./resources/amario.json", "validate"]);
@@ -0,0 +1,308 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, SystemTime};
+
+use clap::Parser;
+use mrml::prelude::parser::loader::{IncludeLoader, IncludeLoaderError};
+use mrml::prelude::parser::ParserOptions;
+use mrml::prelude::render::RenderOptions;
+
+use crate::{Options, Render};
+
+const VERSION_PATH: &str = "/__mrml/version";
+/// Time given to a client to send its request or read the response.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(5);
+const RELOAD_SCRIPT: &str = r#"<script>(function(){var current=null;setInterval(function(){fetch("/__mrml/version").then(function(res){return res.text();}).then(function(version){if(current!==null&&current!==version){window.location.reload();}current=version;}).catch(function(){});},1000);})();</script>"#;
+
+#[derive(Clone, Debug, Parser)]
+pub(crate) struct Watch {
+    /// Address the preview server listens on
+    #[clap(long, default_value = "127.0.0.1")]
+    pub host: String,
+    /// Port the preview server listens on
+    #[clap(short, long, default_value_t = 8080)]
+    pub port: u16,
+    /// Interval, in milliseconds, between two checks of the input file and
+    /// of the files it includes
+    #[clap(long, default_value_t = 500)]
+    pub interval: u64,
+    #[clap(flatten)]
+    pub render: Render,
+}
+
+#[derive(Debug, Default)]
+struct Preview {
+    version: u64,
+    content: String,
+}
+
+fn escape_html(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn inject_reload_script(html: &str) -> String {
+    match html.rfind("</body>") {
+        Some(index) => format!("{}{RELOAD_SCRIPT}{}", &html[..index], &html[index..]),
+        None => format!("{html}{RELOAD_SCRIPT}"),
+    }
+}
+
+/// Include loader recording the paths of the templates it loads.
+#[derive(Debug)]
+struct RecordingIncludeLoader {
+    inner: Box<dyn IncludeLoader>,
+    paths: Arc<Mutex<Vec<String>>>,
+}
+
+impl IncludeLoader for RecordingIncludeLoader {
+    fn resolve(&self, path: &str) -> Result<String, IncludeLoaderError> {
+        self.paths.lock().unwrap().push(path.to_string());
+        self.inner.resolve(path)
+    }
+}
+
+/// Local file loaded for an `mj-include` path, when it's resolved by the
+/// local loader.
+fn include_file(options: &Options, path: &str) -> Option<PathBuf> {
+    let root = options.local_loader.as_ref()?;
+    path.strip_prefix("file:///").map(|path| root.join(path))
+}
+
+/// Renders the preview, and returns it with the files it was built from.
+fn build_preview(options: &Options, render_opts: &RenderOptions) -> (String, Vec<PathBuf>) {
+    let includes = Arc::new(Mutex::new(Vec::new()));
+    let result = options
+        .include_loader()
+        .map(|inner| ParserOptions {
+            include_loader: Box::new(RecordingIncludeLoader {
+                inner,
+                paths: includes.clone(),
+            }),
+            ..Default::default()
+        })
+        .and_then(|parser_opts| {
+            options
+                .read_input()
+                .and_then(|input| options.parse_input_with_options(input, &parser_opts))
+        })
+        .and_then(|root| {
+            for warning in root.warnings.iter() {
+                log::warn!("{warning}");
+            }
+            root.element
+                .render(render_opts)
+                .map_err(|err| format!("couldn't render template: {err}"))
+        });
+    let files = options
+        .input
+        .iter()
+        .map(PathBuf::from)
+        .chain(
+            includes
+                .lock()
+                .unwrap()
+                .iter()
+                .filter_map(|path| include_file(options, path)),
+        )
+        .collect();
+    let content = match result {
+        Ok(html) => inject_reload_script(&html),
+        Err(error) => {
+            log::error!("{error}");
+            inject_reload_script(&format!(
+                "<!doctype html><html><body><pre>{}</pre></body></html>",
+                escape_html(&error)
+            ))
+        }
+    };
+    (content, files)
+}
+
+fn modified_at(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|path| {
+            std::fs::metadata(path)
+                .and_then(|meta| meta.modified())
+                .ok()
+        })
+        .collect()
+}
+
+fn request_path(request_line: &str) -> Option<&str> {
+    let mut parts = request_line.split_whitespace();
+    let _method = parts.next()?;
+    parts.next()
+}
+
+fn respond(mut stream: TcpStream, status: &str, content_type: &str, body: &str) {
+    let result = write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    if let Err(err) = result {
+        log::debug!("couldn't write response: {err}");
+    }
+}
+
+fn handle(stream: TcpStream, preview: &RwLock<Preview>) {
+    let timeouts = stream
+        .set_read_timeout(Some(CONNECTION_TIMEOUT))
+        .and_then(|_| stream.set_write_timeout(Some(CONNECTION_TIMEOUT)));
+    if let Err(err) = timeouts {
+        log::debug!("couldn't configure connection: {err}");
+        return;
+    }
+    let mut request_line = String::new();
+    if let Err(err) = BufReader::new(&stream).read_line(&mut request_line) {
+        log::debug!("couldn't read request: {err}");
+        return;
+    }
+    match request_path(&request_line) {
+        Some(VERSION_PATH) => {
+            let version = preview.read().unwrap().version.to_string();
+            respond(stream, "200 OK", "text/plain", &version)
+        }
+        Some(_) => {
+            let content = preview.read().unwrap().content.clone();
+            respond(stream, "200 OK", "text/html; charset=utf-8", &content)
+        }
+        None => respond(stream, "400 Bad Request", "text/plain", "bad request"),
+    }
+}
+
+pub(crate) fn serve(options: Options, args: Watch) -> Result<(), String> {
+    if options.input.is_none() {
+        return Err(String::from("the watch mode requires an input file"));
+    }
+    let Watch {
+        host,
+        port,
+        interval,
+        render,
+    } = args;
+    let render_opts = RenderOptions::from(render);
+    let (content, mut files) = build_preview(&options, &render_opts);
+    let preview = Arc::new(RwLock::new(Preview {
+        version: 0,
+        content,
+    }));
+
+    let listener = TcpListener::bind((host.as_str(), port))
+        .map_err(|err| format!("couldn't listen on {host}:{port}: {err}"))?;
+    println!("serving preview on http://{host}:{port}/");
+
+    let watched = preview.clone();
+    let interval = Duration::from_millis(interval);
+    std::thread::spawn(move || {
+        let mut last = modified_at(&files);
+        loop {
+            std::thread::sleep(interval);
+            if modified_at(&files) != last {
+                log::info!("input changed, rendering again");
+                let (content, current) = build_preview(&options, &render_opts);
+                files = current;
+                last = modified_at(&files);
+                let mut preview = watched.write().unwrap();
+                preview.version += 1;
+                preview.content = content;
+            }
+        }
+    });
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                let preview = preview.clone();
+                std::thread::spawn(move || handle(stream, &preview));
+            }
+            Err(err) => log::debug!("couldn't accept connection: {err}"),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use clap::Parser;
+
+    use super::{build_preview, escape_html, inject_reload_script, request_path, RELOAD_SCRIPT};
+    use crate::{Options, SubCommand};
+
+    #[test]
+    fn should_inject_script_before_body_end() {
+        assert_eq!(
+            inject_reload_script("<html><body>Hello</body></html>"),
+            format!("<html><body>Hello{RELOAD_SCRIPT}</body></html>")
+        );
+        assert_eq!(
+            inject_reload_script("Hello"),
+            format!("Hello{RELOAD_SCRIPT}")
+        );
+    }
+
+    #[test]
+    fn should_extract_request_path() {
+        assert_eq!(request_path("GET / HTTP/1.1\r\n"), Some("/"));
+        assert_eq!(
+            request_path("GET /__mrml/version HTTP/1.1\r\n"),
+            Some("/__mrml/version")
+        );
+        assert_eq!(request_path(""), None);
+    }
+
+    #[test]
+    fn should_escape_errors() {
+        assert_eq!(escape_html("<mjml> & co"), "&lt;mjml&gt; &amp; co");
+    }
+
+    #[test]
+    fn should_build_preview() {
+        let options = Options::parse_from(["mrml-cli", "./resources/amario.mjml", "watch"]);
+        let SubCommand::Watch(ref args) = options.subcmd else {
+            panic!("expected watch subcommand");
+        };
+        let render_opts = args.render.clone().into();
+        let (html, files) = build_preview(&options, &render_opts);
+        assert!(html.starts_with("<!doctype html>"));
+        assert!(html.contains(RELOAD_SCRIPT));
+        assert_eq!(files, vec![PathBuf::from("./resources/amario.mjml")]);
+    }
+
+    #[test]
+    fn should_build_error_preview() {
+        let options = Options::parse_from(["mrml-cli", "./cant/be/found.mjml", "watch"]);
+        let render_opts = Default::default();
+        let (html, _) = build_preview(&options, &render_opts);
+        assert!(html.contains("<pre>"));
+    }
+
+    #[test]
+    fn should_list_included_files() {
+        let options = Options::parse_from([
+            "mrml-cli",
+            "--local-loader",
+            "./resources/partials",
+            "./resources/with-local-include.mjml",
+            "watch",
+        ]);
+        let render_opts = Default::default();
+        let (html, files) = build_preview(&options, &render_opts);
+        assert!(!html.contains("<pre>"));
+        assert_eq!(
+            files,
+            vec![
+                PathBuf::from("./resources/with-local-include.mjml"),
+                PathBuf::from("./resources/partials/hello-world.mjml"),
+            ]
+        );
+    }
+}
@@ -10,6 +10,11 @@ fn render(input: &str) {
 fn criterion_benchmark(c: &mut Criterion) {
     let data = include_str!("../resources/template/amario.mjml");
     c.bench_function("amario", |b| b.iter(|| render(black_box(data))));
+
+    let opts = RenderOptions::default();
+    let root = mrml::mjml::Mjml::parse(data).unwrap();
+    let prepared = root.element.prepare(&opts);
+    c.bench_function("amario prepared", |b| b.iter(|| prepared.render().unwrap()));
 }
 
 criterion_group!(benches, criterion_benchmark);
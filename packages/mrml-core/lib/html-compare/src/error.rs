@@ -85,13 +85,138 @@ pub enum ErrorKind<'a> {
     },
 }
 
+type Range = (usize, usize);
+
+fn str_span_range(value: &StrSpan<'_>) -> Range {
+    (value.start(), value.end())
+}
+
+fn token_range(value: &Token<'_>) -> Range {
+    str_span_range(&value.span())
+}
+
 impl<'a> ErrorKind<'a> {
     pub fn display(&self) -> String {
-        // TODO improve error display
         format!("{self:?}")
     }
+
+    /// Short description of the difference.
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::ExpectedElementNotFound { .. } => "Expected element not found",
+            Self::UnexpectedElementFound { .. } => "Unexpected element found",
+            Self::ElementMismatch { .. } => "Element mismatch",
+            Self::EndOfElementMismatch { .. } => "End of element mismatch",
+            Self::InvalidElementTag { .. } => "Invalid element tag",
+            Self::ExpectedAttributesNotFound { .. } => "Expected attributes not found",
+            Self::UnexpectedAttributesFound(_) => "Unexpected attributes found",
+            Self::ExpectedAttributeNotFound { .. } => "Expected attribute not found",
+            Self::InvalidAttributeValue { .. } => "Invalid attribute value",
+            Self::ExpectedClassesNotFound { .. } => "Expected classes not found",
+            Self::UnexpectedClassesFound { .. } => "Unexpected classes found",
+            Self::ExpectedStylesNotFound { .. } => "Expected styles not found",
+            Self::UnexpectedStylesFound { .. } => "Unexpected styles found",
+            Self::ExpectedStyleNotFound { .. } => "Expected style not found",
+            Self::InvalidStyleValue { .. } => "Invalid style value",
+            Self::TextMismatch { .. } => "Text mismatch",
+            Self::CssMismatch { .. } => "CSS mismatch",
+        }
+    }
+
+    /// Ranges, in bytes, of the first divergence in the expected and in the
+    /// generated html, when known.
+    pub fn ranges(&self) -> (Option<Range>, Option<Range>) {
+        match self {
+            Self::ExpectedElementNotFound {
+                expected_element,
+                generated_parent,
+                ..
+            } => (
+                Some(token_range(expected_element)),
+                Some(str_span_range(generated_parent)),
+            ),
+            Self::UnexpectedElementFound { generated } => (None, Some(token_range(generated))),
+            Self::ElementMismatch {
+                expected,
+                generated,
+            } => (Some(token_range(expected)), Some(token_range(generated))),
+            Self::EndOfElementMismatch {
+                expected,
+                generated,
+            } => (
+                Some(str_span_range(&expected.span)),
+                Some(str_span_range(&generated.span)),
+            ),
+            Self::InvalidElementTag {
+                expected,
+                generated,
+            }
+            | Self::ExpectedAttributesNotFound {
+                expected,
+                generated,
+                ..
+            } => (
+                Some(str_span_range(&expected.span)),
+                Some(str_span_range(&generated.span)),
+            ),
+            Self::UnexpectedAttributesFound(items) => (None, items.first().map(str_span_range)),
+            Self::ExpectedAttributeNotFound { expected } => {
+                (Some(str_span_range(&expected.span)), None)
+            }
+            Self::InvalidAttributeValue {
+                expected,
+                generated,
+            } => (
+                Some(str_span_range(&expected.value)),
+                Some(str_span_range(&generated.value)),
+            ),
+            Self::ExpectedClassesNotFound {
+                expected,
+                generated,
+                ..
+            }
+            | Self::UnexpectedClassesFound {
+                expected,
+                generated,
+                ..
+            }
+            | Self::ExpectedStylesNotFound {
+                expected,
+                generated,
+                ..
+            }
+            | Self::UnexpectedStylesFound {
+                expected,
+                generated,
+                ..
+            }
+            | Self::ExpectedStyleNotFound {
+                expected,
+                generated,
+                ..
+            }
+            | Self::InvalidStyleValue {
+                expected,
+                generated,
+                ..
+            }
+            | Self::TextMismatch {
+                expected,
+                generated,
+            }
+            | Self::CssMismatch {
+                expected,
+                generated,
+                ..
+            } => (
+                Some(str_span_range(expected)),
+                Some(str_span_range(generated)),
+            ),
+        }
+    }
 }
 
+/// Difference found between the expected and the generated html.
 #[derive(Debug)]
 pub struct Error<'a> {
     pub expected: &'a str,
@@ -99,6 +224,46 @@ pub struct Error<'a> {
     pub kind: ErrorKind<'a>,
 }
 
+/// Converts an offset in bytes into a line and a column, both starting at 1.
+fn line_column(data: &str, offset: usize) -> (usize, usize) {
+    let before = &data[..offset.min(data.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rfind('\n')
+        .map_or(before.len(), |index| before.len() - index - 1)
+        + 1;
+    (line, column)
+}
+
+impl<'a> Error<'a> {
+    /// Line and column of the first divergence in the expected html.
+    pub fn expected_position(&self) -> Option<(usize, usize)> {
+        self.kind
+            .ranges()
+            .0
+            .map(|(start, _)| line_column(self.expected, start))
+    }
+
+    /// Line and column of the first divergence in the generated html.
+    pub fn generated_position(&self) -> Option<(usize, usize)> {
+        self.kind
+            .ranges()
+            .1
+            .map(|(start, _)| line_column(self.generated, start))
+    }
+
+    fn write_position(
+        f: &mut std::fmt::Formatter<'_>,
+        name: &str,
+        position: Option<(usize, usize)>,
+    ) -> std::fmt::Result {
+        match position {
+            Some((line, column)) => writeln!(f, "{name} at line {line}, column {column}"),
+            None => writeln!(f, "{name} at unknown position"),
+        }
+    }
+}
+
 fn display_subset(data: &str, span_start: usize, span_end: usize, gap: usize) -> String {
     let start = span_start.saturating_sub(gap);
     let end = usize::min(span_end + gap, data.len());
@@ -114,6 +279,8 @@ const SUBSET_GAP: usize = 150;
 
 impl<'a> std::fmt::Display for Error<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Self::write_position(f, "Expected html diverges", self.expected_position())?;
+        Self::write_position(f, "Generated html diverges", self.generated_position())?;
         match &self.kind {
             ErrorKind::ElementMismatch {
                 expected,
@@ -263,10 +430,44 @@ impl<'a> std::fmt::Display for Error<'a> {
                 writeln!(f, "== Problem")?;
                 writeln!(f, "Missing {error:?}")?;
             }
-            _ => {
-                writeln!(f, "{:?}", self.kind)?;
+            other => {
+                writeln!(f, "= {}", other.title())?;
+                let (expected, generated) = other.ranges();
+                if let Some((start, end)) = expected {
+                    writeln!(f, "== Expected result")?;
+                    writeln!(
+                        f,
+                        "{}",
+                        display_subset(self.expected, start, end, SUBSET_GAP)
+                    )?;
+                    writeln!(f)?;
+                }
+                if let Some((start, end)) = generated {
+                    writeln!(f, "== Generated result")?;
+                    writeln!(
+                        f,
+                        "{}",
+                        display_subset(self.generated, start, end, SUBSET_GAP)
+                    )?;
+                    writeln!(f)?;
+                }
+                writeln!(f, "== Problem")?;
+                writeln!(f, "{other:?}")?;
             }
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::line_column;
+
+    #[test]
+    fn should_compute_line_column() {
+        let data = "<html>\n  <body>\n</html>";
+        assert_eq!(line_column(data, 0), (1, 1));
+        assert_eq!(line_column(data, 9), (2, 3));
+        assert_eq!(line_column(data, data.len()), (3, 8));
+    }
+}
@@ -1,3 +1,24 @@
+//! Compares two html documents, ignoring the differences that don't change
+//! the rendering: the order of the attributes, classes and styles, the
+//! formatting of the css and the whitespaces in the texts.
+//!
+//! It's used to compare the output of MRML with the one of the mjml
+//! javascript implementation, and can be used the same way to write golden
+//! tests.
+//!
+//! ```rust
+//! let expected = r#"<div class="a b" style="color:red;width:100%">Hello</div>"#;
+//! let generated = r#"<div style="width: 100%; color: red;" class="b a">Hello</div>"#;
+//! html_compare::assert_similar(expected, generated);
+//!
+//! let error = html_compare::compare("<p>Hello</p>", "<p>World</p>").unwrap_err();
+//! assert_eq!(error.kind.title(), "Text mismatch");
+//! assert_eq!(error.generated_position(), Some((1, 4)));
+//! ```
+//!
+//! When the documents differ, the error displays the first divergence with
+//! the surrounding html of both documents.
+
 #![allow(clippy::result_large_err)]
 
 mod error;
@@ -7,11 +28,11 @@ mod token;
 
 use std::collections::{BTreeMap, BTreeSet};
 
+pub use error::{Error, ErrorKind};
 use htmlparser::{ElementEnd as HtmlElementEnd, StrSpan, Token};
+pub use token::{Attribute, ElementEnd, ElementStart};
 
-use crate::error::*;
 use crate::helper::cleanup_text;
-use crate::token::*;
 
 struct Cursor<'a> {
     expected: crate::stack::TokenStack<'a>,
@@ -225,7 +246,7 @@ fn compare_attributes<'a>(
         } else if exp_attr.value.as_str() != gen_attr.value.as_str() {
             return Err(ErrorKind::InvalidAttributeValue {
                 expected: exp_attr.clone(),
-                generated: exp_attr.clone(),
+                generated: gen_attr.clone(),
             });
         }
     }
@@ -397,7 +418,7 @@ fn compare_all<'a>(
     }
 }
 
-/// Compare html values without being too extreme
+/// Compares the html values, returning the first difference found.
 pub fn compare<'a>(expected: &'a str, generated: &'a str) -> Result<(), Error<'a>> {
     let mut cursor = Cursor::new(expected, generated);
     if let Err(kind) = compare_all(
@@ -416,6 +437,8 @@ pub fn compare<'a>(expected: &'a str, generated: &'a str) -> Result<(), Error<'a
     }
 }
 
+/// Panics, with a readable report of the first difference, when the html
+/// values are not similar.
 pub fn assert_similar(expected: &str, generated: &str) {
     if let Err(error) = compare(expected, generated) {
         panic!("{error}");
@@ -434,6 +457,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_report_first_divergence() {
+        let expected = "<div>\n  <p>Hello</p>\n</div>";
+        let generated = "<div>\n  <p>World</p>\n</div>";
+        let error = compare(expected, generated).unwrap_err();
+        assert_eq!(error.expected_position(), Some((2, 6)));
+        assert_eq!(error.generated_position(), Some((2, 6)));
+        let report = error.to_string();
+        assert!(report.contains("Expected html diverges at line 2, column 6"));
+        assert!(report.contains("= Text mismatch"));
+    }
+
     #[test]
     fn simple_same_classes() {
         compare_attr_classes("foo bar baz".into(), "baz foo bar".into()).unwrap();
@@ -0,0 +1,414 @@
+//! Structural comparison of two templates, to review the changes made to a
+//! template without comparing the raw sources.
+//!
+//! The attributes of the `mjml` element and the body are compared, the
+//! comments and the whitespaces are ignored.
+//!
+//! ```rust
+//! # #[cfg(feature = "parse")]
+//! # {
+//! let before = mrml::parse("<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>").unwrap();
+//! let after = mrml::parse(r#"<mjml><mj-body><mj-text color="red">World</mj-text></mj-body></mjml>"#).unwrap();
+//! for change in mrml::diff(&before.element, &after.element) {
+//!     println!("{change}");
+//! }
+//! # }
+//! ```
+
+use crate::mjml::Mjml;
+use crate::prelude::path::ElementPath;
+use crate::prelude::tree::{TreeElement, TreeNode, BODY_PATH};
+
+const ROOT_PATH: &str = "/mjml";
+/// Name of the text nodes in the paths, like in xpath.
+const TEXT_TAG: &str = "text()";
+
+/// Change between two templates. The path is the one of the element in the
+/// template it belongs to, like `/mjml/mj-body/mj-section[1]/mj-column[2]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Change {
+    ElementAdded {
+        path: String,
+        tag: String,
+    },
+    ElementRemoved {
+        path: String,
+        tag: String,
+    },
+    AttributeAdded {
+        path: String,
+        name: String,
+        value: String,
+    },
+    AttributeRemoved {
+        path: String,
+        name: String,
+        value: String,
+    },
+    AttributeChanged {
+        path: String,
+        name: String,
+        before: String,
+        after: String,
+    },
+    /// A text was changed, added (with an empty `before`) or removed (with
+    /// an empty `after`).
+    TextChanged {
+        path: String,
+        before: String,
+        after: String,
+    },
+}
+
+impl Change {
+    pub fn path(&self) -> &str {
+        match self {
+            Self::ElementAdded { path, .. }
+            | Self::ElementRemoved { path, .. }
+            | Self::AttributeAdded { path, .. }
+            | Self::AttributeRemoved { path, .. }
+            | Self::AttributeChanged { path, .. }
+            | Self::TextChanged { path, .. } => path.as_str(),
+        }
+    }
+
+    /// Path of the changed element, the parent of the text for the changes of
+    /// text, to find it with [`Mjml::element_at`].
+    pub fn element_path(&self) -> Option<ElementPath> {
+        let path = self.path().parse::<ElementPath>().ok()?;
+        match path.tag() {
+            Some(TEXT_TAG) => path.parent(),
+            _ => Some(path),
+        }
+    }
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ElementAdded { path, tag } => write!(f, "{path}: {tag} added"),
+            Self::ElementRemoved { path, tag } => write!(f, "{path}: {tag} removed"),
+            Self::AttributeAdded { path, name, value } => {
+                write!(f, "{path}: attribute {name} added with {value:?}")
+            }
+            Self::AttributeRemoved { path, name, value } => {
+                write!(f, "{path}: attribute {name} removed, was {value:?}")
+            }
+            Self::AttributeChanged {
+                path,
+                name,
+                before,
+                after,
+            } => write!(
+                f,
+                "{path}: attribute {name} changed from {before:?} to {after:?}"
+            ),
+            Self::TextChanged {
+                path,
+                before,
+                after,
+            } => write!(f, "{path}: text changed from {before:?} to {after:?}"),
+        }
+    }
+}
+
+/// Node of the tree, without the comments and the empty texts, with its
+/// path.
+enum Item<'t, 'a> {
+    Element(&'t TreeElement<'a>, String),
+    Text(String, String),
+}
+
+impl<'t, 'a> Item<'t, 'a> {
+    fn key(&self) -> &str {
+        match self {
+            Self::Element(element, _) => element.tag,
+            Self::Text(..) => TEXT_TAG,
+        }
+    }
+}
+
+fn items<'t, 'a>(parent: &str, children: &'t [TreeNode<'a>]) -> Vec<Item<'t, 'a>> {
+    let mut counters: Vec<(&str, usize)> = Vec::new();
+    let mut result = Vec::new();
+    for child in children.iter() {
+        let (key, text) = match child {
+            TreeNode::Element(element) => (element.tag, None),
+            TreeNode::Text(value) => {
+                let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+                if value.is_empty() {
+                    continue;
+                }
+                (TEXT_TAG, Some(value))
+            }
+            TreeNode::Comment(_) => continue,
+        };
+        let index = match counters.iter_mut().find(|(tag, _)| *tag == key) {
+            Some((_, count)) => {
+                *count += 1;
+                *count
+            }
+            None => {
+                counters.push((key, 1));
+                1
+            }
+        };
+        let path = format!("{parent}/{key}[{index}]");
+        result.push(match (child, text) {
+            (TreeNode::Element(element), _) => Item::Element(element, path),
+            (_, text) => Item::Text(text.unwrap_or_default(), path),
+        });
+    }
+    result
+}
+
+fn diff_attributes(path: &str, before: &[(&str, &str)], after: &[(&str, &str)]) -> Vec<Change> {
+    let mut result = Vec::new();
+    for (name, value) in before.iter() {
+        match after.iter().find(|(key, _)| key == name) {
+            None => result.push(Change::AttributeRemoved {
+                path: path.to_string(),
+                name: name.to_string(),
+                value: value.to_string(),
+            }),
+            Some((_, other)) if other != value => result.push(Change::AttributeChanged {
+                path: path.to_string(),
+                name: name.to_string(),
+                before: value.to_string(),
+                after: other.to_string(),
+            }),
+            _ => {}
+        }
+    }
+    for (name, value) in after.iter() {
+        if !before.iter().any(|(key, _)| key == name) {
+            result.push(Change::AttributeAdded {
+                path: path.to_string(),
+                name: name.to_string(),
+                value: value.to_string(),
+            });
+        }
+    }
+    result
+}
+
+/// Pairs the items with the same key, keeping their order, using the
+/// longest common subsequence.
+fn align(before: &[Item], after: &[Item]) -> Vec<(Option<usize>, Option<usize>)> {
+    let mut lengths = vec![vec![0usize; after.len() + 1]; before.len() + 1];
+    for i in (0..before.len()).rev() {
+        for j in (0..after.len()).rev() {
+            lengths[i][j] = if before[i].key() == after[j].key() {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < before.len() || j < after.len() {
+        if i < before.len() && j < after.len() && before[i].key() == after[j].key() {
+            result.push((Some(i), Some(j)));
+            i += 1;
+            j += 1;
+        } else if j == after.len() || (i < before.len() && lengths[i + 1][j] >= lengths[i][j + 1]) {
+            result.push((Some(i), None));
+            i += 1;
+        } else {
+            result.push((None, Some(j)));
+            j += 1;
+        }
+    }
+    result
+}
+
+fn diff_item(before: Option<&Item>, after: Option<&Item>, result: &mut Vec<Change>) {
+    match (before, after) {
+        (Some(Item::Element(before, before_path)), Some(Item::Element(after, after_path))) => {
+            diff_element(before, before_path, after, after_path, result);
+        }
+        (Some(Item::Text(before, _)), Some(Item::Text(after, path))) if before != after => {
+            result.push(Change::TextChanged {
+                path: path.clone(),
+                before: before.clone(),
+                after: after.clone(),
+            });
+        }
+        (Some(Item::Element(element, path)), None) => result.push(Change::ElementRemoved {
+            path: path.clone(),
+            tag: element.tag.to_string(),
+        }),
+        (None, Some(Item::Element(element, path))) => result.push(Change::ElementAdded {
+            path: path.clone(),
+            tag: element.tag.to_string(),
+        }),
+        (Some(Item::Text(before, path)), None) => result.push(Change::TextChanged {
+            path: path.clone(),
+            before: before.clone(),
+            after: String::new(),
+        }),
+        (None, Some(Item::Text(after, path))) => result.push(Change::TextChanged {
+            path: path.clone(),
+            before: String::new(),
+            after: after.clone(),
+        }),
+        _ => {}
+    }
+}
+
+fn diff_element(
+    before: &TreeElement,
+    before_path: &str,
+    after: &TreeElement,
+    after_path: &str,
+    result: &mut Vec<Change>,
+) {
+    result.extend(diff_attributes(
+        after_path,
+        &before.attributes,
+        &after.attributes,
+    ));
+    let before_items = items(before_path, &before.children);
+    let after_items = items(after_path, &after.children);
+    for (i, j) in align(&before_items, &after_items) {
+        diff_item(
+            i.map(|i| &before_items[i]),
+            j.map(|j| &after_items[j]),
+            result,
+        );
+    }
+}
+
+fn root_attributes(root: &Mjml) -> Vec<(&str, &str)> {
+    [
+        ("owa", root.attributes.owa.as_deref()),
+        ("lang", root.attributes.lang.as_deref()),
+        ("dir", root.attributes.dir.as_deref()),
+    ]
+    .iter()
+    .filter_map(|(name, value)| value.map(|value| (*name, value)))
+    .collect()
+}
+
+/// Lists the changes needed to go from the `before` template to the `after`
+/// one.
+pub fn diff(before: &Mjml, after: &Mjml) -> Vec<Change> {
+    let mut result = diff_attributes(ROOT_PATH, &root_attributes(before), &root_attributes(after));
+    match (before.body_tree(), after.body_tree()) {
+        (Some(before), Some(after)) => {
+            diff_element(&before, BODY_PATH, &after, BODY_PATH, &mut result);
+        }
+        (Some(_), None) => result.push(Change::ElementRemoved {
+            path: BODY_PATH.to_string(),
+            tag: crate::mj_body::NAME.to_string(),
+        }),
+        (None, Some(_)) => result.push(Change::ElementAdded {
+            path: BODY_PATH.to_string(),
+            tag: crate::mj_body::NAME.to_string(),
+        }),
+        (None, None) => {}
+    }
+    result
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use super::{diff, Change};
+
+    fn parse(template: &str) -> crate::mjml::Mjml {
+        crate::parse(template).unwrap().element
+    }
+
+    #[test]
+    fn should_find_no_change() {
+        let template = r#"<mjml><mj-body>
+  <mj-section><mj-column><mj-text>Hello   World</mj-text></mj-column></mj-section>
+</mj-body></mjml>"#;
+        let other = r#"<mjml><mj-body><!-- comment -->
+  <mj-section><mj-column><mj-text>Hello World</mj-text></mj-column></mj-section>
+</mj-body></mjml>"#;
+        assert!(diff(&parse(template), &parse(other)).is_empty());
+    }
+
+    #[test]
+    fn should_find_attribute_and_text_changes() {
+        let before = parse(
+            r#"<mjml lang="en"><mj-body><mj-section><mj-column><mj-text color="red" align="left">Hello</mj-text></mj-column></mj-section></mj-body></mjml>"#,
+        );
+        let after = parse(
+            r#"<mjml><mj-body><mj-section><mj-column><mj-text color="blue" padding="0">World</mj-text></mj-column></mj-section></mj-body></mjml>"#,
+        );
+        let path = "/mjml/mj-body/mj-section[1]/mj-column[1]/mj-text[1]";
+        assert_eq!(
+            diff(&before, &after),
+            vec![
+                Change::AttributeRemoved {
+                    path: "/mjml".into(),
+                    name: "lang".into(),
+                    value: "en".into(),
+                },
+                Change::AttributeChanged {
+                    path: path.into(),
+                    name: "color".into(),
+                    before: "red".into(),
+                    after: "blue".into(),
+                },
+                Change::AttributeRemoved {
+                    path: path.into(),
+                    name: "align".into(),
+                    value: "left".into(),
+                },
+                Change::AttributeAdded {
+                    path: path.into(),
+                    name: "padding".into(),
+                    value: "0".into(),
+                },
+                Change::TextChanged {
+                    path: format!("{path}/text()[1]"),
+                    before: "Hello".into(),
+                    after: "World".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_find_added_and_removed_elements() {
+        let before = parse(
+            r#"<mjml><mj-body><mj-section><mj-column><mj-image src="a.png" /><mj-text>Hello</mj-text></mj-column></mj-section></mj-body></mjml>"#,
+        );
+        let after = parse(
+            r#"<mjml><mj-body><mj-section><mj-column><mj-text>Hello</mj-text><mj-button>Go</mj-button></mj-column></mj-section><mj-section /></mj-body></mjml>"#,
+        );
+        let changes = diff(&before, &after)
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            vec![
+                "/mjml/mj-body/mj-section[1]/mj-column[1]/mj-image[1]: mj-image removed",
+                "/mjml/mj-body/mj-section[1]/mj-column[1]/mj-button[1]: mj-button added",
+                "/mjml/mj-body/mj-section[2]: mj-section added",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_resolve_the_changed_elements() {
+        let before = parse(
+            r#"<mjml><mj-body><mj-section><mj-column><mj-text>Hello</mj-text><mj-text>World</mj-text></mj-column></mj-section></mj-body></mjml>"#,
+        );
+        let after = parse(
+            r#"<mjml><mj-body><mj-section><mj-column><mj-text>Hello</mj-text><mj-text>Everyone</mj-text></mj-column></mj-section></mj-body></mjml>"#,
+        );
+        let changes = diff(&before, &after);
+        let path = changes[0].element_path().unwrap();
+        assert_eq!(
+            path.to_string(),
+            "/mjml/mj-body/mj-section[1]/mj-column[1]/mj-text[2]"
+        );
+        assert_eq!(after.element_at(&path).unwrap().text_content(), "Everyone");
+    }
+}
@@ -0,0 +1,308 @@
+//! Modifications of the body of a template, like the ones made by an editor,
+//! addressing the elements by their [`ChildPath`].
+//!
+//! ```rust
+//! # #[cfg(feature = "parse")]
+//! # {
+//! use mrml::edit::ChildPath;
+//! use mrml::mj_body::MjBodyChild;
+//! use mrml::mj_section::MjSection;
+//!
+//! let mut root = mrml::parse("<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>").unwrap();
+//! let body = root.element.children.body.as_mut().unwrap();
+//! let section = MjBodyChild::MjSection(MjSection::default());
+//! body.wrap_in(&ChildPath::from(vec![0]), section).unwrap();
+//! assert!(matches!(body.get_by_path(&"/0/0".parse().unwrap()), Some(MjBodyChild::MjText(_))));
+//! # }
+//! ```
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::mj_body::{MjBody, MjBodyChild};
+
+/// Position of an element of the body, made of the indexes of the element and
+/// of its ancestors in the children of their parent, starting from the
+/// `mj-body`. The texts and the comments are counted. It's written like
+/// `/0/2/1`, the body itself being `/`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ChildPath(Vec<usize>);
+
+impl ChildPath {
+    /// Path of the body itself.
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    pub fn indexes(&self) -> &[usize] {
+        &self.0
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Path of the child at `index` of this element.
+    pub fn child(&self, index: usize) -> Self {
+        let mut result = self.clone();
+        result.0.push(index);
+        result
+    }
+
+    /// Path of the parent and index of the element in its children, `None`
+    /// for the body.
+    pub fn split_last(&self) -> Option<(Self, usize)> {
+        self.0
+            .split_last()
+            .map(|(last, parent)| (Self(parent.to_vec()), *last))
+    }
+}
+
+impl From<Vec<usize>> for ChildPath {
+    fn from(value: Vec<usize>) -> Self {
+        Self(value)
+    }
+}
+
+impl Display for ChildPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            return f.write_str("/");
+        }
+        for index in self.0.iter() {
+            write!(f, "/{index}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ChildPath {
+    type Err = EditError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        value
+            .split('/')
+            .filter(|item| !item.is_empty())
+            .map(|item| item.parse::<usize>())
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+            .map_err(|_| EditError::InvalidPath(value.to_string()))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EditError {
+    InvalidPath(String),
+    NotFound(ChildPath),
+    NotAContainer(ChildPath),
+    OutOfBounds { path: ChildPath, index: usize },
+}
+
+impl Display for EditError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidPath(value) => write!(f, "invalid path {value:?}"),
+            Self::NotFound(path) => write!(f, "no element at {path}"),
+            Self::NotAContainer(path) => {
+                write!(f, "the element at {path} can't contain other elements")
+            }
+            Self::OutOfBounds { path, index } => {
+                write!(f, "the element at {path} has no position {index}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EditError {}
+
+/// Children of the elements containing other elements of the body.
+fn children(element: &MjBodyChild) -> Option<&Vec<MjBodyChild>> {
+    match element {
+        MjBodyChild::MjColumn(inner) => Some(&inner.children),
+        MjBodyChild::MjGroup(inner) => Some(&inner.children),
+        MjBodyChild::MjHero(inner) => Some(&inner.children),
+        MjBodyChild::MjMsoOffice(inner) => Some(&inner.children),
+        MjBodyChild::MjNotMso(inner) => Some(&inner.children),
+        MjBodyChild::MjSection(inner) => Some(&inner.children),
+        MjBodyChild::MjWrapper(inner) => Some(&inner.children),
+        _ => None,
+    }
+}
+
+fn children_mut(element: &mut MjBodyChild) -> Option<&mut Vec<MjBodyChild>> {
+    match element {
+        MjBodyChild::MjColumn(inner) => Some(&mut inner.children),
+        MjBodyChild::MjGroup(inner) => Some(&mut inner.children),
+        MjBodyChild::MjHero(inner) => Some(&mut inner.children),
+        MjBodyChild::MjMsoOffice(inner) => Some(&mut inner.children),
+        MjBodyChild::MjNotMso(inner) => Some(&mut inner.children),
+        MjBodyChild::MjSection(inner) => Some(&mut inner.children),
+        MjBodyChild::MjWrapper(inner) => Some(&mut inner.children),
+        _ => None,
+    }
+}
+
+impl MjBody {
+    pub fn get_by_path(&self, path: &ChildPath) -> Option<&MjBodyChild> {
+        let (first, rest) = path.indexes().split_first()?;
+        let mut current = self.children.get(*first)?;
+        for index in rest {
+            current = children(current)?.get(*index)?;
+        }
+        Some(current)
+    }
+
+    pub fn get_by_path_mut(&mut self, path: &ChildPath) -> Option<&mut MjBodyChild> {
+        let (first, rest) = path.indexes().split_first()?;
+        let mut current = self.children.get_mut(*first)?;
+        for index in rest {
+            current = children_mut(current)?.get_mut(*index)?;
+        }
+        Some(current)
+    }
+
+    /// Children of the element at `path`, the body being a container.
+    fn children_at_mut(&mut self, path: &ChildPath) -> Result<&mut Vec<MjBodyChild>, EditError> {
+        if path.is_root() {
+            return Ok(&mut self.children);
+        }
+        let element = self
+            .get_by_path_mut(path)
+            .ok_or_else(|| EditError::NotFound(path.clone()))?;
+        children_mut(element).ok_or_else(|| EditError::NotAContainer(path.clone()))
+    }
+
+    /// Inserts the `child` at `index` in the children of the element at
+    /// `parent`. An `index` equal to the number of children appends it.
+    pub fn insert_child_at(
+        &mut self,
+        parent: &ChildPath,
+        index: usize,
+        child: MjBodyChild,
+    ) -> Result<(), EditError> {
+        let children = self.children_at_mut(parent)?;
+        if index > children.len() {
+            return Err(EditError::OutOfBounds {
+                path: parent.clone(),
+                index,
+            });
+        }
+        children.insert(index, child);
+        Ok(())
+    }
+
+    /// Replaces the element at `path` by `child`, returning the previous one.
+    pub fn replace_child(
+        &mut self,
+        path: &ChildPath,
+        child: MjBodyChild,
+    ) -> Result<MjBodyChild, EditError> {
+        let element = self
+            .get_by_path_mut(path)
+            .ok_or_else(|| EditError::NotFound(path.clone()))?;
+        Ok(std::mem::replace(element, child))
+    }
+
+    /// Removes the element at `path`, returning it.
+    pub fn remove_by_path(&mut self, path: &ChildPath) -> Result<MjBodyChild, EditError> {
+        let (parent, index) = path
+            .split_last()
+            .ok_or_else(|| EditError::NotFound(path.clone()))?;
+        let children = self.children_at_mut(&parent)?;
+        if index >= children.len() {
+            return Err(EditError::NotFound(path.clone()));
+        }
+        Ok(children.remove(index))
+    }
+
+    /// Replaces the element at `path` by the `wrapper`, like a `mj-section`,
+    /// with the element added at the end of its children.
+    pub fn wrap_in(&mut self, path: &ChildPath, mut wrapper: MjBodyChild) -> Result<(), EditError> {
+        if children(&wrapper).is_none() {
+            return Err(EditError::NotAContainer(path.clone()));
+        }
+        let element = self
+            .get_by_path_mut(path)
+            .ok_or_else(|| EditError::NotFound(path.clone()))?;
+        std::mem::swap(element, &mut wrapper);
+        if let Some(children) = children_mut(element) {
+            children.push(wrapper);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use super::{ChildPath, EditError};
+    use crate::mj_body::MjBodyChild;
+    use crate::mj_column::MjColumn;
+    use crate::mj_spacer::MjSpacer;
+
+    const TEMPLATE: &str = r#"<mjml><mj-body><mj-section><mj-column><mj-text>A</mj-text><mj-image src="b.png" /></mj-column></mj-section></mj-body></mjml>"#;
+
+    fn path(value: &str) -> ChildPath {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn should_parse_and_print_paths() {
+        assert_eq!(path("/0/2/1").indexes(), &[0, 2, 1]);
+        assert_eq!(path("/0/2/1").to_string(), "/0/2/1");
+        assert!(path("/").is_root());
+        assert_eq!(ChildPath::root().to_string(), "/");
+        assert_eq!(
+            "/0/a".parse::<ChildPath>(),
+            Err(EditError::InvalidPath("/0/a".into()))
+        );
+        assert_eq!(path("/0/1").split_last(), Some((path("/0"), 1)));
+    }
+
+    #[test]
+    fn should_modify_the_body() {
+        let mut root = crate::parse(TEMPLATE).unwrap();
+        let body = root.element.children.body.as_mut().unwrap();
+
+        body.insert_child_at(&path("/0/0"), 1, MjBodyChild::MjSpacer(MjSpacer::default()))
+            .unwrap();
+        assert!(matches!(
+            body.get_by_path(&path("/0/0/1")),
+            Some(MjBodyChild::MjSpacer(_))
+        ));
+        assert_eq!(
+            body.insert_child_at(
+                &path("/0/0"),
+                10,
+                MjBodyChild::MjSpacer(MjSpacer::default())
+            ),
+            Err(EditError::OutOfBounds {
+                path: path("/0/0"),
+                index: 10
+            })
+        );
+        assert_eq!(
+            body.insert_child_at(
+                &path("/0/0/0"),
+                0,
+                MjBodyChild::MjSpacer(MjSpacer::default())
+            ),
+            Err(EditError::NotAContainer(path("/0/0/0")))
+        );
+
+        let removed = body.remove_by_path(&path("/0/0/2")).unwrap();
+        assert!(matches!(removed, MjBodyChild::MjImage(_)));
+        let previous = body.replace_child(&path("/0/0/1"), removed).unwrap();
+        assert!(matches!(previous, MjBodyChild::MjSpacer(_)));
+
+        body.wrap_in(&path("/0/0/1"), MjBodyChild::MjColumn(MjColumn::default()))
+            .unwrap();
+        assert!(matches!(
+            body.get_by_path(&path("/0/0/1/0")),
+            Some(MjBodyChild::MjImage(_))
+        ));
+        assert_eq!(
+            body.remove_by_path(&path("/0/5")).map(|_| ()),
+            Err(EditError::NotFound(path("/0/5")))
+        );
+    }
+}
@@ -0,0 +1,299 @@
+//! Generation of random, but valid, templates with the
+//! [`arbitrary`](https://docs.rs/arbitrary) crate, to fuzz the parser and
+//! the pipelines built on top of MRML.
+//!
+//! The generated templates only contain values that survive a round trip,
+//! so [`check_round_trip`] should never fail on them.
+//!
+//! ```rust
+//! use arbitrary::{Arbitrary, Unstructured};
+//!
+//! let data = [42u8; 256];
+//! let mut input = Unstructured::new(&data);
+//! let template = mrml::mjml::Mjml::arbitrary(&mut input).expect("couldn't generate template");
+//! mrml::fuzz::check_round_trip(&template).expect("round trip failed");
+//! ```
+//!
+//! With [cargo-fuzz](https://github.com/rust-fuzz/cargo-fuzz), a target can
+//! directly receive a template.
+//!
+//! ```rust,ignore
+//! fuzz_target!(|template: mrml::mjml::Mjml| {
+//!     let _ = template.render(&Default::default());
+//! });
+//! ```
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::comment::Comment;
+use crate::mj_body::{MjBody, MjBodyChild};
+use crate::mj_button::MjButton;
+use crate::mj_column::MjColumn;
+use crate::mj_divider::MjDivider;
+use crate::mj_head::{MjHead, MjHeadChild};
+use crate::mj_image::MjImage;
+use crate::mj_preview::MjPreview;
+use crate::mj_section::MjSection;
+use crate::mj_spacer::MjSpacer;
+use crate::mj_text::MjText;
+use crate::mj_title::MjTitle;
+use crate::mjml::{Mjml, MjmlAttributes, MjmlChildren};
+use crate::prelude::hash::Map;
+use crate::prelude::print::Printable;
+use crate::text::Text;
+
+const WORDS: [&str; 8] = [
+    "hello", "world", "mjml", "email", "lorem", "ipsum", "dolor", "amet",
+];
+const COLORS: [&str; 4] = ["#000000", "#ffffff", "#f45e43", "red"];
+const SIZES: [&str; 4] = ["0px", "10px", "25px", "50%"];
+const URLS: [&str; 3] = [
+    "https://example.com",
+    "https://example.com/image.png",
+    "#top",
+];
+const ALIGNS: [&str; 3] = ["left", "center", "right"];
+
+#[derive(Debug, thiserror::Error)]
+pub enum RoundTripError {
+    #[error("unable to print template")]
+    Print(#[from] std::fmt::Error),
+    #[error("unable to parse printed template")]
+    Parse(#[from] crate::prelude::parser::Error),
+    #[error("template changed after round trip")]
+    Mismatch { expected: String, found: String },
+}
+
+/// Prints the template, parses the output and checks that printing it again
+/// gives the same result.
+pub fn check_round_trip(template: &Mjml) -> std::result::Result<(), RoundTripError> {
+    let expected = template.print_dense()?;
+    let parsed = crate::parse(&expected)?;
+    let found = parsed.element.print_dense()?;
+    if expected == found {
+        Ok(())
+    } else {
+        Err(RoundTripError::Mismatch { expected, found })
+    }
+}
+
+fn words(u: &mut Unstructured<'_>) -> Result<String> {
+    let count = u.int_in_range(1..=5)?;
+    let mut result = Vec::with_capacity(count);
+    for _ in 0..count {
+        result.push(*u.choose(&WORDS)?);
+    }
+    Ok(result.join(" "))
+}
+
+fn attributes(u: &mut Unstructured<'_>, names: &[(&str, &[&str])]) -> Result<Map<String, String>> {
+    let mut result = Map::new();
+    for (name, values) in names {
+        if u.arbitrary()? {
+            result.insert(name.to_string(), u.choose(values)?.to_string());
+        }
+    }
+    Ok(result)
+}
+
+fn vec_of<'a, T: Arbitrary<'a>>(u: &mut Unstructured<'a>, max: usize) -> Result<Vec<T>> {
+    let count = u.int_in_range(0..=max)?;
+    (0..count).map(|_| T::arbitrary(u)).collect()
+}
+
+impl<'a> Arbitrary<'a> for Comment {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Comment::from(words(u)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Text {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Text::from(words(u)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for MjTitle {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(MjTitle::from(words(u)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for MjPreview {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(MjPreview::from(words(u)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for MjHead {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut children = Vec::new();
+        if u.arbitrary()? {
+            children.push(MjHeadChild::MjTitle(u.arbitrary()?));
+        }
+        if u.arbitrary()? {
+            children.push(MjHeadChild::MjPreview(u.arbitrary()?));
+        }
+        Ok(MjHead::new((), children))
+    }
+}
+
+impl<'a> Arbitrary<'a> for MjText {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let attributes = attributes(
+            u,
+            &[("align", &ALIGNS), ("color", &COLORS), ("padding", &SIZES)],
+        )?;
+        Ok(MjText::new(
+            attributes,
+            vec![MjBodyChild::Text(u.arbitrary()?)],
+        ))
+    }
+}
+
+impl<'a> Arbitrary<'a> for MjButton {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let attributes = attributes(
+            u,
+            &[
+                ("background-color", &COLORS),
+                ("color", &COLORS),
+                ("href", &URLS),
+            ],
+        )?;
+        Ok(MjButton::new(
+            attributes,
+            vec![MjBodyChild::Text(u.arbitrary()?)],
+        ))
+    }
+}
+
+impl<'a> Arbitrary<'a> for MjImage {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut attributes =
+            attributes(u, &[("align", &ALIGNS), ("href", &URLS), ("width", &SIZES)])?;
+        attributes.insert("src".to_string(), u.choose(&URLS)?.to_string());
+        attributes.insert("alt".to_string(), words(u)?);
+        Ok(MjImage::new(attributes, ()))
+    }
+}
+
+impl<'a> Arbitrary<'a> for MjDivider {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let attributes = attributes(u, &[("border-color", &COLORS), ("padding", &SIZES)])?;
+        Ok(MjDivider::new(attributes, ()))
+    }
+}
+
+impl<'a> Arbitrary<'a> for MjSpacer {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let attributes = attributes(u, &[("height", &SIZES)])?;
+        Ok(MjSpacer::new(attributes, ()))
+    }
+}
+
+impl<'a> Arbitrary<'a> for MjColumn {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let attributes = attributes(
+            u,
+            &[
+                ("background-color", &COLORS),
+                ("padding", &SIZES),
+                ("width", &SIZES),
+            ],
+        )?;
+        let count = u.int_in_range(0..=4)?;
+        let mut children = Vec::with_capacity(count);
+        for _ in 0..count {
+            children.push(match u.int_in_range(0..=5)? {
+                0 => MjBodyChild::Comment(u.arbitrary()?),
+                1 => MjBodyChild::MjButton(u.arbitrary()?),
+                2 => MjBodyChild::MjDivider(u.arbitrary()?),
+                3 => MjBodyChild::MjImage(u.arbitrary()?),
+                4 => MjBodyChild::MjSpacer(u.arbitrary()?),
+                _ => MjBodyChild::MjText(u.arbitrary()?),
+            });
+        }
+        Ok(MjColumn::new(attributes, children))
+    }
+}
+
+impl<'a> Arbitrary<'a> for MjSection {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let attributes = attributes(
+            u,
+            &[
+                ("background-color", &COLORS),
+                ("padding", &SIZES),
+                ("text-align", &ALIGNS),
+            ],
+        )?;
+        let children = vec_of::<MjColumn>(u, 3)?
+            .into_iter()
+            .map(MjBodyChild::MjColumn)
+            .collect();
+        Ok(MjSection::new(attributes, children))
+    }
+}
+
+impl<'a> Arbitrary<'a> for MjBody {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let attributes = attributes(u, &[("background-color", &COLORS)])?;
+        let count = u.int_in_range(0..=4)?;
+        let mut children = Vec::with_capacity(count);
+        for _ in 0..count {
+            children.push(match u.int_in_range(0..=3)? {
+                0 => MjBodyChild::Comment(u.arbitrary()?),
+                _ => MjBodyChild::MjSection(u.arbitrary()?),
+            });
+        }
+        Ok(MjBody::new(attributes, children))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Mjml {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let attributes = MjmlAttributes {
+            owa: None,
+            lang: (*u.choose(&[None, Some("en"), Some("fr")])?).map(String::from),
+            dir: (*u.choose(&[None, Some("ltr"), Some("rtl")])?).map(String::from),
+        };
+        let children = MjmlChildren {
+            head: u.arbitrary()?,
+            body: u.arbitrary()?,
+        };
+        Ok(Mjml::new(attributes, children))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::arbitrary::{Arbitrary, Unstructured};
+
+    use crate::mjml::Mjml;
+
+    #[test]
+    fn should_round_trip_generated_templates() {
+        // simple linear congruential generator, to keep the test deterministic
+        let mut seed: u32 = 42;
+        for _ in 0..200 {
+            let data = (0..512)
+                .map(|_| {
+                    seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                    (seed >> 16) as u8
+                })
+                .collect::<Vec<_>>();
+            let mut input = Unstructured::new(&data);
+            let template = Mjml::arbitrary(&mut input).unwrap();
+            super::check_round_trip(&template).unwrap();
+        }
+    }
+
+    #[test]
+    fn should_round_trip_parsed_template() {
+        let template = crate::parse("<mjml><mj-body><mj-text>a</mj-text></mj-body></mjml>")
+            .unwrap()
+            .element;
+        super::check_round_trip(&template).unwrap();
+    }
+}
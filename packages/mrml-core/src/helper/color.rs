@@ -0,0 +1,384 @@
+//! Parsing and manipulation of the css colors, used to compute the colors
+//! derived from the attributes, like the overlay of the `mj-hero` elements,
+//! or to check their contrast.
+//!
+//! ```rust
+//! use mrml::helper::color::Color;
+//!
+//! let color = Color::parse("hsl(0, 100%, 50%)").unwrap();
+//! assert_eq!(color, Color::rgb(255, 0, 0));
+//! assert_eq!(color.darken(0.2).to_string(), "#990000");
+//! assert!(Color::BLACK.contrast_ratio(&Color::WHITE) > 20.0);
+//! ```
+
+use std::convert::TryFrom;
+
+#[derive(Clone, Debug)]
+pub enum ColorParserError {
+    InvalidHex(String),
+    InvalidFunction(String),
+    Unknown(String),
+}
+
+impl std::fmt::Display for ColorParserError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidHex(value) => write!(f, "invalid hexadecimal color {value:?}"),
+            Self::InvalidFunction(value) => write!(f, "invalid color function {value:?}"),
+            Self::Unknown(value) => write!(f, "unknown color {value:?}"),
+        }
+    }
+}
+
+impl std::error::Error for ColorParserError {}
+
+/// Color in the sRGB space, with an alpha channel between `0` and `1`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    pub alpha: f32,
+}
+
+const NAMED: &[(&str, Color)] = &[
+    ("black", Color::rgb(0, 0, 0)),
+    ("silver", Color::rgb(192, 192, 192)),
+    ("gray", Color::rgb(128, 128, 128)),
+    ("grey", Color::rgb(128, 128, 128)),
+    ("white", Color::rgb(255, 255, 255)),
+    ("maroon", Color::rgb(128, 0, 0)),
+    ("red", Color::rgb(255, 0, 0)),
+    ("purple", Color::rgb(128, 0, 128)),
+    ("fuchsia", Color::rgb(255, 0, 255)),
+    ("magenta", Color::rgb(255, 0, 255)),
+    ("green", Color::rgb(0, 128, 0)),
+    ("lime", Color::rgb(0, 255, 0)),
+    ("olive", Color::rgb(128, 128, 0)),
+    ("yellow", Color::rgb(255, 255, 0)),
+    ("navy", Color::rgb(0, 0, 128)),
+    ("blue", Color::rgb(0, 0, 255)),
+    ("teal", Color::rgb(0, 128, 128)),
+    ("aqua", Color::rgb(0, 255, 255)),
+    ("cyan", Color::rgb(0, 255, 255)),
+    ("orange", Color::rgb(255, 165, 0)),
+    ("transparent", Color::rgba(0, 0, 0, 0.0)),
+];
+
+impl Color {
+    pub const BLACK: Self = Self::rgb(0, 0, 0);
+    pub const WHITE: Self = Self::rgb(255, 255, 255);
+
+    pub const fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self::rgba(red, green, blue, 1.0)
+    }
+
+    pub const fn rgba(red: u8, green: u8, blue: u8, alpha: f32) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    /// Parses a hexadecimal color, like `#rgb`, `#rgba`, `#rrggbb` or
+    /// `#rrggbbaa`.
+    pub fn from_hex(value: &str) -> Result<Self, ColorParserError> {
+        let invalid = || ColorParserError::InvalidHex(value.to_string());
+        let hex = value.trim().strip_prefix('#').ok_or_else(invalid)?;
+        if !hex.is_ascii() {
+            return Err(invalid());
+        }
+        let channel = |value: &str| u8::from_str_radix(value, 16).map_err(|_| invalid());
+        let channels = match hex.len() {
+            3 | 4 => hex
+                .chars()
+                .map(|c| channel(&format!("{c}{c}")))
+                .collect::<Result<Vec<_>, _>>()?,
+            6 | 8 => (0..hex.len())
+                .step_by(2)
+                .map(|index| channel(&hex[index..index + 2]))
+                .collect::<Result<Vec<_>, _>>()?,
+            _ => return Err(invalid()),
+        };
+        let alpha = channels.get(3).map_or(1.0, |value| *value as f32 / 255.0);
+        Ok(Self::rgba(channels[0], channels[1], channels[2], alpha))
+    }
+
+    /// Parses a css color: hexadecimal, `rgb()`, `rgba()`, `hsl()`, `hsla()`
+    /// or one of the basic named colors.
+    pub fn parse(value: &str) -> Result<Self, ColorParserError> {
+        let value = value.trim().to_ascii_lowercase();
+        if value.starts_with('#') {
+            return Self::from_hex(&value);
+        }
+        if let Some((name, args)) = value
+            .strip_suffix(')')
+            .and_then(|inner| inner.split_once('('))
+        {
+            return Self::parse_function(name.trim(), args)
+                .ok_or_else(|| ColorParserError::InvalidFunction(value.clone()));
+        }
+        NAMED
+            .iter()
+            .find(|(name, _)| *name == value)
+            .map(|(_, color)| *color)
+            .ok_or(ColorParserError::Unknown(value))
+    }
+
+    fn parse_function(name: &str, args: &str) -> Option<Self> {
+        let args = args
+            .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+            .filter(|item| !item.is_empty())
+            .collect::<Vec<_>>();
+        let alpha = match args.get(3) {
+            Some(value) => parse_alpha(value)?,
+            None => 1.0,
+        };
+        if args.len() < 3 || args.len() > 4 {
+            return None;
+        }
+        match name {
+            "rgb" | "rgba" => {
+                let channel = |value: &str| -> Option<u8> {
+                    let value = match value.strip_suffix('%') {
+                        Some(value) => value.parse::<f32>().ok()? * 255.0 / 100.0,
+                        None => value.parse::<f32>().ok()?,
+                    };
+                    Some(value.round().clamp(0.0, 255.0) as u8)
+                };
+                Some(Self::rgba(
+                    channel(args[0])?,
+                    channel(args[1])?,
+                    channel(args[2])?,
+                    alpha,
+                ))
+            }
+            "hsl" | "hsla" => {
+                let hue = args[0].strip_suffix("deg").unwrap_or(args[0]);
+                let hue = hue.parse::<f32>().ok()?;
+                let percent = |value: &str| -> Option<f32> {
+                    let value = value.strip_suffix('%')?.parse::<f32>().ok()?;
+                    Some((value / 100.0).clamp(0.0, 1.0))
+                };
+                let mut color = Self::from_hsl(hue, percent(args[1])?, percent(args[2])?);
+                color.alpha = alpha;
+                Some(color)
+            }
+            _ => None,
+        }
+    }
+
+    /// Builds a color from its hue, in degrees, and its saturation and
+    /// lightness, between `0` and `1`.
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        let hue = hue.rem_euclid(360.0) / 60.0;
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let second = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+        let (red, green, blue) = match hue as u8 {
+            0 => (chroma, second, 0.0),
+            1 => (second, chroma, 0.0),
+            2 => (0.0, chroma, second),
+            3 => (0.0, second, chroma),
+            4 => (second, 0.0, chroma),
+            _ => (chroma, 0.0, second),
+        };
+        let offset = lightness - chroma / 2.0;
+        let channel = |value: f32| ((value + offset) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Self::rgb(channel(red), channel(green), channel(blue))
+    }
+
+    /// Hue, in degrees, saturation and lightness, between `0` and `1`, of
+    /// the color.
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let red = self.red as f32 / 255.0;
+        let green = self.green as f32 / 255.0;
+        let blue = self.blue as f32 / 255.0;
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let lightness = (max + min) / 2.0;
+        let delta = max - min;
+        if delta == 0.0 {
+            return (0.0, 0.0, lightness);
+        }
+        let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+        let hue = if max == red {
+            ((green - blue) / delta).rem_euclid(6.0)
+        } else if max == green {
+            (blue - red) / delta + 2.0
+        } else {
+            (red - green) / delta + 4.0
+        };
+        (hue * 60.0, saturation, lightness)
+    }
+
+    /// Increases the lightness of the color by `amount`, between `0` and `1`.
+    pub fn lighten(&self, amount: f32) -> Self {
+        let (hue, saturation, lightness) = self.to_hsl();
+        let mut result = Self::from_hsl(hue, saturation, (lightness + amount).clamp(0.0, 1.0));
+        result.alpha = self.alpha;
+        result
+    }
+
+    /// Decreases the lightness of the color by `amount`, between `0` and `1`.
+    pub fn darken(&self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Relative luminance of the color, as defined by the WCAG.
+    pub fn luminance(&self) -> f64 {
+        let linear = |value: u8| {
+            let value = value as f64 / 255.0;
+            if value <= 0.03928 {
+                value / 12.92
+            } else {
+                ((value + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(self.red) + 0.7152 * linear(self.green) + 0.0722 * linear(self.blue)
+    }
+
+    /// Contrast ratio between two colors, as defined by the WCAG, from `1` to
+    /// `21`.
+    pub fn contrast_ratio(&self, other: &Self) -> f64 {
+        let (first, second) = (self.luminance(), other.luminance());
+        let (lighter, darker) = if first > second {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
+    /// Black or white, depending on which one is the most readable over the
+    /// color.
+    pub fn contrasting(&self) -> Self {
+        if self.contrast_ratio(&Self::BLACK) >= self.contrast_ratio(&Self::WHITE) {
+            Self::BLACK
+        } else {
+            Self::WHITE
+        }
+    }
+
+    pub fn is_opaque(&self) -> bool {
+        self.alpha >= 1.0
+    }
+
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+}
+
+fn parse_alpha(value: &str) -> Option<f32> {
+    let alpha = match value.strip_suffix('%') {
+        Some(value) => value.parse::<f32>().ok()? / 100.0,
+        None => value.parse::<f32>().ok()?,
+    };
+    Some(alpha.clamp(0.0, 1.0))
+}
+
+impl TryFrom<&str> for Color {
+    type Error = ColorParserError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+impl std::fmt::Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_opaque() {
+            f.write_str(&self.to_hex())
+        } else {
+            write!(
+                f,
+                "rgba({},{},{},{})",
+                self.red, self.green, self.blue, self.alpha
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Color, ColorParserError};
+
+    #[test]
+    fn should_parse_hex() {
+        assert_eq!(Color::from_hex("#fff").unwrap(), Color::WHITE);
+        assert_eq!(Color::from_hex("#0a0B0c").unwrap(), Color::rgb(10, 11, 12));
+        assert_eq!(
+            Color::from_hex("#00000080").unwrap(),
+            Color::rgba(0, 0, 0, 128.0 / 255.0)
+        );
+        assert!(Color::from_hex("rgba(0,0,0,0.5)").is_err());
+        assert!(Color::from_hex("#12").is_err());
+        assert!(Color::from_hex("#ggg").is_err());
+    }
+
+    #[test]
+    fn should_parse_functions() {
+        assert_eq!(Color::parse("rgb(1, 2, 3)").unwrap(), Color::rgb(1, 2, 3));
+        assert_eq!(
+            Color::parse("RGBA(0,0,0,0.5)").unwrap(),
+            Color::rgba(0, 0, 0, 0.5)
+        );
+        assert_eq!(
+            Color::parse("rgb(100% 0% 0% / 50%)").unwrap(),
+            Color::rgba(255, 0, 0, 0.5)
+        );
+        assert_eq!(
+            Color::parse("hsl(120, 100%, 25%)").unwrap(),
+            Color::rgb(0, 128, 0)
+        );
+        assert_eq!(
+            Color::parse("hsla(240deg, 100%, 50%, 1)").unwrap(),
+            Color::rgb(0, 0, 255)
+        );
+        assert!(matches!(
+            Color::parse("rgb(1, 2)"),
+            Err(ColorParserError::InvalidFunction(_))
+        ));
+        assert!(matches!(
+            Color::parse("cmyk(1, 2, 3, 4)"),
+            Err(ColorParserError::InvalidFunction(_))
+        ));
+    }
+
+    #[test]
+    fn should_parse_names() {
+        assert_eq!(Color::parse("White").unwrap(), Color::WHITE);
+        assert!(!Color::parse("transparent").unwrap().is_opaque());
+        assert!(matches!(
+            Color::parse("blurple"),
+            Err(ColorParserError::Unknown(_))
+        ));
+    }
+
+    #[test]
+    fn should_convert_hsl() {
+        let color = Color::rgb(65, 78, 158);
+        let (hue, saturation, lightness) = color.to_hsl();
+        assert_eq!(Color::from_hsl(hue, saturation, lightness), color);
+    }
+
+    #[test]
+    fn should_lighten_and_darken() {
+        assert_eq!(Color::rgb(255, 0, 0).darken(0.2).to_string(), "#990000");
+        assert_eq!(Color::rgb(255, 0, 0).lighten(0.2).to_string(), "#ff6666");
+        assert_eq!(Color::WHITE.lighten(0.5), Color::WHITE);
+        assert_eq!(
+            Color::rgba(0, 0, 0, 0.5).lighten(1.0).to_string(),
+            "rgba(255,255,255,0.5)"
+        );
+    }
+
+    #[test]
+    fn should_compute_contrast() {
+        let ratio = Color::BLACK.contrast_ratio(&Color::WHITE);
+        assert!((ratio - 21.0).abs() < 0.01);
+        assert_eq!(Color::rgb(255, 255, 0).contrasting(), Color::BLACK);
+        assert_eq!(Color::rgb(0, 0, 128).contrasting(), Color::WHITE);
+    }
+}
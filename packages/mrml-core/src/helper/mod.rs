@@ -1,8 +1,15 @@
+//! Helpers used to render the elements, for the authors of custom
+//! components.
+
+pub mod color;
 #[cfg(feature = "render")]
 pub mod size;
 #[cfg(feature = "render")]
-pub mod sort;
+pub(crate) mod sort;
 #[cfg(feature = "render")]
 pub mod spacing;
 #[cfg(feature = "render")]
-pub mod style;
+pub(crate) mod style;
+pub mod url;
+#[cfg(feature = "render")]
+pub mod width;
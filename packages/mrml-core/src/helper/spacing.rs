@@ -1,6 +1,6 @@
 use std::convert::TryFrom;
 
-use crate::helper::size::{Pixel, SizeParserError};
+use crate::helper::size::{Percent, Pixel, SizeParserError};
 
 #[derive(Debug, thiserror::Error)]
 pub enum SpacingParserError {
@@ -12,18 +12,106 @@ pub enum SpacingParserError {
     ),
     #[error("no value provided")]
     Empty,
+    #[error("expected at most 4 values, got {0}")]
+    TooManyValues(usize),
+    #[error("value {0:?} should have a unit")]
+    MissingUnit(String),
+    #[error("unknown unit {unit:?} in value {value:?}")]
+    UnknownUnit { value: String, unit: String },
 }
 
-/// representation of spacing
+/// One of the values of a spacing shorthand, like `margin` or `padding`.
+///
+/// A unitless value is only accepted when it's `0`, like in css.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SpacingValue {
+    Auto,
+    Pixel(Pixel),
+    Percent(Percent),
+    Em(f32),
+    Rem(f32),
+}
+
+impl SpacingValue {
+    pub fn is_auto(&self) -> bool {
+        matches!(self, Self::Auto)
+    }
+
+    pub fn as_pixel(&self) -> Option<&Pixel> {
+        match self {
+            Self::Pixel(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn into_pixel(self) -> Option<Pixel> {
+        match self {
+            Self::Pixel(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<Pixel> for SpacingValue {
+    fn from(value: Pixel) -> Self {
+        Self::Pixel(value)
+    }
+}
+
+impl std::fmt::Display for SpacingValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => f.write_str("auto"),
+            Self::Pixel(inner) => inner.fmt(f),
+            Self::Percent(inner) => inner.fmt(f),
+            Self::Em(inner) => write!(f, "{inner}em"),
+            Self::Rem(inner) => write!(f, "{inner}rem"),
+        }
+    }
+}
+
+impl TryFrom<&str> for SpacingValue {
+    type Error = SpacingParserError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        if input.eq_ignore_ascii_case("auto") {
+            return Ok(Self::Auto);
+        }
+        let index = input
+            .find(|c: char| c.is_ascii_alphabetic() || c == '%')
+            .unwrap_or(input.len());
+        let (number, unit) = input.split_at(index);
+        let number = number
+            .parse::<f32>()
+            .map_err(SizeParserError::InvalidFloat)?;
+        match unit.to_ascii_lowercase().as_str() {
+            "px" => Ok(Self::Pixel(Pixel::new(number))),
+            "%" => Ok(Self::Percent(Percent::new(number))),
+            "em" => Ok(Self::Em(number)),
+            "rem" => Ok(Self::Rem(number)),
+            "" if number == 0.0 => Ok(Self::Pixel(Pixel::new(0.0))),
+            "" => Err(SpacingParserError::MissingUnit(input.to_string())),
+            _ => Err(SpacingParserError::UnknownUnit {
+                value: input.to_string(),
+                unit: unit.to_string(),
+            }),
+        }
+    }
+}
+
+/// representation of spacing, following the order of the css shorthands:
+/// `top`, `right`, `bottom` then `left`, the missing values being taken from
+/// the opposite side.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Spacing {
-    Single(Pixel),
-    Two(Pixel, Pixel),
-    Three(Pixel, Pixel, Pixel),
-    Four(Pixel, Pixel, Pixel, Pixel),
+    Single(SpacingValue),
+    Two(SpacingValue, SpacingValue),
+    Three(SpacingValue, SpacingValue, SpacingValue),
+    Four(SpacingValue, SpacingValue, SpacingValue, SpacingValue),
 }
 
 impl Spacing {
-    pub fn top(&self) -> &Pixel {
+    pub fn top(&self) -> &SpacingValue {
         match self {
             Self::Single(top) => top,
             Self::Two(vertical, _horizontal) => vertical,
@@ -32,7 +120,7 @@ impl Spacing {
         }
     }
 
-    pub fn into_top(self) -> Pixel {
+    pub fn into_top(self) -> SpacingValue {
         match self {
             Self::Single(top) => top,
             Self::Two(vertical, _horizontal) => vertical,
@@ -41,7 +129,7 @@ impl Spacing {
         }
     }
 
-    pub fn right(&self) -> &Pixel {
+    pub fn right(&self) -> &SpacingValue {
         match self {
             Self::Single(top) => top,
             Self::Two(_vertical, horizontal) => horizontal,
@@ -50,7 +138,7 @@ impl Spacing {
         }
     }
 
-    pub fn into_right(self) -> Pixel {
+    pub fn into_right(self) -> SpacingValue {
         match self {
             Self::Single(top) => top,
             Self::Two(_vertical, horizontal) => horizontal,
@@ -59,7 +147,7 @@ impl Spacing {
         }
     }
 
-    pub fn bottom(&self) -> &Pixel {
+    pub fn bottom(&self) -> &SpacingValue {
         match self {
             Self::Single(top) => top,
             Self::Two(vertical, _horizontal) => vertical,
@@ -68,7 +156,7 @@ impl Spacing {
         }
     }
 
-    pub fn into_bottom(self) -> Pixel {
+    pub fn into_bottom(self) -> SpacingValue {
         match self {
             Self::Single(top) => top,
             Self::Two(vertical, _horizontal) => vertical,
@@ -77,7 +165,7 @@ impl Spacing {
         }
     }
 
-    pub fn left(&self) -> &Pixel {
+    pub fn left(&self) -> &SpacingValue {
         match self {
             Self::Single(top) => top,
             Self::Two(_vertical, horizontal) => horizontal,
@@ -86,7 +174,7 @@ impl Spacing {
         }
     }
 
-    pub fn into_left(self) -> Pixel {
+    pub fn into_left(self) -> SpacingValue {
         match self {
             Self::Single(top) => top,
             Self::Two(_vertical, horizontal) => horizontal,
@@ -113,29 +201,17 @@ impl TryFrom<&str> for Spacing {
     type Error = SpacingParserError;
 
     fn try_from(input: &str) -> Result<Self, Self::Error> {
-        let mut sections = input.split(' ');
-        match (
-            sections.next(),
-            sections.next(),
-            sections.next(),
-            sections.next(),
-        ) {
-            (Some(first), None, None, None) => Ok(Self::Single(Pixel::try_from(first)?)),
-            (Some(first), Some(second), None, None) => {
-                Ok(Self::Two(Pixel::try_from(first)?, Pixel::try_from(second)?))
-            }
-            (Some(first), Some(second), Some(third), None) => Ok(Self::Three(
-                Pixel::try_from(first)?,
-                Pixel::try_from(second)?,
-                Pixel::try_from(third)?,
-            )),
-            (Some(first), Some(second), Some(third), Some(four)) => Ok(Self::Four(
-                Pixel::try_from(first)?,
-                Pixel::try_from(second)?,
-                Pixel::try_from(third)?,
-                Pixel::try_from(four)?,
-            )),
-            _ => Err(SpacingParserError::Empty),
+        let values = input
+            .split_whitespace()
+            .map(SpacingValue::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        match values.as_slice() {
+            [] => Err(SpacingParserError::Empty),
+            [first] => Ok(Self::Single(*first)),
+            [first, second] => Ok(Self::Two(*first, *second)),
+            [first, second, third] => Ok(Self::Three(*first, *second, *third)),
+            [first, second, third, fourth] => Ok(Self::Four(*first, *second, *third, *fourth)),
+            _ => Err(SpacingParserError::TooManyValues(values.len())),
         }
     }
 }
@@ -143,12 +219,12 @@ impl TryFrom<&str> for Spacing {
 #[cfg(test)]
 pub mod tests {
     use super::*;
-    use crate::helper::size::Pixel;
+    use crate::helper::size::{Percent, Pixel};
 
     #[test]
     fn single_value() {
         let res: Spacing = Spacing::try_from("1px").unwrap();
-        assert_eq!(res.top(), &Pixel::new(1.0));
+        assert_eq!(res.top(), &SpacingValue::from(Pixel::new(1.0)));
         assert_eq!(res.top(), res.bottom());
         assert_eq!(res.top(), res.right());
         assert_eq!(res.right(), res.left());
@@ -157,28 +233,28 @@ pub mod tests {
     #[test]
     fn two_values() {
         let res: Spacing = Spacing::try_from("2px 4px").unwrap();
-        assert_eq!(res.top(), &Pixel::new(2.0));
+        assert_eq!(res.top(), &SpacingValue::from(Pixel::new(2.0)));
         assert_eq!(res.top(), res.bottom());
-        assert_eq!(res.left(), &Pixel::new(4.0));
+        assert_eq!(res.left(), &SpacingValue::from(Pixel::new(4.0)));
         assert_eq!(res.left(), res.right());
     }
 
     #[test]
     fn three_values() {
         let res: Spacing = Spacing::try_from("2px 3px 4px").unwrap();
-        assert_eq!(res.top(), &Pixel::new(2.0));
-        assert_eq!(res.right(), &Pixel::new(3.0));
+        assert_eq!(res.top(), &SpacingValue::from(Pixel::new(2.0)));
+        assert_eq!(res.right(), &SpacingValue::from(Pixel::new(3.0)));
         assert_eq!(res.left(), res.right());
-        assert_eq!(res.bottom(), &Pixel::new(4.0));
+        assert_eq!(res.bottom(), &SpacingValue::from(Pixel::new(4.0)));
     }
 
     #[test]
     fn four_values() {
         let res: Spacing = Spacing::try_from("2px 3px 4px 5px").unwrap();
-        assert_eq!(res.top(), &Pixel::new(2.0));
-        assert_eq!(res.right(), &Pixel::new(3.0));
-        assert_eq!(res.bottom(), &Pixel::new(4.0));
-        assert_eq!(res.left(), &Pixel::new(5.0));
+        assert_eq!(res.top(), &SpacingValue::from(Pixel::new(2.0)));
+        assert_eq!(res.right(), &SpacingValue::from(Pixel::new(3.0)));
+        assert_eq!(res.bottom(), &SpacingValue::from(Pixel::new(4.0)));
+        assert_eq!(res.left(), &SpacingValue::from(Pixel::new(5.0)));
     }
 
     #[test]
@@ -186,4 +262,45 @@ pub mod tests {
         let res = Spacing::try_from("2tx 3px 4px 5px");
         assert!(res.is_err());
     }
+
+    #[test]
+    fn mixed_values() {
+        let res = Spacing::try_from("auto -2px 10% 1.5em").unwrap();
+        assert!(res.top().is_auto());
+        assert_eq!(res.right(), &SpacingValue::from(Pixel::new(-2.0)));
+        assert_eq!(res.bottom(), &SpacingValue::Percent(Percent::new(10.0)));
+        assert_eq!(res.left(), &SpacingValue::Em(1.5));
+        assert_eq!(res.to_string(), "auto -2px 10% 1.5em");
+    }
+
+    #[test]
+    fn unitless_zero() {
+        let res = Spacing::try_from("0  AUTO").unwrap();
+        assert_eq!(res.top().as_pixel(), Some(&Pixel::new(0.0)));
+        assert!(res.left().is_auto());
+    }
+
+    #[test]
+    fn precise_errors() {
+        assert!(matches!(
+            Spacing::try_from(" "),
+            Err(SpacingParserError::Empty)
+        ));
+        assert!(matches!(
+            Spacing::try_from("1px 2px 3px 4px 5px"),
+            Err(SpacingParserError::TooManyValues(5))
+        ));
+        assert!(matches!(
+            Spacing::try_from("1px 2"),
+            Err(SpacingParserError::MissingUnit(value)) if value == "2"
+        ));
+        assert!(matches!(
+            Spacing::try_from("2tx"),
+            Err(SpacingParserError::UnknownUnit { unit, .. }) if unit == "tx"
+        ));
+        assert!(matches!(
+            Spacing::try_from("1.2.3px"),
+            Err(SpacingParserError::InvalidSize(_))
+        ));
+    }
 }
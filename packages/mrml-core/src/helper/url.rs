@@ -0,0 +1,67 @@
+//! Building of the urls generated by the elements, like the links of
+//! `mj-calendar-button` or the images of `mj-map`.
+//!
+//! ```rust
+//! use mrml::helper::url;
+//!
+//! assert_eq!(url::encode_component("Paris & co"), "Paris%20%26%20co");
+//! assert_eq!(
+//!     url::fill_template("https://example.com/?q={query}&z={zoom}", &[("query", "Paris & co"), ("zoom", "14")]),
+//!     "https://example.com/?q=Paris%20%26%20co&z=14"
+//! );
+//! ```
+
+/// Encodes the value to be used in the query of an url, keeping only the
+/// unreserved characters.
+pub fn encode_component(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            result.push(byte as char);
+        } else {
+            result.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    result
+}
+
+/// Replaces the `{name}` placeholders of the template with the encoded
+/// values. The unknown placeholders are kept as is.
+pub fn fill_template(template: &str, values: &[(&str, &str)]) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let found = after.find('}').and_then(|end| {
+            let name = &after[..end];
+            values
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| (end, value))
+        });
+        match found {
+            Some((end, value)) => {
+                result.push_str(&encode_component(value));
+                rest = &after[end + 1..];
+            }
+            None => {
+                result.push('{');
+                rest = after;
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn should_keep_unknown_placeholders() {
+        assert_eq!(
+            super::fill_template("/{a}/{b}/{", &[("a", "x/y")]),
+            "/x%2Fy/{b}/{"
+        );
+    }
+}
@@ -0,0 +1,123 @@
+//! Computation of the widths used by the elements, mostly for the tables
+//! rendered for outlook that don't support the percentages nor the
+//! `max-width`.
+//!
+//! ```rust
+//! use mrml::helper::size::{Pixel, Size};
+//! use mrml::helper::width;
+//!
+//! // a column of 50% in a section of 600px, with 10px of padding on each side
+//! let column = width::child_width(Pixel::new(600.0), Some(Size::percent(50.0)), 1, Pixel::new(20.0));
+//! assert_eq!(column, Pixel::new(280.0));
+//! // an element of 100px in this column with a padding of 25px on each side
+//! let content = width::content_width(column, Some(Pixel::new(100.0)), Pixel::new(50.0), Pixel::new(0.0));
+//! assert_eq!(content, Pixel::new(100.0));
+//! ```
+
+use super::size::{Pixel, Size};
+
+/// Width available inside an element, once its horizontal padding and
+/// borders are removed from the width of its container.
+pub fn box_width(container: Pixel, padding: Pixel, border: Pixel) -> Pixel {
+    Pixel::new(container.value() - border.value() - padding.value())
+}
+
+/// Resolves a width relative to the container, the percentages being
+/// applied to the width of the container.
+pub fn resolve(container: Pixel, width: Size) -> Pixel {
+    match width {
+        Size::Percent(value) => Pixel::new(container.value() * value.value() / 100.0),
+        Size::Pixel(value) => value,
+        Size::Raw(value) => Pixel::new(value),
+    }
+}
+
+/// Width of the content of a column or a group, sharing the width of their
+/// section with their `siblings` when they don't define a `width`. The
+/// `spacing` is the sum of their horizontal paddings and borders.
+pub fn child_width(parent: Pixel, width: Option<Size>, siblings: usize, spacing: Pixel) -> Pixel {
+    let width = width.unwrap_or_else(|| Size::pixel(parent.value() / siblings.max(1) as f32));
+    Pixel::new(resolve(parent, width).value() - spacing.value())
+}
+
+/// Width of a content element, like an image, limited to the width
+/// available in its container.
+pub fn content_width(
+    container: Pixel,
+    width: Option<Pixel>,
+    padding: Pixel,
+    border: Pixel,
+) -> Pixel {
+    let available = box_width(container, padding, border);
+    match width {
+        Some(width) if width.value() < available.value() => width,
+        _ => available,
+    }
+}
+
+/// Width of the table rendered for outlook, taking the percentages
+/// relatively to the width available inside the padding of the element.
+pub fn outlook_width(container: Pixel, width: Option<Size>, padding: Pixel) -> Pixel {
+    let available = box_width(container, padding, Pixel::default());
+    match width {
+        Some(Size::Pixel(value)) => value,
+        Some(Size::Percent(value)) => Pixel::new(available.value() * (value.value() / 100.0)),
+        _ => available,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_compute_child_width() {
+        let parent = Pixel::new(600.0);
+        assert_eq!(
+            child_width(parent, None, 3, Pixel::default()),
+            Pixel::new(200.0)
+        );
+        assert_eq!(
+            child_width(parent, Some(Size::pixel(150.0)), 3, Pixel::new(10.0)),
+            Pixel::new(140.0)
+        );
+        assert_eq!(
+            child_width(parent, Some(Size::percent(25.0)), 3, Pixel::default()),
+            Pixel::new(150.0)
+        );
+    }
+
+    #[test]
+    fn should_compute_outlook_width() {
+        let container = Pixel::new(600.0);
+        let padding = Pixel::new(50.0);
+        assert_eq!(outlook_width(container, None, padding), Pixel::new(550.0));
+        assert_eq!(
+            outlook_width(container, Some(Size::percent(50.0)), padding),
+            Pixel::new(275.0)
+        );
+        assert_eq!(
+            outlook_width(container, Some(Size::pixel(100.0)), padding),
+            Pixel::new(100.0)
+        );
+    }
+
+    #[test]
+    fn should_limit_content_width() {
+        let container = Pixel::new(300.0);
+        let padding = Pixel::new(50.0);
+        let border = Pixel::new(2.0);
+        assert_eq!(
+            content_width(container, None, padding, border),
+            Pixel::new(248.0)
+        );
+        assert_eq!(
+            content_width(container, Some(Pixel::new(400.0)), padding, border),
+            Pixel::new(248.0)
+        );
+        assert_eq!(
+            content_width(container, Some(Pixel::new(100.0)), padding, border),
+            Pixel::new(100.0)
+        );
+    }
+}
@@ -40,6 +40,7 @@
 //! let loader = MemoryIncludeLoader::from(vec![("partial.mjml", "<mj-button>Hello</mj-button>")]);
 //! let options = ParserOptions {
 //!     include_loader: Box::new(loader),
+//!     ..Default::default()
 //! };
 //! match mrml::parse_with_options("<mjml><mj-head /><mj-body><mj-include path=\"partial.mjml\" /></mj-body></mjml>", &options) {
 //!     Ok(_) => println!("Success!"),
@@ -75,6 +76,7 @@
 //!     .with_any(Box::<NoopIncludeLoader>::default());
 //! let parser_options = AsyncParserOptions {
 //!     include_loader: Box::new(resolver),
+//!     ..Default::default()
 //! };
 //! let render_options = RenderOptions::default();
 //! let json = r#"<mjml>
@@ -125,6 +127,14 @@
 //! one (and WebAssembly one) can be.
 
 pub mod comment;
+pub mod diff;
+pub mod edit;
+#[cfg(feature = "arbitrary")]
+pub mod fuzz;
+#[cfg(feature = "render")]
+pub mod lint;
+#[cfg(feature = "lettre")]
+pub mod mail;
 pub mod mj_accordion;
 pub mod mj_accordion_element;
 pub mod mj_accordion_text;
@@ -133,42 +143,66 @@ pub mod mj_attributes;
 pub mod mj_attributes_all;
 pub mod mj_attributes_class;
 pub mod mj_attributes_element;
+pub mod mj_avatar;
+pub mod mj_badge;
 pub mod mj_body;
 pub mod mj_breakpoint;
 pub mod mj_button;
+pub mod mj_calendar_button;
 pub mod mj_carousel;
 pub mod mj_carousel_image;
+pub mod mj_code;
 pub mod mj_column;
 pub mod mj_divider;
 pub mod mj_font;
+pub mod mj_footer;
 pub mod mj_group;
 pub mod mj_head;
 pub mod mj_hero;
 pub mod mj_image;
 pub mod mj_include;
+pub mod mj_map;
+pub mod mj_msooffice;
 pub mod mj_navbar;
 pub mod mj_navbar_link;
+pub mod mj_not_mso;
 pub mod mj_preview;
+pub mod mj_quote;
 pub mod mj_raw;
 pub mod mj_section;
 pub mod mj_social;
 pub mod mj_social_element;
 pub mod mj_spacer;
+pub mod mj_stat;
+pub mod mj_stats;
 pub mod mj_style;
+pub mod mj_tab;
 pub mod mj_table;
+pub mod mj_tabs;
 pub mod mj_text;
 pub mod mj_title;
+#[cfg(feature = "i18n")]
+pub mod mj_trans;
 pub mod mj_wrapper;
 pub mod mjml;
 pub mod node;
 pub mod prelude;
+#[cfg(feature = "registry")]
+pub mod registry;
+#[cfg(feature = "sanitize")]
+pub mod sanitize;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod text;
 
+pub use diff::diff;
+
 // Only used to ignore the comments at the root level
 #[cfg(feature = "parse")]
 mod root;
 
-mod helper;
+pub mod helper;
+mod trace;
 
 #[cfg(feature = "parse")]
 /// Function to parse a raw mjml template with some parsing
@@ -186,6 +220,7 @@ mod helper;
 ///
 /// let options = ParserOptions {
 ///     include_loader: Box::new(MemoryIncludeLoader::default()),
+///     ..Default::default()
 /// };
 /// match mrml::parse_with_options("<mjml><mj-head /><mj-body /></mjml>", &options) {
 ///     Ok(_) => println!("Success!"),
@@ -224,6 +259,7 @@ pub fn parse_with_options<T: AsRef<str>>(
 ///
 /// let options = std::sync::Arc::new(AsyncParserOptions {
 ///     include_loader: Box::new(MemoryIncludeLoader::default()),
+///     ..Default::default()
 /// });
 /// match mrml::async_parse_with_options("<mjml><mj-head /><mj-body /></mjml>", options).await {
 ///     Ok(_) => println!("Success!"),
@@ -262,6 +298,44 @@ pub fn parse<T: AsRef<str>>(
     parse_with_options(input, &opts)
 }
 
+#[cfg(feature = "encoding")]
+/// Function to parse a raw mjml template that might not be encoded in UTF-8,
+/// with some parsing [options](crate::prelude::parser::ParserOptions). The
+/// encoding is [detected](crate::prelude::parser::encoding) from the byte order
+/// mark or the declared charset and the template is transcoded before being
+/// parsed.
+///
+/// ```rust
+/// let options = mrml::prelude::parser::ParserOptions::default();
+/// match mrml::parse_bytes_with_options(b"<mjml><mj-body>Caf\xe9</mj-body></mjml>", &options) {
+///     Ok(_) => println!("Success!"),
+///     Err(err) => eprintln!("Something went wrong: {err:?}"),
+/// }
+/// ```
+pub fn parse_bytes_with_options(
+    input: &[u8],
+    opts: &crate::prelude::parser::ParserOptions,
+) -> Result<crate::prelude::parser::ParseOutput<mjml::Mjml>, prelude::parser::Error> {
+    parse_with_options(prelude::parser::encoding::decode(input), opts)
+}
+
+#[cfg(feature = "encoding")]
+/// Function to parse a raw mjml template that might not be encoded in UTF-8,
+/// using the default parsing [options](crate::prelude::parser::ParserOptions).
+///
+/// ```rust
+/// match mrml::parse_bytes(b"<mjml><mj-body>Caf\xe9</mj-body></mjml>") {
+///     Ok(_) => println!("Success!"),
+///     Err(err) => eprintln!("Something went wrong: {err:?}"),
+/// }
+/// ```
+pub fn parse_bytes(
+    input: &[u8],
+) -> Result<prelude::parser::ParseOutput<mjml::Mjml>, prelude::parser::Error> {
+    let opts = crate::prelude::parser::ParserOptions::default();
+    parse_bytes_with_options(input, &opts)
+}
+
 #[cfg(all(feature = "parse", feature = "async"))]
 /// Function to parse a raw mjml template using the default parsing
 /// [options](crate::prelude::parser::ParserOptions).
@@ -281,6 +355,43 @@ pub async fn async_parse<T: AsRef<str>>(
     async_parse_with_options(input, opts).await
 }
 
+#[cfg(feature = "parse")]
+/// Function to parse a single element, like a `mj-section`, outside of a
+/// template, with some parsing
+/// [options](crate::prelude::parser::ParserOptions). The element can later be
+/// inserted in a document.
+pub fn parse_fragment_with_options<F, T>(
+    input: T,
+    opts: &crate::prelude::parser::ParserOptions,
+) -> Result<crate::prelude::parser::ParseOutput<F>, prelude::parser::Error>
+where
+    F: prelude::parser::fragment::Fragment,
+    T: AsRef<str>,
+{
+    prelude::parser::fragment::parse_fragment_with_options(input.as_ref(), opts)
+}
+
+#[cfg(feature = "parse")]
+/// Function to parse a single element, like a `mj-section`, using the default
+/// parsing [options](crate::prelude::parser::ParserOptions).
+///
+/// ```rust
+/// use mrml::mj_section::MjSection;
+///
+/// let section = mrml::parse_fragment::<MjSection, _>("<mj-section><mj-column /></mj-section>").unwrap();
+/// assert_eq!(section.element.children.len(), 1);
+/// ```
+pub fn parse_fragment<F, T>(
+    input: T,
+) -> Result<crate::prelude::parser::ParseOutput<F>, prelude::parser::Error>
+where
+    F: prelude::parser::fragment::Fragment,
+    T: AsRef<str>,
+{
+    let opts = crate::prelude::parser::ParserOptions::default();
+    parse_fragment_with_options(input, &opts)
+}
+
 #[cfg(all(test, feature = "parse"))]
 mod tests {
     #[test]
@@ -293,4 +404,14 @@ mod tests {
         let _ =
             crate::parse_with_options("<mjml><mj-head /><mj-body /></mjml>", &Default::default());
     }
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn parse_bytes_in_latin1() {
+        let root =
+            crate::parse_bytes(b"<mjml><mj-body><mj-text>Caf\xe9</mj-text></mj-body></mjml>")
+                .unwrap();
+        let debug = format!("{:?}", root.element);
+        assert!(debug.contains("Café"));
+    }
 }
@@ -0,0 +1,451 @@
+//! Accessibility rules.
+//!
+//! - `a11y/image-alt`: the `mj-image` and `mj-carousel-image` elements need an
+//!   `alt` attribute, that can be empty for decorative images.
+//! - `a11y/html-lang`: the `mjml` element needs a `lang` attribute.
+//! - `a11y/contrast`: the contrast ratio between the `color` of an element and
+//!   its background is lower than 4.5:1, both being resolved like when
+//!   rendering, through the `mj-attributes`, the `mj-class` and the default
+//!   values of the components.
+//! - `a11y/link-text`: the text of a link doesn't describe its target, like
+//!   "click here".
+
+use super::{Context, Finding, Rule, Severity};
+use crate::helper::color::Color;
+use crate::mjml::Mjml;
+use crate::prelude::path::ElementPath;
+use crate::prelude::render::{Header, RenderContext, RenderOptions, Renderable};
+use crate::prelude::tree::TreeElement;
+
+pub const IMAGE_ALT: &str = "a11y/image-alt";
+pub const HTML_LANG: &str = "a11y/html-lang";
+pub const CONTRAST: &str = "a11y/contrast";
+pub const LINK_TEXT: &str = "a11y/link-text";
+
+/// Minimum contrast ratio for normal text (WCAG 2.1, level AA).
+const MIN_CONTRAST_RATIO: f64 = 4.5;
+
+const VAGUE_LINK_TEXTS: [&str; 8] = [
+    "click here",
+    "click",
+    "here",
+    "link",
+    "more",
+    "read more",
+    "learn more",
+    "this",
+];
+
+pub(crate) fn rules() -> Vec<Box<dyn Rule>> {
+    vec![
+        Box::new(ImageAlt),
+        Box::new(HtmlLang),
+        Box::new(Contrast),
+        Box::new(LinkText),
+    ]
+}
+
+fn each_element<'a, F>(ctx: &Context<'a>, mut visitor: F)
+where
+    F: FnMut(&TreeElement<'a>, &ElementPath, &[&TreeElement<'a>]),
+{
+    if let Some(ref body) = ctx.body {
+        body.walk_elements(&ElementPath::body(), &mut visitor);
+    }
+}
+
+struct ImageAlt;
+
+impl Rule for ImageAlt {
+    fn name(&self) -> &'static str {
+        IMAGE_ALT
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn check(&self, ctx: &Context<'_>) -> Vec<Finding> {
+        let mut result = Vec::new();
+        each_element(ctx, |element, path, _| {
+            if matches!(element.tag, "mj-image" | "mj-carousel-image")
+                && element.attribute("alt").is_none()
+            {
+                let target = element.attribute("src").unwrap_or_default();
+                result.push(
+                    Finding::new(
+                        element.tag,
+                        format!(
+                            "image {target:?} has no alt attribute, use alt=\"\" when decorative"
+                        ),
+                    )
+                    .with_path(path.clone()),
+                );
+            }
+        });
+        result
+    }
+}
+
+struct HtmlLang;
+
+impl Rule for HtmlLang {
+    fn name(&self) -> &'static str {
+        HTML_LANG
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, ctx: &Context<'_>) -> Vec<Finding> {
+        match ctx.root.attributes.lang.as_deref() {
+            Some(value) if !value.trim().is_empty() => Vec::new(),
+            _ => vec![Finding::new(
+                crate::mjml::NAME,
+                "the lang attribute is missing on the mjml element",
+            )],
+        }
+    }
+}
+
+/// Opaque enough color to compute its contrast, `transparent` being ignored.
+fn parse_color(value: &str) -> Option<Color> {
+    Color::parse(value).ok().filter(|color| color.alpha > 0.0)
+}
+
+/// Resolves the attributes of the elements of the body like when rendering.
+struct Resolver<'a> {
+    context: RenderContext<'a>,
+}
+
+fn resolve_as<'r, C>(context: &'r RenderContext<'r>, component: &'r C, key: &str) -> Option<String>
+where
+    C: Renderable<'r, 'r>,
+{
+    let renderer = component.renderer(context);
+    let resolved = renderer.resolve_attribute(key);
+    resolved.value().map(String::from)
+}
+
+macro_rules! resolve_component {
+    ($context:expr, $element:expr, $key:expr, $($module:ident::$kind:ident),+) => {
+        match $element.tag {
+            $(crate::$module::NAME => {
+                let attributes = $element
+                    .attributes
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect();
+                let component = crate::$module::$kind::new(attributes, Default::default());
+                resolve_as($context, &component, $key)
+            })+
+            _ => $element.attribute($key).map(String::from),
+        }
+    };
+}
+
+impl<'a> Resolver<'a> {
+    fn new(root: &'a Mjml, options: &'a RenderOptions) -> Self {
+        let header = Header::new(root.head(), root.attributes.lang.as_deref());
+        Self {
+            context: RenderContext::new(options, header),
+        }
+    }
+
+    fn attribute(
+        &self,
+        element: &TreeElement<'_>,
+        ancestors: &[&TreeElement<'_>],
+        key: &str,
+    ) -> Option<String> {
+        // the mj-attributes of an included template apply in its scope
+        let scopes = ancestors
+            .iter()
+            .filter(|item| item.tag == crate::mj_include::NAME)
+            .filter_map(|item| item.attribute("scope"))
+            .collect::<Vec<_>>();
+        for scope in scopes.iter() {
+            self.context.scopes.enter(scope);
+        }
+        let result = resolve_component!(
+            &self.context,
+            element,
+            key,
+            mj_avatar::MjAvatar,
+            mj_badge::MjBadge,
+            mj_body::MjBody,
+            mj_button::MjButton,
+            mj_calendar_button::MjCalendarButton,
+            mj_code::MjCode,
+            mj_column::MjColumn,
+            mj_footer::MjFooter,
+            mj_group::MjGroup,
+            mj_hero::MjHero,
+            mj_navbar_link::MjNavbarLink,
+            mj_quote::MjQuote,
+            mj_section::MjSection,
+            mj_social_element::MjSocialElement,
+            mj_stat::MjStat,
+            mj_tab::MjTab,
+            mj_table::MjTable,
+            mj_text::MjText,
+            mj_wrapper::MjWrapper
+        );
+        for _ in scopes.iter() {
+            self.context.scopes.leave();
+        }
+        result
+    }
+
+    fn background(
+        &self,
+        element: &TreeElement<'_>,
+        ancestors: &[&TreeElement<'_>],
+    ) -> Option<String> {
+        self.attribute(element, ancestors, "background-color")
+            .or_else(|| self.attribute(element, ancestors, "container-background-color"))
+            .or_else(|| {
+                (0..ancestors.len()).rev().find_map(|index| {
+                    self.attribute(ancestors[index], &ancestors[..index], "background-color")
+                })
+            })
+    }
+}
+
+struct Contrast;
+
+impl Rule for Contrast {
+    fn name(&self) -> &'static str {
+        CONTRAST
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, ctx: &Context<'_>) -> Vec<Finding> {
+        let options = RenderOptions::default();
+        let resolver = Resolver::new(ctx.root, &options);
+        let mut result = Vec::new();
+        each_element(ctx, |element, path, ancestors| {
+            let Some(color) = resolver.attribute(element, ancestors, "color") else {
+                return;
+            };
+            let Some(background) = resolver.background(element, ancestors) else {
+                return;
+            };
+            let (Some(fg), Some(bg)) = (parse_color(&color), parse_color(&background)) else {
+                return;
+            };
+            let ratio = fg.contrast_ratio(&bg);
+            if ratio < MIN_CONTRAST_RATIO {
+                result.push(
+                    Finding::new(
+                        element.tag,
+                        format!(
+                            "low contrast between {color} and {background} ({ratio:.2}:1, expected at least {MIN_CONTRAST_RATIO}:1)"
+                        ),
+                    )
+                    .with_path(path.clone()),
+                );
+            }
+        });
+        result
+    }
+}
+
+struct LinkText;
+
+impl Rule for LinkText {
+    fn name(&self) -> &'static str {
+        LINK_TEXT
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, ctx: &Context<'_>) -> Vec<Finding> {
+        let mut result = Vec::new();
+        each_element(ctx, |element, path, _| {
+            let is_link = match element.tag {
+                "mj-button" | "mj-navbar-link" => element.attribute("href").is_some(),
+                "a" => true,
+                _ => false,
+            };
+            if !is_link {
+                return;
+            }
+            let text = element.text_content();
+            let normalized = text
+                .trim_end_matches(|c: char| c.is_ascii_punctuation())
+                .to_lowercase();
+            if VAGUE_LINK_TEXTS.contains(&normalized.as_str()) {
+                result.push(
+                    Finding::new(
+                        element.tag,
+                        format!("the link text {text:?} doesn't describe its target"),
+                    )
+                    .with_path(path.clone()),
+                );
+            }
+        });
+        result
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use super::{parse_color, Color, CONTRAST, HTML_LANG, IMAGE_ALT, LINK_TEXT};
+    use crate::lint::{Linter, Severity};
+    use crate::mjml::Mjml;
+
+    fn check(linter: &Linter, template: &str) -> Vec<(&'static str, Severity, String)> {
+        let root = Mjml::parse(template).unwrap();
+        linter
+            .check(&root.element)
+            .into_iter()
+            .map(|item| (item.rule, item.severity, item.tag))
+            .collect()
+    }
+
+    #[test]
+    fn should_parse_colors() {
+        assert_eq!(parse_color("#fff"), Some(Color::WHITE));
+        assert_eq!(parse_color("#0A0b0c"), Some(Color::rgb(10, 11, 12)));
+        assert_eq!(parse_color("rgb(1, 2, 3)"), Some(Color::rgb(1, 2, 3)));
+        assert_eq!(parse_color("White"), Some(Color::WHITE));
+        assert_eq!(parse_color("transparent"), None);
+        assert_eq!(parse_color("#12"), None);
+        let ratio = Color::BLACK.contrast_ratio(&Color::WHITE);
+        assert!((ratio - 21.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn should_accept_accessible_template() {
+        let template = r##"<mjml lang="en">
+  <mj-body background-color="#ffffff">
+    <mj-section>
+      <mj-column>
+        <mj-image src="logo.png" alt="" />
+        <mj-text color="#222222">Check <a href="https://mrml.io">the documentation</a></mj-text>
+        <mj-button href="https://mrml.io" background-color="#000000" color="#ffffff">Get started</mj-button>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"##;
+        assert!(check(&Linter::a11y(), template).is_empty());
+    }
+
+    #[test]
+    fn should_report_issues() {
+        let template = r##"<mjml>
+  <mj-body background-color="#ffffff">
+    <mj-section>
+      <mj-column>
+        <mj-image src="logo.png" />
+        <mj-carousel>
+          <mj-carousel-image src="slide.png" />
+        </mj-carousel>
+        <mj-text color="#cccccc">Hello <a href="https://mrml.io">click here</a></mj-text>
+        <mj-button href="https://mrml.io">Read more!</mj-button>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"##;
+        assert_eq!(
+            check(&Linter::a11y(), template),
+            vec![
+                (IMAGE_ALT, Severity::Error, "mj-image".to_string()),
+                (IMAGE_ALT, Severity::Error, "mj-carousel-image".to_string()),
+                (HTML_LANG, Severity::Warning, "mjml".to_string()),
+                (CONTRAST, Severity::Warning, "mj-text".to_string()),
+                (LINK_TEXT, Severity::Warning, "a".to_string()),
+                (LINK_TEXT, Severity::Warning, "mj-button".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_resolve_the_default_colors() {
+        let template = r##"<mjml lang="en">
+  <mj-body>
+    <mj-section background-color="#000">
+      <mj-column>
+        <mj-text>Hello</mj-text>
+        <mj-button href="https://mrml.io">Get started</mj-button>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"##;
+        let root = Mjml::parse(template).unwrap();
+        let found = Linter::a11y().check(&root.element);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].tag, "mj-text");
+        assert!(found[0].message.contains("#000000 and #000"));
+    }
+
+    #[test]
+    fn should_resolve_the_head_attributes() {
+        let template = r##"<mjml lang="en">
+  <mj-head>
+    <mj-attributes>
+      <mj-class name="dark" background-color="#111111" />
+      <mj-text color="#333333" />
+    </mj-attributes>
+  </mj-head>
+  <mj-body>
+    <mj-section mj-class="dark">
+      <mj-column>
+        <mj-text>Hello</mj-text>
+        <mj-text color="#ffffff">World</mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"##;
+        let root = Mjml::parse(template).unwrap();
+        let paths = Linter::a11y()
+            .check(&root.element)
+            .into_iter()
+            .filter(|item| item.rule == CONTRAST)
+            .map(|item| item.path.unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec!["/mjml/mj-body/mj-section[1]/mj-column[1]/mj-text[1]"]
+        );
+    }
+
+    #[test]
+    fn should_override_severities() {
+        let template = r#"<mjml><mj-body><mj-image src="logo.png" /></mj-body></mjml>"#;
+        let linter = Linter::a11y()
+            .with_severity(HTML_LANG, Severity::Off)
+            .with_severity(IMAGE_ALT, Severity::Warning);
+        assert_eq!(
+            check(&linter, template),
+            vec![(IMAGE_ALT, Severity::Warning, "mj-image".to_string())]
+        );
+        assert_eq!(linter.severity(CONTRAST), Some(Severity::Warning));
+        assert_eq!(linter.severity("unknown"), None);
+    }
+
+    #[test]
+    fn should_report_paths() {
+        let template = r#"<mjml lang="en"><mj-body><mj-section><mj-column><mj-image src="a.png" alt="" /><mj-image src="b.png" /><mj-text><a href="https://mrml.io">here</a></mj-text></mj-column></mj-section></mj-body></mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let paths = Linter::a11y()
+            .check(&root.element)
+            .into_iter()
+            .map(|item| item.path.unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                "/mjml/mj-body/mj-section[1]/mj-column[1]/mj-image[2]",
+                "/mjml/mj-body/mj-section[1]/mj-column[1]/mj-text[1]/a[1]",
+            ]
+        );
+    }
+}
@@ -0,0 +1,327 @@
+//! Compliance rules, checking the mentions that the anti-spam laws, like
+//! CAN-SPAM or the GDPR, require in the commercial emails.
+//!
+//! - `compliance/unsubscribe`: the email has no unsubscribe link.
+//! - `compliance/address`: the email has no physical postal address.
+//!
+//! The rules can be configured, to follow the placeholders of the emailing
+//! service or the address of the organization.
+//!
+//! ```rust
+//! # #[cfg(feature = "parse")]
+//! # {
+//! use mrml::lint::compliance::{Address, Unsubscribe};
+//! use mrml::lint::Linter;
+//!
+//! let linter = Linter::default()
+//!     .with_rule(Unsubscribe::default().with_pattern("{{manage_preferences}}"))
+//!     .with_rule(Address::new(["42 Main Street"]));
+//! # }
+//! ```
+
+use super::{Context, Finding, Rule, Severity};
+use crate::prelude::path::ElementPath;
+use crate::prelude::tree::{TreeElement, TreeNode};
+
+pub const UNSUBSCRIBE: &str = "compliance/unsubscribe";
+pub const ADDRESS: &str = "compliance/address";
+
+/// Patterns of the unsubscribe links, and of the placeholders used for them by
+/// the common emailing services.
+const UNSUBSCRIBE_PATTERNS: &[&str] = &[
+    "unsubscribe",
+    "unsub",
+    "opt-out",
+    "optout",
+    "désinscri",
+    "desinscri",
+    "désabonne",
+    "desabonne",
+];
+
+/// Words ending the street part of an address.
+const STREET_WORDS: &[&str] = &[
+    "street",
+    "st",
+    "avenue",
+    "ave",
+    "road",
+    "rd",
+    "boulevard",
+    "blvd",
+    "lane",
+    "ln",
+    "drive",
+    "dr",
+    "way",
+    "court",
+    "ct",
+    "place",
+    "pl",
+    "square",
+    "sq",
+    "rue",
+    "allée",
+    "chemin",
+    "quai",
+    "strasse",
+    "straße",
+    "via",
+    "calle",
+];
+
+pub(crate) fn rules() -> Vec<Box<dyn Rule>> {
+    vec![
+        Box::new(Unsubscribe::default()),
+        Box::new(Address::default()),
+    ]
+}
+
+fn each_element<'a, F>(ctx: &Context<'a>, mut visitor: F)
+where
+    F: FnMut(&TreeElement<'a>),
+{
+    if let Some(ref body) = ctx.body {
+        body.walk(&mut |node, _| {
+            if let TreeNode::Element(element) = node {
+                visitor(element);
+            }
+        });
+    }
+}
+
+fn body_text(ctx: &Context<'_>) -> String {
+    ctx.body
+        .as_ref()
+        .map(|body| body.text_content())
+        .unwrap_or_default()
+}
+
+/// Checks that the email contains an unsubscribe link, where either the link
+/// or its text matches one of the patterns, ignoring the case.
+#[derive(Clone, Debug)]
+pub struct Unsubscribe {
+    patterns: Vec<String>,
+}
+
+impl Default for Unsubscribe {
+    fn default() -> Self {
+        Self::new(UNSUBSCRIBE_PATTERNS.iter().copied())
+    }
+}
+
+impl Unsubscribe {
+    /// Rule with only the given patterns, replacing the default ones.
+    pub fn new<I, P>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<str>,
+    {
+        Self {
+            patterns: patterns
+                .into_iter()
+                .map(|item| item.as_ref().to_lowercase())
+                .collect(),
+        }
+    }
+
+    /// Adds a pattern, like the placeholder of the emailing service.
+    pub fn with_pattern<P: AsRef<str>>(mut self, pattern: P) -> Self {
+        self.patterns.push(pattern.as_ref().to_lowercase());
+        self
+    }
+
+    fn matches(&self, value: &str) -> bool {
+        let value = value.to_lowercase();
+        self.patterns
+            .iter()
+            .any(|pattern| value.contains(pattern.as_str()))
+    }
+}
+
+impl Rule for Unsubscribe {
+    fn name(&self) -> &'static str {
+        UNSUBSCRIBE
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn check(&self, ctx: &Context<'_>) -> Vec<Finding> {
+        let mut found = false;
+        each_element(ctx, |element| {
+            if let Some(href) = element.attribute("href") {
+                found |= self.matches(href) || self.matches(&element.text_content());
+            }
+        });
+        if found || ctx.body.is_none() {
+            return Vec::new();
+        }
+        vec![
+            Finding::new(crate::mj_body::NAME, "the email has no unsubscribe link")
+                .with_path(ElementPath::body()),
+        ]
+    }
+}
+
+/// Checks that the email contains the physical address of the sender.
+///
+/// Without patterns, the rule looks for an element with the `address` class
+/// or an `<address>` tag, or for a text looking like a street address, like
+/// `42 Main Street` or `12 rue de la Paix`, or a post office box. With
+/// patterns, the text of the email should contain one of them.
+#[derive(Clone, Debug, Default)]
+pub struct Address {
+    patterns: Vec<String>,
+}
+
+impl Address {
+    /// Rule looking for the given addresses, ignoring the case.
+    pub fn new<I, P>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<str>,
+    {
+        Self {
+            patterns: patterns
+                .into_iter()
+                .map(|item| item.as_ref().to_lowercase())
+                .collect(),
+        }
+    }
+
+    fn has_address_element(ctx: &Context<'_>) -> bool {
+        let mut found = false;
+        each_element(ctx, |element| {
+            found |= element.tag == "address"
+                || ["css-class", "class"].iter().any(|name| {
+                    element
+                        .attribute(name)
+                        .is_some_and(|value| value.split_whitespace().any(|c| c == "address"))
+                });
+        });
+        found
+    }
+}
+
+/// Looks for a number followed, in the next words, by a street word, or for
+/// a number followed by the street word in languages putting it first.
+fn looks_like_address(text: &str) -> bool {
+    let words = text
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .map(|word| word.trim_matches(|c: char| c == '.').to_lowercase())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>();
+    if text.to_lowercase().contains("p.o. box") || text.to_lowercase().contains("po box") {
+        return true;
+    }
+    words.iter().enumerate().any(|(index, word)| {
+        word.chars().next().is_some_and(|c| c.is_ascii_digit())
+            && word.chars().filter(|c| c.is_ascii_digit()).count() * 2 >= word.len()
+            && words
+                .iter()
+                .skip(index + 1)
+                .take(4)
+                .any(|next| STREET_WORDS.contains(&next.as_str()))
+    })
+}
+
+impl Rule for Address {
+    fn name(&self) -> &'static str {
+        ADDRESS
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, ctx: &Context<'_>) -> Vec<Finding> {
+        if ctx.body.is_none() {
+            return Vec::new();
+        }
+        let text = body_text(ctx);
+        let found = if self.patterns.is_empty() {
+            Self::has_address_element(ctx) || looks_like_address(&text)
+        } else {
+            let text = text.to_lowercase();
+            self.patterns
+                .iter()
+                .any(|pattern| text.contains(pattern.as_str()))
+        };
+        match found {
+            true => Vec::new(),
+            false => vec![Finding::new(
+                crate::mj_body::NAME,
+                "the email has no physical address of the sender",
+            )
+            .with_path(ElementPath::body())],
+        }
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use super::{looks_like_address, Address, Unsubscribe, ADDRESS, UNSUBSCRIBE};
+    use crate::lint::Linter;
+    use crate::mjml::Mjml;
+
+    fn check(linter: &Linter, template: &str) -> Vec<&'static str> {
+        let root = Mjml::parse(template).unwrap();
+        linter
+            .check(&root.element)
+            .iter()
+            .map(|item| item.rule)
+            .collect()
+    }
+
+    #[test]
+    fn should_accept_compliant_email() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-text>Hello</mj-text>
+    <mj-text>ACME, 42 Main Street, Springfield</mj-text>
+    <mj-text><a href="https://example.com/unsubscribe?id=1">Stop receiving these emails</a></mj-text>
+  </mj-body>
+</mjml>"#;
+        assert!(check(&Linter::compliance(), template).is_empty());
+    }
+
+    #[test]
+    fn should_flag_missing_mentions() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-button href="https://example.com">Buy now</mj-button>
+  </mj-body>
+</mjml>"#;
+        assert_eq!(
+            check(&Linter::compliance(), template),
+            vec![UNSUBSCRIBE, ADDRESS]
+        );
+    }
+
+    #[test]
+    fn should_match_link_text_and_placeholders() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-text css-class="address">ACME</mj-text>
+    <mj-button href="{{manage_url}}">Manage preferences</mj-button>
+    <mj-text><a href="*|UNSUB|*">Leave</a></mj-text>
+  </mj-body>
+</mjml>"#;
+        assert!(check(&Linter::compliance(), template).is_empty());
+        let linter = Linter::default()
+            .with_rule(Unsubscribe::new(["{{manage_url}}"]))
+            .with_rule(Address::new(["1 Infinite Loop"]));
+        assert_eq!(check(&linter, template), vec![ADDRESS]);
+    }
+
+    #[test]
+    fn should_detect_addresses() {
+        assert!(looks_like_address("ACME, 42 Main St., Springfield"));
+        assert!(looks_like_address("12 rue de la Paix, Paris"));
+        assert!(looks_like_address("PO Box 12, London"));
+        assert!(!looks_like_address("Save 20 percent on all items"));
+        assert!(!looks_like_address("Hello world"));
+    }
+}
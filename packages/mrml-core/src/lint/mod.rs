@@ -0,0 +1,349 @@
+//! Checks that can be run on a parsed template to spot the mistakes that
+//! the parser accepts, like the accessibility issues.
+//!
+//! ```rust
+//! # #[cfg(feature = "parse")]
+//! # {
+//! use mrml::lint::{Linter, Severity};
+//!
+//! let root = mrml::parse(r#"<mjml><mj-body><mj-image src="logo.png" /></mj-body></mjml>"#)
+//!     .expect("couldn't parse template");
+//! let linter = Linter::a11y().with_severity("a11y/html-lang", Severity::Off);
+//! for item in linter.check(&root.element) {
+//!     println!("{}[{}]: {}", item.severity, item.rule, item.message);
+//! }
+//! # }
+//! ```
+//!
+//! The organizations can add their own rules by implementing [`Rule`].
+//!
+//! ```rust
+//! # #[cfg(feature = "parse")]
+//! # {
+//! use mrml::lint::{Context, Finding, Linter, Rule, Severity};
+//!
+//! struct Footer;
+//!
+//! impl Rule for Footer {
+//!     fn name(&self) -> &'static str {
+//!         "acme/footer"
+//!     }
+//!
+//!     fn default_severity(&self) -> Severity {
+//!         Severity::Error
+//!     }
+//!
+//!     fn check(&self, ctx: &Context<'_>) -> Vec<Finding> {
+//!         let mut found = false;
+//!         if let Some(body) = ctx.body.as_ref() {
+//!             body.walk(&mut |node, _| {
+//!                 found |= node
+//!                     .as_element()
+//!                     .and_then(|element| element.attribute("css-class"))
+//!                     .is_some_and(|value| value == "footer");
+//!             });
+//!         }
+//!         match found {
+//!             true => Vec::new(),
+//!             false => vec![Finding::new("mj-body", "the footer is missing")],
+//!         }
+//!     }
+//! }
+//!
+//! let root = mrml::parse("<mjml><mj-body></mj-body></mjml>").unwrap();
+//! let linter = Linter::default().with_rule(Footer);
+//! assert_eq!(linter.check(&root.element)[0].rule, "acme/footer");
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::mjml::Mjml;
+use crate::prelude::path::ElementPath;
+use crate::prelude::tree::TreeElement;
+
+pub mod a11y;
+pub mod compliance;
+pub mod spam;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Off,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidSeverity(pub String);
+
+impl Display for InvalidSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid severity {:?}, expected off, warning or error",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for InvalidSeverity {}
+
+impl FromStr for Severity {
+    type Err = InvalidSeverity;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "off" => Ok(Self::Off),
+            "warning" | "warn" => Ok(Self::Warning),
+            "error" => Ok(Self::Error),
+            other => Err(InvalidSeverity(other.to_string())),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Name of the rule that reported the issue, like `a11y/image-alt`.
+    pub rule: &'static str,
+    pub severity: Severity,
+    pub message: String,
+    /// Tag of the element where the issue has been found.
+    pub tag: String,
+    /// Path of the element where the issue has been found, when it's in the
+    /// body.
+    pub path: Option<ElementPath>,
+    /// Weight of the issue for the rules computing a score, like the spam
+    /// rules, 0 otherwise.
+    pub score: u32,
+}
+
+/// Sums the scores of the diagnostics. With the spam rules, the higher the
+/// score, the more likely the email is to be flagged as spam.
+pub fn total_score(diagnostics: &[Diagnostic]) -> u32 {
+    diagnostics.iter().map(|item| item.score).sum()
+}
+
+/// Issue reported by a rule, before its severity is resolved.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finding {
+    pub tag: String,
+    pub path: Option<ElementPath>,
+    pub message: String,
+    pub score: u32,
+}
+
+impl Finding {
+    pub fn new<T: Into<String>, M: Into<String>>(tag: T, message: M) -> Self {
+        Self {
+            tag: tag.into(),
+            path: None,
+            message: message.into(),
+            score: 0,
+        }
+    }
+
+    pub fn with_path(mut self, path: ElementPath) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    pub fn with_score(mut self, score: u32) -> Self {
+        self.score = score;
+        self
+    }
+}
+
+/// Template checked by the rules.
+pub struct Context<'a> {
+    pub root: &'a Mjml,
+    /// Tree of the body, to walk through its elements.
+    pub body: Option<TreeElement<'a>>,
+}
+
+/// Check run by a [`Linter`] on the templates.
+pub trait Rule {
+    /// Name of the rule, prefixed by its group, like `a11y/image-alt`. It's
+    /// used to override its severity.
+    fn name(&self) -> &'static str;
+    fn default_severity(&self) -> Severity;
+    fn check(&self, ctx: &Context<'_>) -> Vec<Finding>;
+}
+
+/// Set of rules, with their severities, to run on a template.
+#[derive(Default)]
+pub struct Linter {
+    rules: Vec<Box<dyn Rule>>,
+    severities: HashMap<String, Severity>,
+}
+
+impl std::fmt::Debug for Linter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Linter")
+            .field("rules", &self.rule_names())
+            .field("severities", &self.severities)
+            .finish()
+    }
+}
+
+impl Linter {
+    /// Linter with all the accessibility rules.
+    pub fn a11y() -> Self {
+        Self::default().with_a11y_rules()
+    }
+
+    /// Linter with all the deliverability rules.
+    pub fn spam() -> Self {
+        Self::default().with_spam_rules()
+    }
+
+    /// Linter with the unsubscribe link and postal address rules.
+    pub fn compliance() -> Self {
+        Self::default().with_compliance_rules()
+    }
+
+    pub fn with_a11y_rules(mut self) -> Self {
+        self.rules.extend(a11y::rules());
+        self
+    }
+
+    pub fn with_spam_rules(mut self) -> Self {
+        self.rules.extend(spam::rules());
+        self
+    }
+
+    pub fn with_compliance_rules(mut self) -> Self {
+        self.rules.extend(compliance::rules());
+        self
+    }
+
+    /// Adds a rule, like a custom one.
+    pub fn with_rule<R: Rule + 'static>(mut self, rule: R) -> Self {
+        self.add_rule(rule);
+        self
+    }
+
+    pub fn add_rule<R: Rule + 'static>(&mut self, rule: R) {
+        self.rules.push(Box::new(rule));
+    }
+
+    pub fn rule_names(&self) -> Vec<&'static str> {
+        self.rules.iter().map(|rule| rule.name()).collect()
+    }
+
+    /// Overrides the severity of a rule. [`Severity::Off`] disables it.
+    pub fn with_severity<N: Into<String>>(mut self, name: N, severity: Severity) -> Self {
+        self.set_severity(name, severity);
+        self
+    }
+
+    pub fn set_severity<N: Into<String>>(&mut self, name: N, severity: Severity) {
+        self.severities.insert(name.into(), severity);
+    }
+
+    pub fn severity(&self, name: &str) -> Option<Severity> {
+        self.severities.get(name).copied().or_else(|| {
+            self.rules
+                .iter()
+                .find(|rule| rule.name() == name)
+                .map(|rule| rule.default_severity())
+        })
+    }
+
+    pub fn check(&self, root: &Mjml) -> Vec<Diagnostic> {
+        let ctx = Context {
+            root,
+            body: root.body_tree(),
+        };
+        let mut result = Vec::new();
+        for rule in self.rules.iter() {
+            let severity = self
+                .severities
+                .get(rule.name())
+                .copied()
+                .unwrap_or_else(|| rule.default_severity());
+            if severity == Severity::Off {
+                continue;
+            }
+            result.extend(rule.check(&ctx).into_iter().map(|item| Diagnostic {
+                rule: rule.name(),
+                severity,
+                message: item.message,
+                tag: item.tag,
+                path: item.path,
+                score: item.score,
+            }));
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Context, Finding, Linter, Rule, Severity};
+
+    struct Title;
+
+    impl Rule for Title {
+        fn name(&self) -> &'static str {
+            "custom/title"
+        }
+
+        fn default_severity(&self) -> Severity {
+            Severity::Warning
+        }
+
+        fn check(&self, ctx: &Context<'_>) -> Vec<Finding> {
+            match ctx.root.head().and_then(|head| head.title()) {
+                Some(_) => Vec::new(),
+                None => vec![Finding::new("mj-head", "the title is missing").with_score(2)],
+            }
+        }
+    }
+
+    #[test]
+    fn should_parse_severity() {
+        assert_eq!("off".parse::<Severity>(), Ok(Severity::Off));
+        assert_eq!("warn".parse::<Severity>(), Ok(Severity::Warning));
+        assert_eq!("error".parse::<Severity>(), Ok(Severity::Error));
+        assert!("fatal".parse::<Severity>().is_err());
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_run_custom_rules() {
+        let root = crate::parse("<mjml><mj-body></mj-body></mjml>").unwrap();
+        let mut linter = Linter::a11y().with_rule(Title);
+        assert!(linter.rule_names().contains(&"custom/title"));
+        assert_eq!(linter.severity("custom/title"), Some(Severity::Warning));
+        let result = linter.check(&root.element);
+        let found = result
+            .iter()
+            .find(|item| item.rule == "custom/title")
+            .unwrap();
+        assert_eq!(found.severity, Severity::Warning);
+        assert_eq!(found.score, 2);
+        linter.set_severity("custom/title", Severity::Off);
+        assert!(linter
+            .check(&root.element)
+            .iter()
+            .all(|item| item.rule != "custom/title"));
+    }
+}
@@ -0,0 +1,233 @@
+//! Deliverability rules, flagging what the spam filters usually penalize.
+//! Each finding has a score, see [`total_score`](super::total_score).
+//!
+//! - `spam/image-ratio`: the email is mostly made of images.
+//! - `spam/title-caps`: the title is written in capital letters.
+//! - `spam/punctuation`: the title or the text contains repeated exclamation or
+//!   question marks.
+//! - `spam/plain-text`: the email has no text, so its plain text version would
+//!   be empty.
+
+use super::{Context, Finding, Rule, Severity};
+use crate::prelude::path::ElementPath;
+use crate::prelude::tree::TreeNode;
+
+pub const IMAGE_RATIO: &str = "spam/image-ratio";
+pub const TITLE_CAPS: &str = "spam/title-caps";
+pub const PUNCTUATION: &str = "spam/punctuation";
+pub const PLAIN_TEXT: &str = "spam/plain-text";
+
+/// Minimum number of words expected for each image.
+const MIN_WORDS_PER_IMAGE: usize = 40;
+
+pub(crate) fn rules() -> Vec<Box<dyn Rule>> {
+    vec![
+        Box::new(ImageRatio),
+        Box::new(TitleCaps),
+        Box::new(Punctuation),
+        Box::new(PlainText),
+    ]
+}
+
+fn body_text(ctx: &Context<'_>) -> String {
+    ctx.body
+        .as_ref()
+        .map(|body| body.text_content())
+        .unwrap_or_default()
+}
+
+fn count_images(ctx: &Context<'_>) -> usize {
+    let mut count = 0;
+    if let Some(ref body) = ctx.body {
+        body.walk(&mut |node, _| {
+            if let TreeNode::Element(element) = node {
+                if matches!(element.tag, "mj-image" | "mj-carousel-image" | "img") {
+                    count += 1;
+                }
+            }
+        });
+    }
+    count
+}
+
+fn has_repeated_punctuation(value: &str) -> bool {
+    let mut run = 0;
+    for c in value.chars() {
+        if c == '!' || c == '?' {
+            run += 1;
+            if run >= 2 {
+                return true;
+            }
+        } else {
+            run = 0;
+        }
+    }
+    false
+}
+
+struct ImageRatio;
+
+impl Rule for ImageRatio {
+    fn name(&self) -> &'static str {
+        IMAGE_RATIO
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, ctx: &Context<'_>) -> Vec<Finding> {
+        let images = count_images(ctx);
+        let words = body_text(ctx).split_whitespace().count();
+        if images == 0 || words >= images * MIN_WORDS_PER_IMAGE {
+            return Vec::new();
+        }
+        vec![Finding::new(
+            crate::mj_body::NAME,
+            format!("{images} image(s) for {words} word(s), expected at least {MIN_WORDS_PER_IMAGE} words per image"),
+        )
+        .with_path(ElementPath::body())
+        .with_score(if words == 0 { 3 } else { 2 })]
+    }
+}
+
+struct TitleCaps;
+
+impl Rule for TitleCaps {
+    fn name(&self) -> &'static str {
+        TITLE_CAPS
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, ctx: &Context<'_>) -> Vec<Finding> {
+        let Some(title) = ctx.root.get_title() else {
+            return Vec::new();
+        };
+        let letters = title.chars().filter(|c| c.is_alphabetic()).count();
+        let uppercase = title.chars().filter(|c| c.is_uppercase()).count();
+        // a short title, like an acronym, is not considered
+        if letters < 4 || uppercase * 10 < letters * 7 {
+            return Vec::new();
+        }
+        vec![Finding::new(
+            crate::mj_title::NAME,
+            format!("the title {title:?} is written in capital letters"),
+        )
+        .with_score(2)]
+    }
+}
+
+struct Punctuation;
+
+impl Rule for Punctuation {
+    fn name(&self) -> &'static str {
+        PUNCTUATION
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, ctx: &Context<'_>) -> Vec<Finding> {
+        let mut result = Vec::new();
+        if let Some(title) = ctx.root.get_title().filter(|value| has_repeated_punctuation(value)) {
+            result.push(
+                Finding::new(
+                    crate::mj_title::NAME,
+                    format!("the title {title:?} contains repeated punctuation"),
+                )
+                .with_score(1),
+            );
+        }
+        if has_repeated_punctuation(&body_text(ctx)) {
+            result.push(
+                Finding::new(
+                    crate::mj_body::NAME,
+                    "the text contains repeated punctuation",
+                )
+                .with_path(ElementPath::body())
+                .with_score(1),
+            );
+        }
+        result
+    }
+}
+
+struct PlainText;
+
+impl Rule for PlainText {
+    fn name(&self) -> &'static str {
+        PLAIN_TEXT
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, ctx: &Context<'_>) -> Vec<Finding> {
+        if !body_text(ctx).is_empty() {
+            return Vec::new();
+        }
+        vec![Finding::new(
+            crate::mj_body::NAME,
+            "the email has no text, its plain text version would be empty",
+        )
+        .with_path(ElementPath::body())
+        .with_score(3)]
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use super::{IMAGE_RATIO, PLAIN_TEXT, PUNCTUATION, TITLE_CAPS};
+    use crate::lint::{total_score, Linter};
+    use crate::mjml::Mjml;
+
+    fn check(template: &str) -> (Vec<&'static str>, u32) {
+        let root = Mjml::parse(template).unwrap();
+        let diagnostics = Linter::spam().check(&root.element);
+        let rules = diagnostics.iter().map(|item| item.rule).collect();
+        (rules, total_score(&diagnostics))
+    }
+
+    #[test]
+    fn should_accept_regular_email() {
+        let template = format!(
+            r#"<mjml>
+  <mj-head><mj-title>Our monthly newsletter</mj-title></mj-head>
+  <mj-body>
+    <mj-image src="https://example.com/banner.png" alt="" />
+    <mj-text>{}</mj-text>
+  </mj-body>
+</mjml>"#,
+            "Some interesting news. ".repeat(20)
+        );
+        assert_eq!(check(&template), (Vec::new(), 0));
+    }
+
+    #[test]
+    fn should_flag_image_only_email() {
+        let template = r#"<mjml>
+  <mj-head><mj-title>HUGE SALE TODAY!!!</mj-title></mj-head>
+  <mj-body>
+    <mj-image src="https://example.com/sale.png" />
+  </mj-body>
+</mjml>"#;
+        assert_eq!(
+            check(template),
+            (vec![IMAGE_RATIO, TITLE_CAPS, PUNCTUATION, PLAIN_TEXT], 9)
+        );
+    }
+
+    #[test]
+    fn should_ignore_short_acronyms() {
+        let template = r#"<mjml>
+  <mj-head><mj-title>FAQ</mj-title></mj-head>
+  <mj-body><mj-text>Your questions, answered.</mj-text></mj-body>
+</mjml>"#;
+        assert_eq!(check(template), (Vec::new(), 0));
+    }
+}
@@ -0,0 +1,110 @@
+//! Module building emails, ready to be sent with [`lettre`], from a template.
+//!
+//! ```rust
+//! # #[cfg(feature = "parse")]
+//! # {
+//! use mrml::mail::build_message;
+//! use mrml::prelude::render::RenderOptions;
+//!
+//! let root = mrml::parse(
+//!     "<mjml><mj-head><mj-title>Welcome</mj-title></mj-head><mj-body><mj-text>Hello!</mj-text></mj-body></mjml>",
+//! )
+//! .expect("couldn't parse template");
+//! let builder = lettre::Message::builder()
+//!     .from("Sender <sender@example.com>".parse().unwrap())
+//!     .to("Receiver <receiver@example.com>".parse().unwrap());
+//! let message = build_message(&root.element, &RenderOptions::default(), builder)
+//!     .expect("couldn't build message");
+//! # }
+//! ```
+
+use lettre::message::{MessageBuilder, MultiPart};
+use lettre::Message;
+
+use crate::mjml::Mjml;
+use crate::prelude::render::{html_to_plain_text, RenderOptions};
+
+#[derive(Debug, thiserror::Error)]
+pub enum MailError {
+    #[error("unable to render template")]
+    Render(#[from] crate::prelude::render::Error),
+    #[error("unable to build message")]
+    Message(#[from] lettre::error::Error),
+}
+
+/// Renders the template and builds a `multipart/alternative` message
+/// containing the html version and a plain text version generated from it.
+///
+/// When the template has an `mj-title` element, it's used as the subject of
+/// the message. The other headers (`From`, `To`...) have to be set on the
+/// provided `builder`.
+pub fn build_message(
+    template: &Mjml,
+    options: &RenderOptions,
+    builder: MessageBuilder,
+) -> Result<Message, MailError> {
+    let html = template.render(options)?;
+    let text = html_to_plain_text(&html);
+    let builder = match template.get_title() {
+        Some(title) => builder.subject(title.trim()),
+        None => builder,
+    };
+    Ok(builder.multipart(MultiPart::alternative_plain_html(text, html))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use lettre::message::MessageBuilder;
+
+    use super::build_message;
+    use crate::prelude::render::RenderOptions;
+
+    const TEMPLATE: &str = r#"<mjml>
+  <mj-head>
+    <mj-title>Welcome aboard</mj-title>
+  </mj-head>
+  <mj-body>
+    <mj-section><mj-column>
+      <mj-text>Hello World!</mj-text>
+    </mj-column></mj-section>
+  </mj-body>
+</mjml>"#;
+
+    fn builder() -> MessageBuilder {
+        lettre::Message::builder()
+            .from("Sender <sender@example.com>".parse().unwrap())
+            .to("Receiver <receiver@example.com>".parse().unwrap())
+    }
+
+    #[test]
+    fn should_build_message() {
+        let root = crate::parse(TEMPLATE).unwrap();
+        let message = build_message(&root.element, &RenderOptions::default(), builder()).unwrap();
+        let formatted = String::from_utf8(message.formatted()).unwrap();
+        assert!(formatted.contains("Subject: Welcome aboard"));
+        assert!(formatted.contains("multipart/alternative"));
+        assert!(formatted.contains("text/plain"));
+        assert!(formatted.contains("text/html"));
+    }
+
+    #[test]
+    fn should_build_message_without_title() {
+        let root =
+            crate::parse("<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>").unwrap();
+        let message = build_message(&root.element, &RenderOptions::default(), builder()).unwrap();
+        let formatted = String::from_utf8(message.formatted()).unwrap();
+        assert!(!formatted.contains("Subject:"));
+        assert!(formatted.contains("Hello"));
+    }
+
+    #[test]
+    fn should_fail_without_sender() {
+        let root = crate::parse(TEMPLATE).unwrap();
+        let result = build_message(
+            &root.element,
+            &RenderOptions::default(),
+            lettre::Message::builder(),
+        );
+        assert!(result.is_err());
+    }
+}
@@ -1,5 +1,6 @@
 use super::{MjAccordion, MjAccordionChild, NAME};
 use crate::helper::size::{Pixel, Size};
+use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
 const CHILDREN_ATTRIBUTES: [&str; 9] = [
@@ -61,6 +62,10 @@ impl<'root> Render<'root> for Renderer<'root, MjAccordion, ()> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
     fn tag(&self) -> Option<&str> {
         Some(NAME)
     }
@@ -94,10 +99,12 @@ impl<'root> Render<'root> for Renderer<'root, MjAccordion, ()> {
             .add_style("border-collapse", "collapse")
             .maybe_add_style("border", self.attribute("border"))
             .add_style("border-bottom", "none")
-            .maybe_add_style("font-family", self.attribute("font-family"))
+            .maybe_add_style("font-family", self.attribute_font_family("font-family"))
             .add_attribute("cellspacing", "0")
             .add_attribute("cellpadding", "0")
+            .maybe_add_attribute("role", self.context.options.aria.presentation_role())
             .add_class("mj-accordion");
+        let table = self.passthrough_attributes(table);
 
         table.render_open(&mut cursor.buffer)?;
         tbody.render_open(&mut cursor.buffer)?;
@@ -148,4 +155,21 @@ mod tests {
     crate::should_render!(font_padding, "mj-accordion-font-padding");
     crate::should_render!(icon, "mj-accordion-icon");
     crate::should_render!(other, "mj-accordion-other");
+
+    #[test]
+    fn should_add_presentation_role() {
+        let opts = crate::prelude::render::RenderOptions {
+            aria: crate::prelude::render::AriaOptions {
+                presentation_tables: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let template = include_str!("../../resources/compare/success/mj-accordion.mjml");
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&opts).unwrap();
+        let tables = result.matches("<table").count();
+        assert!(tables > 0);
+        assert_eq!(result.matches(r#"role="presentation""#).count(), tables);
+    }
 }
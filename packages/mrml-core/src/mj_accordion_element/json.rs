@@ -37,7 +37,9 @@ impl Serialize for MjAccordionElementChildren {
     where
         S: Serializer,
     {
-        let mut map = serializer.serialize_seq(Some(2))?;
+        let mut map = serializer.serialize_seq(Some(
+            self.title.is_some() as usize + self.text.is_some() as usize,
+        ))?;
         if let Some(ref title) = self.title {
             map.serialize_element(title)?;
         }
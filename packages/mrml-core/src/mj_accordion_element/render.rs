@@ -76,6 +76,10 @@ impl<'root> Render<'root> for Renderer<'root, MjAccordionElement, MjAccordionEle
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
     fn tag(&self) -> Option<&str> {
         Some(NAME)
     }
@@ -85,26 +89,24 @@ impl<'root> Render<'root> for Renderer<'root, MjAccordionElement, MjAccordionEle
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
-        let input = Tag::new("input")
-            .add_attribute("type", "checkbox")
-            .add_class("mj-accordion-checkbox")
-            .add_style("display", "none");
+        let toggle = Toggle::checkbox("mj-accordion-checkbox");
+        let input = toggle.input().add_style("display", "none");
         let div = Tag::div();
-        let label = Tag::new("label")
+        let label = toggle
+            .label()
             .add_class("mj-accordion-element")
             .add_style("font-size", "13px")
-            .maybe_add_style("font-family", self.attribute("font-family"));
+            .maybe_add_style("font-family", self.attribute_font_family("font-family"));
         let td = Tag::td()
             .add_style("padding", "0px")
             .maybe_add_style("background-color", self.attribute("background-color"));
         let tr = Tag::tr().maybe_add_class(self.attribute("css-class"));
+        let tr = self.passthrough_attributes(tr);
 
         tr.render_open(&mut cursor.buffer)?;
         td.render_open(&mut cursor.buffer)?;
         label.render_open(&mut cursor.buffer)?;
-        cursor.buffer.start_negation_conditional_tag();
-        input.render_closed(&mut cursor.buffer)?;
-        cursor.buffer.end_negation_conditional_tag();
+        toggle.render_input(&input, &mut cursor.buffer, InputGate::NotMsoNorIe)?;
         div.render_open(&mut cursor.buffer)?;
         self.render_children(cursor)?;
         div.render_close(&mut cursor.buffer);
@@ -12,7 +12,7 @@ impl<'root> Renderer<'root, MjAccordionText, MjAccordionTextExtra<'root>> {
             .maybe_add_class(self.attribute("css-class"))
             .maybe_add_style("background", self.attribute("background-color"))
             .maybe_add_style("font-size", self.attribute("font-size"))
-            .maybe_add_style("font-family", self.attribute("font-family"))
+            .maybe_add_style("font-family", self.attribute_font_family("font-family"))
             .maybe_add_style("line-height", self.attribute("line-height"))
             .maybe_add_style("color", self.attribute("color"))
             .maybe_add_style("padding-top", self.attribute("padding-top"))
@@ -20,6 +20,7 @@ impl<'root> Renderer<'root, MjAccordionText, MjAccordionTextExtra<'root>> {
             .maybe_add_style("padding-bottom", self.attribute("padding-bottom"))
             .maybe_add_style("padding-left", self.attribute("padding-left"))
             .maybe_add_style("padding", self.attribute("padding"));
+        let td = self.passthrough_attributes(td);
 
         td.render_open(&mut cursor.buffer)?;
         for child in self.element.children.iter() {
@@ -54,6 +55,10 @@ impl<'root> Render<'root> for Renderer<'root, MjAccordionText, MjAccordionTextEx
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
     fn tag(&self) -> Option<&str> {
         Some(NAME)
     }
@@ -71,6 +76,7 @@ impl<'root> Render<'root> for Renderer<'root, MjAccordionText, MjAccordionTextEx
         let table = Tag::table()
             .add_attribute("cellspacing", "0")
             .add_attribute("cellpadding", "0")
+            .maybe_add_attribute("role", self.context.options.aria.presentation_role())
             .add_style("width", "100%")
             .maybe_add_style("border-bottom", self.attribute("border"));
         let div = Tag::div().add_class("mj-accordion-content");
@@ -1,3 +1,19 @@
+use super::MjAttributesAttributes;
+use crate::prelude::json::JsonAttributes;
+
+impl JsonAttributes for MjAttributesAttributes {
+    fn has_attributes(&self) -> bool {
+        self.scope.is_some()
+    }
+
+    fn try_from_serde<Err: serde::de::Error>(this: Option<Self>) -> Result<Self, Err>
+    where
+        Self: Sized,
+    {
+        Ok(this.unwrap_or_default())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::mj_attributes::{MjAttributes, MjAttributesChild};
@@ -33,4 +49,12 @@ mod tests {
         let next = serde_json::to_string(&res).unwrap();
         assert_eq!(next, json);
     }
+    #[test]
+    fn with_scope() {
+        let json = r#"{"type":"mj-attributes","attributes":{"scope":"card"},"children":[{"type":"mj-all"}]}"#;
+        let res: MjAttributes = serde_json::from_str(json).unwrap();
+        assert_eq!(res.scope(), Some("card"));
+        let next = serde_json::to_string(&res).unwrap();
+        assert_eq!(next, json);
+    }
 }
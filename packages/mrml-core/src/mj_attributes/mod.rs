@@ -14,6 +14,16 @@ use crate::prelude::{Component, StaticTag};
 
 pub const NAME: &str = "mj-attributes";
 
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+pub struct MjAttributesAttributes {
+    /// Name of the scope the attributes are restricted to. They only apply to
+    /// the content of the `mj-include` elements with the same `scope`, and
+    /// that content ignores the attributes without scope.
+    #[cfg_attr(feature = "json", serde(skip_serializing_if = "Option::is_none"))]
+    pub scope: Option<String>,
+}
+
 pub struct MjAttributesTag;
 
 impl StaticTag for MjAttributesTag {
@@ -22,7 +32,8 @@ impl StaticTag for MjAttributesTag {
     }
 }
 
-pub type MjAttributes = Component<PhantomData<MjAttributesTag>, (), Vec<MjAttributesChild>>;
+pub type MjAttributes =
+    Component<PhantomData<MjAttributesTag>, MjAttributesAttributes, Vec<MjAttributesChild>>;
 
 #[cfg(feature = "render")]
 impl MjAttributes {
@@ -65,6 +76,10 @@ impl MjAttributes {
 }
 
 impl MjAttributes {
+    pub fn scope(&self) -> Option<&str> {
+        self.attributes.scope.as_deref()
+    }
+
     pub fn children(&self) -> &Vec<MjAttributesChild> {
         &self.children
     }
@@ -1,14 +1,49 @@
 use xmlparser::StrSpan;
 
-use super::MjAttributesChild;
+use super::{MjAttributesAttributes, MjAttributesChild};
 use crate::mj_attributes_all::NAME as MJ_ALL;
 use crate::mj_attributes_class::NAME as MJ_CLASS;
 #[cfg(feature = "async")]
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
-    Error, MrmlCursor, MrmlParser, MrmlToken, ParseChildren, ParseElement,
+    Error, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren, ParseElement,
+    WarningKind,
 };
 
+#[inline]
+fn parse_attributes(cursor: &mut MrmlCursor<'_>) -> Result<MjAttributesAttributes, Error> {
+    let mut result = MjAttributesAttributes::default();
+    while let Some(attr) = cursor.next_attribute()? {
+        if attr.local.as_str() == "scope" {
+            result.scope = Some(attr.value.to_string());
+        } else {
+            cursor.add_warning(WarningKind::UnexpectedAttribute, attr.span);
+        }
+    }
+    Ok(result)
+}
+
+impl<'opts> ParseAttributes<MjAttributesAttributes> for MrmlParser<'opts> {
+    fn parse_attributes(
+        &self,
+        cursor: &mut MrmlCursor<'_>,
+        _tag: &StrSpan<'_>,
+    ) -> Result<MjAttributesAttributes, Error> {
+        parse_attributes(cursor)
+    }
+}
+
+#[cfg(feature = "async")]
+impl ParseAttributes<MjAttributesAttributes> for AsyncMrmlParser {
+    fn parse_attributes(
+        &self,
+        cursor: &mut MrmlCursor<'_>,
+        _tag: &StrSpan<'_>,
+    ) -> Result<MjAttributesAttributes, Error> {
+        parse_attributes(cursor)
+    }
+}
+
 impl<'opts> ParseElement<MjAttributesChild> for MrmlParser<'opts> {
     fn parse<'a>(
         &self,
@@ -109,4 +144,16 @@ mod tests {
     </mj-attributes>
 "#
     );
+    crate::should_sync_parse!(
+        with_scope,
+        MjAttributes,
+        r#"<mj-attributes scope="card"><mj-all color="red" /></mj-attributes>"#
+    );
+
+    crate::should_sync_parse!(
+        unexpected_attributes,
+        MjAttributes,
+        r#"<mj-attributes name="card"><mj-all color="red" /></mj-attributes>"#,
+        1
+    );
 }
@@ -1,3 +1,14 @@
+use crate::prelude::print::PrintableAttributes;
+
+impl PrintableAttributes for super::MjAttributesAttributes {
+    fn print<P: crate::prelude::print::Printer>(&self, printer: &mut P) -> std::fmt::Result {
+        if let Some(ref scope) = self.scope {
+            printer.push_attribute("scope", scope.as_str())?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::print::Printable;
@@ -7,4 +18,13 @@ mod tests {
         let item = crate::mj_attributes::MjAttributes::default();
         assert_eq!("<mj-attributes />", item.print_dense().unwrap());
     }
+    #[test]
+    fn with_scope() {
+        let mut item = crate::mj_attributes::MjAttributes::default();
+        item.attributes.scope = Some("card".into());
+        assert_eq!(
+            "<mj-attributes scope=\"card\" />",
+            item.print_dense().unwrap()
+        );
+    }
 }
@@ -14,7 +14,7 @@ impl Serialize for MjAttributesElement {
     where
         S: Serializer,
     {
-        let mut map = serializer.serialize_map(Some(3))?;
+        let mut map = serializer.serialize_map(Some(2 + !self.attributes.is_empty() as usize))?;
         map.serialize_entry("type", NAME)?;
         map.serialize_entry("name", self.name())?;
         if !self.attributes.is_empty() {
@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_avatar::MjAvatar;
+
+    #[test]
+    fn serialize() {
+        let mut elt = MjAvatar::default();
+        elt.attributes.insert("name".into(), "Ada Lovelace".into());
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r#"{"type":"mj-avatar","attributes":{"name":"Ada Lovelace"}}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{"type":"mj-avatar","attributes":{"name":"Ada Lovelace","src":"https://example.com/ada.png"}}"#;
+        let res: MjAvatar = serde_json::from_str(json).unwrap();
+        assert_eq!(res.attributes.len(), 2);
+        assert_eq!(
+            res.attributes.get("src").unwrap(),
+            "https://example.com/ada.png"
+        );
+    }
+}
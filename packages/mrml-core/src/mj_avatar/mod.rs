@@ -0,0 +1,66 @@
+//! Module containing the `mj-avatar` element, rendering the rounded picture
+//! of a person, like the senders listed in a digest of notifications.
+//!
+//! Without a `src`, or for the authors preferring it, the initials of the
+//! `name` are rendered in a cell filled with the `background-color`, which
+//! stays readable as a square in the clients ignoring the `border-radius`.
+//!
+//! ```rust
+//! let template = r##"<mjml><mj-body><mj-section><mj-column>
+//!   <mj-avatar name="Ada Lovelace" src="https://example.com/ada.png" />
+//!   <mj-avatar name="Alan Turing" background-color="#2e86de" size="32px" />
+//! </mj-column></mj-section></mj-body></mjml>"##;
+//! let root = mrml::parse(template).expect("parse template");
+//! let opts = mrml::prelude::render::Options::default();
+//! match root.element.render(&opts) {
+//!     Ok(content) => println!("{content}"),
+//!     Err(_) => println!("couldn't render mjml template"),
+//! };
+//! ```
+
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "print")]
+mod print;
+#[cfg(feature = "render")]
+mod render;
+
+use std::marker::PhantomData;
+
+use crate::prelude::hash::Map;
+use crate::prelude::{Component, StaticTag};
+
+pub const NAME: &str = "mj-avatar";
+
+pub struct MjAvatarTag;
+
+impl StaticTag for MjAvatarTag {
+    fn static_tag() -> &'static str {
+        NAME
+    }
+}
+
+pub type MjAvatar = Component<PhantomData<MjAvatarTag>, Map<String, String>, ()>;
+
+/// Initials of a name, made of the first letters of its first and last
+/// words.
+///
+/// ```rust
+/// use mrml::mj_avatar::initials;
+///
+/// assert_eq!(initials("Ada Lovelace"), "AL");
+/// assert_eq!(initials("grace brewster murray hopper"), "GH");
+/// assert_eq!(initials("Plato"), "P");
+/// ```
+pub fn initials(name: &str) -> String {
+    let mut words = name
+        .split_whitespace()
+        .filter_map(|word| word.chars().find(|c| c.is_alphanumeric()));
+    let first = words.next();
+    let last = words.next_back();
+    first
+        .into_iter()
+        .chain(last)
+        .flat_map(char::to_uppercase)
+        .collect()
+}
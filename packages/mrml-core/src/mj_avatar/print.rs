@@ -0,0 +1,15 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::print::Printable;
+
+    #[test]
+    fn empty() {
+        let mut item = crate::mj_avatar::MjAvatar::default();
+        item.attributes
+            .insert("name".to_string(), "Ada Lovelace".into());
+        assert_eq!(
+            "<mj-avatar name=\"Ada Lovelace\" />",
+            item.print_dense().unwrap()
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use super::{initials, MjAvatar, NAME};
+use crate::helper::size::Pixel;
+use crate::prelude::hash::Map;
+use crate::prelude::render::*;
+
+impl<'root> Renderer<'root, MjAvatar, ()> {
+    fn get_size(&self) -> Option<Pixel> {
+        self.attribute_as_pixel("size")
+    }
+
+    /// Font size of the initials, proportional to the size of the avatar by
+    /// default.
+    fn get_font_size(&self, size: Pixel) -> String {
+        match self.attribute("font-size") {
+            Some(value) => value.to_string(),
+            None => Pixel::new((size.value() * 0.4).round()).to_string(),
+        }
+    }
+
+    fn render_link<F>(&self, buf: &mut RenderBuffer, cb: F) -> std::fmt::Result
+    where
+        F: FnOnce(&mut RenderBuffer) -> std::fmt::Result,
+    {
+        match self.attribute("href") {
+            Some(href) => Tag::new("a")
+                .add_attribute("href", href)
+                .maybe_add_attribute("rel", self.attribute("rel"))
+                .maybe_add_attribute("target", self.attribute("target"))
+                .maybe_add_style("color", self.attribute("color"))
+                .add_style("text-decoration", "none")
+                .render_with(buf, cb),
+            None => cb(buf),
+        }
+    }
+
+    fn render_image(&self, src: &str, size: Pixel, buf: &mut RenderBuffer) -> std::fmt::Result {
+        let dimension = size.value().to_string();
+        let img = Tag::new("img")
+            .maybe_add_attribute("alt", self.attribute("alt").or(self.attribute("name")))
+            .add_attribute("height", dimension.clone())
+            .add_attribute("src", src)
+            .maybe_add_attribute("title", self.attribute("title"))
+            .add_attribute("width", dimension)
+            .add_style("border", "0")
+            .maybe_add_style("border-radius", self.attribute("border-radius"))
+            .add_style("display", "block")
+            .add_style("outline", "none")
+            .add_style("text-decoration", "none")
+            .add_style("height", size.to_string())
+            .add_style("width", size.to_string());
+        self.passthrough_attributes(img).render_closed(buf)
+    }
+}
+
+impl<'root> Render<'root> for Renderer<'root, MjAvatar, ()> {
+    fn default_attribute(&self, key: &str) -> Option<&'static str> {
+        match key {
+            "align" => Some("center"),
+            "background-color" => Some("#7f8c8d"),
+            "border-radius" => Some("50%"),
+            "color" => Some("#ffffff"),
+            "font-family" => Some("Ubuntu, Helvetica, Arial, sans-serif"),
+            "font-weight" => Some("bold"),
+            "padding" => Some("10px 25px"),
+            "size" => Some("48px"),
+            "target" => Some("_blank"),
+            _ => None,
+        }
+    }
+
+    fn raw_attribute(&self, key: &str) -> Option<&'root str> {
+        self.element.attributes.get(key).map(|v| v.as_str())
+    }
+
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
+    fn tag(&self) -> Option<&str> {
+        Some(NAME)
+    }
+
+    fn set_container_width(&mut self, width: Option<Pixel>) {
+        self.container_width = width;
+    }
+
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.context
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let Some(size) = self.get_size() else {
+            return Ok(());
+        };
+        let src = self.attribute("src");
+        let letters = self.attribute("name").map(initials).unwrap_or_default();
+        if src.is_none() && letters.is_empty() {
+            return Ok(());
+        }
+
+        let table = Tag::table_presentation()
+            .add_style("border-collapse", "collapse")
+            .add_style("border-spacing", "0px");
+        let tbody = Tag::tbody();
+        let tr = Tag::tr();
+
+        table.render_open(&mut cursor.buffer)?;
+        tbody.render_open(&mut cursor.buffer)?;
+        tr.render_open(&mut cursor.buffer)?;
+        match src {
+            Some(src) => {
+                Tag::td()
+                    .add_style("width", size.to_string())
+                    .render_with(&mut cursor.buffer, |b| {
+                        self.render_link(b, |b| self.render_image(src, size, b))
+                    })?;
+            }
+            None => {
+                cursor
+                    .header
+                    .maybe_add_font_families(self.attribute("font-family"));
+                let td = Tag::td()
+                    .add_attribute("align", "center")
+                    .maybe_add_attribute("bgcolor", self.attribute("background-color"))
+                    .add_attribute("height", size.value().to_string())
+                    .add_attribute("valign", "middle")
+                    .add_attribute("width", size.value().to_string())
+                    .maybe_add_style("background-color", self.attribute("background-color"))
+                    .maybe_add_style("border-radius", self.attribute("border-radius"))
+                    .maybe_add_style("color", self.attribute("color"))
+                    .maybe_add_style("font-family", self.attribute_font_family("font-family"))
+                    .add_style("font-size", self.get_font_size(size))
+                    .maybe_add_style("font-weight", self.attribute("font-weight"))
+                    .add_style("height", size.to_string())
+                    .add_style("line-height", size.to_string())
+                    .add_style("text-align", "center")
+                    .add_style("width", size.to_string());
+                let td = self.passthrough_attributes(td);
+                td.render_with(&mut cursor.buffer, |b| {
+                    self.render_link(b, |b| {
+                        b.push_str(&letters);
+                        Ok(())
+                    })
+                })?;
+            }
+        }
+        tr.render_close(&mut cursor.buffer);
+        tbody.render_close(&mut cursor.buffer);
+        table.render_close(&mut cursor.buffer);
+
+        Ok(())
+    }
+}
+
+impl<'render, 'root: 'render> Renderable<'render, 'root> for MjAvatar {
+    fn renderer(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        Box::new(Renderer::new(context, self, ()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::should_render!(basic, "mj-avatar");
+    crate::should_render!(initials, "mj-avatar-initials");
+    crate::should_render!(empty, "mj-avatar-empty");
+}
@@ -0,0 +1,26 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_badge::MjBadge;
+    use crate::mj_body::MjBodyChild;
+    use crate::text::Text;
+
+    #[test]
+    fn serialize() {
+        let mut elt = MjBadge::default();
+        elt.attributes
+            .insert("background-color".into(), "#27ae60".into());
+        elt.children.push(MjBodyChild::Text(Text::from("Shipped")));
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r##"{"type":"mj-badge","attributes":{"background-color":"#27ae60"},"children":["Shipped"]}"##
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{"type":"mj-badge","attributes":{"size":"small"},"children":[{"type":"comment","children":"status"},"Shipped"]}"#;
+        let res: MjBadge = serde_json::from_str(json).unwrap();
+        assert_eq!(res.attributes.len(), 1);
+        assert_eq!(res.children.len(), 2);
+    }
+}
@@ -0,0 +1,47 @@
+//! Module containing the `mj-badge` element, rendering a short text in a
+//! pill, like a "NEW" label, the status of an order or the tags of an
+//! article.
+//!
+//! The `size` attribute picks the font size, the line height and the inner
+//! padding among the `small`, `medium` and `large` presets, each of them
+//! being overridable with its own attribute.
+//!
+//! ```rust
+//! let template = r##"<mjml><mj-body><mj-section><mj-column>
+//!   <mj-badge size="small" background-color="#e74c3c">New</mj-badge>
+//!   <mj-badge href="https://example.com/orders/42">Shipped</mj-badge>
+//! </mj-column></mj-section></mj-body></mjml>"##;
+//! let root = mrml::parse(template).expect("parse template");
+//! let opts = mrml::prelude::render::Options::default();
+//! match root.element.render(&opts) {
+//!     Ok(content) => println!("{content}"),
+//!     Err(_) => println!("couldn't render mjml template"),
+//! };
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::mj_body::MjBodyChild;
+use crate::prelude::hash::Map;
+use crate::prelude::{Component, StaticTag};
+
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "parse")]
+mod parse;
+#[cfg(feature = "print")]
+mod print;
+#[cfg(feature = "render")]
+mod render;
+
+pub const NAME: &str = "mj-badge";
+
+pub struct MjBadgeTag;
+
+impl StaticTag for MjBadgeTag {
+    fn static_tag() -> &'static str {
+        NAME
+    }
+}
+
+pub type MjBadge = Component<PhantomData<MjBadgeTag>, Map<String, String>, Vec<MjBodyChild>>;
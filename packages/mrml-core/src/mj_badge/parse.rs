@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_badge::MjBadge;
+
+    crate::should_parse!(
+        success,
+        MjBadge,
+        r#"<mj-badge>
+    <!-- status -->
+    <b>New</b>
+</mj-badge>"#
+    );
+
+    crate::should_async_parse!(
+        async_success,
+        MjBadge,
+        r#"<mj-badge>
+    <!-- status -->
+    <b>New</b>
+</mj-badge>"#
+    );
+}
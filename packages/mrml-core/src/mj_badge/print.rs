@@ -0,0 +1,19 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::print::Printable;
+
+    #[test]
+    fn empty() {
+        let mut item = crate::mj_badge::MjBadge::default();
+        item.attributes
+            .insert("href".to_string(), "http://localhost".into());
+        item.children
+            .push(crate::mj_body::MjBodyChild::Text(crate::text::Text::from(
+                "New".to_string(),
+            )));
+        assert_eq!(
+            "<mj-badge href=\"http://localhost\">New</mj-badge>",
+            item.print_dense().unwrap()
+        );
+    }
+}
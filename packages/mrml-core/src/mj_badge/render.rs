@@ -0,0 +1,182 @@
+use super::{MjBadge, NAME};
+use crate::prelude::hash::Map;
+use crate::prelude::render::*;
+
+const SIZES: [&str; 3] = ["small", "medium", "large"];
+
+/// Value of the attribute `key` for the `size` preset.
+fn preset(size: &str, key: &str) -> Option<&'static str> {
+    match (size, key) {
+        ("small", "font-size") => Some("10px"),
+        ("small", "inner-padding") => Some("2px 6px"),
+        ("small", "line-height") => Some("14px"),
+        ("large", "font-size") => Some("14px"),
+        ("large", "inner-padding") => Some("4px 12px"),
+        ("large", "line-height") => Some("20px"),
+        (_, "font-size") => Some("12px"),
+        (_, "inner-padding") => Some("3px 8px"),
+        (_, "line-height") => Some("16px"),
+        _ => None,
+    }
+}
+
+impl<'root> Renderer<'root, MjBadge, ()> {
+    fn render_children(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        for child in self.element.children.iter() {
+            let renderer = child.renderer(self.context());
+            renderer.render(cursor)?;
+        }
+        Ok(())
+    }
+
+    fn set_style_td<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 'a,
+        'a: 't,
+    {
+        tag.maybe_add_style("border", self.attribute("border"))
+            .maybe_add_style("border-radius", self.attribute("border-radius"))
+            .maybe_add_style("mso-padding-alt", self.attribute("inner-padding"))
+            .maybe_add_style("background", self.attribute("background-color"))
+    }
+
+    fn set_style_content<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 'a,
+        'a: 't,
+    {
+        tag.add_style("display", "inline-block")
+            .maybe_add_style("background", self.attribute("background-color"))
+            .maybe_add_style("color", self.attribute("color"))
+            .maybe_add_style("font-family", self.attribute_font_family("font-family"))
+            .maybe_add_style("font-size", self.attribute("font-size"))
+            .maybe_add_style("font-weight", self.attribute("font-weight"))
+            .maybe_add_style("letter-spacing", self.attribute("letter-spacing"))
+            .maybe_add_style("line-height", self.attribute("line-height"))
+            .add_style("text-decoration", "none")
+            .maybe_add_style("text-transform", self.attribute("text-transform"))
+            .add_style("white-space", "nowrap")
+            .maybe_add_style("padding", self.attribute("inner-padding"))
+            .add_style("mso-padding-alt", "0px")
+            .maybe_add_style("border-radius", self.attribute("border-radius"))
+    }
+}
+
+impl<'root> Render<'root> for Renderer<'root, MjBadge, ()> {
+    fn default_attribute(&self, key: &str) -> Option<&'static str> {
+        match key {
+            "align" => Some("left"),
+            "background-color" => Some("#414141"),
+            "border-radius" => Some("999px"),
+            "color" => Some("#ffffff"),
+            "font-family" => Some("Ubuntu, Helvetica, Arial, sans-serif"),
+            "font-weight" => Some("bold"),
+            "padding" => Some("10px 25px"),
+            "size" => Some("medium"),
+            "target" => Some("_blank"),
+            "text-transform" => Some("none"),
+            "font-size" | "inner-padding" | "line-height" => {
+                preset(self.attribute("size").unwrap_or_default(), key)
+            }
+            _ => None,
+        }
+    }
+
+    fn raw_attribute(&self, key: &str) -> Option<&'root str> {
+        self.element.attributes.get(key).map(|v| v.as_str())
+    }
+
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
+    fn tag(&self) -> Option<&str> {
+        Some(NAME)
+    }
+
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.context
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        if let Some(size) = self.attribute("size") {
+            if !SIZES.contains(&size) {
+                self.report_invalid_attribute("size", size);
+            }
+        }
+        cursor
+            .header
+            .maybe_add_font_families(self.attribute("font-family"));
+
+        let table = Tag::table_presentation()
+            .add_style("border-collapse", "separate")
+            .add_style("line-height", "100%");
+        let tbody = Tag::tbody();
+        let tr = Tag::tr();
+        let td = self
+            .set_style_td(Tag::td())
+            .add_attribute("align", "center")
+            .maybe_add_attribute("bgcolor", self.attribute("background-color"))
+            .add_attribute("valign", "middle");
+        let content = Tag::new(self.attribute("href").map(|_| "a").unwrap_or("span"))
+            .maybe_add_attribute("href", self.attribute("href"))
+            .maybe_add_attribute("rel", self.attribute("rel"))
+            .maybe_add_attribute(
+                "target",
+                self.attribute("href")
+                    .and_then(|_v| self.attribute("target")),
+            );
+        let content = self.passthrough_attributes(self.set_style_content(content));
+
+        table.render_open(&mut cursor.buffer)?;
+        tbody.render_open(&mut cursor.buffer)?;
+        tr.render_open(&mut cursor.buffer)?;
+        td.render_open(&mut cursor.buffer)?;
+        content.render_open(&mut cursor.buffer)?;
+        self.render_children(cursor)?;
+        content.render_close(&mut cursor.buffer);
+        td.render_close(&mut cursor.buffer);
+        tr.render_close(&mut cursor.buffer);
+        tbody.render_close(&mut cursor.buffer);
+        table.render_close(&mut cursor.buffer);
+
+        Ok(())
+    }
+}
+
+impl<'render, 'root: 'render> Renderable<'render, 'root> for MjBadge {
+    fn renderer(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        Box::new(Renderer::new(context, self, ()))
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use crate::mjml::Mjml;
+    use crate::prelude::render::RenderWarningKind;
+
+    crate::should_render!(basic, "mj-badge");
+    crate::should_render!(size, "mj-badge-size");
+    crate::should_render!(href, "mj-badge-href");
+
+    #[test]
+    fn should_report_unknown_sizes() {
+        let template = r#"<mjml><mj-body><mj-badge size="huge">New</mj-badge></mj-body></mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let (html, warnings) = root
+            .element
+            .render_with_report(&Default::default())
+            .unwrap();
+        assert!(html.contains("font-size:12px;"));
+        assert_eq!(
+            warnings[0].kind,
+            RenderWarningKind::InvalidAttribute {
+                name: "size".into(),
+                value: "huge".into(),
+            }
+        );
+    }
+}
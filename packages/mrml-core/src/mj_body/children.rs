@@ -1,24 +1,35 @@
 use crate::comment::Comment;
 use crate::mj_accordion::MjAccordion;
+use crate::mj_avatar::MjAvatar;
+use crate::mj_badge::MjBadge;
 use crate::mj_button::MjButton;
+use crate::mj_calendar_button::MjCalendarButton;
 use crate::mj_carousel::MjCarousel;
+use crate::mj_code::MjCode;
 use crate::mj_column::MjColumn;
 use crate::mj_divider::MjDivider;
+use crate::mj_footer::MjFooter;
 use crate::mj_group::MjGroup;
 use crate::mj_hero::MjHero;
 use crate::mj_image::MjImage;
 use crate::mj_include::body::MjIncludeBody;
+use crate::mj_map::MjMap;
+use crate::mj_msooffice::MjMsoOffice;
 use crate::mj_navbar::MjNavbar;
+use crate::mj_not_mso::MjNotMso;
+use crate::mj_quote::MjQuote;
 use crate::mj_raw::MjRaw;
 use crate::mj_section::MjSection;
 use crate::mj_social::MjSocial;
 use crate::mj_spacer::MjSpacer;
+use crate::mj_stats::MjStats;
 use crate::mj_table::MjTable;
+use crate::mj_tabs::MjTabs;
 use crate::mj_text::MjText;
 use crate::mj_wrapper::MjWrapper;
 use crate::node::Node;
 #[cfg(feature = "render")]
-use crate::prelude::render::{Render, RenderContext, Renderable};
+use crate::prelude::render::{annotate, Render, RenderContext, Renderable};
 use crate::text::Text;
 
 #[derive(Clone, Debug)]
@@ -28,20 +39,31 @@ use crate::text::Text;
 pub enum MjBodyChild {
     Comment(Comment),
     MjAccordion(MjAccordion),
+    MjAvatar(MjAvatar),
+    MjBadge(MjBadge),
     MjButton(MjButton),
+    MjCalendarButton(MjCalendarButton),
     MjCarousel(MjCarousel),
+    MjCode(MjCode),
     MjColumn(MjColumn),
     MjDivider(MjDivider),
+    MjFooter(MjFooter),
     MjGroup(MjGroup),
     MjHero(MjHero),
     MjInclude(MjIncludeBody),
     MjImage(MjImage),
+    MjMap(MjMap),
+    MjMsoOffice(MjMsoOffice),
     MjNavbar(MjNavbar),
+    MjNotMso(MjNotMso),
+    MjQuote(MjQuote),
     MjRaw(MjRaw),
     MjSection(MjSection),
     MjSocial(MjSocial),
     MjSpacer(MjSpacer),
+    MjStats(MjStats),
     MjTable(MjTable),
+    MjTabs(MjTabs),
     MjText(MjText),
     MjWrapper(MjWrapper),
     Node(Node<MjBodyChild>),
@@ -54,20 +76,31 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjBodyChild {
         match self {
             Self::Comment(elt) => elt.is_raw(),
             Self::MjAccordion(elt) => elt.is_raw(),
+            Self::MjAvatar(elt) => elt.is_raw(),
+            Self::MjBadge(elt) => elt.is_raw(),
             Self::MjButton(elt) => elt.is_raw(),
+            Self::MjCalendarButton(elt) => elt.is_raw(),
             Self::MjCarousel(elt) => elt.is_raw(),
+            Self::MjCode(elt) => elt.is_raw(),
             Self::MjColumn(elt) => elt.is_raw(),
             Self::MjDivider(elt) => elt.is_raw(),
+            Self::MjFooter(elt) => elt.is_raw(),
             Self::MjGroup(elt) => elt.is_raw(),
             Self::MjHero(elt) => elt.is_raw(),
             Self::MjInclude(elt) => elt.is_raw(),
             Self::MjImage(elt) => elt.is_raw(),
+            Self::MjMap(elt) => elt.is_raw(),
+            Self::MjMsoOffice(elt) => elt.is_raw(),
             Self::MjNavbar(elt) => elt.is_raw(),
+            Self::MjNotMso(elt) => elt.is_raw(),
+            Self::MjQuote(elt) => elt.is_raw(),
             Self::MjRaw(elt) => elt.is_raw(),
             Self::MjSection(elt) => elt.is_raw(),
             Self::MjSocial(elt) => elt.is_raw(),
             Self::MjSpacer(elt) => elt.is_raw(),
+            Self::MjStats(elt) => elt.is_raw(),
             Self::MjTable(elt) => elt.is_raw(),
+            Self::MjTabs(elt) => elt.is_raw(),
             Self::MjText(elt) => elt.is_raw(),
             Self::MjWrapper(elt) => elt.is_raw(),
             Self::Node(elt) => elt.is_raw(),
@@ -79,27 +112,39 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjBodyChild {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        match self {
-            Self::Comment(elt) => elt.renderer(context),
+        let renderer = match self {
+            Self::Comment(elt) => return elt.renderer(context),
             Self::MjAccordion(elt) => elt.renderer(context),
+            Self::MjAvatar(elt) => elt.renderer(context),
+            Self::MjBadge(elt) => elt.renderer(context),
             Self::MjButton(elt) => elt.renderer(context),
+            Self::MjCalendarButton(elt) => elt.renderer(context),
             Self::MjCarousel(elt) => elt.renderer(context),
+            Self::MjCode(elt) => elt.renderer(context),
             Self::MjColumn(elt) => elt.renderer(context),
             Self::MjDivider(elt) => elt.renderer(context),
+            Self::MjFooter(elt) => elt.renderer(context),
             Self::MjGroup(elt) => elt.renderer(context),
             Self::MjHero(elt) => elt.renderer(context),
             Self::MjInclude(elt) => elt.renderer(context),
             Self::MjImage(elt) => elt.renderer(context),
+            Self::MjMap(elt) => elt.renderer(context),
+            Self::MjMsoOffice(elt) => elt.renderer(context),
             Self::MjNavbar(elt) => elt.renderer(context),
+            Self::MjNotMso(elt) => elt.renderer(context),
+            Self::MjQuote(elt) => elt.renderer(context),
             Self::MjRaw(elt) => elt.renderer(context),
             Self::MjSection(elt) => elt.renderer(context),
             Self::MjSocial(elt) => elt.renderer(context),
             Self::MjSpacer(elt) => elt.renderer(context),
+            Self::MjStats(elt) => elt.renderer(context),
             Self::MjTable(elt) => elt.renderer(context),
+            Self::MjTabs(elt) => elt.renderer(context),
             Self::MjText(elt) => elt.renderer(context),
             Self::MjWrapper(elt) => elt.renderer(context),
-            Self::Node(elt) => elt.renderer(context),
-            Self::Text(elt) => elt.renderer(context),
-        }
+            Self::Node(elt) => return elt.renderer(context),
+            Self::Text(elt) => return elt.renderer(context),
+        };
+        annotate(context, renderer)
     }
 }
@@ -11,6 +11,8 @@ mod render;
 use std::marker::PhantomData;
 
 pub use children::MjBodyChild;
+#[cfg(feature = "render")]
+pub(crate) use render::render_tracking_pixel;
 
 use crate::prelude::hash::Map;
 use crate::prelude::{Component, StaticTag};
@@ -3,31 +3,51 @@ use xmlparser::StrSpan;
 use super::MjBodyChild;
 use crate::comment::Comment;
 use crate::mj_accordion::NAME as MJ_ACCORDION;
+use crate::mj_avatar::NAME as MJ_AVATAR;
+use crate::mj_badge::NAME as MJ_BADGE;
 use crate::mj_button::NAME as MJ_BUTTON;
+use crate::mj_calendar_button::NAME as MJ_CALENDAR_BUTTON;
 use crate::mj_carousel::NAME as MJ_CAROUSEL;
+use crate::mj_code::NAME as MJ_CODE;
 use crate::mj_column::NAME as MJ_COLUMN;
 use crate::mj_divider::NAME as MJ_DIVIDER;
+use crate::mj_footer::NAME as MJ_FOOTER;
 use crate::mj_group::NAME as MJ_GROUP;
 use crate::mj_hero::NAME as MJ_HERO;
 use crate::mj_image::NAME as MJ_IMAGE;
 use crate::mj_include::NAME as MJ_INCLUDE;
+use crate::mj_map::NAME as MJ_MAP;
+use crate::mj_msooffice::NAME as MJ_MSOOFFICE;
 use crate::mj_navbar::NAME as MJ_NAVBAR;
+use crate::mj_not_mso::NAME as MJ_NOT_MSO;
+use crate::mj_quote::NAME as MJ_QUOTE;
 use crate::mj_raw::NAME as MJ_RAW;
 use crate::mj_section::NAME as MJ_SECTION;
 use crate::mj_social::NAME as MJ_SOCIAL;
 use crate::mj_spacer::NAME as MJ_SPACER;
+use crate::mj_stats::NAME as MJ_STATS;
 use crate::mj_table::NAME as MJ_TABLE;
+use crate::mj_tabs::NAME as MJ_TABS;
 use crate::mj_text::NAME as MJ_TEXT;
 use crate::mj_wrapper::NAME as MJ_WRAPPER;
 use crate::node::Node;
 use crate::prelude::is_void_element;
 use crate::prelude::parser::{
-    parse_attributes_map, Error, MrmlCursor, MrmlParser, MrmlToken, ParseChildren, ParseElement,
+    parse_attributes_map, ElementClose, Error, MrmlCursor, MrmlParser, MrmlToken, ParseChildren,
+    ParseElement, WarningKind,
 };
 #[cfg(feature = "async")]
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::text::Text;
 
+/// Html is case insensitive, the closing tag only has to match the opening one
+/// ignoring the case.
+fn check_node_close(cursor: &mut MrmlCursor<'_>, tag: &str, close: &ElementClose<'_>) {
+    if !close.local.as_str().eq_ignore_ascii_case(tag) {
+        cursor.add_warning(WarningKind::UnbalancedHtml, close.span);
+    }
+}
+
 impl<'opts> ParseElement<Node<MjBodyChild>> for MrmlParser<'opts> {
     fn parse<'a>(
         &self,
@@ -38,6 +58,7 @@ impl<'opts> ParseElement<Node<MjBodyChild>> for MrmlParser<'opts> {
         let attributes = parse_attributes_map(cursor)?;
         let ending = cursor.assert_element_end()?;
         if ending.empty || is_void_element(tag.as_str()) {
+            cursor.end_void_element(&tag, &ending)?;
             return Ok(Node {
                 tag,
                 attributes,
@@ -46,7 +67,10 @@ impl<'opts> ParseElement<Node<MjBodyChild>> for MrmlParser<'opts> {
         }
         let children = self.parse_children(cursor)?;
 
-        cursor.assert_element_close()?;
+        let close = cursor.assert_element_close()?;
+        if self.options.validate_html {
+            check_node_close(cursor, &tag, &close);
+        }
 
         Ok(Node {
             tag,
@@ -69,6 +93,7 @@ impl AsyncParseElement<Node<MjBodyChild>> for AsyncMrmlParser {
         let attributes = parse_attributes_map(cursor)?;
         let ending = cursor.assert_element_end()?;
         if ending.empty || is_void_element(tag.as_str()) {
+            cursor.end_void_element(&tag, &ending)?;
             return Ok(Node {
                 tag,
                 attributes,
@@ -77,7 +102,10 @@ impl AsyncParseElement<Node<MjBodyChild>> for AsyncMrmlParser {
         }
         let children = self.async_parse_children(cursor).await?;
 
-        cursor.assert_element_close()?;
+        let close = cursor.assert_element_close()?;
+        if self.options.validate_html {
+            check_node_close(cursor, &tag, &close);
+        }
 
         Ok(Node {
             tag,
@@ -95,20 +123,31 @@ impl<'opts> ParseElement<MjBodyChild> for MrmlParser<'opts> {
     ) -> Result<MjBodyChild, Error> {
         match tag.as_str() {
             MJ_ACCORDION => Ok(MjBodyChild::MjAccordion(self.parse(cursor, tag)?)),
+            MJ_AVATAR => Ok(MjBodyChild::MjAvatar(self.parse(cursor, tag)?)),
+            MJ_BADGE => Ok(MjBodyChild::MjBadge(self.parse(cursor, tag)?)),
             MJ_BUTTON => Ok(MjBodyChild::MjButton(self.parse(cursor, tag)?)),
+            MJ_CALENDAR_BUTTON => Ok(MjBodyChild::MjCalendarButton(self.parse(cursor, tag)?)),
             MJ_CAROUSEL => Ok(MjBodyChild::MjCarousel(self.parse(cursor, tag)?)),
+            MJ_CODE => Ok(MjBodyChild::MjCode(self.parse(cursor, tag)?)),
             MJ_COLUMN => Ok(MjBodyChild::MjColumn(self.parse(cursor, tag)?)),
             MJ_DIVIDER => Ok(MjBodyChild::MjDivider(self.parse(cursor, tag)?)),
+            MJ_FOOTER => Ok(MjBodyChild::MjFooter(self.parse(cursor, tag)?)),
             MJ_GROUP => Ok(MjBodyChild::MjGroup(self.parse(cursor, tag)?)),
             MJ_HERO => Ok(MjBodyChild::MjHero(self.parse(cursor, tag)?)),
             MJ_IMAGE => Ok(MjBodyChild::MjImage(self.parse(cursor, tag)?)),
             MJ_INCLUDE => Ok(MjBodyChild::MjInclude(self.parse(cursor, tag)?)),
+            MJ_MAP => Ok(MjBodyChild::MjMap(self.parse(cursor, tag)?)),
+            MJ_MSOOFFICE => Ok(MjBodyChild::MjMsoOffice(self.parse(cursor, tag)?)),
             MJ_NAVBAR => Ok(MjBodyChild::MjNavbar(self.parse(cursor, tag)?)),
+            MJ_NOT_MSO => Ok(MjBodyChild::MjNotMso(self.parse(cursor, tag)?)),
+            MJ_QUOTE => Ok(MjBodyChild::MjQuote(self.parse(cursor, tag)?)),
             MJ_RAW => Ok(MjBodyChild::MjRaw(self.parse(cursor, tag)?)),
             MJ_SECTION => Ok(MjBodyChild::MjSection(self.parse(cursor, tag)?)),
             MJ_SOCIAL => Ok(MjBodyChild::MjSocial(self.parse(cursor, tag)?)),
             MJ_SPACER => Ok(MjBodyChild::MjSpacer(self.parse(cursor, tag)?)),
+            MJ_STATS => Ok(MjBodyChild::MjStats(self.parse(cursor, tag)?)),
             MJ_TABLE => Ok(MjBodyChild::MjTable(self.parse(cursor, tag)?)),
+            MJ_TABS => Ok(MjBodyChild::MjTabs(self.parse(cursor, tag)?)),
             MJ_TEXT => Ok(MjBodyChild::MjText(self.parse(cursor, tag)?)),
             MJ_WRAPPER => Ok(MjBodyChild::MjWrapper(self.parse(cursor, tag)?)),
             _ => Ok(MjBodyChild::Node(self.parse(cursor, tag)?)),
@@ -129,22 +168,37 @@ impl AsyncParseElement<MjBodyChild> for AsyncMrmlParser {
             MJ_ACCORDION => Ok(MjBodyChild::MjAccordion(
                 self.async_parse(cursor, tag).await?,
             )),
+            MJ_AVATAR => Ok(MjBodyChild::MjAvatar(self.async_parse(cursor, tag).await?)),
+            MJ_BADGE => Ok(MjBodyChild::MjBadge(self.async_parse(cursor, tag).await?)),
             MJ_BUTTON => Ok(MjBodyChild::MjButton(self.async_parse(cursor, tag).await?)),
+            MJ_CALENDAR_BUTTON => Ok(MjBodyChild::MjCalendarButton(
+                self.async_parse(cursor, tag).await?,
+            )),
             MJ_CAROUSEL => Ok(MjBodyChild::MjCarousel(
                 self.async_parse(cursor, tag).await?,
             )),
+            MJ_CODE => Ok(MjBodyChild::MjCode(self.async_parse(cursor, tag).await?)),
             MJ_COLUMN => Ok(MjBodyChild::MjColumn(self.async_parse(cursor, tag).await?)),
             MJ_DIVIDER => Ok(MjBodyChild::MjDivider(self.async_parse(cursor, tag).await?)),
+            MJ_FOOTER => Ok(MjBodyChild::MjFooter(self.async_parse(cursor, tag).await?)),
             MJ_GROUP => Ok(MjBodyChild::MjGroup(self.async_parse(cursor, tag).await?)),
             MJ_HERO => Ok(MjBodyChild::MjHero(self.async_parse(cursor, tag).await?)),
             MJ_IMAGE => Ok(MjBodyChild::MjImage(self.async_parse(cursor, tag).await?)),
             MJ_INCLUDE => Ok(MjBodyChild::MjInclude(self.async_parse(cursor, tag).await?)),
+            MJ_MAP => Ok(MjBodyChild::MjMap(self.async_parse(cursor, tag).await?)),
+            MJ_MSOOFFICE => Ok(MjBodyChild::MjMsoOffice(
+                self.async_parse(cursor, tag).await?,
+            )),
             MJ_NAVBAR => Ok(MjBodyChild::MjNavbar(self.async_parse(cursor, tag).await?)),
+            MJ_NOT_MSO => Ok(MjBodyChild::MjNotMso(self.async_parse(cursor, tag).await?)),
+            MJ_QUOTE => Ok(MjBodyChild::MjQuote(self.async_parse(cursor, tag).await?)),
             MJ_RAW => Ok(MjBodyChild::MjRaw(self.async_parse(cursor, tag).await?)),
             MJ_SECTION => Ok(MjBodyChild::MjSection(self.async_parse(cursor, tag).await?)),
             MJ_SOCIAL => Ok(MjBodyChild::MjSocial(self.async_parse(cursor, tag).await?)),
             MJ_SPACER => Ok(MjBodyChild::MjSpacer(self.async_parse(cursor, tag).await?)),
+            MJ_STATS => Ok(MjBodyChild::MjStats(self.async_parse(cursor, tag).await?)),
             MJ_TABLE => Ok(MjBodyChild::MjTable(self.async_parse(cursor, tag).await?)),
+            MJ_TABS => Ok(MjBodyChild::MjTabs(self.async_parse(cursor, tag).await?)),
             MJ_TEXT => Ok(MjBodyChild::MjText(self.async_parse(cursor, tag).await?)),
             MJ_WRAPPER => Ok(MjBodyChild::MjWrapper(self.async_parse(cursor, tag).await?)),
             _ => Ok(MjBodyChild::Node(self.async_parse(cursor, tag).await?)),
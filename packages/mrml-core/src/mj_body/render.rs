@@ -2,8 +2,40 @@ use std::convert::TryFrom;
 
 use super::MjBody;
 use crate::helper::size::Pixel;
+use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
+/// Renders the tracking pixel defined in the options, if any. It's hidden
+/// with its size and not with `display:none`, that would prevent some email
+/// clients from loading it.
+pub(crate) fn render_tracking_pixel(
+    options: &RenderOptions,
+    buf: &mut RenderBuffer,
+) -> Result<(), Error> {
+    let Some(ref src) = options.tracking_pixel else {
+        return Ok(());
+    };
+    Tag::new("img")
+        .add_attribute("src", src.as_str())
+        .add_attribute("width", "1")
+        .add_attribute("height", "1")
+        .add_attribute("alt", "")
+        .add_attribute("border", "0")
+        .add_style("display", "block")
+        .add_style("height", "1px")
+        .add_style("width", "1px")
+        .add_style("max-height", "1px")
+        .add_style("max-width", "1px")
+        .add_style("border", "0")
+        .add_style("margin", "0")
+        .add_style("padding", "0")
+        .add_style("opacity", "0")
+        .add_style("overflow", "hidden")
+        .add_style("mso-hide", "all")
+        .render_closed(buf)?;
+    Ok(())
+}
+
 impl<'root> Renderer<'root, MjBody, ()> {
     fn get_width(&self) -> Option<Pixel> {
         self.attribute("width")
@@ -15,9 +47,11 @@ impl<'root> Renderer<'root, MjBody, ()> {
     }
 
     fn get_content_div_tag(&self) -> Tag {
-        self.set_body_style(Tag::new("div"))
+        let div = self
+            .set_body_style(Tag::new("div"))
             .maybe_add_attribute("class", self.attribute("css-class"))
-            .maybe_add_attribute("lang", self.context.header.lang())
+            .maybe_add_attribute("lang", self.context.header.lang());
+        self.passthrough_attributes(div)
     }
 
     fn set_body_style<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
@@ -36,26 +70,80 @@ impl<'root> Renderer<'root, MjBody, ()> {
         }
     }
 
-    fn render_content(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
-        let div = self.get_content_div_tag();
-        let element_width = self.get_width();
-
-        div.render_open(&mut cursor.buffer)?;
-        let raw_siblings = self
-            .element
+    fn raw_siblings(&self) -> usize {
+        self.element
             .children
             .iter()
             .filter(|item| item.is_raw())
-            .count();
-        for (index, child) in self.element.children.iter().enumerate() {
-            let mut renderer = child.renderer(self.context());
-            renderer.set_container_width(element_width);
-            renderer.set_index(index);
-            renderer.set_raw_siblings(raw_siblings);
-            renderer.set_siblings(self.element.children.len());
-            renderer.render(cursor)?;
-        }
+            .count()
+    }
+
+    fn render_child(
+        &self,
+        cursor: &mut RenderCursor,
+        index: usize,
+        raw_siblings: usize,
+    ) -> Result<(), Error> {
+        let mut renderer = self.element.children[index].renderer(self.context());
+        let span = crate::trace::span!(
+            "mrml::render_element",
+            tag = renderer.tag().unwrap_or_default(),
+            index = index,
+            size = crate::trace::Empty,
+        )
+        .entered();
+        let start = cursor.buffer.len();
+        renderer.set_container_width(self.get_width());
+        renderer.set_index(index);
+        renderer.set_raw_siblings(raw_siblings);
+        renderer.set_siblings(self.element.children.len());
+        renderer.render(cursor)?;
+        span.record("size", cursor.buffer.len() - start);
+        Ok(())
+    }
+
+    /// Opens the content of the body, after the preview, and returns the
+    /// `div` to close once the children are rendered.
+    fn render_content_open(&self, cursor: &mut RenderCursor) -> Result<Tag<'_>, Error> {
+        self.render_preview(&mut cursor.buffer);
+        let div = self.get_content_div_tag();
+        div.render_open(&mut cursor.buffer)?;
+        Ok(div)
+    }
+
+    fn render_content_close(&self, div: Tag<'_>, cursor: &mut RenderCursor) -> Result<(), Error> {
         div.render_close(&mut cursor.buffer);
+        render_tracking_pixel(self.context.options, &mut cursor.buffer)
+    }
+
+    /// Renders the content of the `body` element, without the element.
+    pub(crate) fn render_inner(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let div = self.render_content_open(cursor)?;
+        let raw_siblings = self.raw_siblings();
+        for index in 0..self.element.children.len() {
+            self.render_child(cursor, index, raw_siblings)?;
+        }
+        self.render_content_close(div, cursor)
+    }
+
+    /// Renders the body like [`Render::render`], giving back the control to
+    /// the executor and checking the token between each child.
+    #[cfg(feature = "async")]
+    pub(crate) async fn async_render(
+        &self,
+        cursor: &mut RenderCursor,
+        token: &CancellationToken,
+    ) -> Result<(), Error> {
+        let body = self.get_body_tag();
+        body.render_open(&mut cursor.buffer)?;
+        let div = self.render_content_open(cursor)?;
+        let raw_siblings = self.raw_siblings();
+        for index in 0..self.element.children.len() {
+            checkpoint(token).await?;
+            self.render_child(cursor, index, raw_siblings)?;
+        }
+        self.render_content_close(div, cursor)?;
+        body.render_close(&mut cursor.buffer);
         Ok(())
     }
 }
@@ -65,6 +153,10 @@ impl<'root> Render<'root> for Renderer<'root, MjBody, ()> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
     fn default_attribute(&self, key: &str) -> Option<&'static str> {
         match key {
             "width" => Some("600px"),
@@ -79,8 +171,7 @@ impl<'root> Render<'root> for Renderer<'root, MjBody, ()> {
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         let body = self.get_body_tag();
         body.render_open(&mut cursor.buffer)?;
-        self.render_preview(&mut cursor.buffer);
-        self.render_content(cursor)?;
+        self.render_inner(cursor)?;
         body.render_close(&mut cursor.buffer);
         Ok(())
     }
@@ -98,4 +189,27 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjBody {
 #[cfg(test)]
 mod tests {
     crate::should_render!(empty, "mj-body");
+
+    #[test]
+    fn should_render_tracking_pixel() {
+        let opts = crate::prelude::render::RenderOptions {
+            tracking_pixel: Some("https://example.com/open.gif?id=42".into()),
+            ..Default::default()
+        };
+        let root =
+            crate::mjml::Mjml::parse("<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>")
+                .unwrap();
+        let result = root.element.render(&opts).unwrap();
+        let pixel = result.find("https://example.com/open.gif?id=42").unwrap();
+        assert!(pixel > result.find("Hello").unwrap());
+        assert!(pixel < result.find("</body>").unwrap());
+        let start = result[..pixel].rfind('<').unwrap();
+        let tag = &result[start..start + result[start..].find('>').unwrap()];
+        assert!(tag.starts_with("<img"));
+        assert!(tag.contains("width:1px"));
+
+        let root = crate::mjml::Mjml::parse("<mjml></mjml>").unwrap();
+        let result = root.element.render(&opts).unwrap();
+        assert!(result.contains("https://example.com/open.gif?id=42"));
+    }
 }
@@ -3,7 +3,7 @@ use crate::prelude::json::JsonAttributes;
 
 impl JsonAttributes for MjBreakpointAttributes {
     fn has_attributes(&self) -> bool {
-        !self.width.is_empty()
+        !self.width.is_empty() || self.css_class.is_some()
     }
 
     fn try_from_serde<Err: serde::de::Error>(this: Option<Self>) -> Result<Self, Err>
@@ -23,6 +23,7 @@ mod tests {
         let elt = MjBreakpoint::new(
             MjBreakpointAttributes {
                 width: "12px".to_string(),
+                css_class: None,
             },
             (),
         );
@@ -37,6 +38,7 @@ mod tests {
         let elt = MjBreakpoint::new(
             MjBreakpointAttributes {
                 width: "12px".to_string(),
+                css_class: None,
             },
             (),
         );
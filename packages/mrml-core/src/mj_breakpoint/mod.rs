@@ -16,6 +16,12 @@ pub const NAME: &str = "mj-breakpoint";
 pub struct MjBreakpointAttributes {
     #[cfg_attr(feature = "json", serde(skip_serializing_if = "String::is_empty"))]
     pub width: String,
+    /// Class of the `style` elements containing the media queries.
+    #[cfg_attr(
+        feature = "json",
+        serde(rename = "css-class", skip_serializing_if = "Option::is_none")
+    )]
+    pub css_class: Option<String>,
 }
 
 pub struct MjBreakpointTag;
@@ -32,4 +38,13 @@ impl MjBreakpoint {
     pub fn value(&self) -> &str {
         &self.attributes.width
     }
+
+    /// Widths listed in the `width` attribute, separated by spaces or commas,
+    /// like `480px 768px` to define a tablet breakpoint after the mobile one.
+    pub fn values(&self) -> impl Iterator<Item = &str> {
+        self.attributes
+            .width
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|item| !item.is_empty())
+    }
 }
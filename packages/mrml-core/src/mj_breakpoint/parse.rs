@@ -11,6 +11,8 @@ fn parse_attributes(cursor: &mut MrmlCursor<'_>) -> Result<MjBreakpointAttribute
     while let Some(attr) = cursor.next_attribute()? {
         if attr.local.as_str() == "width" {
             result.width = attr.value.to_string();
+        } else if attr.local.as_str() == "css-class" {
+            result.css_class = Some(attr.value.to_string());
         } else {
             cursor.add_warning(WarningKind::UnexpectedAttribute, attr.span);
         }
@@ -49,6 +51,12 @@ mod tests {
         r#"<mj-breakpoint width="42px" />"#,
         0
     );
+    crate::should_sync_parse!(
+        with_css_class,
+        MjBreakpoint,
+        r#"<mj-breakpoint width="42px" css-class="queries" />"#,
+        0
+    );
     crate::should_sync_parse!(
         unexpected_attributes,
         MjBreakpoint,
@@ -2,7 +2,11 @@ use crate::prelude::print::PrintableAttributes;
 
 impl PrintableAttributes for super::MjBreakpointAttributes {
     fn print<P: crate::prelude::print::Printer>(&self, printer: &mut P) -> std::fmt::Result {
-        printer.push_attribute("width", self.width.as_str())
+        printer.push_attribute("width", self.width.as_str())?;
+        if let Some(ref css_class) = self.css_class {
+            printer.push_attribute("css-class", css_class.as_str())?;
+        }
+        Ok(())
     }
 }
 
@@ -16,6 +20,7 @@ mod tests {
         let item = MjBreakpoint::new(
             MjBreakpointAttributes {
                 width: String::from("10px"),
+                css_class: None,
             },
             (),
         );
@@ -24,4 +29,19 @@ mod tests {
             item.print_dense().unwrap()
         );
     }
+
+    #[test]
+    fn with_css_class() {
+        let item = MjBreakpoint::new(
+            MjBreakpointAttributes {
+                width: String::from("10px"),
+                css_class: Some(String::from("queries")),
+            },
+            (),
+        );
+        assert_eq!(
+            "<mj-breakpoint width=\"10px\" css-class=\"queries\" />",
+            item.print_dense().unwrap()
+        );
+    }
 }
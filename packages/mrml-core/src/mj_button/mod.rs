@@ -13,6 +13,9 @@ mod print;
 #[cfg(feature = "render")]
 mod render;
 
+#[cfg(feature = "render")]
+pub(crate) use render::render_hover_style;
+
 pub const NAME: &str = "mj-button";
 
 pub struct MjButtonTag;
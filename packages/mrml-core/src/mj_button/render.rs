@@ -1,7 +1,39 @@
 use super::{MjButton, NAME};
 use crate::helper::size::Pixel;
+use crate::helper::style::Style;
+use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
+/// Head style applying the `hover-background-color` and `hover-color`
+/// attributes to the `targets` of the element with the `class`, when it's
+/// hovered or when it contains the focus.
+pub(crate) fn render_hover_style(
+    class: &str,
+    targets: &[&str],
+    background: Option<&str>,
+    color: Option<&str>,
+) -> String {
+    let style = ["hover", "focus-within"]
+        .iter()
+        .flat_map(|state| {
+            targets
+                .iter()
+                .map(move |target| format!(".{class}:{state}{target}"))
+        })
+        .fold(Style::default(), Style::add_selector);
+    let style = match background {
+        Some(value) => style
+            .add_content(format!("background: {value} !important;"))
+            .add_content(format!("background-color: {value} !important;")),
+        None => style,
+    };
+    let style = match color {
+        Some(value) => style.add_content(format!("color: {value} !important;")),
+        None => style,
+    };
+    style.to_string()
+}
+
 impl<'root> Renderer<'root, MjButton, ()> {
     fn content_width(&self) -> Option<String> {
         if let Some(width) = self.attribute_as_pixel("width") {
@@ -10,7 +42,7 @@ impl<'root> Renderer<'root, MjButton, ()> {
                 .map(|pad| pad.value())
                 .or_else(|| {
                     self.attribute_as_spacing("inner-padding")
-                        .map(|pad| pad.left().value())
+                        .and_then(|pad| pad.left().as_pixel().map(Pixel::value))
                 })
                 .unwrap_or(0.0);
             let pad_right = self
@@ -18,7 +50,7 @@ impl<'root> Renderer<'root, MjButton, ()> {
                 .map(|pad| pad.value())
                 .or_else(|| {
                     self.attribute_as_spacing("inner-padding")
-                        .map(|pad| pad.right().value())
+                        .and_then(|pad| pad.right().as_pixel().map(Pixel::value))
                 })
                 .unwrap_or(0.0);
             Some(Pixel::new(width.value() - pad_left - pad_right).to_string())
@@ -27,6 +59,27 @@ impl<'root> Renderer<'root, MjButton, ()> {
         }
     }
 
+    /// Percentage width of the button. The link then fills the whole button,
+    /// and not only its text, so that the whole button is clickable.
+    fn percent_width(&self) -> Option<&str> {
+        self.attribute("width").filter(|_| {
+            self.attribute_as_size("width")
+                .is_some_and(|w| w.is_percent())
+        })
+    }
+
+    /// Class of the button, generated when it has a hover style.
+    fn hover_class(&self) -> Option<String> {
+        if self.attribute_exists("hover-background-color") || self.attribute_exists("hover-color") {
+            Some(format!(
+                "mj-button-hover-{}",
+                self.context.generator.next_id()
+            ))
+        } else {
+            None
+        }
+    }
+
     fn render_children(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         for child in self.element.children.iter() {
             let renderer = child.renderer(self.context());
@@ -69,11 +122,16 @@ impl<'root> Renderer<'root, MjButton, ()> {
         'root: 'a,
         'a: 't,
     {
-        tag.add_style("display", "inline-block")
+        let display = if self.percent_width().is_some() {
+            "block"
+        } else {
+            "inline-block"
+        };
+        tag.add_style("display", display)
             .maybe_add_style("width", self.content_width())
             .maybe_add_style("background", self.attribute("background-color"))
             .maybe_add_style("color", self.attribute("color"))
-            .maybe_add_style("font-family", self.attribute("font-family"))
+            .maybe_add_style("font-family", self.attribute_font_family("font-family"))
             .maybe_add_style("font-size", self.attribute("font-size"))
             .maybe_add_style("font-style", self.attribute("font-style"))
             .maybe_add_style("font-weight", self.attribute("font-weight"))
@@ -114,6 +172,10 @@ impl<'root> Render<'root> for Renderer<'root, MjButton, ()> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
     fn tag(&self) -> Option<&str> {
         Some(NAME)
     }
@@ -126,7 +188,20 @@ impl<'root> Render<'root> for Renderer<'root, MjButton, ()> {
         let font_family = self.attribute("font-family");
         cursor.header.maybe_add_font_families(font_family);
 
-        let table = self.set_style_table(Tag::table_presentation());
+        let hover_class = self.hover_class();
+        if let Some(class) = hover_class.as_deref() {
+            cursor.header.add_style(render_hover_style(
+                class,
+                &[" td", " a", " p"],
+                self.attribute("hover-background-color"),
+                self.attribute("hover-color"),
+            ));
+        }
+
+        let table = self
+            .set_style_table(Tag::table_presentation())
+            .maybe_add_attribute("width", self.percent_width())
+            .maybe_add_class(hover_class);
         let tbody = Tag::tbody();
         let tr = Tag::tr();
         let td = self
@@ -144,7 +219,7 @@ impl<'root> Render<'root> for Renderer<'root, MjButton, ()> {
                 self.attribute("href")
                     .and_then(|_v| self.attribute("target")),
             );
-        let link = self.set_style_content(link);
+        let link = self.passthrough_attributes(self.set_style_content(link));
 
         table.render_open(&mut cursor.buffer)?;
         tbody.render_open(&mut cursor.buffer)?;
@@ -198,4 +273,28 @@ mod tests {
     crate::should_render!(text_transform, "mj-button-text-transform");
     crate::should_render!(vertical_align, "mj-button-vertical-align");
     crate::should_render!(width, "mj-button-width");
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_hover_style() {
+        let template = r##"<mjml><mj-body><mj-button href="https://example.com" hover-background-color="#000000" hover-color="#ffffff">Click</mj-button><mj-button>Plain</mj-button></mj-body></mjml>"##;
+        let root = crate::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        assert!(result.contains(":hover td,"));
+        assert!(result.contains(":focus-within a,"));
+        assert!(result.contains("background-color: #000000 !important;"));
+        assert!(result.contains("color: #ffffff !important;"));
+        assert_eq!(result.matches(r#"class="mj-button-hover-"#).count(), 1);
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_fill_percent_width() {
+        let template = r#"<mjml><mj-body><mj-section><mj-column><mj-button href="https://example.com" width="100%">Click</mj-button><mj-button href="https://example.com" width="200px">Fixed</mj-button></mj-column></mj-section></mj-body></mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        assert!(result.contains(r#"width="100%""#));
+        assert!(result.contains(r#"style="display:block;background"#));
+        assert!(result.contains(r#"style="display:inline-block;width:150px;background"#));
+    }
 }
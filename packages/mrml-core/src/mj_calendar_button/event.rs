@@ -0,0 +1,436 @@
+//! Event of a [`MjCalendarButton`](super::MjCalendarButton) and the links
+//! adding it to the calendars.
+//!
+//! ```rust
+//! use mrml::mj_calendar_button::CalendarEvent;
+//!
+//! let event = CalendarEvent::new("Launch party", "2026-10-16T18:00".parse().unwrap())
+//!     .with_end("2026-10-16T20:00".parse().unwrap())
+//!     .with_timezone("Europe/Paris")
+//!     .with_utc_offset(120);
+//! assert_eq!(
+//!     event.google_url(),
+//!     "https://calendar.google.com/calendar/render?action=TEMPLATE&text=Launch%20party&dates=20261016T180000/20261016T200000&ctz=Europe%2FParis"
+//! );
+//! let ics = event.ics("launch@example.com");
+//! assert!(ics.contains("BEGIN:VTIMEZONE\r\nTZID:Europe/Paris\r\n"));
+//! assert!(ics.contains("DTSTART;TZID=Europe/Paris:20261016T180000\r\n"));
+//! ```
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::helper::url::encode_component as encode;
+
+/// Date of an event, like `2026-10-16` for the events lasting the whole day,
+/// or with a time, like `2026-10-16T18:00` in the timezone of the event or
+/// `2026-10-16T16:00:00Z` in UTC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EventTime {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    /// Hour, minute and second, `None` for the events lasting the whole day.
+    pub time: Option<(u8, u8, u8)>,
+    pub utc: bool,
+}
+
+fn is_leap_year(year: u16) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl EventTime {
+    pub fn is_all_day(&self) -> bool {
+        self.time.is_none()
+    }
+
+    /// Beginning of the next day, the exclusive end of an event lasting the
+    /// whole day.
+    pub fn next_day(&self) -> Self {
+        let (year, month, day) = if self.day < days_in_month(self.year, self.month) {
+            (self.year, self.month, self.day + 1)
+        } else if self.month < 12 {
+            (self.year, self.month + 1, 1)
+        } else {
+            (self.year + 1, 1, 1)
+        };
+        Self {
+            year,
+            month,
+            day,
+            time: None,
+            utc: false,
+        }
+    }
+
+    /// Written like `20261016T180000`, for Google and the ics files.
+    pub fn compact(&self) -> String {
+        let mut result = format!("{:04}{:02}{:02}", self.year, self.month, self.day);
+        if let Some((hour, minute, second)) = self.time {
+            result.push_str(&format!("T{hour:02}{minute:02}{second:02}"));
+            if self.utc {
+                result.push('Z');
+            }
+        }
+        result
+    }
+
+    /// Written like `2026-10-16T18:00:00`, for Outlook.
+    pub fn iso(&self) -> String {
+        let mut result = format!("{:04}-{:02}-{:02}", self.year, self.month, self.day);
+        if let Some((hour, minute, second)) = self.time {
+            result.push_str(&format!("T{hour:02}:{minute:02}:{second:02}"));
+            if self.utc {
+                result.push('Z');
+            }
+        }
+        result
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidEventTime(pub String);
+
+impl Display for InvalidEventTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid event time {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidEventTime {}
+
+fn parse_numbers<const N: usize>(value: &str, separator: char) -> Option<[u16; N]> {
+    let mut result = [0; N];
+    let mut items = value.split(separator);
+    for item in result.iter_mut() {
+        let part = items.next()?;
+        if part.is_empty() || !part.bytes().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        *item = part.parse().ok()?;
+    }
+    items.next().is_none().then_some(result)
+}
+
+fn parse_time(value: &str) -> Option<(u8, u8, u8)> {
+    let (hour, minute, second) = match parse_numbers::<3>(value, ':') {
+        Some([hour, minute, second]) => (hour, minute, second),
+        None => {
+            let [hour, minute] = parse_numbers::<2>(value, ':')?;
+            (hour, minute, 0)
+        }
+    };
+    (hour < 24 && minute < 60 && second < 60).then_some((hour as u8, minute as u8, second as u8))
+}
+
+impl FromStr for EventTime {
+    type Err = InvalidEventTime;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidEventTime(value.to_string());
+        let (date, time) = match value.trim().split_once('T') {
+            Some((date, time)) => (date, Some(time)),
+            None => (value.trim(), None),
+        };
+        let [year, month, day] = parse_numbers::<3>(date, '-').ok_or_else(invalid)?;
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month as u8) as u16 {
+            return Err(invalid());
+        }
+        let (time, utc) = match time {
+            Some(time) => {
+                let (time, utc) = match time.strip_suffix('Z') {
+                    Some(time) => (time, true),
+                    None => (time, false),
+                };
+                (Some(parse_time(time).ok_or_else(invalid)?), utc)
+            }
+            None => (None, false),
+        };
+        Ok(Self {
+            year,
+            month: month as u8,
+            day: day as u8,
+            time,
+            utc,
+        })
+    }
+}
+
+/// Escapes the text of a property of an ics file.
+fn escape_ics(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CalendarEvent {
+    pub title: String,
+    pub start: EventTime,
+    /// Last day of the events lasting the whole day, the end of the other
+    /// ones. The event ends when it starts when missing.
+    pub end: Option<EventTime>,
+    pub location: Option<String>,
+    pub description: Option<String>,
+    /// Name of the timezone of the times not in UTC, like `Europe/Paris`.
+    pub timezone: Option<String>,
+    /// Offset of the timezone from UTC during the event, in minutes, like
+    /// `120` for Paris in summer. The ics file needs it to describe the
+    /// timezone.
+    pub utc_offset: Option<i16>,
+}
+
+impl CalendarEvent {
+    pub fn new<T: Into<String>>(title: T, start: EventTime) -> Self {
+        Self {
+            title: title.into(),
+            start,
+            end: None,
+            location: None,
+            description: None,
+            timezone: None,
+            utc_offset: None,
+        }
+    }
+
+    pub fn with_end(mut self, end: EventTime) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    pub fn with_location<V: Into<String>>(mut self, value: V) -> Self {
+        self.location = Some(value.into());
+        self
+    }
+
+    pub fn with_description<V: Into<String>>(mut self, value: V) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    pub fn with_timezone<V: Into<String>>(mut self, value: V) -> Self {
+        self.timezone = Some(value.into());
+        self
+    }
+
+    pub fn with_utc_offset(mut self, value: i16) -> Self {
+        self.utc_offset = Some(value);
+        self
+    }
+
+    /// End of the event, the day after the last one for the events lasting
+    /// the whole day.
+    fn exclusive_end(&self) -> EventTime {
+        let end = self.end.unwrap_or(self.start);
+        if self.start.is_all_day() {
+            end.next_day()
+        } else {
+            end
+        }
+    }
+
+    /// Timezone of the times, when they are not in UTC.
+    fn local_timezone(&self) -> Option<&str> {
+        self.timezone
+            .as_deref()
+            .filter(|_| !self.start.is_all_day() && !self.start.utc)
+    }
+
+    pub fn google_url(&self) -> String {
+        let mut result = format!(
+            "https://calendar.google.com/calendar/render?action=TEMPLATE&text={}&dates={}/{}",
+            encode(&self.title),
+            self.start.compact(),
+            self.exclusive_end().compact()
+        );
+        if let Some(ref value) = self.description {
+            result.push_str(&format!("&details={}", encode(value)));
+        }
+        if let Some(ref value) = self.location {
+            result.push_str(&format!("&location={}", encode(value)));
+        }
+        if let Some(value) = self.local_timezone() {
+            result.push_str(&format!("&ctz={}", encode(value)));
+        }
+        result
+    }
+
+    /// Link of Outlook on the web, the times without timezone being the ones
+    /// of the user.
+    pub fn outlook_url(&self) -> String {
+        let mut result = format!(
+            "https://outlook.live.com/calendar/0/deeplink/compose?path=%2Fcalendar%2Faction%2Fcompose&rru=addevent&subject={}&startdt={}&enddt={}",
+            encode(&self.title),
+            encode(&self.start.iso()),
+            encode(&self.exclusive_end().iso())
+        );
+        if self.start.is_all_day() {
+            result.push_str("&allday=true");
+        }
+        if let Some(ref value) = self.description {
+            result.push_str(&format!("&body={}", encode(value)));
+        }
+        if let Some(ref value) = self.location {
+            result.push_str(&format!("&location={}", encode(value)));
+        }
+        result
+    }
+
+    /// Timezone of the times in the ics file, with its offset to describe
+    /// it.
+    fn ics_timezone(&self) -> Option<(&str, i16)> {
+        Some((self.local_timezone()?, self.utc_offset?))
+    }
+
+    fn ics_time(&self, name: &str, value: &EventTime) -> String {
+        if value.is_all_day() {
+            format!("{name};VALUE=DATE:{}", value.compact())
+        } else if let Some((timezone, _)) = self.ics_timezone() {
+            format!("{name};TZID={timezone}:{}", value.compact())
+        } else {
+            format!("{name}:{}", value.compact())
+        }
+    }
+
+    /// Content of the ics file of the event, opened by Apple Calendar and
+    /// the desktop clients. The `uid` identifies the event, to update it
+    /// instead of adding it twice, like `launch-2026@example.com`.
+    ///
+    /// The timezone is described with the `utc_offset` of the event, the
+    /// times being written in the timezone of the user without it.
+    pub fn ics(&self, uid: &str) -> String {
+        // the stamp is the start of the event, to render the same output
+        let stamp = EventTime {
+            time: Some(self.start.time.unwrap_or_default()),
+            utc: true,
+            ..self.start
+        };
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            "PRODID:-//mrml//mj-calendar-button//EN".to_string(),
+        ];
+        if let Some((timezone, offset)) = self.ics_timezone() {
+            let offset = format!(
+                "{}{:02}{:02}",
+                if offset < 0 { '-' } else { '+' },
+                offset.unsigned_abs() / 60,
+                offset.unsigned_abs() % 60
+            );
+            lines.extend([
+                "BEGIN:VTIMEZONE".to_string(),
+                format!("TZID:{timezone}"),
+                "BEGIN:STANDARD".to_string(),
+                "DTSTART:19700101T000000".to_string(),
+                format!("TZOFFSETFROM:{offset}"),
+                format!("TZOFFSETTO:{offset}"),
+                "END:STANDARD".to_string(),
+                "END:VTIMEZONE".to_string(),
+            ]);
+        }
+        lines.extend([
+            "BEGIN:VEVENT".to_string(),
+            format!("UID:{}", escape_ics(uid)),
+            format!("DTSTAMP:{}", stamp.compact()),
+            self.ics_time("DTSTART", &self.start),
+            self.ics_time("DTEND", &self.exclusive_end()),
+            format!("SUMMARY:{}", escape_ics(&self.title)),
+        ]);
+        if let Some(ref value) = self.description {
+            lines.push(format!("DESCRIPTION:{}", escape_ics(value)));
+        }
+        if let Some(ref value) = self.location {
+            lines.push(format!("LOCATION:{}", escape_ics(value)));
+        }
+        lines.push("END:VEVENT".to_string());
+        lines.push("END:VCALENDAR".to_string());
+        let mut result = lines.join("\r\n");
+        result.push_str("\r\n");
+        result
+    }
+
+    /// The ics file in a `data:` url. Gmail and Outlook block those links,
+    /// the rendered buttons linking a hosted file instead.
+    pub fn ics_data_url(&self, uid: &str) -> String {
+        format!(
+            "data:text/calendar;charset=utf-8,{}",
+            encode(&self.ics(uid))
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CalendarEvent, EventTime, InvalidEventTime};
+
+    #[test]
+    fn should_parse_times() {
+        let value: EventTime = "2026-10-16T18:30Z".parse().unwrap();
+        assert_eq!(value.time, Some((18, 30, 0)));
+        assert!(value.utc);
+        assert_eq!(value.compact(), "20261016T183000Z");
+        assert_eq!(value.iso(), "2026-10-16T18:30:00Z");
+        let value: EventTime = "2024-02-29".parse().unwrap();
+        assert!(value.is_all_day());
+        assert_eq!(value.next_day().iso(), "2024-03-01");
+        assert_eq!(
+            "2026-12-31".parse::<EventTime>().unwrap().next_day().iso(),
+            "2027-01-01"
+        );
+        assert_eq!(
+            "2025-02-29".parse::<EventTime>(),
+            Err(InvalidEventTime("2025-02-29".into()))
+        );
+        assert!("2026-10-16T25:00".parse::<EventTime>().is_err());
+        assert!("tomorrow".parse::<EventTime>().is_err());
+    }
+
+    #[test]
+    fn should_build_links_of_all_day_events() {
+        let event = CalendarEvent::new("Sale, day 1", "2026-11-27".parse().unwrap())
+            .with_location("Paris & online");
+        assert_eq!(
+            event.google_url(),
+            "https://calendar.google.com/calendar/render?action=TEMPLATE&text=Sale%2C%20day%201&dates=20261127/20261128&location=Paris%20%26%20online"
+        );
+        assert!(event.outlook_url().ends_with(
+            "&startdt=2026-11-27&enddt=2026-11-28&allday=true&location=Paris%20%26%20online"
+        ));
+        let ics = event.ics("sale@example.com");
+        assert!(ics.contains("\r\nDTSTART;VALUE=DATE:20261127\r\nDTEND;VALUE=DATE:20261128\r\n"));
+        assert!(ics.contains("\r\nSUMMARY:Sale\\, day 1\r\n"));
+        assert!(event
+            .ics_data_url("sale@example.com")
+            .starts_with("data:text/calendar;charset=utf-8,BEGIN%3AVCALENDAR%0D%0A"));
+    }
+
+    #[test]
+    fn should_describe_the_timezone_in_the_ics() {
+        let event = CalendarEvent::new("Call", "2026-01-15T09:30".parse().unwrap())
+            .with_timezone("America/New_York")
+            .with_utc_offset(-300);
+        let ics = event.ics("call@example.com");
+        assert!(ics.contains(
+            "\r\nBEGIN:VTIMEZONE\r\nTZID:America/New_York\r\nBEGIN:STANDARD\r\nDTSTART:19700101T000000\r\nTZOFFSETFROM:-0500\r\nTZOFFSETTO:-0500\r\nEND:STANDARD\r\nEND:VTIMEZONE\r\nBEGIN:VEVENT\r\n"
+        ));
+        assert!(ics.contains("\r\nDTSTART;TZID=America/New_York:20260115T093000\r\n"));
+        // without the offset, the timezone can't be described
+        let event = CalendarEvent::new("Call", "2026-01-15T09:30".parse().unwrap())
+            .with_timezone("America/New_York");
+        let ics = event.ics("call@example.com");
+        assert!(!ics.contains("TZID"));
+        assert!(ics.contains("\r\nDTSTART:20260115T093000\r\n"));
+    }
+}
@@ -0,0 +1,23 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_calendar_button::MjCalendarButton;
+
+    #[test]
+    fn serialize() {
+        let mut elt = MjCalendarButton::default();
+        elt.attributes.insert("title".into(), "Launch".into());
+        elt.attributes.insert("start".into(), "2026-10-16".into());
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r#"{"type":"mj-calendar-button","attributes":{"title":"Launch","start":"2026-10-16"}}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{"type":"mj-calendar-button","attributes":{"title":"Sale","start":"2026-11-27","providers":"ics"}}"#;
+        let res: MjCalendarButton = serde_json::from_str(json).unwrap();
+        assert_eq!(res.attributes.len(), 3);
+        assert_eq!(res.attributes.get("providers").unwrap(), "ics");
+    }
+}
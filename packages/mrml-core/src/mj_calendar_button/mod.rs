@@ -0,0 +1,49 @@
+//! Module containing the `mj-calendar-button` element, rendering buttons
+//! adding an event to Google Calendar, Outlook or, with an ics file, to the
+//! other calendars.
+//!
+//! The event is described with the `title`, `start`, `end`, `location`,
+//! `description` and `timezone` attributes, the dates being written like
+//! `2026-10-16` or `2026-10-16T18:00`. The `providers` attribute lists the
+//! buttons, `google outlook ics` by default. The `data:` links being blocked
+//! by Gmail and Outlook, the ics file has to be hosted, the `ics` button only
+//! being rendered when linked with `ics-href`.
+//!
+//! ```rust
+//! let template = r#"<mjml><mj-body><mj-section><mj-column>
+//!   <mj-calendar-button title="Launch party" start="2026-10-16T18:00" end="2026-10-16T20:00" timezone="Europe/Paris" ics-href="https://example.com/launch.ics" />
+//! </mj-column></mj-section></mj-body></mjml>"#;
+//! let root = mrml::parse(template).expect("parse template");
+//! let opts = mrml::prelude::render::Options::default();
+//! match root.element.render(&opts) {
+//!     Ok(content) => println!("{content}"),
+//!     Err(_) => println!("couldn't render mjml template"),
+//! };
+//! ```
+
+mod event;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "print")]
+mod print;
+#[cfg(feature = "render")]
+mod render;
+
+use std::marker::PhantomData;
+
+pub use event::{CalendarEvent, EventTime, InvalidEventTime};
+
+use crate::prelude::hash::Map;
+use crate::prelude::{Component, StaticTag};
+
+pub const NAME: &str = "mj-calendar-button";
+
+pub struct MjCalendarButtonTag;
+
+impl StaticTag for MjCalendarButtonTag {
+    fn static_tag() -> &'static str {
+        NAME
+    }
+}
+
+pub type MjCalendarButton = Component<PhantomData<MjCalendarButtonTag>, Map<String, String>, ()>;
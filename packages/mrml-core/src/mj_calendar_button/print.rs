@@ -0,0 +1,15 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::print::Printable;
+
+    #[test]
+    fn empty() {
+        let mut item = crate::mj_calendar_button::MjCalendarButton::default();
+        item.attributes
+            .insert("start".to_string(), "2026-10-16".into());
+        assert_eq!(
+            "<mj-calendar-button start=\"2026-10-16\" />",
+            item.print_dense().unwrap()
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use super::{CalendarEvent, EventTime, MjCalendarButton, NAME};
+use crate::prelude::hash::Map;
+use crate::prelude::render::*;
+
+const GOOGLE: &str = "google";
+const OUTLOOK: &str = "outlook";
+const ICS: &str = "ics";
+
+impl<'root> Renderer<'root, MjCalendarButton, ()> {
+    fn event_time(&self, name: &str) -> Option<EventTime> {
+        let value = self.attribute(name)?;
+        let result = value.parse::<EventTime>().ok();
+        if result.is_none() {
+            self.report_invalid_attribute(name, value);
+        }
+        result
+    }
+
+    /// The event described by the attributes, `None` without a valid
+    /// `start`.
+    fn event(&self) -> Option<CalendarEvent> {
+        let start = self.event_time("start")?;
+        let mut result = CalendarEvent::new(self.attribute("title").unwrap_or_default(), start);
+        result.end = self.event_time("end");
+        result.location = self.attribute("location").map(String::from);
+        result.description = self.attribute("description").map(String::from);
+        result.timezone = self.attribute("timezone").map(String::from);
+        Some(result)
+    }
+
+    /// The link of the provider. The `data:` links being blocked by Gmail and
+    /// Outlook, the ics file is only linked when hosted, with `ics-href`.
+    fn link(&self, event: &CalendarEvent, provider: &str) -> Option<String> {
+        match provider {
+            GOOGLE => Some(event.google_url()),
+            OUTLOOK => Some(event.outlook_url()),
+            ICS => {
+                let result = self.attribute("ics-href").map(String::from);
+                if result.is_none() {
+                    self.report_missing_attribute("ics-href");
+                }
+                result
+            }
+            _ => None,
+        }
+    }
+
+    fn set_style_link<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 'a,
+        'a: 't,
+    {
+        tag.add_style("display", "inline-block")
+            .maybe_add_style("background", self.attribute("background-color"))
+            .maybe_add_style("border", self.attribute("border"))
+            .maybe_add_style("border-radius", self.attribute("border-radius"))
+            .maybe_add_style("color", self.attribute("color"))
+            .maybe_add_style("font-family", self.attribute_font_family("font-family"))
+            .maybe_add_style("font-size", self.attribute("font-size"))
+            .maybe_add_style("font-weight", self.attribute("font-weight"))
+            .maybe_add_style("line-height", self.attribute("line-height"))
+            .maybe_add_style("padding", self.attribute("inner-padding"))
+            .maybe_add_style("text-decoration", self.attribute("text-decoration"))
+            .maybe_add_style("text-transform", self.attribute("text-transform"))
+            .add_style("mso-padding-alt", "0px")
+    }
+}
+
+impl<'root> Render<'root> for Renderer<'root, MjCalendarButton, ()> {
+    fn default_attribute(&self, key: &str) -> Option<&'static str> {
+        match key {
+            "align" => Some("center"),
+            "background-color" => Some("#414141"),
+            "border" => Some("none"),
+            "border-radius" => Some("3px"),
+            "color" => Some("#ffffff"),
+            "font-family" => Some("Ubuntu, Helvetica, Arial, sans-serif"),
+            "font-size" => Some("13px"),
+            "font-weight" => Some("normal"),
+            "google-label" => Some("Google"),
+            "ics-label" => Some("iCal"),
+            "inner-padding" => Some("10px 25px"),
+            "line-height" => Some("120%"),
+            "outlook-label" => Some("Outlook"),
+            "padding" => Some("10px 25px"),
+            "providers" => Some("google outlook ics"),
+            "spacing" => Some("10px"),
+            "target" => Some("_blank"),
+            "text-decoration" => Some("none"),
+            "text-transform" => Some("none"),
+            _ => None,
+        }
+    }
+
+    fn raw_attribute(&self, key: &str) -> Option<&'root str> {
+        self.element.attributes.get(key).map(|v| v.as_str())
+    }
+
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
+    fn tag(&self) -> Option<&str> {
+        Some(NAME)
+    }
+
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.context
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let Some(event) = self.event() else {
+            if self.raw_attribute("title").is_some() && !self.attribute_exists("start") {
+                self.report_missing_attribute("start");
+            }
+            return Ok(());
+        };
+        cursor
+            .header
+            .maybe_add_font_families(self.attribute("font-family"));
+
+        let links = self
+            .attribute("providers")
+            .unwrap_or_default()
+            .split_whitespace()
+            .filter_map(|provider| {
+                let href = self.link(&event, provider)?;
+                let label = self.attribute(&format!("{provider}-label"))?;
+                Some((provider, href, label))
+            })
+            .collect::<Vec<_>>();
+        let div = self.passthrough_attributes(
+            Tag::div().maybe_add_style("text-align", self.attribute("align")),
+        );
+
+        div.render_open(&mut cursor.buffer)?;
+        for (index, (provider, href, label)) in links.iter().enumerate() {
+            let link = self
+                .set_style_link(Tag::new("a"))
+                .maybe_add_style(
+                    "margin-right",
+                    self.attribute("spacing")
+                        .filter(|_| index + 1 < links.len()),
+                )
+                .add_attribute("href", href.as_str())
+                .maybe_add_attribute("target", self.attribute("target"))
+                .add_class("mj-calendar-button")
+                .add_class(format!("mj-calendar-button-{provider}"));
+            link.render_open(&mut cursor.buffer)?;
+            cursor.buffer.push_str(label);
+            link.render_close(&mut cursor.buffer);
+        }
+        div.render_close(&mut cursor.buffer);
+        Ok(())
+    }
+}
+
+impl<'render, 'root: 'render> Renderable<'render, 'root> for MjCalendarButton {
+    fn renderer(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        Box::new(Renderer::new(context, self, ()))
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use crate::mjml::Mjml;
+    use crate::prelude::render::RenderWarningKind;
+
+    crate::should_render!(basic, "mj-calendar-button");
+    crate::should_render!(providers, "mj-calendar-button-providers");
+
+    #[test]
+    fn should_skip_the_ics_link_without_href() {
+        let template = r#"<mjml><mj-body><mj-section><mj-column><mj-calendar-button title="Sale" start="2026-11-27" /></mj-column></mj-section></mj-body></mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let (html, warnings) = root
+            .element
+            .render_with_report(&Default::default())
+            .unwrap();
+        assert!(!html.contains("data:text/calendar"));
+        assert!(!html.contains(">iCal</a>"));
+        assert!(html.contains(">Google</a>"));
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].kind,
+            RenderWarningKind::MissingAttribute {
+                name: "ics-href".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn should_skip_invalid_events() {
+        let template = r#"<mjml><mj-body><mj-section><mj-column><mj-calendar-button title="Sale" start="tomorrow" /></mj-column></mj-section></mj-body></mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let (html, warnings) = root
+            .element
+            .render_with_report(&Default::default())
+            .unwrap();
+        assert!(!html.contains("mj-calendar-button"));
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].kind,
+            RenderWarningKind::InvalidAttribute {
+                name: "start".into(),
+                value: "tomorrow".into(),
+            }
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use super::{MjCarousel, MjCarouselChild, NAME};
 use crate::helper::size::{Pixel, Size};
 use crate::helper::style::Style;
+use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
 impl MjCarouselChild {
@@ -33,6 +34,26 @@ struct MjCarouselExtra {
 }
 
 impl<'root> Renderer<'root, MjCarousel, MjCarouselExtra> {
+    /// Position in the children of the image shown by default and by the
+    /// clients without interactivity, the one selected with `fallback-image`,
+    /// starting at 1, or the first one.
+    fn selected_index(&self) -> usize {
+        let images = self
+            .element
+            .children
+            .iter()
+            .enumerate()
+            .filter(|(_, child)| child.as_mj_carousel_image().is_some())
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        self.attribute("fallback-image")
+            .and_then(|value| value.trim().parse::<usize>().ok())
+            .and_then(|position| images.get(position.checked_sub(1)?))
+            .or_else(|| images.first())
+            .copied()
+            .unwrap_or(0)
+    }
+
     fn get_thumbnails_width(&self) -> Pixel {
         let count = self.element.children.len();
         if count == 0 {
@@ -98,6 +119,7 @@ impl<'root> Renderer<'root, MjCarousel, MjCarouselExtra> {
         let border_radius = self.attribute("border-radius");
         let tb_border = self.attribute("tb-border");
         let tb_border_radius = self.attribute("tb-border-radius");
+        let selected = self.selected_index().to_string();
 
         for (index, child) in self.element.children.iter().enumerate() {
             let mut renderer = child.renderer(self.context());
@@ -105,6 +127,7 @@ impl<'root> Renderer<'root, MjCarousel, MjCarouselExtra> {
             renderer.maybe_add_extra_attribute("border-radius", border_radius);
             renderer.maybe_add_extra_attribute("tb-border", tb_border);
             renderer.maybe_add_extra_attribute("tb-border-radius", tb_border_radius);
+            renderer.add_extra_attribute("carousel-selected", &selected);
             renderer.set_index(index);
             renderer.render_fragment("radio", cursor)?;
         }
@@ -139,6 +162,7 @@ impl<'root> Renderer<'root, MjCarousel, MjCarouselExtra> {
         &self,
         direction: &str,
         icon: &str,
+        aria_label: Option<&str>,
         buf: &mut RenderBuffer,
     ) -> Result<(), Error> {
         let icon_width = self
@@ -165,7 +189,8 @@ impl<'root> Renderer<'root, MjCarousel, MjCarouselExtra> {
                     format!("mj-carousel-{}-radio-{}", self.extra.id, index + 1),
                 )
                 .add_class(format!("mj-carousel-{direction}"))
-                .add_class(format!("mj-carousel-{}-{}", direction, index + 1));
+                .add_class(format!("mj-carousel-{}-{}", direction, index + 1))
+                .maybe_add_attribute("aria-label", aria_label);
             label.render_open(buf)?;
             img.render_closed(buf)?;
             label.render_close(buf);
@@ -183,9 +208,11 @@ impl<'root> Renderer<'root, MjCarousel, MjCarouselExtra> {
         td.render_open(&mut cursor.buffer)?;
         div.render_open(&mut cursor.buffer)?;
 
+        let selected = self.selected_index().to_string();
         for (index, child) in self.element.children.iter().enumerate() {
             let mut renderer = child.renderer(self.context());
             renderer.add_extra_attribute("carousel-id", &self.extra.id);
+            renderer.add_extra_attribute("carousel-selected", &selected);
             renderer.maybe_add_extra_attribute("border-radius", self.attribute("border-radius"));
             renderer.maybe_add_extra_attribute("tb-border", self.attribute("tb-border"));
             renderer
@@ -216,12 +243,14 @@ impl<'root> Renderer<'root, MjCarousel, MjCarouselExtra> {
         self.render_controls(
             "previous",
             self.attribute("left-icon").unwrap(),
+            self.context.options.aria.carousel_previous_label.as_deref(),
             &mut cursor.buffer,
         )?;
         self.render_images(cursor)?;
         self.render_controls(
             "next",
             self.attribute("right-icon").unwrap(),
+            self.context.options.aria.carousel_next_label.as_deref(),
             &mut cursor.buffer,
         )?;
 
@@ -236,8 +265,8 @@ impl<'root> Renderer<'root, MjCarousel, MjCarouselExtra> {
         if let Some(child) = self
             .element
             .children
-            .iter()
-            .find_map(|child| child.as_mj_carousel_image())
+            .get(self.selected_index())
+            .and_then(|child| child.as_mj_carousel_image())
         {
             let mut renderer = child.renderer(self.context());
             renderer.add_extra_attribute("carousel-id", &self.extra.id);
@@ -377,9 +406,10 @@ impl<'root> Renderer<'root, MjCarousel, MjCarouselExtra> {
             res.add_selector(format!(".mj-carousel-{}-thumbnail-{}:hover {}+ .mj-carousel-main .mj-carousel-image-{}", self.extra.id, idx + 1, ext, idx + 1))
         }).add_content("display: block !important;").to_string());
         style.push(".mj-carousel noinput { display:block !important; }".into());
-        style.push(
-            ".mj-carousel noinput .mj-carousel-image-1 { display: block !important;  }".into(),
-        );
+        let selected = self.selected_index() + 1;
+        style.push(format!(
+            ".mj-carousel noinput .mj-carousel-image-{selected} {{ display: block !important;  }}"
+        ));
         style.push(".mj-carousel noinput .mj-carousel-arrows, .mj-carousel noinput .mj-carousel-thumbnails { display: none !important; }".into());
         style.push("[owa] .mj-carousel-thumbnail { display: none !important; }".into());
 
@@ -392,14 +422,14 @@ impl<'root> Renderer<'root, MjCarousel, MjCarouselExtra> {
                 display: none !important;
             }}
 
-            .mj-carousel-{}-radio-1:checked {}+ .mj-carousel-content .mj-carousel-{}-thumbnail-1 {{
+            .mj-carousel-{}-radio-{selected}:checked {}+ .mj-carousel-content .mj-carousel-{}-thumbnail-{selected} {{
                 border-color: transparent;
             }}
         }}
         "#,
             self.extra.id,
             self.extra.id,
-            repeat(length - 1, "+ *"),
+            repeat(length - selected, "+ *"),
             self.extra.id
         ));
         Some(style.join("\n"))
@@ -427,6 +457,10 @@ impl<'root> Render<'root> for Renderer<'root, MjCarousel, MjCarouselExtra> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
     fn tag(&self) -> Option<&str> {
         Some(NAME)
     }
@@ -459,6 +493,7 @@ impl<'root> Render<'root> for Renderer<'root, MjCarousel, MjCarouselExtra> {
             .add_class("mj-carousel-content")
             .add_class(format!("mj-carousel-{}-content", self.extra.id));
         let div = Tag::div().add_class("mj-carousel");
+        let div = self.passthrough_attributes(div);
 
         cursor.buffer.start_mso_negation_conditional_tag();
         div.render_open(&mut cursor.buffer)?;
@@ -495,4 +530,40 @@ mod tests {
     crate::should_render!(icon, "mj-carousel-icon");
     crate::should_render!(tb, "mj-carousel-tb");
     crate::should_render!(thumbnails, "mj-carousel-thumbnails");
+
+    #[test]
+    fn should_label_controls() {
+        let opts = crate::prelude::render::RenderOptions {
+            aria: crate::prelude::render::AriaOptions {
+                carousel_previous_label: Some("Previous image".into()),
+                carousel_next_label: Some("Next image".into()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let template = include_str!("../../resources/compare/success/mj-carousel.mjml");
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&opts).unwrap();
+        assert!(result.contains(r#"aria-label="Previous image""#));
+        assert!(result.contains(r#"aria-label="Next image""#));
+    }
+
+    #[test]
+    fn should_show_the_selected_fallback_image() {
+        let template = r#"<mjml><mj-body><mj-carousel fallback-image="2">
+  <mj-carousel-image src="https://example.com/a.png" />
+  <mj-carousel-image src="https://example.com/b.png" />
+  <mj-carousel-image src="https://example.com/c.png" />
+</mj-carousel></mj-body></mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        let start = result.rfind("<!--[if mso]>").unwrap();
+        let fallback = &result[start..start + result[start..].find("<![endif]-->").unwrap()];
+        assert!(fallback.contains("b.png"));
+        assert!(!fallback.contains("a.png"));
+        assert!(result.contains("noinput .mj-carousel-image-2 { display: block"));
+        let checked = result.find(r#"checked="checked""#).unwrap();
+        let end = checked + result[checked..].find("/>").unwrap();
+        assert!(result[checked..end].contains(r#"id="mj-carousel-00000000-radio-2""#));
+    }
 }
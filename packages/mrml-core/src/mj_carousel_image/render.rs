@@ -8,6 +8,16 @@ struct MjCarouselImageExtra<'a> {
 }
 
 impl<'root> Renderer<'root, MjCarouselImage, MjCarouselImageExtra<'root>> {
+    /// Whether the image is the one shown by default, the first one unless
+    /// the carousel selects another one.
+    fn is_selected(&self) -> bool {
+        self.extra
+            .attributes
+            .get("carousel-selected")
+            .and_then(|value| value.parse::<usize>().ok())
+            .map_or(self.index == 0, |selected| selected == self.index)
+    }
+
     fn set_style_images_img<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
     where
         'root: 'a,
@@ -63,7 +73,7 @@ impl<'root> Renderer<'root, MjCarouselImage, MjCarouselImageExtra<'root>> {
             )
             .maybe_add_attribute(
                 "checked",
-                if self.index == 0 {
+                if self.is_selected() {
                     Some("checked")
                 } else {
                     None
@@ -163,6 +173,10 @@ impl<'root> Render<'root> for Renderer<'root, MjCarouselImage, MjCarouselImageEx
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
     fn tag(&self) -> Option<&str> {
         Some(NAME)
     }
@@ -204,7 +218,7 @@ impl<'root> Render<'root> for Renderer<'root, MjCarouselImage, MjCarouselImageEx
                     .as_ref()
                     .map(|width| width.value().to_string()),
             );
-        let div = if self.index == 0 {
+        let div = if self.is_selected() {
             Tag::div()
         } else {
             Tag::div()
@@ -215,6 +229,7 @@ impl<'root> Render<'root> for Renderer<'root, MjCarouselImage, MjCarouselImageEx
             .add_class("mj-carousel-image")
             .add_class(format!("mj-carousel-image-{}", self.index + 1))
             .maybe_add_class(self.attribute("css-class"));
+        let div = self.passthrough_attributes(div);
 
         div.render_open(&mut cursor.buffer)?;
         if let Some(href) = self.attribute("href") {
@@ -0,0 +1,24 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_code::MjCode;
+
+    #[test]
+    fn serialize() {
+        let mut elt = MjCode::default();
+        elt.attributes.insert("language".into(), "rust".into());
+        elt.children = "let a = 1;\nlet b = 2;".into();
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r#"{"type":"mj-code","attributes":{"language":"rust"},"children":"let a = 1;\nlet b = 2;"}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let json =
+            r#"{"type":"mj-code","attributes":{"line-numbers":"true"},"children":"<p>Hello</p>"}"#;
+        let res: MjCode = serde_json::from_str(json).unwrap();
+        assert_eq!(res.attributes.len(), 1);
+        assert_eq!(res.children, "<p>Hello</p>");
+    }
+}
@@ -0,0 +1,48 @@
+//! Module containing the `mj-code` element, rendering a block of
+//! preformatted text in a monospace font, like a stack trace in an error
+//! digest or an api key.
+//!
+//! The content is kept as written, the markup being escaped so that it's
+//! shown and not interpreted, and the long lines wrap instead of scrolling.
+//! With `line-numbers="true"`, each line is rendered in the row of a table,
+//! next to its number.
+//!
+//! ```rust
+//! let template = r#"<mjml><mj-body><mj-section><mj-column>
+//!   <mj-code language="rust" line-numbers="true">fn main() {
+//!     println!("Hello &lt;world&gt;");
+//! }</mj-code>
+//! </mj-column></mj-section></mj-body></mjml>"#;
+//! let root = mrml::parse(template).expect("parse template");
+//! let opts = mrml::prelude::render::Options::default();
+//! match root.element.render(&opts) {
+//!     Ok(content) => println!("{content}"),
+//!     Err(_) => println!("couldn't render mjml template"),
+//! };
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::prelude::hash::Map;
+use crate::prelude::{Component, StaticTag};
+
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "parse")]
+mod parse;
+#[cfg(feature = "print")]
+mod print;
+#[cfg(feature = "render")]
+mod render;
+
+pub const NAME: &str = "mj-code";
+
+pub struct MjCodeTag;
+
+impl StaticTag for MjCodeTag {
+    fn static_tag() -> &'static str {
+        NAME
+    }
+}
+
+pub type MjCode = Component<PhantomData<MjCodeTag>, Map<String, String>, String>;
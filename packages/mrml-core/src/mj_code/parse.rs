@@ -0,0 +1,14 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_code::MjCode;
+
+    crate::should_parse!(empty, MjCode, "<mj-code />");
+
+    crate::should_parse!(
+        success,
+        MjCode,
+        r#"<mj-code language="rust">fn main() {
+    println!("&lt;hello&gt;");
+}</mj-code>"#
+    );
+}
@@ -0,0 +1,43 @@
+use crate::prelude::print::{Printable, PrintableAttributes};
+
+impl Printable for super::MjCode {
+    /// The content is printed as is, without indentation, to keep the
+    /// formatting of the code.
+    fn print<P: crate::prelude::print::Printer>(&self, printer: &mut P) -> std::fmt::Result {
+        printer.push_indent();
+        printer.open_tag(super::NAME)?;
+        self.attributes.print(printer)?;
+        if self.children.is_empty() {
+            printer.closed_tag();
+        } else {
+            printer.close_tag();
+            printer.push_str(&self.children);
+            printer.end_tag(super::NAME)?;
+        }
+        printer.push_new_line();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::print::Printable;
+
+    #[test]
+    fn dense() {
+        let mut item = crate::mj_code::MjCode::default();
+        item.attributes
+            .insert("language".to_string(), "rust".into());
+        item.children = "fn main() {\n    todo!()\n}".into();
+        assert_eq!(
+            "<mj-code language=\"rust\">fn main() {\n    todo!()\n}</mj-code>",
+            item.print_dense().unwrap()
+        );
+    }
+
+    #[test]
+    fn pretty() {
+        let item = crate::mj_code::MjCode::new(Default::default(), "a\n  b".into());
+        assert_eq!("<mj-code>a\n  b</mj-code>\n", item.print_pretty().unwrap());
+    }
+}
@@ -0,0 +1,236 @@
+use super::{MjCode, NAME};
+use crate::prelude::hash::Map;
+use crate::prelude::render::*;
+
+fn is_entity(value: &str) -> bool {
+    value.find(';').is_some_and(|end| {
+        end > 1
+            && value[1..end]
+                .chars()
+                .enumerate()
+                .all(|(index, c)| c.is_ascii_alphanumeric() || (index == 0 && c == '#'))
+    })
+}
+
+/// Escapes the markup of the code, keeping the entities written in the
+/// template, like `&lt;`, that are already escaped.
+fn escape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for (index, c) in value.char_indices() {
+        match c {
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '&' if !is_entity(&value[index..]) => result.push_str("&amp;"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Lines of the code, without the line breaks following the opening tag and
+/// preceding the closing tag.
+fn lines(value: &str) -> Vec<&str> {
+    let value = value
+        .strip_prefix("\r\n")
+        .or_else(|| value.strip_prefix('\n'))
+        .unwrap_or(value);
+    let value = match value.rfind('\n') {
+        Some(index) if value[index..].trim().is_empty() => &value[..index],
+        _ => value,
+    };
+    value
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .collect()
+}
+
+impl<'root> Renderer<'root, MjCode, ()> {
+    fn has_line_numbers(&self) -> bool {
+        self.attribute("line-numbers")
+            .is_some_and(|value| value == "true")
+    }
+
+    fn set_style_box<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 'a,
+        'a: 't,
+    {
+        tag.maybe_add_style("background", self.attribute("background-color"))
+            .maybe_add_style("border", self.attribute("border"))
+            .maybe_add_style("border-radius", self.attribute("border-radius"))
+            .maybe_add_style("padding", self.attribute("inner-padding"))
+    }
+
+    fn set_style_font<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 'a,
+        'a: 't,
+    {
+        tag.maybe_add_style("font-family", self.attribute_font_family("font-family"))
+            .maybe_add_style("font-size", self.attribute("font-size"))
+            .maybe_add_style("line-height", self.attribute("line-height"))
+            .add_style("margin", "0")
+    }
+
+    fn set_style_code<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 'a,
+        'a: 't,
+    {
+        self.set_style_font(tag.maybe_add_style("color", self.attribute("color")))
+            .add_style("text-align", "left")
+            .add_style("white-space", "pre-wrap")
+            .add_style("word-break", "break-all")
+            .add_style("word-wrap", "break-word")
+    }
+
+    fn render_plain(&self, lines: &[&str], buf: &mut RenderBuffer) -> std::fmt::Result {
+        let pre = self.set_style_code(Tag::new("pre"));
+        let code = Tag::new("code").maybe_add_class(
+            self.attribute("language")
+                .map(|language| format!("language-{language}")),
+        );
+        pre.render_open(buf)?;
+        code.render_text(buf, &escape(&lines.join("\n")))?;
+        pre.render_close(buf);
+        Ok(())
+    }
+
+    fn render_numbered(&self, lines: &[&str], buf: &mut RenderBuffer) -> std::fmt::Result {
+        let table = Tag::table_presentation()
+            .add_attribute("width", "100%")
+            .add_style("border-collapse", "collapse");
+        let tbody = Tag::tbody();
+        let tr = Tag::tr();
+        let number = self
+            .set_style_font(
+                Tag::td()
+                    .add_attribute("valign", "top")
+                    .maybe_add_style("color", self.attribute("line-number-color")),
+            )
+            .add_style("padding-right", "12px")
+            .add_style("text-align", "right")
+            .add_style("user-select", "none")
+            .add_style("white-space", "nowrap")
+            .add_style("width", "1%");
+        let code = self
+            .set_style_code(Tag::td())
+            .add_attribute("valign", "top");
+
+        table.render_open(buf)?;
+        tbody.render_open(buf)?;
+        for (index, line) in lines.iter().enumerate() {
+            tr.render_open(buf)?;
+            number.render_text(buf, &(index + 1).to_string())?;
+            if line.is_empty() {
+                code.render_text(buf, "&nbsp;")?;
+            } else {
+                code.render_text(buf, &escape(line))?;
+            }
+            tr.render_close(buf);
+        }
+        tbody.render_close(buf);
+        table.render_close(buf);
+        Ok(())
+    }
+}
+
+impl<'root> Render<'root> for Renderer<'root, MjCode, ()> {
+    fn default_attribute(&self, key: &str) -> Option<&'static str> {
+        match key {
+            "background-color" => Some("#f6f8fa"),
+            "border" => Some("1px solid #e1e4e8"),
+            "border-radius" => Some("6px"),
+            "color" => Some("#24292e"),
+            "font-family" => Some("Menlo, Consolas, 'Courier New', monospace"),
+            "font-size" => Some("13px"),
+            "inner-padding" => Some("12px 16px"),
+            "line-height" => Some("20px"),
+            "line-number-color" => Some("#8c959f"),
+            "padding" => Some("10px 25px"),
+            _ => None,
+        }
+    }
+
+    fn raw_attribute(&self, key: &str) -> Option<&'root str> {
+        self.element.attributes.get(key).map(|v| v.as_str())
+    }
+
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
+    fn tag(&self) -> Option<&str> {
+        Some(NAME)
+    }
+
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.context
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let lines = lines(&self.element.children);
+
+        let table = Tag::table_presentation()
+            .add_attribute("width", "100%")
+            .add_style("border-collapse", "separate")
+            .add_style("table-layout", "fixed");
+        let tbody = Tag::tbody();
+        let tr = Tag::tr();
+        let td = self.passthrough_attributes(
+            self.set_style_box(Tag::td())
+                .maybe_add_attribute("bgcolor", self.attribute("background-color")),
+        );
+
+        table.render_open(&mut cursor.buffer)?;
+        tbody.render_open(&mut cursor.buffer)?;
+        tr.render_open(&mut cursor.buffer)?;
+        td.render_open(&mut cursor.buffer)?;
+        if self.has_line_numbers() {
+            self.render_numbered(&lines, &mut cursor.buffer)?;
+        } else {
+            self.render_plain(&lines, &mut cursor.buffer)?;
+        }
+        td.render_close(&mut cursor.buffer);
+        tr.render_close(&mut cursor.buffer);
+        tbody.render_close(&mut cursor.buffer);
+        table.render_close(&mut cursor.buffer);
+
+        Ok(())
+    }
+}
+
+impl<'render, 'root: 'render> Renderable<'render, 'root> for MjCode {
+    fn renderer(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        Box::new(Renderer::new(context, self, ()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape, lines};
+
+    #[test]
+    fn should_escape_the_markup() {
+        assert_eq!(
+            escape(r#"<script>alert("&")</script>"#),
+            r#"&lt;script&gt;alert("&amp;")&lt;/script&gt;"#
+        );
+        assert_eq!(
+            escape("a &lt; b &amp;&#38; c & d;"),
+            "a &lt; b &amp;&#38; c &amp; d;"
+        );
+    }
+
+    #[test]
+    fn should_trim_the_surrounding_line_breaks() {
+        assert_eq!(lines("\n  a\n\n  b\n  "), vec!["  a", "", "  b"]);
+        assert_eq!(lines("a\r\nb"), vec!["a", "b"]);
+    }
+
+    crate::should_render!(basic, "mj-code");
+    crate::should_render!(line_numbers, "mj-code-line-numbers");
+}
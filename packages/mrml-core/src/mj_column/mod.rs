@@ -11,6 +11,9 @@ mod print;
 #[cfg(feature = "render")]
 mod render;
 
+#[cfg(feature = "render")]
+pub(crate) use render::render_row;
+
 pub const NAME: &str = "mj-column";
 
 pub struct MjColumnTag;
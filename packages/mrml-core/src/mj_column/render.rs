@@ -1,5 +1,7 @@
 use super::{MjColumn, NAME};
 use crate::helper::size::{Pixel, Size};
+use crate::helper::width;
+use crate::mj_body::MjBodyChild;
 use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
@@ -24,16 +26,12 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
         let inner_borders = inner_border_left + inner_border_right;
         let all_paddings = paddings.value() + borders.value() + inner_borders;
 
-        let container_width = self
-            .attribute_as_size("width")
-            .unwrap_or_else(|| Size::pixel(parent_width.value() / (non_raw_siblings as f32)));
-        if let Size::Percent(pc) = container_width {
-            Some(Pixel::new(
-                (parent_width.value() * pc.value() / 100.0) - all_paddings,
-            ))
-        } else {
-            Some(Pixel::new(container_width.value() - all_paddings))
-        }
+        Some(width::child_width(
+            *parent_width,
+            self.attribute_as_size("width"),
+            non_raw_siblings,
+            Pixel::new(all_paddings),
+        ))
     }
 
     fn non_raw_siblings(&self) -> usize {
@@ -55,6 +53,22 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
         (classname.replace('.', "-"), parsed_width)
     }
 
+    /// Class applying the `tablet-width` between the breakpoint and the
+    /// tablet breakpoint, when both are defined.
+    fn get_tablet_column_class(&self) -> Option<(String, Size)> {
+        if self.context.options.layout == Layout::Hybrid {
+            return None;
+        }
+        self.context.header.tablet_breakpoint()?;
+        let width = self.attribute_as_size("tablet-width")?;
+        let classname = if width.is_percent() {
+            format!("mj-column-tablet-per-{}", width.value())
+        } else {
+            format!("mj-column-tablet-px-{}", width.value())
+        };
+        Some((classname.replace('.', "-"), width))
+    }
+
     fn get_mobile_width(&self) -> Option<Size> {
         if !self.attribute_exists("mobile-width") {
             return Some(Size::percent(100.0));
@@ -101,8 +115,30 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
         'root: 'a,
         'a: 't,
     {
-        tag.maybe_add_style("vertical-align", self.attribute("vertical-align"))
-            .add_style("width", self.get_width_as_pixel())
+        let tag = tag
+            .maybe_add_style("vertical-align", self.attribute("vertical-align"))
+            .add_style("width", self.get_width_as_pixel());
+        if self.context.options.layout == Layout::Hybrid {
+            self.set_attributes_td_hybrid(tag)
+        } else {
+            tag
+        }
+    }
+
+    /// With the hybrid layout, the ghost table cells of outlook also get the
+    /// `width` and `valign` attributes, the outlook versions ignoring the
+    /// styles of the cells keep the columns side by side.
+    fn set_attributes_td_hybrid<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 'a,
+        'a: 't,
+    {
+        let width = self.get_width_as_pixel();
+        tag.maybe_add_attribute(
+            "width",
+            width.strip_suffix("px").map(|value| value.to_string()),
+        )
+        .maybe_add_attribute("valign", self.attribute("vertical-align"))
     }
 
     fn set_style_root_div<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
@@ -116,6 +152,33 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
             .add_style("display", "inline-block")
             .maybe_add_style("vertical-align", self.attribute("vertical-align"))
             .maybe_add_style("width", self.get_mobile_width().map(|v| v.to_string()))
+            .maybe_add_style("max-width", self.get_hybrid_max_width())
+    }
+
+    /// With the hybrid layout, the column is limited to its width instead of
+    /// relying on the media queries.
+    fn get_hybrid_max_width(&self) -> Option<String> {
+        if self.context.options.layout == Layout::Hybrid {
+            Some(self.get_width_as_pixel())
+        } else {
+            None
+        }
+    }
+
+    /// Rounded tables containing images hide the overflowing corners of the
+    /// images.
+    fn set_style_clip<'t>(&self, tag: Tag<'t>, radius: Option<&str>) -> Tag<'t> {
+        let has_image = self
+            .element
+            .children
+            .iter()
+            .any(|child| matches!(child, MjBodyChild::MjImage(_)));
+        if radius.is_some() && has_image {
+            tag.add_style("border-collapse", "separate")
+                .add_style("overflow", "hidden")
+        } else {
+            tag
+        }
     }
 
     fn set_style_table_gutter<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
@@ -123,41 +186,42 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
         'root: 'a,
         'a: 't,
     {
-        tag.maybe_add_style(
-            "background-color",
-            self.attribute("inner-background-color")
-                .or_else(|| self.attribute("background-color")),
-        )
-        .maybe_add_style(
-            "border",
-            self.attribute("inner-border")
-                .or_else(|| self.attribute("border")),
-        )
-        .maybe_add_style(
-            "border-bottom",
-            self.attribute("inner-border-bottom")
-                .or_else(|| self.attribute("border-bottom")),
-        )
-        .maybe_add_style(
-            "border-left",
-            self.attribute("inner-border-left")
-                .or_else(|| self.attribute("border-left")),
-        )
-        .maybe_add_style(
-            "border-radius",
-            self.attribute("inner-border-radius")
-                .or_else(|| self.attribute("border-radius")),
-        )
-        .maybe_add_style(
-            "border-right",
-            self.attribute("inner-border-right")
-                .or_else(|| self.attribute("border-right")),
-        )
-        .maybe_add_style(
-            "border-top",
-            self.attribute("inner-border-top")
-                .or_else(|| self.attribute("border-top")),
-        )
+        let radius = self
+            .attribute("inner-border-radius")
+            .or_else(|| self.attribute("border-radius"));
+        let tag = tag
+            .maybe_add_style(
+                "background-color",
+                self.attribute("inner-background-color")
+                    .or_else(|| self.attribute("background-color")),
+            )
+            .maybe_add_style(
+                "border",
+                self.attribute("inner-border")
+                    .or_else(|| self.attribute("border")),
+            )
+            .maybe_add_style(
+                "border-bottom",
+                self.attribute("inner-border-bottom")
+                    .or_else(|| self.attribute("border-bottom")),
+            )
+            .maybe_add_style(
+                "border-left",
+                self.attribute("inner-border-left")
+                    .or_else(|| self.attribute("border-left")),
+            )
+            .maybe_add_style("border-radius", radius)
+            .maybe_add_style(
+                "border-right",
+                self.attribute("inner-border-right")
+                    .or_else(|| self.attribute("border-right")),
+            )
+            .maybe_add_style(
+                "border-top",
+                self.attribute("inner-border-top")
+                    .or_else(|| self.attribute("border-top")),
+            );
+        self.set_style_clip(tag, radius)
     }
 
     fn set_style_table_simple<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
@@ -215,10 +279,26 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
         if self.has_gutter() {
             self.set_style_table_gutter(tag)
         } else {
-            self.set_style_table_simple(tag)
+            let radius = self.attribute("border-radius");
+            self.set_style_clip(self.set_style_table_simple(tag), radius)
         }
     }
 
+    /// The content of the column is right to left when the column defines a
+    /// `rtl` direction or, without direction, when its section does. The
+    /// elements supporting it, like `mj-social` and `mj-navbar`, mirror
+    /// their layout.
+    fn is_rtl_content(&self) -> bool {
+        let mut defined = None;
+        self.visit_attribute("direction", &mut |source, value| {
+            if source != AttributeSource::Default {
+                defined = Some(value == "rtl");
+            }
+            true
+        });
+        defined.unwrap_or_else(|| self.raw_extra_attribute("section-direction") == Some("rtl"))
+    }
+
     fn render_column(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         let table = self
             .set_style_table(Tag::table_presentation())
@@ -227,6 +307,7 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
         let siblings = self.element.children.len();
         let raw_siblings = self.element.children.iter().filter(|i| i.is_raw()).count();
         let current_width = self.current_width();
+        let is_rtl = self.is_rtl_content();
 
         table.render_open(&mut cursor.buffer)?;
         tbody.render_open(&mut cursor.buffer)?;
@@ -237,31 +318,13 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
             renderer.set_raw_siblings(raw_siblings);
             renderer.set_siblings(siblings);
             renderer.set_container_width(current_width);
+            if is_rtl {
+                renderer.add_extra_attribute("direction", "rtl");
+            }
             if child.is_raw() {
                 renderer.render(cursor)?;
             } else {
-                let tr = Tag::tr();
-                let td = Tag::td()
-                    .maybe_add_style(
-                        "background",
-                        renderer.attribute("container-background-color"),
-                    )
-                    .add_style("font-size", "0px")
-                    .maybe_add_style("padding", renderer.attribute("padding"))
-                    .maybe_add_style("padding-top", renderer.attribute("padding-top"))
-                    .maybe_add_style("padding-right", renderer.attribute("padding-right"))
-                    .maybe_add_style("padding-bottom", renderer.attribute("padding-bottom"))
-                    .maybe_add_style("padding-left", renderer.attribute("padding-left"))
-                    .add_style("word-break", "break-word")
-                    .maybe_add_attribute("align", renderer.attribute("align"))
-                    .maybe_add_attribute("vertical-align", renderer.attribute("vertical-align"))
-                    .maybe_add_class(renderer.attribute("css-class"));
-
-                tr.render_open(&mut cursor.buffer)?;
-                td.render_open(&mut cursor.buffer)?;
-                renderer.render(cursor)?;
-                td.render_close(&mut cursor.buffer);
-                tr.render_close(&mut cursor.buffer);
+                render_row(renderer.as_ref(), cursor)?;
             }
         }
 
@@ -272,6 +335,37 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
     }
 }
 
+/// Renders an element of the column in its own row, with the padding and
+/// the alignment of the element.
+pub(crate) fn render_row<'root>(
+    renderer: &dyn Render<'root>,
+    cursor: &mut RenderCursor,
+) -> Result<(), Error> {
+    let tr = Tag::tr();
+    let td = Tag::td()
+        .maybe_add_style(
+            "background",
+            renderer.attribute("container-background-color"),
+        )
+        .add_style("font-size", "0px")
+        .maybe_add_style("padding", renderer.attribute("padding"))
+        .maybe_add_style("padding-top", renderer.attribute("padding-top"))
+        .maybe_add_style("padding-right", renderer.attribute("padding-right"))
+        .maybe_add_style("padding-bottom", renderer.attribute("padding-bottom"))
+        .maybe_add_style("padding-left", renderer.attribute("padding-left"))
+        .add_style("word-break", "break-word")
+        .maybe_add_attribute("align", renderer.attribute("align"))
+        .maybe_add_attribute("vertical-align", renderer.attribute("vertical-align"))
+        .maybe_add_class(renderer.attribute("css-class"));
+
+    tr.render_open(&mut cursor.buffer)?;
+    td.render_open(&mut cursor.buffer)?;
+    renderer.render(cursor)?;
+    td.render_close(&mut cursor.buffer);
+    tr.render_close(&mut cursor.buffer);
+    Ok(())
+}
+
 impl<'root> Render<'root> for Renderer<'root, MjColumn, MjColumnExtra<'root>> {
     fn default_attribute(&self, name: &str) -> Option<&'static str> {
         match name {
@@ -289,6 +383,10 @@ impl<'root> Render<'root> for Renderer<'root, MjColumn, MjColumnExtra<'root>> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
     fn raw_extra_attribute(&self, key: &str) -> Option<&'root str> {
         self.extra.attributes.get(key).copied()
     }
@@ -330,13 +428,23 @@ impl<'root> Render<'root> for Renderer<'root, MjColumn, MjColumnExtra<'root>> {
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         let (classname, size) = self.get_column_class();
-        cursor.header.add_media_query(classname.clone(), size);
+        if self.context.options.layout == Layout::Classic {
+            cursor.header.add_media_query(classname.clone(), size);
+        }
+        let tablet_classname = self.get_tablet_column_class().map(|(classname, size)| {
+            cursor
+                .header
+                .add_tablet_media_query(classname.clone(), size);
+            classname
+        });
 
         let div = self
             .set_style_root_div(Tag::div())
             .add_class("mj-outlook-group-fix")
             .add_class(classname)
+            .maybe_add_class(tablet_classname)
             .maybe_add_class(self.attribute("css-class"));
+        let div = self.passthrough_attributes(div);
 
         div.render_open(&mut cursor.buffer)?;
         if self.has_gutter() {
@@ -375,4 +483,54 @@ mod tests {
     crate::should_render!(padding, "mj-column-padding");
     crate::should_render!(vertical_align, "mj-column-vertical-align");
     crate::should_render!(width, "mj-column-width");
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_clip_images_with_border_radius() {
+        let render = |attributes: &str| {
+            let template = format!(
+                "<mjml><mj-body><mj-section><mj-column {attributes}><mj-image src=\"https://example.com/card.png\" /><mj-text>Card</mj-text></mj-column></mj-section></mj-body></mjml>"
+            );
+            crate::parse(template)
+                .unwrap()
+                .element
+                .render(&Default::default())
+                .unwrap()
+        };
+        let html = render(r#"border-radius="8px""#);
+        assert!(html.contains(
+            "border-radius:8px;vertical-align:top;border-collapse:separate;overflow:hidden;"
+        ));
+        let html = render(
+            r#"padding="4px" border-radius="8px" inner-border="1px solid #ccc" inner-border-radius="6px""#,
+        );
+        assert!(html.contains(
+            "border:1px solid #ccc;border-radius:6px;border-collapse:separate;overflow:hidden;"
+        ));
+        let html = render("");
+        assert!(!html.contains("overflow:hidden"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_hybrid_layout() {
+        use crate::prelude::render::{Layout, RenderOptions};
+
+        let root = crate::parse(
+            r#"<mjml><mj-body><mj-section><mj-column width="200px" tablet-width="50%"><mj-text>A</mj-text></mj-column><mj-column><mj-text>B</mj-text></mj-column></mj-section></mj-body></mjml>"#,
+        )
+        .unwrap();
+        let opts = RenderOptions {
+            layout: Layout::Hybrid,
+            breakpoint: Some("480px 768px".into()),
+            ..Default::default()
+        };
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains("width:100%;max-width:200px;"));
+        assert!(html.contains("width:100%;max-width:300px;"));
+        assert!(!html.contains("@media only screen and (min-width:480px) { .mj-column"));
+        assert!(!html.contains("mj-column-tablet"));
+        assert!(html
+            .contains(r#"<td width="200" valign="top" style="vertical-align:top;width:200px;">"#));
+    }
 }
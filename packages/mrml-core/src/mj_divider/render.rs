@@ -1,5 +1,7 @@
 use super::{MjDivider, NAME};
-use crate::helper::size::{Pixel, Size};
+use crate::helper::size::Pixel;
+use crate::helper::width;
+use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
 impl<'root> Renderer<'root, MjDivider, ()> {
@@ -31,20 +33,11 @@ impl<'root> Renderer<'root, MjDivider, ()> {
     }
 
     fn get_outlook_width(&self) -> Pixel {
-        let container_width = self.container_width.as_ref().unwrap();
-        let padding_horizontal = self.get_padding_horizontal();
-        let width = self
-            .attribute_as_size("width")
-            .unwrap_or_else(|| Size::percent(100.0));
-        match width {
-            Size::Percent(value) => {
-                let effective = container_width.value() - padding_horizontal.value();
-                let multiplier = value.value() / 100.0;
-                Pixel::new(effective * multiplier)
-            }
-            Size::Pixel(value) => value,
-            _ => Pixel::new(container_width.value() - padding_horizontal.value()),
-        }
+        width::outlook_width(
+            self.container_width.unwrap(),
+            self.attribute_as_size("width"),
+            self.get_padding_horizontal(),
+        )
     }
 
     fn render_after(&self, buf: &mut RenderBuffer) -> Result<(), Error> {
@@ -86,6 +79,10 @@ impl<'root> Render<'root> for Renderer<'root, MjDivider, ()> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
     fn tag(&self) -> Option<&str> {
         Some(NAME)
     }
@@ -99,7 +96,7 @@ impl<'root> Render<'root> for Renderer<'root, MjDivider, ()> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
-        let p = self.set_style_p(Tag::new("p"));
+        let p = self.passthrough_attributes(self.set_style_p(Tag::new("p")));
         p.render_text(&mut cursor.buffer, "")?;
 
         self.render_after(&mut cursor.buffer)?;
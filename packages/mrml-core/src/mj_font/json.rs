@@ -30,6 +30,7 @@ mod tests {
             MjFontAttributes {
                 name: "Comic".to_string(),
                 href: "somewhere".to_string(),
+                ..Default::default()
             },
             (),
         );
@@ -45,6 +46,7 @@ mod tests {
             MjFontAttributes {
                 name: "Comic".to_string(),
                 href: "somewhere".to_string(),
+                ..Default::default()
             },
             (),
         );
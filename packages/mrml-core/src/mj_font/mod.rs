@@ -16,6 +16,14 @@ pub const NAME: &str = "mj-font";
 pub struct MjFontAttributes {
     pub name: String,
     pub href: String,
+    /// Value of the `font-display` descriptor, like `swap`, added to the url
+    /// of the stylesheet.
+    #[cfg_attr(feature = "json", serde(skip_serializing_if = "Option::is_none"))]
+    pub display: Option<String>,
+    /// Families appended to the `font-family` values referencing the font,
+    /// like `Arial, sans-serif`.
+    #[cfg_attr(feature = "json", serde(skip_serializing_if = "Option::is_none"))]
+    pub fallback: Option<String>,
 }
 
 pub struct MjFontTag;
@@ -35,6 +43,7 @@ impl MjFont {
             MjFontAttributes {
                 name: name.into(),
                 href: href.into(),
+                ..Default::default()
             },
             (),
         )
@@ -49,4 +58,12 @@ impl MjFont {
     pub fn href(&self) -> &str {
         &self.attributes.href
     }
+
+    pub fn display(&self) -> Option<&str> {
+        self.attributes.display.as_deref()
+    }
+
+    pub fn fallback(&self) -> Option<&str> {
+        self.attributes.fallback.as_deref()
+    }
 }
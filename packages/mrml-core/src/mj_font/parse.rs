@@ -13,6 +13,8 @@ fn parse_attributes(cursor: &mut MrmlCursor<'_>) -> Result<MjFontAttributes, Err
         match attrs.local.as_str() {
             "name" => result.name = attrs.value.to_string(),
             "href" => result.href = attrs.value.to_string(),
+            "display" => result.display = Some(attrs.value.to_string()),
+            "fallback" => result.fallback = Some(attrs.value.to_string()),
             _ => cursor.add_warning(WarningKind::UnexpectedAttribute, attrs.span),
         }
     }
@@ -51,6 +53,12 @@ mod tests {
         r#"<mj-font name="Comic" href="https://jolimail.io" />"#
     );
 
+    crate::should_sync_parse!(
+        with_display_and_fallback,
+        MjFont,
+        r#"<mj-font name="Comic" href="https://jolimail.io" display="swap" fallback="Arial, sans-serif" />"#
+    );
+
     crate::should_sync_parse!(
         unexpected_attribute,
         MjFont,
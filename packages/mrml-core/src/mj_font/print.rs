@@ -3,7 +3,14 @@ use crate::prelude::print::PrintableAttributes;
 impl PrintableAttributes for super::MjFontAttributes {
     fn print<P: crate::prelude::print::Printer>(&self, printer: &mut P) -> std::fmt::Result {
         printer.push_attribute("name", self.name.as_str())?;
-        printer.push_attribute("href", self.href.as_str())
+        printer.push_attribute("href", self.href.as_str())?;
+        if let Some(ref display) = self.display {
+            printer.push_attribute("display", display.as_str())?;
+        }
+        if let Some(ref fallback) = self.fallback {
+            printer.push_attribute("fallback", fallback.as_str())?;
+        }
+        Ok(())
     }
 }
 
@@ -20,4 +27,15 @@ mod tests {
             item.print_dense().unwrap()
         );
     }
+
+    #[test]
+    fn with_display_and_fallback() {
+        let mut item = MjFont::build("Comic sans MS", "http://localhost");
+        item.attributes.display = Some("swap".into());
+        item.attributes.fallback = Some("Arial, sans-serif".into());
+        assert_eq!(
+            "<mj-font name=\"Comic sans MS\" href=\"http://localhost\" display=\"swap\" fallback=\"Arial, sans-serif\" />",
+            item.print_dense().unwrap()
+        );
+    }
 }
@@ -0,0 +1,26 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_body::MjBodyChild;
+    use crate::mj_footer::MjFooter;
+    use crate::mj_social::MjSocial;
+
+    #[test]
+    fn serialize() {
+        let mut elt = MjFooter::default();
+        elt.attributes.insert("legal".into(), "Acme SAS".into());
+        elt.children
+            .push(MjBodyChild::MjSocial(MjSocial::default()));
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r#"{"type":"mj-footer","attributes":{"legal":"Acme SAS"},"children":[{"type":"mj-social"}]}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{"type":"mj-footer","attributes":{"address":"1 rue de la Paix, Paris"},"children":[{"type":"comment","children":"social"},{"type":"mj-social"}]}"#;
+        let res: MjFooter = serde_json::from_str(json).unwrap();
+        assert_eq!(res.attributes.len(), 1);
+        assert_eq!(res.children.len(), 2);
+    }
+}
@@ -0,0 +1,52 @@
+//! Module containing the `mj-footer` element, rendering the usual footer of
+//! an email as a section: the content of the element, like the social
+//! icons, followed by the postal address, the unsubscribe and preferences
+//! links and the legal text.
+//!
+//! The parts are filled with the `address`, `unsubscribe-href`,
+//! `preferences-href` and `legal` attributes, each one being skipped when
+//! missing, and themed with the `color`, `link-color`, `font-family`,
+//! `font-size` and `background-color` attributes.
+//!
+//! ```rust
+//! let template = r#"<mjml><mj-body>
+//!   <mj-footer address="Acme, 1 rue de la Paix, 75002 Paris" unsubscribe-href="{{unsubscribe_url}}" preferences-href="{{preferences_url}}" legal="You receive this email because you subscribed to our newsletter.">
+//!     <mj-social>
+//!       <mj-social-element name="facebook" href="https://facebook.com/acme" />
+//!     </mj-social>
+//!   </mj-footer>
+//! </mj-body></mjml>"#;
+//! let root = mrml::parse(template).expect("parse template");
+//! let opts = mrml::prelude::render::Options::default();
+//! match root.element.render(&opts) {
+//!     Ok(content) => println!("{content}"),
+//!     Err(_) => println!("couldn't render mjml template"),
+//! };
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::mj_body::MjBodyChild;
+use crate::prelude::hash::Map;
+use crate::prelude::{Component, StaticTag};
+
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "parse")]
+mod parse;
+#[cfg(feature = "print")]
+mod print;
+#[cfg(feature = "render")]
+mod render;
+
+pub const NAME: &str = "mj-footer";
+
+pub struct MjFooterTag;
+
+impl StaticTag for MjFooterTag {
+    fn static_tag() -> &'static str {
+        NAME
+    }
+}
+
+pub type MjFooter = Component<PhantomData<MjFooterTag>, Map<String, String>, Vec<MjBodyChild>>;
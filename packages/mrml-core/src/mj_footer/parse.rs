@@ -0,0 +1,19 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_footer::MjFooter;
+
+    crate::should_parse!(
+        empty,
+        MjFooter,
+        r#"<mj-footer address="1 rue de la Paix, Paris" />"#
+    );
+
+    crate::should_parse!(
+        with_children,
+        MjFooter,
+        r#"<mj-footer unsubscribe-href="{{unsubscribe}}">
+    <!-- social -->
+    <mj-social><mj-social-element name="facebook" href="https://facebook.com/acme" /></mj-social>
+</mj-footer>"#
+    );
+}
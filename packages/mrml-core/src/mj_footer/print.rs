@@ -0,0 +1,26 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::print::Printable;
+
+    #[test]
+    fn empty() {
+        let mut item = crate::mj_footer::MjFooter::default();
+        item.attributes
+            .insert("legal".to_string(), "Acme SAS".into());
+        assert_eq!(
+            "<mj-footer legal=\"Acme SAS\" />",
+            item.print_dense().unwrap()
+        );
+    }
+
+    #[test]
+    fn with_children() {
+        let mut item = crate::mj_footer::MjFooter::default();
+        item.children
+            .push(crate::mj_social::MjSocial::default().into());
+        assert_eq!(
+            "<mj-footer><mj-social /></mj-footer>",
+            item.print_dense().unwrap()
+        );
+    }
+}
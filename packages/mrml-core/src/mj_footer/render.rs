@@ -0,0 +1,222 @@
+use super::{MjFooter, NAME};
+use crate::helper::size::Pixel;
+use crate::helper::width;
+use crate::mj_column::render_row;
+use crate::mj_section::{SectionLikeRender, WithMjSectionBackground};
+use crate::prelude::hash::Map;
+use crate::prelude::render::*;
+
+const LINKS: [&str; 2] = ["preferences", "unsubscribe"];
+
+impl<'root> Renderer<'root, MjFooter, ()> {
+    fn current_width(&self) -> Option<Pixel> {
+        self.container_width.map(|container| {
+            width::box_width(
+                container,
+                self.get_padding_horizontal(),
+                self.get_border_horizontal(),
+            )
+        })
+    }
+
+    fn set_style_text<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 'a,
+        'a: 't,
+    {
+        tag.maybe_add_style("color", self.attribute("color"))
+            .maybe_add_style("font-family", self.attribute_font_family("font-family"))
+            .maybe_add_style("font-size", self.attribute("font-size"))
+            .maybe_add_style("line-height", self.attribute("line-height"))
+            .maybe_add_style("text-align", self.attribute("align"))
+    }
+
+    fn render_text_row<F>(
+        &self,
+        class: &str,
+        cursor: &mut RenderCursor,
+        content: F,
+    ) -> Result<(), Error>
+    where
+        F: FnOnce(&mut RenderBuffer) -> std::fmt::Result,
+    {
+        let tr = Tag::tr();
+        let td = Tag::td()
+            .maybe_add_attribute("align", self.attribute("align"))
+            .add_style("font-size", "0px")
+            .maybe_add_style("padding", self.attribute("row-padding"))
+            .add_style("word-break", "break-word");
+        let div = self.set_style_text(Tag::div()).add_class(class);
+
+        tr.render_open(&mut cursor.buffer)?;
+        td.render_open(&mut cursor.buffer)?;
+        div.render_with(&mut cursor.buffer, content)?;
+        td.render_close(&mut cursor.buffer);
+        tr.render_close(&mut cursor.buffer);
+        Ok(())
+    }
+
+    /// The links with an url, in the order of [`LINKS`].
+    fn links(&self) -> Vec<(&str, &str)> {
+        LINKS
+            .iter()
+            .filter_map(|name| {
+                let href = self.attribute(&format!("{name}-href"))?;
+                let label = self.attribute(&format!("{name}-label"))?;
+                Some((href, label))
+            })
+            .collect()
+    }
+
+    fn render_links(&self, buf: &mut RenderBuffer) -> std::fmt::Result {
+        for (index, (href, label)) in self.links().into_iter().enumerate() {
+            if index > 0 {
+                buf.push_str(self.attribute("link-separator").unwrap_or_default());
+            }
+            Tag::new("a")
+                .add_attribute("href", href)
+                .maybe_add_attribute("target", self.attribute("target"))
+                .maybe_add_style("color", self.attribute("link-color"))
+                .maybe_add_style("text-decoration", self.attribute("text-decoration"))
+                .render_text(buf, label)?;
+        }
+        Ok(())
+    }
+
+    fn render_content(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let table = Tag::table_presentation().add_attribute("width", "100%");
+        let tbody = Tag::tbody();
+
+        table.render_open(&mut cursor.buffer)?;
+        tbody.render_open(&mut cursor.buffer)?;
+        for (index, child) in self.element.children.iter().enumerate() {
+            let mut renderer = child.renderer(self.context());
+            renderer.set_index(index);
+            renderer.set_container_width(self.current_width());
+            if child.is_raw() {
+                renderer.render(cursor)?;
+            } else {
+                render_row(renderer.as_ref(), cursor)?;
+            }
+        }
+        if let Some(address) = self.attribute("address") {
+            self.render_text_row("mj-footer-address", cursor, |buf| {
+                buf.push_str(address);
+                Ok(())
+            })?;
+        }
+        if !self.links().is_empty() {
+            self.render_text_row("mj-footer-links", cursor, |buf| self.render_links(buf))?;
+        }
+        if let Some(legal) = self.attribute("legal") {
+            self.render_text_row("mj-footer-legal", cursor, |buf| {
+                buf.push_str(legal);
+                Ok(())
+            })?;
+        }
+        tbody.render_close(&mut cursor.buffer);
+        table.render_close(&mut cursor.buffer);
+        Ok(())
+    }
+}
+
+impl<'root> WithMjSectionBackground<'root> for Renderer<'root, MjFooter, ()> {}
+
+impl<'root> SectionLikeRender<'root> for Renderer<'root, MjFooter, ()> {
+    fn children(&self) -> &Vec<crate::mj_body::MjBodyChild> {
+        &self.element.children
+    }
+
+    fn container_width(&self) -> &Option<Pixel> {
+        &self.container_width
+    }
+
+    /// The parts of the footer are rows of a single column.
+    fn render_wrapped_children(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let tr = Tag::tr();
+        let td = Tag::td()
+            .add_style("vertical-align", "top")
+            .maybe_add_style("width", self.current_width().map(|v| v.to_string()));
+
+        tr.render_open(&mut cursor.buffer)?;
+        td.render_open(&mut cursor.buffer)?;
+        cursor.buffer.end_conditional_tag();
+        self.render_content(cursor)?;
+        cursor.buffer.start_conditional_tag();
+        td.render_close(&mut cursor.buffer);
+        tr.render_close(&mut cursor.buffer);
+        Ok(())
+    }
+}
+
+impl<'root> Render<'root> for Renderer<'root, MjFooter, ()> {
+    fn default_attribute(&self, name: &str) -> Option<&'static str> {
+        match name {
+            "align" => Some("center"),
+            "background-position" => Some("top center"),
+            "background-repeat" => Some("repeat"),
+            "background-size" => Some("auto"),
+            "color" => Some("#777777"),
+            "direction" => Some("ltr"),
+            "font-family" => Some("Ubuntu, Helvetica, Arial, sans-serif"),
+            "font-size" => Some("12px"),
+            "line-height" => Some("18px"),
+            "link-color" => Some("#555555"),
+            "link-separator" => Some("&nbsp;&nbsp;|&nbsp;&nbsp;"),
+            "padding" => Some("20px 0"),
+            "preferences-label" => Some("Manage preferences"),
+            "row-padding" => Some("4px 25px"),
+            "target" => Some("_blank"),
+            "text-align" => Some("center"),
+            "text-decoration" => Some("underline"),
+            "unsubscribe-label" => Some("Unsubscribe"),
+            _ => None,
+        }
+    }
+
+    fn raw_attribute(&self, key: &str) -> Option<&'root str> {
+        self.element.attributes.get(key).map(|v| v.as_str())
+    }
+
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
+    fn tag(&self) -> Option<&str> {
+        Some(NAME)
+    }
+
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.context
+    }
+
+    fn set_container_width(&mut self, width: Option<Pixel>) {
+        self.container_width = width;
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        cursor
+            .header
+            .maybe_add_font_families(self.attribute("font-family"));
+        if self.is_full_width() {
+            self.render_full_width(cursor)
+        } else {
+            self.render_simple(cursor)
+        }
+    }
+}
+
+impl<'render, 'root: 'render> Renderable<'render, 'root> for MjFooter {
+    fn renderer(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        Box::new(Renderer::new(context, self, ()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::should_render!(basic, "mj-footer");
+    crate::should_render!(partial, "mj-footer-partial");
+}
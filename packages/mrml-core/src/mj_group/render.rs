@@ -1,7 +1,9 @@
-use std::borrow::Cow;
+use std::convert::TryFrom;
 
 use super::{MjGroup, NAME};
 use crate::helper::size::{Pixel, Size};
+use crate::helper::width;
+use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
 impl<'root> Renderer<'root, MjGroup, ()> {
@@ -21,14 +23,12 @@ impl<'root> Renderer<'root, MjGroup, ()> {
         let inner_borders = inner_border_left + inner_border_right;
         let all_paddings = paddings.value() + borders.value() + inner_borders;
 
-        let container_width = self
-            .attribute_as_size("width")
-            .unwrap_or_else(|| Size::pixel(parent_width.value() / (non_raw_siblings as f32)));
-        if let Size::Percent(pc) = container_width {
-            Pixel::new((parent_width.value() * pc.value() / 100.0) - all_paddings)
-        } else {
-            Pixel::new(container_width.value() - all_paddings)
-        }
+        width::child_width(
+            *parent_width,
+            self.attribute_as_size("width"),
+            non_raw_siblings,
+            Pixel::new(all_paddings),
+        )
     }
 
     fn non_raw_siblings(&self) -> usize {
@@ -63,6 +63,22 @@ impl<'root> Renderer<'root, MjGroup, ()> {
             .maybe_add_style("direction", self.attribute("direction"))
             .maybe_add_style("background-color", self.attribute("background-color"))
             .maybe_add_style("vertical-align", self.attribute("vertical-align"))
+            .maybe_add_style("max-width", self.get_hybrid_max_width())
+    }
+
+    /// With the hybrid layout, the group is limited to its width instead of
+    /// relying on the media queries.
+    fn get_hybrid_max_width(&self) -> Option<String> {
+        if self.context.options.layout != Layout::Hybrid {
+            return None;
+        }
+        let container_width = self.container_width.as_ref()?;
+        let width = match self.get_parsed_width() {
+            Size::Percent(value) => Pixel::new(container_width.value() * value.value() / 100.0),
+            Size::Pixel(value) => value,
+            _ => return None,
+        };
+        Some(width.to_string())
     }
 
     fn set_style_td_outlook<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
@@ -70,8 +86,32 @@ impl<'root> Renderer<'root, MjGroup, ()> {
         'root: 'a,
         'a: 't,
     {
-        tag.maybe_add_style("vertical-align", self.attribute("vertical-align"))
-            .add_style("width", self.current_width().to_string())
+        let width = self.current_width();
+        let tag = tag
+            .maybe_add_style("vertical-align", self.attribute("vertical-align"))
+            .add_style("width", width.to_string());
+        if self.context.options.layout == Layout::Hybrid {
+            tag.add_attribute("width", width.value().to_string())
+                .maybe_add_attribute("valign", self.attribute("vertical-align"))
+        } else {
+            tag
+        }
+    }
+
+    /// Width of a child, including its paddings, computed like mjml does
+    /// for the outlook cells: the percentages are relative to the group and
+    /// the children without width share the room equally.
+    fn get_element_width(
+        &self,
+        width: Option<&str>,
+        current_width: &Pixel,
+        non_raw_siblings: usize,
+    ) -> Pixel {
+        match width.and_then(|value| Size::try_from(value).ok()) {
+            Some(Size::Percent(value)) => Pixel::new(current_width.value() * value.value() / 100.0),
+            Some(Size::Pixel(value)) => value,
+            _ => Pixel::new(current_width.value() / (non_raw_siblings.max(1) as f32)),
+        }
     }
 
     fn render_children(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
@@ -91,24 +131,51 @@ impl<'root> Renderer<'root, MjGroup, ()> {
             renderer.set_raw_siblings(raw_siblings);
             renderer.set_container_width(Some(current_width));
             renderer.add_extra_attribute("mobile-width", "mobile-width");
+            if self.attribute_equals("direction", "rtl") {
+                renderer.add_extra_attribute("section-direction", "rtl");
+            }
             if child.is_raw() {
                 renderer.render(cursor)?;
             } else {
+                let width = self.get_element_width(
+                    renderer.attribute("width"),
+                    &current_width,
+                    siblings - raw_siblings,
+                );
                 let td = Tag::td()
                     .maybe_add_style("align", renderer.attribute("align"))
                     .maybe_add_style("vertical-align", renderer.attribute("vertical-align"))
-                    .maybe_add_style(
-                        "width",
-                        renderer
-                            .get_width()
-                            .map(|w| Cow::Owned(w.to_string()))
-                            .or_else(|| renderer.attribute("width").map(Cow::Borrowed)),
-                    );
+                    .add_style("width", width.to_string());
+                let td = if self.context.options.layout == Layout::Hybrid {
+                    td.add_attribute("width", width.value().to_string())
+                        .maybe_add_attribute("valign", renderer.attribute("vertical-align"))
+                } else {
+                    td
+                };
 
                 cursor.buffer.start_conditional_tag();
                 td.render_open(&mut cursor.buffer)?;
                 cursor.buffer.end_conditional_tag();
-                renderer.render(cursor)?;
+                if renderer.tag() == Some(crate::mj_column::NAME) {
+                    renderer.render(cursor)?;
+                } else {
+                    // the other elements, like the spacers, are kept in line
+                    // with the columns
+                    let div = Tag::div()
+                        .add_style("display", "inline-block")
+                        .add_style(
+                            "vertical-align",
+                            renderer.attribute("vertical-align").unwrap_or("top"),
+                        )
+                        .add_style(
+                            "width",
+                            Size::percent(width.value() * 100.0 / current_width.value())
+                                .to_string(),
+                        );
+                    div.render_open(&mut cursor.buffer)?;
+                    renderer.render(cursor)?;
+                    div.render_close(&mut cursor.buffer);
+                }
                 cursor.buffer.start_conditional_tag();
                 td.render_close(&mut cursor.buffer);
                 cursor.buffer.end_conditional_tag();
@@ -130,6 +197,10 @@ impl<'root> Render<'root> for Renderer<'root, MjGroup, ()> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
     fn tag(&self) -> Option<&str> {
         Some(NAME)
     }
@@ -167,13 +238,16 @@ impl<'root> Render<'root> for Renderer<'root, MjGroup, ()> {
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         let (classname, size) = self.get_column_class();
-        cursor.header.add_media_query(classname.clone(), size);
+        if self.context.options.layout == Layout::Classic {
+            cursor.header.add_media_query(classname.clone(), size);
+        }
 
         let div = self
             .set_style_root_div(Tag::div())
             .add_class(classname)
             .add_class("mj-outlook-group-fix")
             .maybe_add_class(self.attribute("css-class"));
+        let div = self.passthrough_attributes(div);
         let table = Tag::table_presentation().maybe_add_attribute(
             "bgcolor",
             self.attribute("background-color").and_then(|color| {
@@ -219,4 +293,40 @@ mod tests {
     crate::should_render!(direction, "mj-group-direction");
     crate::should_render!(vertical_align, "mj-group-vertical-align");
     crate::should_render!(width, "mj-group-width");
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_compute_mixed_widths() {
+        let root = crate::parse(
+            r#"<mjml><mj-body><mj-section><mj-group><mj-column width="25%" padding="10px"><mj-text>A</mj-text></mj-column><mj-column width="300px"><mj-text>B</mj-text></mj-column><mj-spacer height="10px" width="150px" /><mj-raw><span>raw</span></mj-raw></mj-group></mj-section></mj-body></mjml>"#,
+        )
+        .unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        assert!(html.contains(r#"<td style="vertical-align:top;width:150px;">"#));
+        assert!(html.contains(r#"<td style="vertical-align:top;width:300px;">"#));
+        assert!(html.contains(r#"<td style="width:150px;">"#));
+        assert!(
+            html.contains(r#"<div style="display:inline-block;vertical-align:top;width:25%;">"#)
+        );
+        assert!(html.contains("<span>raw</span>"));
+    }
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_hybrid_layout() {
+        use crate::prelude::render::{Layout, RenderOptions};
+
+        let root = crate::parse(
+            r#"<mjml><mj-body><mj-section><mj-group width="50%"><mj-column><mj-text>A</mj-text></mj-column></mj-group></mj-section></mj-body></mjml>"#,
+        )
+        .unwrap();
+        let opts = RenderOptions {
+            layout: Layout::Hybrid,
+            ..Default::default()
+        };
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains("width:100%;direction:ltr;max-width:300px;"));
+        assert!(html
+            .contains(r#"<td width="300" valign="top" style="vertical-align:top;width:300px;">"#));
+        assert!(!html.contains("{ .mj-column-per-50"));
+    }
 }
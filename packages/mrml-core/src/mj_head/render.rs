@@ -1,5 +1,11 @@
+use std::borrow::Cow;
+
 use super::MjHead;
+use crate::helper::size::Size;
 use crate::helper::sort::sort_by_key;
+use crate::mj_attributes::MjAttributes;
+use crate::mj_font::MjFont;
+use crate::mj_raw::MjRawPosition;
 use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
@@ -38,73 +44,121 @@ fn combine_attribute_map<'a>(
 }
 
 impl MjHead {
-    pub fn build_attributes_all(&self) -> Map<&str, &str> {
+    /// The `mj-attributes` of the head and of the included heads, restricted
+    /// to the given scope, or to the ones without scope.
+    fn mj_attributes<'a>(
+        &'a self,
+        scope: Option<&'a str>,
+    ) -> impl Iterator<Item = &'a MjAttributes> {
         self.children
             .iter()
             .flat_map(|item| {
-                item.as_mj_attributes()
-                    .into_iter()
-                    .flat_map(|inner| inner.mj_attributes_all_iter())
-                    .chain(
-                        item.as_mj_include()
-                            .filter(|item| item.0.attributes.kind.is_mjml())
-                            .into_iter()
-                            .flat_map(|inner| inner.mj_attributes_all_iter()),
-                    )
+                item.as_mj_attributes().into_iter().chain(
+                    item.as_mj_include()
+                        .filter(|item| item.0.attributes.kind.is_mjml())
+                        .into_iter()
+                        .flat_map(|incl| incl.0.children.iter())
+                        .filter_map(|child| child.as_mj_attributes()),
+                )
             })
-            .collect()
+            .filter(move |item| item.scope() == scope)
+    }
+
+    /// Names of the scopes defined by the `mj-attributes`, in order of
+    /// appearance.
+    pub fn attributes_scopes(&self) -> Vec<&str> {
+        let mut result: Vec<&str> = Vec::new();
+        for scope in self.children.iter().flat_map(|item| {
+            item.as_mj_attributes()
+                .and_then(|inner| inner.scope())
+                .into_iter()
+                .chain(
+                    item.as_mj_include()
+                        .filter(|item| item.0.attributes.kind.is_mjml())
+                        .into_iter()
+                        .flat_map(|incl| incl.0.children.iter())
+                        .filter_map(|child| child.as_mj_attributes())
+                        .filter_map(|inner| inner.scope()),
+                )
+        }) {
+            if !result.contains(&scope) {
+                result.push(scope);
+            }
+        }
+        result
+    }
+
+    pub fn build_attributes_all(&self) -> Map<&str, &str> {
+        self.build_scoped_attributes_all(None)
     }
 
     pub fn build_attributes_class(&self) -> Map<&str, Map<&str, &str>> {
-        self.children
-            .iter()
-            .flat_map(|item| {
-                item.as_mj_attributes()
-                    .into_iter()
-                    .flat_map(|inner| inner.mj_attributes_class_iter())
-                    .chain(
-                        item.as_mj_include()
-                            .filter(|item| item.0.attributes.kind.is_mjml())
-                            .into_iter()
-                            .flat_map(|inner| inner.mj_attributes_class_iter()),
-                    )
-            })
-            .fold(Map::new(), combine_attribute_map)
+        self.build_scoped_attributes_class(None)
     }
 
     pub fn build_attributes_element(&self) -> Map<&str, Map<&str, &str>> {
-        self.children
-            .iter()
-            .flat_map(|item| {
-                item.as_mj_attributes()
-                    .into_iter()
-                    .flat_map(|inner| inner.mj_attributes_element_iter())
-                    .chain(
-                        item.as_mj_include()
-                            .filter(|item| item.0.attributes.kind.is_mjml())
-                            .into_iter()
-                            .flat_map(|inner| inner.mj_attributes_element_iter()),
-                    )
-            })
+        self.build_scoped_attributes_element(None)
+    }
+
+    pub fn build_scoped_attributes_all<'a>(
+        &'a self,
+        scope: Option<&'a str>,
+    ) -> Map<&'a str, &'a str> {
+        self.mj_attributes(scope)
+            .flat_map(|inner| inner.mj_attributes_all_iter())
+            .collect()
+    }
+
+    pub fn build_scoped_attributes_class<'a>(
+        &'a self,
+        scope: Option<&'a str>,
+    ) -> Map<&'a str, Map<&'a str, &'a str>> {
+        self.mj_attributes(scope)
+            .flat_map(|inner| inner.mj_attributes_class_iter())
             .fold(Map::new(), combine_attribute_map)
     }
 
+    pub fn build_scoped_attributes_element<'a>(
+        &'a self,
+        scope: Option<&'a str>,
+    ) -> Map<&'a str, Map<&'a str, &'a str>> {
+        self.mj_attributes(scope)
+            .flat_map(|inner| inner.mj_attributes_element_iter())
+            .fold(Map::new(), combine_attribute_map)
+    }
+
+    fn fonts(&self) -> impl Iterator<Item = &MjFont> {
+        self.children.iter().flat_map(|item| {
+            item.as_mj_font()
+                .into_iter()
+                .chain(item.as_mj_include().into_iter().flat_map(|incl| {
+                    incl.0
+                        .children
+                        .iter()
+                        .filter_map(|child| child.as_mj_font())
+                }))
+        })
+    }
+
     pub fn build_font_families(&self) -> Map<&str, &str> {
-        self.children
-            .iter()
-            .flat_map(|item| {
-                item.as_mj_font()
-                    .into_iter()
-                    .chain(item.as_mj_include().into_iter().flat_map(|incl| {
-                        incl.0
-                            .children
-                            .iter()
-                            .filter_map(|child| child.as_mj_font())
-                    }))
-            })
+        self.fonts()
             .map(|font| (font.name(), font.href()))
             .collect()
     }
+
+    /// Values of the `font-display` descriptor, by font name.
+    pub fn build_font_displays(&self) -> Map<&str, &str> {
+        self.fonts()
+            .filter_map(|font| font.display().map(|value| (font.name(), value)))
+            .collect()
+    }
+
+    /// Fallback stacks, by font name.
+    pub fn build_font_fallbacks(&self) -> Map<&str, &str> {
+        self.fonts()
+            .filter_map(|font| font.fallback().map(|value| (font.name(), value)))
+            .collect()
+    }
 }
 
 fn render_font_import(target: &mut String, href: &str) {
@@ -113,12 +167,55 @@ fn render_font_import(target: &mut String, href: &str) {
     target.push_str(");");
 }
 
+/// Adds the `display` parameter to the url of a font stylesheet, unless it
+/// already has one.
+fn with_font_display<'a>(href: &'a str, display: Option<&str>) -> Cow<'a, str> {
+    match display {
+        Some(display) if !href.contains("display=") => {
+            let separator = if href.contains('?') { '&' } else { '?' };
+            Cow::Owned(format!("{href}{separator}display={display}"))
+        }
+        _ => Cow::Borrowed(href),
+    }
+}
+
+/// Scheme and host of an url, like `https://fonts.googleapis.com`.
+fn url_origin(href: &str) -> Option<&str> {
+    let (_, rest) = href.split_once("://")?;
+    let end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    Some(&href[..href.len() - rest.len() + end])
+}
+
+fn render_font_preconnect(target: &mut String, origin: &str) {
+    target.push_str("<link rel=\"preconnect\" href=\"");
+    target.push_str(origin);
+    target.push_str("\">");
+    // the stylesheets of google fonts load the font files from another host
+    if origin == "https://fonts.googleapis.com" {
+        target.push_str("<link rel=\"preconnect\" href=\"https://fonts.gstatic.com\" crossorigin>");
+    }
+}
+
 fn render_font_link(target: &mut String, href: &str) {
     target.push_str("<link href=\"");
     target.push_str(href);
     target.push_str("\" rel=\"stylesheet\" type=\"text/css\">");
 }
 
+fn push_width_rules(buffer: &mut RenderBuffer, prefix: &str, classnames: &[(&String, &Size)]) {
+    for (classname, size) in classnames.iter() {
+        let size = size.to_string();
+        buffer.push_str(prefix);
+        buffer.push('.');
+        buffer.push_str(classname);
+        buffer.push_str(" { width:");
+        buffer.push_str(size.as_str());
+        buffer.push_str(" !important; max-width:");
+        buffer.push_str(size.as_str());
+        buffer.push_str("; } ");
+    }
+}
+
 impl<'root> Renderer<'root, MjHead, ()> {
     fn mj_style_iter(&self) -> impl Iterator<Item = &str> {
         self.element.children.iter().flat_map(|item| {
@@ -153,81 +250,141 @@ impl<'root> Renderer<'root, MjHead, ()> {
         })
     }
 
-    fn render_font_families(&self, cursor: &mut RenderCursor) {
+    fn render_font_families(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         let used_font_families = cursor.header.used_font_families();
         if used_font_families.is_empty() {
-            return;
+            return Ok(());
         }
 
+        let mut preconnects = String::default();
+        let mut origins: Vec<String> = Vec::new();
         let mut links = String::default();
         let mut imports = String::default();
+        let mut inlined = String::default();
         for name in cursor.header.used_font_families().iter() {
-            if let Some(href) = self.context.header.font_families().get(name.as_str()) {
-                render_font_link(&mut links, href);
-                render_font_import(&mut imports, href);
+            let href = if let Some(href) = self.context.header.font_families().get(name.as_str()) {
+                *href
             } else if let Some(href) = self.context.options.fonts.get(name) {
-                render_font_link(&mut links, href);
-                render_font_import(&mut imports, href);
+                href.as_ref()
             } else {
                 // TODO log a warning
+                continue;
+            };
+            let display = self.context.header.font_display(name).or(self
+                .context
+                .options
+                .font_display
+                .as_deref());
+            let href = with_font_display(href, display);
+            if let FontStrategy::Inline(ref loader) = self.context.options.font_strategy {
+                inlined.push_str(loader.load(name, &href)?.trim());
+                continue;
+            }
+            if self.context.options.font_preconnect {
+                if let Some(origin) = url_origin(&href)
+                    .filter(|item| !origins.iter().any(|known| known.as_str() == *item))
+                {
+                    render_font_preconnect(&mut preconnects, origin);
+                    origins.push(origin.to_string());
+                }
             }
+            render_font_link(&mut links, &href);
+            render_font_import(&mut imports, &href);
         }
 
-        if links.is_empty() && imports.is_empty() {
+        if links.is_empty() && imports.is_empty() && inlined.is_empty() {
         } else {
             cursor.buffer.start_mso_negation_conditional_tag();
+            cursor.buffer.push_str(&preconnects);
             cursor.buffer.push_str(&links);
             if !imports.is_empty() {
                 cursor.buffer.push_str("<style type=\"text/css\">");
                 cursor.buffer.push_str(&imports);
                 cursor.buffer.push_str("</style>");
             }
+            if !inlined.is_empty() {
+                cursor.buffer.push_str("<style type=\"text/css\">");
+                cursor.buffer.push_str(&inlined);
+                cursor.buffer.push_str("</style>");
+            }
             cursor.buffer.end_negation_conditional_tag();
         }
+        Ok(())
+    }
+
+    /// Opening tag of the `style` elements containing the media queries.
+    fn media_query_style<'a>(&'a self) -> Tag<'a> {
+        Tag::new("style")
+            .add_attribute("type", "text/css")
+            .maybe_add_class(self.context.header.breakpoint_class())
     }
 
-    fn render_media_queries(&self, cursor: &mut RenderCursor) {
+    fn render_media_queries(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         if cursor.header.media_queries().is_empty() {
-            return;
+            return Ok(());
         }
         let mut classnames = cursor.header.media_queries().iter().collect::<Vec<_>>();
         classnames.sort_by(sort_by_key);
         let breakpoint = self.context.header.breakpoint().to_string();
-        cursor.buffer.push_str("<style type=\"text/css\">");
+        self.media_query_style().render_open(&mut cursor.buffer)?;
         cursor.buffer.push_str("@media only screen and (min-width:");
         cursor.buffer.push_str(breakpoint.as_str());
         cursor.buffer.push_str(") { ");
-        for (classname, size) in classnames.iter() {
-            let size = size.to_string();
-            cursor.buffer.push('.');
-            cursor.buffer.push_str(classname);
-            cursor.buffer.push_str(" { width:");
-            cursor.buffer.push_str(size.as_str());
-            cursor.buffer.push_str(" !important; max-width:");
-            cursor.buffer.push_str(size.as_str());
-            cursor.buffer.push_str("; } ");
-        }
+        push_width_rules(&mut cursor.buffer, "", &classnames);
         cursor.buffer.push_str(" }");
         cursor.buffer.push_str("</style>");
-        cursor
-            .buffer
-            .push_str("<style media=\"screen and (min-width:");
-        cursor.buffer.push_str(breakpoint.as_str());
-        cursor.buffer.push_str(")\">");
-        for (classname, size) in classnames.iter() {
-            let size = size.to_string();
-            cursor.buffer.push_str(".moz-text-html .");
-            cursor.buffer.push_str(classname);
-            cursor.buffer.push_str(" { width:");
-            cursor.buffer.push_str(size.as_str());
-            cursor.buffer.push_str(" !important; max-width:");
-            cursor.buffer.push_str(size.as_str());
-            cursor.buffer.push_str("; } ");
+        Tag::new("style")
+            .add_attribute("media", format!("screen and (min-width:{breakpoint})"))
+            .maybe_add_class(self.context.header.breakpoint_class())
+            .render_open(&mut cursor.buffer)?;
+        push_width_rules(&mut cursor.buffer, ".moz-text-html ", &classnames);
+        cursor.buffer.push_str("</style>");
+        self.render_tablet_media_queries(cursor)
+    }
+
+    /// Width classes of the columns between the breakpoint and the tablet
+    /// breakpoint, written after the desktop ones to take precedence.
+    fn render_tablet_media_queries(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let Some(tablet_breakpoint) = self.context.header.tablet_breakpoint() else {
+            return Ok(());
+        };
+        if cursor.header.tablet_media_queries().is_empty() {
+            return Ok(());
         }
+        let mut classnames = cursor
+            .header
+            .tablet_media_queries()
+            .iter()
+            .collect::<Vec<_>>();
+        classnames.sort_by(sort_by_key);
+        let query = format!(
+            "only screen and (min-width:{}) and (max-width:{})",
+            self.context.header.breakpoint(),
+            tablet_breakpoint.lower()
+        );
+        self.media_query_style().render_open(&mut cursor.buffer)?;
+        cursor.buffer.push_str("@media ");
+        cursor.buffer.push_str(query.as_str());
+        cursor.buffer.push_str(" { ");
+        push_width_rules(&mut cursor.buffer, "", &classnames);
+        cursor.buffer.push_str(" }");
         cursor.buffer.push_str("</style>");
+        Ok(())
     }
 
-    fn render_styles(&self, cursor: &mut RenderCursor) {
+    /// Content of the `mj-style` elements, without the duplicates. Only the
+    /// last occurrence of a block is kept, to preserve the cascade.
+    fn mj_styles(&self) -> Vec<&str> {
+        let items: Vec<&str> = self.mj_style_iter().collect();
+        items
+            .iter()
+            .enumerate()
+            .filter(|(index, item)| !items[index + 1..].contains(item))
+            .map(|(_, item)| *item)
+            .collect()
+    }
+
+    fn render_component_styles(&self, cursor: &mut RenderCursor) {
         if !cursor.header.styles().is_empty() {
             cursor.buffer.push_str("<style type=\"text/css\">");
             for style in cursor.header.styles().iter() {
@@ -235,36 +392,65 @@ impl<'root> Renderer<'root, MjHead, ()> {
             }
             cursor.buffer.push_str("</style>");
         }
+    }
 
+    fn render_template_styles(&self, cursor: &mut RenderCursor) {
         // TODO this should be optional
         cursor.buffer.push_str("<style type=\"text/css\">");
-        for item in self.mj_style_iter() {
+        for item in self.mj_styles() {
             cursor.buffer.push_str(item);
         }
         cursor.buffer.push_str("</style>");
     }
 
-    fn render_raw(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
-        let mut index: usize = 0;
+    fn render_styles(&self, cursor: &mut RenderCursor) {
+        match self.context.options.style_order {
+            StyleOrder::ComponentsFirst => {
+                self.render_component_styles(cursor);
+                self.render_template_styles(cursor);
+            }
+            StyleOrder::TemplateFirst => {
+                self.render_template_styles(cursor);
+                self.render_component_styles(cursor);
+            }
+        }
+    }
+
+    fn render_meta(&self, cursor: &mut RenderCursor) {
+        cursor.buffer.start_mso_negation_conditional_tag();
+        cursor
+            .buffer
+            .push_str("<meta http-equiv=\"X-UA-Compatible\" content=\"IE=edge\">");
+        cursor.buffer.end_negation_conditional_tag();
+        cursor
+            .buffer
+            .push_str("<meta http-equiv=\"Content-Type\" content=\"text/html; charset=UTF-8\">");
+        cursor
+            .buffer
+            .push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">");
+    }
+
+    /// Writes the content of the `mj-raw` elements, including the ones of the
+    /// included templates, placed at the given position.
+    fn render_raw(&self, cursor: &mut RenderCursor, position: MjRawPosition) -> Result<(), Error> {
         let siblings = self.element.children.len();
-        for child in self.element.children.iter() {
-            if let Some(mj_raw) = child.as_mj_raw() {
-                let mut renderer = mj_raw.renderer(self.context());
-                renderer.set_index(index);
-                renderer.set_siblings(siblings);
-                renderer.render(cursor)?;
-                index += 1;
-            } else if let Some(mj_include) = child.as_mj_include() {
-                for include_child in mj_include.0.children.iter() {
-                    if let Some(mj_raw) = include_child.as_mj_raw() {
-                        let mut renderer = mj_raw.renderer(self.context());
-                        renderer.set_index(index);
-                        renderer.set_siblings(siblings);
-                        renderer.render(cursor)?;
-                        index += 1;
-                    }
-                }
+        let raws = self.element.children.iter().flat_map(|child| {
+            child.as_mj_raw().into_iter().chain(
+                child
+                    .as_mj_include()
+                    .into_iter()
+                    .flat_map(|include| include.0.children.iter())
+                    .filter_map(|item| item.as_mj_raw()),
+            )
+        });
+        for (index, mj_raw) in raws.enumerate() {
+            if mj_raw.position() != position {
+                continue;
             }
+            let mut renderer = mj_raw.renderer(self.context());
+            renderer.set_index(index);
+            renderer.set_siblings(siblings);
+            renderer.render(cursor)?;
         }
         Ok(())
     }
@@ -277,28 +463,26 @@ impl<'root> Render<'root> for Renderer<'root, MjHead, ()> {
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         cursor.buffer.push_str("<head>");
+        self.render_raw(cursor, MjRawPosition::HeadStart)?;
         // we write the title even though there is no content
         cursor.buffer.push_str("<title>");
         if let Some(title) = self.element.title().map(|item| item.content()) {
             cursor.buffer.push_str(title);
         }
         cursor.buffer.push_str("</title>");
-        cursor.buffer.start_mso_negation_conditional_tag();
-        cursor
-            .buffer
-            .push_str("<meta http-equiv=\"X-UA-Compatible\" content=\"IE=edge\">");
-        cursor.buffer.end_negation_conditional_tag();
-        cursor
-            .buffer
-            .push_str("<meta http-equiv=\"Content-Type\" content=\"text/html; charset=UTF-8\">");
-        cursor
-            .buffer
-            .push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">");
-        cursor.buffer.push_str(STYLE_BASE);
-        self.render_font_families(cursor);
-        self.render_media_queries(cursor);
-        self.render_styles(cursor);
-        self.render_raw(cursor)?;
+        for section in self.context.options.head_sections.iter() {
+            match section {
+                HeadSection::Meta => {
+                    self.render_meta(cursor);
+                    self.render_raw(cursor, MjRawPosition::BeforeStyles)?;
+                }
+                HeadSection::BaseStyles => cursor.buffer.push_str(STYLE_BASE),
+                HeadSection::Fonts => self.render_font_families(cursor)?,
+                HeadSection::MediaQueries => self.render_media_queries(cursor)?,
+                HeadSection::Styles => self.render_styles(cursor),
+                HeadSection::Raw => self.render_raw(cursor, MjRawPosition::HeadEnd)?,
+            }
+        }
         cursor.buffer.push_str("</head>");
         Ok(())
     }
@@ -329,13 +513,232 @@ mod tests {
     crate::should_render!(attributes_basic, "mj-attributes");
     crate::should_render!(style_basic, "mj-style");
 
+    #[cfg(feature = "parse")]
+    const STYLES: &str = r#"<mjml>
+  <mj-head>
+    <mj-style>.a { color: red; }</mj-style>
+    <mj-style>.b { color: blue; }</mj-style>
+    <mj-style>.a { color: red; }</mj-style>
+  </mj-head>
+  <mj-body>
+    <mj-column><mj-image src="logo.png" /></mj-column>
+  </mj-body>
+</mjml>"#;
+
+    #[cfg(feature = "parse")]
+    const FONTS: &str = r#"<mjml>
+  <mj-head>
+    <mj-font name="Inter" href="https://fonts.googleapis.com/css?family=Inter" display="optional" fallback="Helvetica, Arial, sans-serif" />
+  </mj-head>
+  <mj-body>
+    <mj-text font-family="Inter, Arial">Hello</mj-text>
+    <mj-text font-family="Roboto">World</mj-text>
+  </mj-body>
+</mjml>"#;
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_place_raw_content_at_its_position() {
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-raw><meta name="end" /></mj-raw>
+    <mj-raw position="before-styles"><meta name="color-scheme" content="light dark" /></mj-raw>
+    <mj-raw position="head-start"><meta name="start" /></mj-raw>
+  </mj-head>
+  <mj-body></mj-body>
+</mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        let start = html.find(r#"<meta name="start""#).unwrap();
+        let scheme = html.find(r#"<meta name="color-scheme""#).unwrap();
+        let end = html.find(r#"<meta name="end""#).unwrap();
+        assert!(html[..start].ends_with("<head>"));
+        assert!(start < html.find("<title>").unwrap());
+        assert!(html.find(r#"name="viewport""#).unwrap() < scheme);
+        assert!(scheme < html.find("#outlook a").unwrap());
+        assert!(end > html.find(".mj-outlook-group-fix").unwrap());
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_follow_the_head_sections() {
+        use crate::prelude::render::{HeadSection, RenderOptions};
+
+        const MEDIA_QUERIES: &str = "@media only screen and (min-width:";
+
+        let root = crate::parse(STYLES).unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        assert!(html.find(MEDIA_QUERIES).unwrap() < html.find(".b { color: blue; }").unwrap());
+
+        let opts = RenderOptions {
+            head_sections: vec![
+                HeadSection::Meta,
+                HeadSection::Styles,
+                HeadSection::MediaQueries,
+            ],
+            ..Default::default()
+        };
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.find(".b { color: blue; }").unwrap() < html.find(MEDIA_QUERIES).unwrap());
+        assert!(html.contains(r#"name="viewport""#));
+        assert!(!html.contains("#outlook a"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_add_font_display_and_preconnect() {
+        use crate::prelude::render::RenderOptions;
+
+        let root = crate::parse(FONTS).unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        assert!(html
+            .contains(r#"href="https://fonts.googleapis.com/css?family=Inter&display=optional""#));
+        assert!(!html.contains("rel=\"preconnect\""));
+
+        let opts = RenderOptions {
+            font_display: Some("swap".into()),
+            font_preconnect: true,
+            ..Default::default()
+        };
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains("family=Inter&display=optional"));
+        assert!(html.contains("family=Roboto:300,400,500,700&display=swap"));
+        assert_eq!(
+            html.matches(r#"<link rel="preconnect" href="https://fonts.googleapis.com">"#)
+                .count(),
+            1
+        );
+        assert!(html
+            .contains(r#"<link rel="preconnect" href="https://fonts.gstatic.com" crossorigin>"#));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_inline_font_stylesheets() {
+        use std::sync::Arc;
+
+        use crate::prelude::render::{Error, FontStrategy, MemoryFontLoader, RenderOptions};
+
+        let root = crate::parse(FONTS).unwrap();
+        let loader = MemoryFontLoader::default()
+            .with_font("Inter", "@font-face { font-family: 'Inter'; }\n")
+            .with_font("Roboto", "@font-face { font-family: 'Roboto'; }");
+        let opts = RenderOptions {
+            font_strategy: FontStrategy::Inline(Arc::new(loader)),
+            ..Default::default()
+        };
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains("<style type=\"text/css\">@font-face { font-family: 'Inter'; }@font-face { font-family: 'Roboto'; }</style>"));
+        assert!(!html.contains("fonts.googleapis.com"));
+
+        let opts = RenderOptions {
+            font_strategy: FontStrategy::Inline(Arc::new(MemoryFontLoader::default())),
+            ..Default::default()
+        };
+        let err = root.element.render(&opts).unwrap_err();
+        assert!(matches!(err, Error::FontLoader(inner) if inner.name == "Inter"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_append_font_fallbacks() {
+        let root = crate::parse(FONTS).unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        assert!(html.contains("font-family:Inter, Arial, Helvetica, sans-serif;"));
+        assert!(html.contains("font-family:Roboto;"));
+    }
+
+    #[test]
+    fn should_find_url_origin() {
+        assert_eq!(
+            super::url_origin("https://fonts.googleapis.com/css?family=Inter"),
+            Some("https://fonts.googleapis.com")
+        );
+        assert_eq!(
+            super::url_origin("https://example.com?a=b"),
+            Some("https://example.com")
+        );
+        assert_eq!(super::url_origin("/fonts.css"), None);
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_deduplicate_styles() {
+        let root = crate::parse(STYLES).unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        assert_eq!(html.matches(".a { color: red; }").count(), 1);
+        assert!(html.find(".b { color: blue; }") < html.find(".a { color: red; }"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_order_styles() {
+        use crate::prelude::render::{RenderOptions, StyleOrder};
+
+        let root = crate::parse(STYLES).unwrap();
+        let html = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(html.find("table.mj-full-width-mobile") < html.find(".b { color: blue; }"));
+        let opts = RenderOptions {
+            style_order: StyleOrder::TemplateFirst,
+            ..Default::default()
+        };
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.find(".b { color: blue; }") < html.find("table.mj-full-width-mobile"));
+    }
+
+    #[cfg(feature = "parse")]
+    const TABLET: &str = r#"<mjml>
+  <mj-head>
+    <mj-breakpoint width="480px 768px" />
+  </mj-head>
+  <mj-body>
+    <mj-section>
+      <mj-column width="25%" tablet-width="50%"><mj-text>A</mj-text></mj-column>
+      <mj-column width="75%" tablet-width="50%"><mj-text>B</mj-text></mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_tablet_breakpoint() {
+        let root = crate::parse(TABLET).unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        assert!(html.contains("@media only screen and (min-width:480px) { .mj-column-per-25"));
+        assert!(html.contains(
+            "@media only screen and (min-width:480px) and (max-width:767px) { .mj-column-tablet-per-50 { width:50% !important; max-width:50%; }  }"
+        ));
+        assert!(html.contains("mj-column-per-25 mj-column-tablet-per-50"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_override_breakpoint() {
+        use crate::prelude::render::RenderOptions;
+
+        let root = crate::parse(TABLET).unwrap();
+        let opts = RenderOptions {
+            breakpoint: Some("600px".into()),
+            ..Default::default()
+        };
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains("@media only screen and (min-width:600px) {"));
+        assert!(!html.contains("mj-column-tablet-per-50"));
+        let opts = RenderOptions {
+            breakpoint: Some("400px, 900px".into()),
+            ..Default::default()
+        };
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains("(min-width:400px) and (max-width:899px)"));
+    }
+
     #[test]
     fn should_keep_order_with_mj_include_attributes_all() {
         let element = MjHead::new(
             (),
             vec![
                 MjHeadChild::MjAttributes(MjAttributes::new(
-                    (),
+                    Default::default(),
                     vec![MjAttributesChild::MjAttributesAll(MjAttributesAll::new(
                         Map::from_iter([(String::from("font-size"), String::from("42px"))]),
                         (),
@@ -347,7 +750,7 @@ mod tests {
                         kind: crate::mj_include::head::MjIncludeHeadKind::Mjml,
                     },
                     vec![MjIncludeHeadChild::MjAttributes(MjAttributes::new(
-                        (),
+                        Default::default(),
                         vec![MjAttributesChild::MjAttributesAll(MjAttributesAll::new(
                             Map::from_iter([
                                 (String::from("font-size"), String::from("21px")),
@@ -358,7 +761,7 @@ mod tests {
                     ))],
                 )),
                 MjHeadChild::MjAttributes(MjAttributes::new(
-                    (),
+                    Default::default(),
                     vec![MjAttributesChild::MjAttributesAll(MjAttributesAll::new(
                         Map::from_iter([(String::from("text-align"), String::from("right"))]),
                         (),
@@ -382,7 +785,7 @@ mod tests {
             (),
             vec![
                 MjHeadChild::MjAttributes(MjAttributes::new(
-                    (),
+                    Default::default(),
                     vec![MjAttributesChild::MjAttributesClass(
                         MjAttributesClass::new(
                             MjAttributesClassAttributes {
@@ -402,7 +805,7 @@ mod tests {
                         kind: crate::mj_include::head::MjIncludeHeadKind::Mjml,
                     },
                     vec![MjIncludeHeadChild::MjAttributes(MjAttributes::new(
-                        (),
+                        Default::default(),
                         vec![
                             MjAttributesChild::MjAttributesClass(MjAttributesClass::new(
                                 MjAttributesClassAttributes {
@@ -428,7 +831,7 @@ mod tests {
                     ))],
                 )),
                 MjHeadChild::MjAttributes(MjAttributes::new(
-                    (),
+                    Default::default(),
                     vec![MjAttributesChild::MjAttributesClass(
                         MjAttributesClass::new(
                             MjAttributesClassAttributes {
@@ -461,7 +864,7 @@ mod tests {
             (),
             vec![
                 MjHeadChild::MjAttributes(MjAttributes::new(
-                    (),
+                    Default::default(),
                     vec![MjAttributesChild::MjAttributesElement(
                         MjAttributesElement {
                             name: String::from("mj-text"),
@@ -478,7 +881,7 @@ mod tests {
                         kind: crate::mj_include::head::MjIncludeHeadKind::Mjml,
                     },
                     vec![MjIncludeHeadChild::MjAttributes(MjAttributes::new(
-                        (),
+                        Default::default(),
                         vec![MjAttributesChild::MjAttributesElement(
                             MjAttributesElement {
                                 name: String::from("mj-text"),
@@ -491,7 +894,7 @@ mod tests {
                     ))],
                 )),
                 MjHeadChild::MjAttributes(MjAttributes::new(
-                    (),
+                    Default::default(),
                     vec![MjAttributesChild::MjAttributesElement(
                         MjAttributesElement {
                             name: String::from("mj-text"),
@@ -1,10 +1,26 @@
 use std::borrow::Cow;
 
 use super::{MjHero, NAME};
+use crate::helper::color::Color;
 use crate::helper::size::Pixel;
+use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
 impl<'root> Renderer<'root, MjHero, ()> {
+    /// Color of the layer displayed over the background image, with the
+    /// `overlay-opacity` applied when the color is in hexadecimal.
+    fn get_overlay(&self) -> Option<String> {
+        let color = self.attribute("overlay-color")?;
+        // has default value
+        let opacity = self.attribute("overlay-opacity").unwrap();
+        Some(match Color::from_hex(color) {
+            Ok(Color {
+                red, green, blue, ..
+            }) => format!("rgba({red},{green},{blue},{opacity})"),
+            Err(_) => color.to_string(),
+        })
+    }
+
     fn set_style_div<'t>(&self, tag: Tag<'t>) -> Tag<'t> {
         tag.add_style("margin", "0 auto").maybe_add_style(
             "max-width",
@@ -73,6 +89,14 @@ impl<'root> Renderer<'root, MjHero, ()> {
     }
 
     fn set_style_outlook_image<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 'a,
+        'a: 't,
+    {
+        self.set_style_outlook_layer(tag).add_style("z-index", "-3")
+    }
+
+    fn set_style_outlook_layer<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
     where
         'root: 'a,
         'a: 't,
@@ -92,7 +116,27 @@ impl<'root> Renderer<'root, MjHero, ()> {
                             .map(|w| Cow::Owned(w.to_string()))
                     }),
             )
-            .add_style("z-index", "-3")
+    }
+
+    /// Layer covering the background image in outlook, with the overlay
+    /// color.
+    fn render_outlook_overlay(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let Some(color) = self.attribute("overlay-color") else {
+            return Ok(());
+        };
+        let rect = self
+            .set_style_outlook_layer(Tag::new("v:rect"))
+            .add_style("z-index", "-2")
+            .add_attribute("xmlns:v", "urn:schemas-microsoft-com:vml")
+            .add_attribute("fill", "true")
+            .add_attribute("stroke", "false");
+        let fill = Tag::new("v:fill")
+            .add_attribute("color", color)
+            .maybe_add_attribute("opacity", self.attribute("overlay-opacity"));
+        rect.render_open(&mut cursor.buffer)?;
+        fill.render_closed(&mut cursor.buffer)?;
+        rect.render_close(&mut cursor.buffer);
+        Ok(())
     }
 
     fn set_style_outlook_td<'t>(&self, tag: Tag<'t>) -> Tag<'t> {
@@ -102,6 +146,20 @@ impl<'root> Renderer<'root, MjHero, ()> {
     }
 
     fn get_background<'a>(&'a self) -> Option<Cow<'a, str>>
+    where
+        'root: 'a,
+    {
+        let background = self.get_image_background();
+        match (self.get_overlay(), background) {
+            (Some(overlay), Some(background)) => Some(Cow::Owned(format!(
+                "linear-gradient({overlay}, {overlay}), {background}"
+            ))),
+            (Some(overlay), None) => Some(Cow::Owned(overlay)),
+            (None, background) => background,
+        }
+    }
+
+    fn get_image_background<'a>(&'a self) -> Option<Cow<'a, str>>
     where
         'root: 'a,
     {
@@ -168,7 +226,7 @@ impl<'root> Renderer<'root, MjHero, ()> {
                         "background",
                         renderer.attribute("container-background-color"),
                     )
-                    .maybe_add_attribute("class", renderer.attribute("css-class"));
+                    .maybe_add_class(renderer.attribute("css-class"));
 
                 tr.render_open(&mut cursor.buffer)?;
                 td.render_open(&mut cursor.buffer)?;
@@ -184,6 +242,7 @@ impl<'root> Renderer<'root, MjHero, ()> {
     fn render_content(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         let table = self
             .set_style_outlook_inner_table(Tag::table_borderless())
+            .maybe_add_attribute("role", self.context.options.aria.presentation_role())
             .maybe_add_attribute("align", self.attribute("align"))
             .maybe_add_attribute(
                 "width",
@@ -278,6 +337,7 @@ impl<'root> Render<'root> for Renderer<'root, MjHero, ()> {
             "background-position" => Some("center center"),
             "height" => Some("0px"),
             "mode" => Some("fixed-height"),
+            "overlay-opacity" => Some("0.5"),
             "padding" => Some("0px"),
             "vertical-align" => Some("top"),
             _ => None,
@@ -288,6 +348,10 @@ impl<'root> Render<'root> for Renderer<'root, MjHero, ()> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
     fn tag(&self) -> Option<&str> {
         Some(NAME)
     }
@@ -326,6 +390,7 @@ impl<'root> Render<'root> for Renderer<'root, MjHero, ()> {
             .set_style_div(Tag::div())
             .maybe_add_attribute("align", self.attribute("align"))
             .maybe_add_class(self.attribute("css-class"));
+        let div = self.passthrough_attributes(div);
         let table = self.set_style_table(Tag::table_presentation());
         let tbody = Tag::tbody();
         let tr = self.set_style_tr(Tag::tr());
@@ -335,6 +400,7 @@ impl<'root> Render<'root> for Renderer<'root, MjHero, ()> {
         outlook_tr.render_open(&mut cursor.buffer)?;
         outlook_td.render_open(&mut cursor.buffer)?;
         v_image.render_closed(&mut cursor.buffer)?;
+        self.render_outlook_overlay(cursor)?;
         cursor.buffer.end_conditional_tag();
 
         div.render_open(&mut cursor.buffer)?;
@@ -381,4 +447,30 @@ mod tests {
     crate::should_render!(mode, "mj-hero-mode");
     crate::should_render!(vertical_align, "mj-hero-vertical-align");
     crate::should_render!(width, "mj-hero-width");
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_overlay() {
+        let root = crate::parse(
+            r##"<mjml><mj-body><mj-hero mode="fixed-height" height="400px" background-url="https://example.com/hero.jpg" background-height="400px" overlay-color="#000" inner-background-color="#ffffff"><mj-text>Hello</mj-text></mj-hero></mj-body></mjml>"##,
+        )
+        .unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        assert!(html.contains("background:linear-gradient(rgba(0,0,0,0.5), rgba(0,0,0,0.5)), #ffffff url('https://example.com/hero.jpg') no-repeat center center / cover;"));
+        assert!(html.contains(r##"<v:fill color="#000" opacity="0.5" />"##));
+        assert!(html.contains("top:0;width:600px;z-index:-2;"));
+        assert!(html.contains("height:400px;"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_not_render_overlay_by_default() {
+        let root = crate::parse(
+            r#"<mjml><mj-body><mj-hero background-url="https://example.com/hero.jpg"><mj-text>Hello</mj-text></mj-hero></mj-body></mjml>"#,
+        )
+        .unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        assert!(!html.contains("linear-gradient"));
+        assert!(!html.contains("v:rect"));
+    }
 }
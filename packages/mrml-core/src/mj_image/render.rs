@@ -1,5 +1,7 @@
 use super::{MjImage, NAME};
 use crate::helper::size::Pixel;
+use crate::helper::width;
+use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
 impl<'root> Renderer<'root, MjImage, ()> {
@@ -13,28 +15,17 @@ impl<'root> Renderer<'root, MjImage, ()> {
         self.attribute_exists("full-width")
     }
 
-    fn get_box_width(&self) -> Option<Pixel> {
-        self.container_width.as_ref().map(|width| {
-            let hborder = self.get_border_horizontal();
-            let hpadding = self.get_padding_horizontal();
-            Pixel::new(width.value() - hborder.value() - hpadding.value())
-        })
-    }
-
     fn get_content_width(&self) -> Option<Pixel> {
-        self.attribute_as_pixel("width")
-            .map(|width| match self.get_box_width() {
-                Some(box_size) => {
-                    if width.value() < box_size.value() {
-                        width
-                    } else {
-                        box_size
-                    }
-                }
-                None => width,
-            })
-            // when no width given
-            .or_else(|| self.get_box_width())
+        let width = self.attribute_as_pixel("width");
+        match self.container_width {
+            Some(container) => Some(width::content_width(
+                container,
+                width,
+                self.get_padding_horizontal(),
+                self.get_border_horizontal(),
+            )),
+            None => width,
+        }
     }
 
     fn set_style_img<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
@@ -101,17 +92,39 @@ impl<'root> Renderer<'root, MjImage, ()> {
                 self.get_content_width()
                     .map(|size| size.value().to_string()),
             )
-            .maybe_add_attribute("usemap", self.attribute("usemap"));
-        let img = self.set_style_img(img);
+            .maybe_add_attribute("usemap", self.attribute("usemap"))
+            .maybe_add_attribute("loading", self.attribute("loading"))
+            .maybe_add_attribute("fetchpriority", self.attribute("fetchpriority"));
+        let img = self.passthrough_attributes(self.set_style_img(img));
         img.render_closed(buf)
     }
 
+    /// Text describing the target of the link, taken from the `alt` of the
+    /// image when enabled in the [`AriaOptions`].
+    fn link_label(&self) -> Option<&str> {
+        let aria = &self.context.options.aria;
+        let label = self
+            .attribute("alt")
+            .filter(|value| !value.trim().is_empty());
+        if aria.report_unlabelled_image_links
+            && label.is_none()
+            && !self.attribute_exists("title")
+            && self.attribute_exists("href")
+        {
+            self.report_missing_attribute("alt");
+        }
+        label.filter(|_| aria.image_link_labels)
+    }
+
     fn render_link(&self, buf: &mut RenderBuffer) -> std::fmt::Result {
+        let label = self.link_label();
         Tag::new("a")
             .maybe_add_attribute("href", self.attribute("href"))
             .maybe_add_attribute("name", self.attribute("name"))
             .maybe_add_attribute("rel", self.attribute("rel"))
             .maybe_add_attribute("target", self.attribute("target"))
+            .maybe_add_attribute("title", label)
+            .maybe_add_attribute("aria-label", label)
             .render_with(buf, |b| self.render_image(b))
     }
 
@@ -144,6 +157,10 @@ impl<'root> Render<'root> for Renderer<'root, MjImage, ()> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
     fn tag(&self) -> Option<&str> {
         Some(NAME)
     }
@@ -214,4 +231,44 @@ mod tests {
     crate::should_render!(height, "mj-image-height");
     crate::should_render!(href, "mj-image-href");
     crate::should_render!(padding, "mj-image-padding");
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_label_image_links() {
+        use crate::prelude::render::{AriaOptions, RenderOptions, RenderWarningKind};
+
+        let template = r#"<mjml><mj-body><mj-image href="/a" alt="Our shop" src="a.png" /><mj-image href="/b" src="b.png" /></mj-body></mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        assert!(!html.contains("aria-label"));
+        let opts = RenderOptions {
+            aria: AriaOptions {
+                image_link_labels: true,
+                report_unlabelled_image_links: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let (html, warnings) = root.element.render_with_report(&opts).unwrap();
+        assert!(
+            html.contains(r#"href="/a" target="_blank" title="Our shop" aria-label="Our shop""#)
+        );
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].path, "/mjml/mj-body/mj-image[2]");
+        assert_eq!(
+            warnings[0].kind,
+            RenderWarningKind::MissingAttribute { name: "alt".into() }
+        );
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_pass_loading_and_data_attributes() {
+        let template = r#"<mjml><mj-body><mj-image src="a.png" loading="lazy" fetchpriority="low" data-esp-id="hero" /><mj-button data-track="cta" href="/buy">Buy</mj-button><mj-text data-block="intro">Hello</mj-text></mj-body></mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        assert!(html.contains(r#"loading="lazy" fetchpriority="low" data-esp-id="hero""#));
+        assert!(html.contains(r#"<a href="/buy" target="_blank" data-track="cta""#));
+        assert!(html.contains(r#"<div data-block="intro""#));
+    }
 }
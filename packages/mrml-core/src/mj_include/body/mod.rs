@@ -17,19 +17,30 @@ use crate::prelude::{Component, StaticTag};
 pub enum MjIncludeBodyChild {
     Comment(crate::comment::Comment),
     MjAccordion(crate::mj_accordion::MjAccordion),
+    MjAvatar(crate::mj_avatar::MjAvatar),
+    MjBadge(crate::mj_badge::MjBadge),
     MjButton(crate::mj_button::MjButton),
+    MjCalendarButton(crate::mj_calendar_button::MjCalendarButton),
     MjCarousel(crate::mj_carousel::MjCarousel),
+    MjCode(crate::mj_code::MjCode),
     MjColumn(crate::mj_column::MjColumn),
     MjDivider(crate::mj_divider::MjDivider),
+    MjFooter(crate::mj_footer::MjFooter),
     MjGroup(crate::mj_group::MjGroup),
     MjHero(crate::mj_hero::MjHero),
     MjImage(crate::mj_image::MjImage),
+    MjMap(crate::mj_map::MjMap),
+    MjMsoOffice(crate::mj_msooffice::MjMsoOffice),
     MjNavbar(crate::mj_navbar::MjNavbar),
+    MjNotMso(crate::mj_not_mso::MjNotMso),
+    MjQuote(crate::mj_quote::MjQuote),
     MjRaw(crate::mj_raw::MjRaw),
     MjSection(crate::mj_section::MjSection),
     MjSocial(crate::mj_social::MjSocial),
     MjSpacer(crate::mj_spacer::MjSpacer),
+    MjStats(crate::mj_stats::MjStats),
     MjTable(crate::mj_table::MjTable),
+    MjTabs(crate::mj_tabs::MjTabs),
     MjText(crate::mj_text::MjText),
     MjWrapper(crate::mj_wrapper::MjWrapper),
     Node(crate::node::Node<crate::mj_body::MjBodyChild>),
@@ -79,6 +90,12 @@ pub struct MjIncludeBodyAttributes {
         )
     )]
     pub kind: MjIncludeBodyKind,
+    /// Scope of the `mj-attributes` applying to the included content.
+    #[cfg_attr(
+        feature = "json",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub scope: Option<String>,
 }
 
 #[cfg(test)]
@@ -87,6 +104,7 @@ impl MjIncludeBodyAttributes {
         Self {
             path: path.into(),
             kind: Default::default(),
+            scope: None,
         }
     }
 
@@ -94,6 +112,11 @@ impl MjIncludeBodyAttributes {
         self.kind = kind;
         self
     }
+
+    pub fn with_scope<S: Into<String>>(mut self, scope: S) -> Self {
+        self.scope = Some(scope.into());
+        self
+    }
 }
 
 pub struct MjIncludeBodyTag;
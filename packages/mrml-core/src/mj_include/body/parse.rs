@@ -3,28 +3,39 @@ use xmlparser::StrSpan;
 use super::{MjIncludeBody, MjIncludeBodyAttributes, MjIncludeBodyChild, MjIncludeBodyKind};
 use crate::comment::Comment;
 use crate::mj_accordion::NAME as MJ_ACCORDION;
+use crate::mj_avatar::NAME as MJ_AVATAR;
+use crate::mj_badge::NAME as MJ_BADGE;
 use crate::mj_body::MjBodyChild;
 use crate::mj_button::NAME as MJ_BUTTON;
+use crate::mj_calendar_button::NAME as MJ_CALENDAR_BUTTON;
 use crate::mj_carousel::NAME as MJ_CAROUSEL;
+use crate::mj_code::NAME as MJ_CODE;
 use crate::mj_column::NAME as MJ_COLUMN;
 use crate::mj_divider::NAME as MJ_DIVIDER;
+use crate::mj_footer::NAME as MJ_FOOTER;
 use crate::mj_group::NAME as MJ_GROUP;
 use crate::mj_hero::NAME as MJ_HERO;
 use crate::mj_image::NAME as MJ_IMAGE;
+use crate::mj_map::NAME as MJ_MAP;
+use crate::mj_msooffice::NAME as MJ_MSOOFFICE;
 use crate::mj_navbar::NAME as MJ_NAVBAR;
+use crate::mj_not_mso::NAME as MJ_NOT_MSO;
+use crate::mj_quote::NAME as MJ_QUOTE;
 use crate::mj_raw::NAME as MJ_RAW;
 use crate::mj_section::NAME as MJ_SECTION;
 use crate::mj_social::NAME as MJ_SOCIAL;
 use crate::mj_spacer::NAME as MJ_SPACER;
+use crate::mj_stats::NAME as MJ_STATS;
 use crate::mj_table::NAME as MJ_TABLE;
+use crate::mj_tabs::NAME as MJ_TABS;
 use crate::mj_text::NAME as MJ_TEXT;
 use crate::mj_wrapper::{MjWrapper, NAME as MJ_WRAPPER};
-#[cfg(feature = "async")]
-use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
-    Error, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren, ParseElement,
-    WarningKind,
+    mask_raw_tokens, Error, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren,
+    ParseElement, WarningKind,
 };
+#[cfg(feature = "async")]
+use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::text::Text;
 
 impl<'opts> ParseElement<MjIncludeBodyChild> for MrmlParser<'opts> {
@@ -35,19 +46,32 @@ impl<'opts> ParseElement<MjIncludeBodyChild> for MrmlParser<'opts> {
     ) -> Result<MjIncludeBodyChild, Error> {
         match tag.as_str() {
             MJ_ACCORDION => Ok(MjIncludeBodyChild::MjAccordion(self.parse(cursor, tag)?)),
+            MJ_AVATAR => Ok(MjIncludeBodyChild::MjAvatar(self.parse(cursor, tag)?)),
+            MJ_BADGE => Ok(MjIncludeBodyChild::MjBadge(self.parse(cursor, tag)?)),
             MJ_BUTTON => Ok(MjIncludeBodyChild::MjButton(self.parse(cursor, tag)?)),
+            MJ_CALENDAR_BUTTON => Ok(MjIncludeBodyChild::MjCalendarButton(
+                self.parse(cursor, tag)?,
+            )),
             MJ_CAROUSEL => Ok(MjIncludeBodyChild::MjCarousel(self.parse(cursor, tag)?)),
+            MJ_CODE => Ok(MjIncludeBodyChild::MjCode(self.parse(cursor, tag)?)),
             MJ_COLUMN => Ok(MjIncludeBodyChild::MjColumn(self.parse(cursor, tag)?)),
             MJ_DIVIDER => Ok(MjIncludeBodyChild::MjDivider(self.parse(cursor, tag)?)),
+            MJ_FOOTER => Ok(MjIncludeBodyChild::MjFooter(self.parse(cursor, tag)?)),
             MJ_GROUP => Ok(MjIncludeBodyChild::MjGroup(self.parse(cursor, tag)?)),
             MJ_HERO => Ok(MjIncludeBodyChild::MjHero(self.parse(cursor, tag)?)),
             MJ_IMAGE => Ok(MjIncludeBodyChild::MjImage(self.parse(cursor, tag)?)),
+            MJ_MAP => Ok(MjIncludeBodyChild::MjMap(self.parse(cursor, tag)?)),
+            MJ_MSOOFFICE => Ok(MjIncludeBodyChild::MjMsoOffice(self.parse(cursor, tag)?)),
             MJ_NAVBAR => Ok(MjIncludeBodyChild::MjNavbar(self.parse(cursor, tag)?)),
+            MJ_NOT_MSO => Ok(MjIncludeBodyChild::MjNotMso(self.parse(cursor, tag)?)),
+            MJ_QUOTE => Ok(MjIncludeBodyChild::MjQuote(self.parse(cursor, tag)?)),
             MJ_RAW => Ok(MjIncludeBodyChild::MjRaw(self.parse(cursor, tag)?)),
             MJ_SECTION => Ok(MjIncludeBodyChild::MjSection(self.parse(cursor, tag)?)),
             MJ_SOCIAL => Ok(MjIncludeBodyChild::MjSocial(self.parse(cursor, tag)?)),
             MJ_SPACER => Ok(MjIncludeBodyChild::MjSpacer(self.parse(cursor, tag)?)),
+            MJ_STATS => Ok(MjIncludeBodyChild::MjStats(self.parse(cursor, tag)?)),
             MJ_TABLE => Ok(MjIncludeBodyChild::MjTable(self.parse(cursor, tag)?)),
+            MJ_TABS => Ok(MjIncludeBodyChild::MjTabs(self.parse(cursor, tag)?)),
             MJ_TEXT => Ok(MjIncludeBodyChild::MjText(self.parse(cursor, tag)?)),
             MJ_WRAPPER => Ok(MjIncludeBodyChild::MjWrapper(self.parse(cursor, tag)?)),
             _ => Err(Error::UnexpectedElement {
@@ -71,18 +95,33 @@ impl AsyncParseElement<MjIncludeBodyChild> for AsyncMrmlParser {
             MJ_ACCORDION => Ok(MjIncludeBodyChild::MjAccordion(
                 self.async_parse(cursor, tag).await?,
             )),
+            MJ_AVATAR => Ok(MjIncludeBodyChild::MjAvatar(
+                self.async_parse(cursor, tag).await?,
+            )),
+            MJ_BADGE => Ok(MjIncludeBodyChild::MjBadge(
+                self.async_parse(cursor, tag).await?,
+            )),
             MJ_BUTTON => Ok(MjIncludeBodyChild::MjButton(
                 self.async_parse(cursor, tag).await?,
             )),
+            MJ_CALENDAR_BUTTON => Ok(MjIncludeBodyChild::MjCalendarButton(
+                self.async_parse(cursor, tag).await?,
+            )),
             MJ_CAROUSEL => Ok(MjIncludeBodyChild::MjCarousel(
                 self.async_parse(cursor, tag).await?,
             )),
+            MJ_CODE => Ok(MjIncludeBodyChild::MjCode(
+                self.async_parse(cursor, tag).await?,
+            )),
             MJ_COLUMN => Ok(MjIncludeBodyChild::MjColumn(
                 self.async_parse(cursor, tag).await?,
             )),
             MJ_DIVIDER => Ok(MjIncludeBodyChild::MjDivider(
                 self.async_parse(cursor, tag).await?,
             )),
+            MJ_FOOTER => Ok(MjIncludeBodyChild::MjFooter(
+                self.async_parse(cursor, tag).await?,
+            )),
             MJ_GROUP => Ok(MjIncludeBodyChild::MjGroup(
                 self.async_parse(cursor, tag).await?,
             )),
@@ -92,9 +131,21 @@ impl AsyncParseElement<MjIncludeBodyChild> for AsyncMrmlParser {
             MJ_IMAGE => Ok(MjIncludeBodyChild::MjImage(
                 self.async_parse(cursor, tag).await?,
             )),
+            MJ_MAP => Ok(MjIncludeBodyChild::MjMap(
+                self.async_parse(cursor, tag).await?,
+            )),
+            MJ_MSOOFFICE => Ok(MjIncludeBodyChild::MjMsoOffice(
+                self.async_parse(cursor, tag).await?,
+            )),
             MJ_NAVBAR => Ok(MjIncludeBodyChild::MjNavbar(
                 self.async_parse(cursor, tag).await?,
             )),
+            MJ_NOT_MSO => Ok(MjIncludeBodyChild::MjNotMso(
+                self.async_parse(cursor, tag).await?,
+            )),
+            MJ_QUOTE => Ok(MjIncludeBodyChild::MjQuote(
+                self.async_parse(cursor, tag).await?,
+            )),
             MJ_RAW => Ok(MjIncludeBodyChild::MjRaw(
                 self.async_parse(cursor, tag).await?,
             )),
@@ -107,9 +158,15 @@ impl AsyncParseElement<MjIncludeBodyChild> for AsyncMrmlParser {
             MJ_SPACER => Ok(MjIncludeBodyChild::MjSpacer(
                 self.async_parse(cursor, tag).await?,
             )),
+            MJ_STATS => Ok(MjIncludeBodyChild::MjStats(
+                self.async_parse(cursor, tag).await?,
+            )),
             MJ_TABLE => Ok(MjIncludeBodyChild::MjTable(
                 self.async_parse(cursor, tag).await?,
             )),
+            MJ_TABS => Ok(MjIncludeBodyChild::MjTabs(
+                self.async_parse(cursor, tag).await?,
+            )),
             MJ_TEXT => Ok(MjIncludeBodyChild::MjText(
                 self.async_parse(cursor, tag).await?,
             )),
@@ -148,6 +205,7 @@ fn parse_attributes(
 ) -> Result<MjIncludeBodyAttributes, Error> {
     let mut path = None;
     let mut kind: Option<MjIncludeBodyKind> = None;
+    let mut scope = None;
     while let Some(attr) = cursor.next_attribute()? {
         match attr.local.as_str() {
             "path" => {
@@ -156,6 +214,9 @@ fn parse_attributes(
             "type" => {
                 kind = Some(MjIncludeBodyKind::parse(cursor, attr.value)?);
             }
+            "scope" => {
+                scope = Some(attr.value.to_string());
+            }
             _ => {
                 cursor.add_warning(WarningKind::UnexpectedAttribute, attr.span);
             }
@@ -168,6 +229,7 @@ fn parse_attributes(
             position: tag.into(),
         })?,
         kind: kind.unwrap_or_default(),
+        scope,
     })
 }
 
@@ -280,6 +342,8 @@ impl<'opts> ParseElement<MjIncludeBody> for MrmlParser<'opts> {
 
         // if a mj-include has some content, we don't load it
         let children: Vec<MjIncludeBodyChild> = if children.is_empty() {
+            let _span =
+                crate::trace::span!("mrml::include", path = attributes.path.as_str()).entered();
             let child = self
                 .options
                 .include_loader
@@ -291,18 +355,22 @@ impl<'opts> ParseElement<MjIncludeBody> for MrmlParser<'opts> {
                 })?;
             match attributes.kind {
                 MjIncludeBodyKind::Html => {
-                    let mut sub = cursor.new_child(&attributes.path, child.as_str());
+                    let sub = cursor.new_child(&attributes.path, child.as_str())?;
+                    let masked = mask_raw_tokens(&self.options.raw_token_patterns, &child);
+                    let mut sub = sub.with_masked_source(child.as_str(), &masked);
                     let children: Vec<MjBodyChild> = self.parse_children(&mut sub)?;
-                    cursor.with_warnings(sub.warnings());
+                    cursor.merge_child(sub);
                     vec![MjIncludeBodyChild::MjWrapper(MjWrapper::new(
                         Default::default(),
                         children,
                     ))]
                 }
                 MjIncludeBodyKind::Mjml => {
-                    let mut sub = cursor.new_child(&attributes.path, child.as_str());
+                    let sub = cursor.new_child(&attributes.path, child.as_str())?;
+                    let masked = mask_raw_tokens(&self.options.raw_token_patterns, &child);
+                    let mut sub = sub.with_masked_source(child.as_str(), &masked);
                     let children = self.parse_children(&mut sub)?;
-                    cursor.with_warnings(sub.warnings());
+                    cursor.merge_child(sub);
                     children
                 }
             }
@@ -330,29 +398,35 @@ impl crate::prelude::parser::AsyncParseElement<MjIncludeBody> for AsyncMrmlParse
 
         // if a mj-include has some content, we don't load it
         let children: Vec<MjIncludeBodyChild> = if children.is_empty() {
-            let child = self
-                .options
-                .include_loader
-                .async_resolve(&attributes.path)
-                .await
-                .map_err(|source| Error::IncludeLoaderError {
-                    origin: cursor.origin(),
-                    position: tag.into(),
-                    source,
-                })?;
+            let span = crate::trace::span!("mrml::include", path = attributes.path.as_str());
+            let child = crate::trace::instrument(
+                span,
+                self.options.include_loader.async_resolve(&attributes.path),
+            )
+            .await
+            .map_err(|source| Error::IncludeLoaderError {
+                origin: cursor.origin(),
+                position: tag.into(),
+                source,
+            })?;
             match attributes.kind {
                 MjIncludeBodyKind::Html => {
-                    let mut sub = cursor.new_child(&attributes.path, child.as_str());
+                    let sub = cursor.new_child(&attributes.path, child.as_str())?;
+                    let masked = mask_raw_tokens(&self.options.raw_token_patterns, &child);
+                    let mut sub = sub.with_masked_source(child.as_str(), &masked);
                     let children: Vec<MjBodyChild> = self.async_parse_children(&mut sub).await?;
+                    cursor.merge_child(sub);
                     vec![MjIncludeBodyChild::MjWrapper(MjWrapper::new(
                         Default::default(),
                         children,
                     ))]
                 }
                 MjIncludeBodyKind::Mjml => {
-                    let mut sub = cursor.new_child(&attributes.path, child.as_str());
+                    let sub = cursor.new_child(&attributes.path, child.as_str())?;
+                    let masked = mask_raw_tokens(&self.options.raw_token_patterns, &child);
+                    let mut sub = sub.with_masked_source(child.as_str(), &masked);
                     let children = self.async_parse_children(&mut sub).await?;
-                    cursor.with_warnings(sub.warnings());
+                    cursor.merge_child(sub);
                     children
                 }
             }
@@ -411,6 +485,7 @@ mod tests {
             MemoryIncludeLoader::from(vec![("basic.mjml", "<mj-button>Hello</mj-button>")]);
         let opts = ParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="basic.mjml" />"#;
         let mut cursor = MrmlCursor::new(raw);
@@ -428,6 +503,7 @@ mod tests {
             MemoryIncludeLoader::from(vec![("basic.mjml", "<mj-button>Hello</mj-button>")]);
         let opts = AsyncParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="basic.mjml" />"#;
         let mut cursor = MrmlCursor::new(raw);
@@ -444,6 +520,7 @@ mod tests {
         let resolver = MemoryIncludeLoader::from(vec![("partial.html", "<h1>Hello World!</h1>")]);
         let opts = ParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="partial.html" type="html" />"#;
         let mut cursor = MrmlCursor::new(raw);
@@ -460,6 +537,7 @@ mod tests {
         let resolver = MemoryIncludeLoader::from(vec![("partial.html", "<h1>Hello World!</h1>")]);
         let opts = AsyncParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="partial.html" type="html" />"#;
         let mut cursor = MrmlCursor::new(raw);
@@ -527,6 +605,7 @@ mod tests {
         )]);
         let opts = ParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="partial.html" type="html" />"#;
         let mut cursor = MrmlCursor::new(raw);
@@ -6,6 +6,9 @@ impl PrintableAttributes for super::MjIncludeBodyAttributes {
         if !self.kind.is_default() {
             printer.push_attribute("type", self.kind.as_ref())?;
         }
+        if let Some(ref scope) = self.scope {
+            printer.push_attribute("scope", scope.as_str())?;
+        }
         Ok(())
     }
 }
@@ -64,4 +67,15 @@ mod tests {
             "<mj-include path=\"memory:include.html\" type=\"html\" />"
         );
     }
+    #[test]
+    fn with_scope() {
+        let elt = MjIncludeBody::new(
+            MjIncludeBodyAttributes::new("memory:include.mjml").with_scope("card"),
+            vec![MjIncludeBodyChild::MjButton(MjButton::default())],
+        );
+        assert_eq!(
+            elt.print_dense().unwrap(),
+            "<mj-include path=\"memory:include.mjml\" scope=\"card\" />"
+        );
+    }
 }
@@ -8,19 +8,30 @@ impl MjIncludeBodyChild {
         match self {
             Self::Comment(elt) => elt,
             Self::MjAccordion(elt) => elt,
+            Self::MjAvatar(elt) => elt,
+            Self::MjBadge(elt) => elt,
             Self::MjButton(elt) => elt,
+            Self::MjCalendarButton(elt) => elt,
             Self::MjCarousel(elt) => elt,
+            Self::MjCode(elt) => elt,
             Self::MjColumn(elt) => elt,
             Self::MjDivider(elt) => elt,
+            Self::MjFooter(elt) => elt,
             Self::MjGroup(elt) => elt,
             Self::MjHero(elt) => elt,
             Self::MjImage(elt) => elt,
+            Self::MjMap(elt) => elt,
+            Self::MjMsoOffice(elt) => elt,
             Self::MjNavbar(elt) => elt,
+            Self::MjNotMso(elt) => elt,
+            Self::MjQuote(elt) => elt,
             Self::MjRaw(elt) => elt,
             Self::MjSection(elt) => elt,
             Self::MjSocial(elt) => elt,
             Self::MjSpacer(elt) => elt,
+            Self::MjStats(elt) => elt,
             Self::MjTable(elt) => elt,
+            Self::MjTabs(elt) => elt,
             Self::MjText(elt) => elt,
             Self::MjWrapper(elt) => elt,
             Self::Node(elt) => elt,
@@ -55,7 +66,21 @@ impl<'root> Render<'root> for Renderer<'root, MjIncludeBody, ()> {
         self.context
     }
 
+    /// Renders the included content in the scope of the `mj-include`, or in
+    /// the one of its parent when it has none.
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let Some(scope) = self.element.0.attributes.scope.as_deref() else {
+            return self.render_children(cursor);
+        };
+        self.context.scopes.enter(scope);
+        let result = self.render_children(cursor);
+        self.context.scopes.leave();
+        result
+    }
+}
+
+impl<'root> Renderer<'root, MjIncludeBody, ()> {
+    fn render_children(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         for (index, child) in self.element.0.children.iter().enumerate() {
             let mut renderer = child.renderer(self.context());
             renderer.set_index(index);
@@ -156,4 +181,48 @@ mod tests {
         };
         assert_eq!(expected, result);
     }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn scoped_mjml_kind() {
+        use crate::prelude::parser::memory_loader::MemoryIncludeLoader;
+        use crate::prelude::parser::ParserOptions;
+
+        let resolver = MemoryIncludeLoader::from(vec![(
+            "card.mjml",
+            r#"<mj-text mj-class="title">In the card</mj-text>"#,
+        )]);
+        let opts = ParserOptions {
+            include_loader: Box::new(resolver),
+            ..Default::default()
+        };
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-attributes>
+      <mj-text color="red" font-size="20px" />
+      <mj-class name="title" font-weight="bold" />
+    </mj-attributes>
+    <mj-attributes scope="card">
+      <mj-text color="blue" />
+      <mj-class name="title" font-style="italic" />
+    </mj-attributes>
+  </mj-head>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-text>Outside</mj-text>
+        <mj-include path="card.mjml" scope="card" />
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::mjml::Mjml::parse_with_options(template, &opts).unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        assert!(html
+            .contains("font-size:20px;line-height:1;text-align:left;color:red;\">Outside</div>"));
+        assert!(html.contains(
+            "font-size:13px;font-style:italic;line-height:1;text-align:left;color:blue;\">In the card</div>"
+        ));
+        assert!(!html.contains("font-weight:bold;"));
+    }
 }
@@ -9,12 +9,12 @@ use crate::mj_preview::NAME as MJ_PREVIEW;
 use crate::mj_raw::NAME as MJ_RAW;
 use crate::mj_style::NAME as MJ_STYLE;
 use crate::mj_title::NAME as MJ_TITLE;
-#[cfg(feature = "async")]
-use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
-    Error, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren, ParseElement,
-    WarningKind,
+    mask_raw_tokens, Error, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren,
+    ParseElement, WarningKind,
 };
+#[cfg(feature = "async")]
+use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::text::Text;
 
 impl<'opts> ParseElement<MjIncludeHeadChild> for MrmlParser<'opts> {
@@ -224,6 +224,8 @@ impl<'opts> ParseElement<MjIncludeHead> for MrmlParser<'opts> {
 
         // if a mj-include has some content, we don't load it
         let children: Vec<MjIncludeHeadChild> = if children.is_empty() {
+            let _span =
+                crate::trace::span!("mrml::include", path = attributes.path.as_str()).entered();
             let child = self
                 .options
                 .include_loader
@@ -242,9 +244,11 @@ impl<'opts> ParseElement<MjIncludeHead> for MrmlParser<'opts> {
                 }
                 MjIncludeHeadKind::Css { inline: true } => unimplemented!(),
                 MjIncludeHeadKind::Mjml => {
-                    let mut sub = cursor.new_child(&attributes.path, child.as_str());
+                    let sub = cursor.new_child(&attributes.path, child.as_str())?;
+                    let masked = mask_raw_tokens(&self.options.raw_token_patterns, &child);
+                    let mut sub = sub.with_masked_source(child.as_str(), &masked);
                     let children = self.parse_children(&mut sub)?;
-                    cursor.with_warnings(sub.warnings());
+                    cursor.merge_child(sub);
                     children
                 }
                 MjIncludeHeadKind::Html => todo!(),
@@ -271,16 +275,17 @@ impl AsyncParseElement<MjIncludeHead> for AsyncMrmlParser {
 
         // if a mj-include has some content, we don't load it
         let children: Vec<MjIncludeHeadChild> = if children.is_empty() {
-            let child = self
-                .options
-                .include_loader
-                .async_resolve(&attributes.path)
-                .await
-                .map_err(|source| Error::IncludeLoaderError {
-                    origin: cursor.origin(),
-                    position: tag.into(),
-                    source,
-                })?;
+            let span = crate::trace::span!("mrml::include", path = attributes.path.as_str());
+            let child = crate::trace::instrument(
+                span,
+                self.options.include_loader.async_resolve(&attributes.path),
+            )
+            .await
+            .map_err(|source| Error::IncludeLoaderError {
+                origin: cursor.origin(),
+                position: tag.into(),
+                source,
+            })?;
 
             match attributes.kind {
                 MjIncludeHeadKind::Css { inline: false } => {
@@ -290,9 +295,11 @@ impl AsyncParseElement<MjIncludeHead> for AsyncMrmlParser {
                 }
                 MjIncludeHeadKind::Css { inline: true } => unimplemented!(),
                 MjIncludeHeadKind::Mjml => {
-                    let mut sub = cursor.new_child(&attributes.path, child.as_str());
+                    let sub = cursor.new_child(&attributes.path, child.as_str())?;
+                    let masked = mask_raw_tokens(&self.options.raw_token_patterns, &child);
+                    let mut sub = sub.with_masked_source(child.as_str(), &masked);
                     let children = self.async_parse_children(&mut sub).await?;
-                    cursor.with_warnings(sub.warnings());
+                    cursor.merge_child(sub);
                     children
                 }
                 MjIncludeHeadKind::Html => unimplemented!(),
@@ -397,6 +404,7 @@ mod tests {
             MemoryIncludeLoader::from(vec![("basic.mjml", "<mj-title>Hello</mj-title>")]);
         let opts = ParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="basic.mjml" />"#;
         let parser = MrmlParser::new(&opts);
@@ -415,6 +423,7 @@ mod tests {
             MemoryIncludeLoader::from(vec![("basic.mjml", "<mj-title>Hello</mj-title>")]);
         let opts = AsyncParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="basic.mjml" />"#;
         let parser = AsyncMrmlParser::new(opts.into());
@@ -431,6 +440,7 @@ mod tests {
         let raw = r#"<mj-include path="partial.css" type="css" />"#;
         let opts = ParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let parser = MrmlParser::new(&opts);
         let mut cursor = MrmlCursor::new(raw);
@@ -452,6 +462,7 @@ mod tests {
         let raw = r#"<mj-include path="partial.css" type="css" />"#;
         let opts = AsyncParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let parser = AsyncMrmlParser::new(opts.into());
         let mut cursor = MrmlCursor::new(raw);
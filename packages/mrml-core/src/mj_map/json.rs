@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_map::MjMap;
+
+    #[test]
+    fn serialize() {
+        let mut elt = MjMap::default();
+        elt.attributes
+            .insert("center".into(), "48.8584,2.2945".into());
+        elt.attributes.insert("zoom".into(), "15".into());
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r#"{"type":"mj-map","attributes":{"center":"48.8584,2.2945","zoom":"15"}}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let json =
+            r#"{"type":"mj-map","attributes":{"center":"Tour Eiffel, Paris","height":"150px"}}"#;
+        let res: MjMap = serde_json::from_str(json).unwrap();
+        assert_eq!(res.attributes.len(), 2);
+        assert_eq!(res.attributes.get("height").unwrap(), "150px");
+    }
+}
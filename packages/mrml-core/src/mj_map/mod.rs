@@ -0,0 +1,46 @@
+//! Module containing the `mj-map` element, rendering the static image of a
+//! map linked to the map itself, like the address of a booking.
+//!
+//! The image and the link are built from the `src-template` and
+//! `href-template` attributes, using Google Maps by default, where the
+//! `{center}`, `{marker}`, `{zoom}`, `{width}`, `{height}`, `{scale}` and
+//! `{api-key}` placeholders are replaced with the values of the element. The
+//! `center` and the `marker` can be coordinates, like `48.8584,2.2945`, or
+//! an address.
+//!
+//! ```rust
+//! let template = r#"<mjml><mj-body><mj-section><mj-column>
+//!   <mj-map center="48.8584,2.2945" zoom="15" api-key="my-key" />
+//!   <mj-map center="48.8584,2.2945" src-template="https://tiles.example.com/static/{center}/{zoom}/{width}x{height}.png" />
+//! </mj-column></mj-section></mj-body></mjml>"#;
+//! let root = mrml::parse(template).expect("parse template");
+//! let opts = mrml::prelude::render::Options::default();
+//! match root.element.render(&opts) {
+//!     Ok(content) => println!("{content}"),
+//!     Err(_) => println!("couldn't render mjml template"),
+//! };
+//! ```
+
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "print")]
+mod print;
+#[cfg(feature = "render")]
+mod render;
+
+use std::marker::PhantomData;
+
+use crate::prelude::hash::Map;
+use crate::prelude::{Component, StaticTag};
+
+pub const NAME: &str = "mj-map";
+
+pub struct MjMapTag;
+
+impl StaticTag for MjMapTag {
+    fn static_tag() -> &'static str {
+        NAME
+    }
+}
+
+pub type MjMap = Component<PhantomData<MjMapTag>, Map<String, String>, ()>;
@@ -0,0 +1,11 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::print::Printable;
+
+    #[test]
+    fn empty() {
+        let mut item = crate::mj_map::MjMap::default();
+        item.attributes.insert("center".to_string(), "Paris".into());
+        assert_eq!("<mj-map center=\"Paris\" />", item.print_dense().unwrap());
+    }
+}
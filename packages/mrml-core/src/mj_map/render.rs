@@ -0,0 +1,185 @@
+use super::{MjMap, NAME};
+use crate::helper::size::Pixel;
+use crate::helper::url::fill_template;
+use crate::helper::width;
+use crate::prelude::hash::Map;
+use crate::prelude::render::*;
+
+const SRC_TEMPLATE: &str = "https://maps.googleapis.com/maps/api/staticmap?center={center}&zoom={zoom}&size={width}x{height}&scale={scale}&markers={marker}&key={api-key}";
+const HREF_TEMPLATE: &str = "https://www.google.com/maps/search/?api=1&query={marker}";
+
+impl<'root> Renderer<'root, MjMap, ()> {
+    fn get_content_width(&self) -> Option<Pixel> {
+        let width = self.attribute_as_pixel("width");
+        match self.container_width {
+            Some(container) => Some(width::content_width(
+                container,
+                width,
+                self.get_padding_horizontal(),
+                self.get_border_horizontal(),
+            )),
+            None => width,
+        }
+    }
+
+    fn get_height(&self) -> Option<Pixel> {
+        self.attribute_as_pixel("height")
+    }
+
+    /// Fills the template with the values of the element, the marker being
+    /// on the center by default.
+    fn fill(&self, template: &str) -> Option<String> {
+        let center = self.attribute("center")?;
+        let width = self
+            .get_content_width()
+            .map(|value| value.value().round().to_string())
+            .unwrap_or_default();
+        let height = self
+            .get_height()
+            .map(|value| value.value().round().to_string())
+            .unwrap_or_default();
+        Some(fill_template(
+            template,
+            &[
+                ("center", center),
+                ("marker", self.attribute("marker").unwrap_or(center)),
+                ("zoom", self.attribute("zoom").unwrap_or_default()),
+                ("width", width.as_str()),
+                ("height", height.as_str()),
+                ("scale", self.attribute("scale").unwrap_or_default()),
+                ("api-key", self.attribute("api-key").unwrap_or_default()),
+            ],
+        ))
+    }
+
+    fn set_style_img<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 'a,
+        'a: 't,
+    {
+        tag.maybe_add_style("border", self.attribute("border"))
+            .maybe_add_style("border-radius", self.attribute("border-radius"))
+            .add_style("display", "block")
+            .add_style("outline", "none")
+            .add_style("text-decoration", "none")
+            .maybe_add_style("height", self.attribute("height"))
+            .add_style("width", "100%")
+            .maybe_add_style("font-size", self.attribute("font-size"))
+    }
+
+    fn render_image(&self, src: String, buf: &mut RenderBuffer) -> std::fmt::Result {
+        let alt = self.attribute("alt").map(String::from).or_else(|| {
+            self.attribute("center")
+                .map(|center| format!("Map of {center}"))
+        });
+        let img = Tag::new("img")
+            .maybe_add_attribute("alt", alt)
+            .maybe_add_attribute(
+                "height",
+                self.get_height().map(|size| size.value().to_string()),
+            )
+            .add_attribute("src", src)
+            .maybe_add_attribute("title", self.attribute("title"))
+            .maybe_add_attribute(
+                "width",
+                self.get_content_width()
+                    .map(|size| size.value().to_string()),
+            );
+        let img = self.passthrough_attributes(self.set_style_img(img));
+        img.render_closed(buf)
+    }
+}
+
+impl<'root> Render<'root> for Renderer<'root, MjMap, ()> {
+    fn default_attribute(&self, key: &str) -> Option<&'static str> {
+        match key {
+            "align" => Some("center"),
+            "border" => Some("0"),
+            "font-size" => Some("13px"),
+            "height" => Some("200px"),
+            "href-template" => Some(HREF_TEMPLATE),
+            "padding" => Some("10px 25px"),
+            "scale" => Some("2"),
+            "src-template" => Some(SRC_TEMPLATE),
+            "target" => Some("_blank"),
+            "zoom" => Some("14"),
+            _ => None,
+        }
+    }
+
+    fn raw_attribute(&self, key: &str) -> Option<&'root str> {
+        self.element.attributes.get(key).map(|v| v.as_str())
+    }
+
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
+    fn tag(&self) -> Option<&str> {
+        Some(NAME)
+    }
+
+    fn set_container_width(&mut self, width: Option<Pixel>) {
+        self.container_width = width;
+    }
+
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.context
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let Some(src) = self.attribute("src-template").and_then(|v| self.fill(v)) else {
+            if self.raw_attribute("zoom").is_some() {
+                self.report_missing_attribute("center");
+            }
+            return Ok(());
+        };
+        let href = self
+            .attribute("href")
+            .map(String::from)
+            .or_else(|| self.attribute("href-template").and_then(|v| self.fill(v)));
+
+        let table = Tag::table_presentation()
+            .add_style("border-collapse", "collapse")
+            .add_style("border-spacing", "0px");
+        let tbody = Tag::tbody();
+        let tr = Tag::tr();
+        let td =
+            Tag::td().maybe_add_style("width", self.get_content_width().map(|v| v.to_string()));
+
+        table.render_open(&mut cursor.buffer)?;
+        tbody.render_open(&mut cursor.buffer)?;
+        tr.render_open(&mut cursor.buffer)?;
+        td.render_open(&mut cursor.buffer)?;
+        match href {
+            Some(href) => Tag::new("a")
+                .add_attribute("href", href)
+                .maybe_add_attribute("rel", self.attribute("rel"))
+                .maybe_add_attribute("target", self.attribute("target"))
+                .render_with(&mut cursor.buffer, |b| self.render_image(src, b))?,
+            None => self.render_image(src, &mut cursor.buffer)?,
+        }
+        td.render_close(&mut cursor.buffer);
+        tr.render_close(&mut cursor.buffer);
+        tbody.render_close(&mut cursor.buffer);
+        table.render_close(&mut cursor.buffer);
+
+        Ok(())
+    }
+}
+
+impl<'render, 'root: 'render> Renderable<'render, 'root> for MjMap {
+    fn renderer(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        Box::new(Renderer::new(context, self, ()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::should_render!(basic, "mj-map");
+    crate::should_render!(template, "mj-map-template");
+    crate::should_render!(without_center, "mj-map-without-center");
+}
@@ -0,0 +1,21 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_msooffice::MjMsoOffice;
+
+    #[test]
+    fn serialize() {
+        let elt = MjMsoOffice::default();
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r#"{"type":"mj-msooffice"}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let json =
+            r#"{"type":"mj-msooffice","children":[{"type":"mj-text","children":["Hello"]}]}"#;
+        let res: MjMsoOffice = serde_json::from_str(json).unwrap();
+        assert_eq!(res.children.len(), 1);
+    }
+}
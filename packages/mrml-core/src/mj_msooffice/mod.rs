@@ -0,0 +1,33 @@
+use std::marker::PhantomData;
+
+use crate::mj_body::MjBodyChild;
+use crate::prelude::hash::Map;
+use crate::prelude::{Component, StaticTag};
+
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "parse")]
+mod parse;
+#[cfg(feature = "print")]
+mod print;
+#[cfg(feature = "render")]
+mod render;
+
+#[cfg(feature = "render")]
+pub(crate) use render::render_conditional;
+
+pub const NAME: &str = "mj-msooffice";
+
+pub struct MjMsoOfficeTag;
+
+impl StaticTag for MjMsoOfficeTag {
+    fn static_tag() -> &'static str {
+        NAME
+    }
+}
+
+/// Content only displayed by outlook, wrapped in a `<!--[if mso]>`
+/// conditional comment. It contains sections, in the body, or the content of
+/// a column.
+pub type MjMsoOffice =
+    Component<PhantomData<MjMsoOfficeTag>, Map<String, String>, Vec<MjBodyChild>>;
@@ -0,0 +1,11 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_msooffice::MjMsoOffice;
+
+    crate::should_parse!(self_closing, MjMsoOffice, "<mj-msooffice />");
+    crate::should_parse!(
+        with_children,
+        MjMsoOffice,
+        "<mj-msooffice><mj-text>Hello</mj-text><mj-image src=\"a.png\" /></mj-msooffice>"
+    );
+}
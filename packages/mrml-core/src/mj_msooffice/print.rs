@@ -0,0 +1,14 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::print::Printable;
+
+    #[test]
+    fn empty() {
+        let mut item = crate::mj_msooffice::MjMsoOffice::default();
+        item.children.push(crate::mj_text::MjText::default().into());
+        assert_eq!(
+            "<mj-msooffice><mj-text /></mj-msooffice>",
+            item.print_dense().unwrap()
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use super::{MjMsoOffice, NAME};
+use crate::helper::size::Pixel;
+use crate::mj_body::MjBodyChild;
+use crate::mj_column::render_row;
+use crate::prelude::render::*;
+
+/// Elements rendered as is in the conditional block. The other ones are
+/// rendered in a row, like in a column.
+const LAYOUT_TAGS: [&str; 5] = [
+    "mj-column",
+    "mj-group",
+    "mj-hero",
+    "mj-section",
+    "mj-wrapper",
+];
+
+/// Renders the children in a conditional comment only visible in outlook,
+/// when `mso` is `true`, or hidden from outlook.
+pub(crate) fn render_conditional<'root>(
+    context: &'root RenderContext<'root>,
+    children: &'root [MjBodyChild],
+    container_width: Option<Pixel>,
+    mso: bool,
+    cursor: &mut RenderCursor,
+) -> Result<(), Error> {
+    let outer = std::mem::take(&mut cursor.buffer);
+    for (index, child) in children.iter().enumerate() {
+        let mut renderer = child.renderer(context);
+        renderer.set_index(index);
+        renderer.set_container_width(container_width);
+        let is_layout = renderer
+            .tag()
+            .is_some_and(|tag| LAYOUT_TAGS.contains(&tag));
+        if child.is_raw() || is_layout {
+            renderer.render(cursor)?;
+        } else {
+            render_row(renderer.as_ref(), cursor)?;
+        }
+    }
+    let content = std::mem::replace(&mut cursor.buffer, outer);
+    let content = flatten_conditionals(&String::from(content), mso);
+    if mso {
+        cursor.buffer.start_mso_conditional_tag();
+        cursor.buffer.push_str(&content);
+        cursor.buffer.end_conditional_tag();
+    } else {
+        cursor.buffer.start_mso_negation_conditional_tag();
+        cursor.buffer.push_str(&content);
+        cursor.buffer.end_negation_conditional_tag();
+    }
+    Ok(())
+}
+
+impl<'root> Render<'root> for Renderer<'root, MjMsoOffice, ()> {
+    fn raw_attribute(&self, key: &str) -> Option<&'root str> {
+        self.element.attributes.get(key).map(|v| v.as_str())
+    }
+
+    fn tag(&self) -> Option<&str> {
+        Some(NAME)
+    }
+
+    fn set_container_width(&mut self, width: Option<Pixel>) {
+        self.container_width = width;
+    }
+
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.context
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        render_conditional(
+            self.context,
+            &self.element.children,
+            self.container_width,
+            true,
+            cursor,
+        )
+    }
+}
+
+impl<'render, 'root: 'render> Renderable<'render, 'root> for MjMsoOffice {
+    fn is_raw(&'root self) -> bool {
+        true
+    }
+
+    fn renderer(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        Box::new(Renderer::new(context, self, ()))
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use crate::mjml::Mjml;
+
+    #[test]
+    fn should_render_content_for_outlook() {
+        let template = r#"<mjml><mj-body><mj-section><mj-column>
+  <mj-text>Everyone</mj-text>
+  <mj-msooffice><mj-text padding="4px">Outlook</mj-text></mj-msooffice>
+</mj-column></mj-section></mj-body></mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        let start = html.find("<!--[if mso]><tr><td").unwrap();
+        let end = start + html[start..].find("<![endif]-->").unwrap();
+        let block = &html[start..end];
+        assert!(block.contains("padding:4px;"));
+        assert!(block.contains("Outlook"));
+        assert!(!block[4..].contains("<!--"));
+    }
+
+    #[test]
+    fn should_render_sections_for_outlook() {
+        let template = r#"<mjml><mj-body><mj-msooffice>
+  <mj-section><mj-column><mj-text>Outlook</mj-text></mj-column></mj-section>
+</mj-msooffice></mj-body></mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        let body = &html[html.find("<body").unwrap()..];
+        let start = body.find("<!--[if mso]>").unwrap();
+        let end = start + body[start..].find("<![endif]-->").unwrap();
+        let block = &body[start..end];
+        assert!(block.contains("<table"));
+        assert!(block.contains("Outlook"));
+        assert!(!block[4..].contains("<!--"));
+    }
+}
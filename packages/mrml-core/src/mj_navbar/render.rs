@@ -1,5 +1,6 @@
 use super::{MjNavbar, MjNavbarChild, NAME};
 use crate::helper::size::{Pixel, Size};
+use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
 impl<'render, 'root: 'render> Renderable<'render, 'root> for MjNavbarChild {
@@ -14,11 +15,12 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjNavbarChild {
     }
 }
 
-struct MjNavbarExtra {
+struct MjNavbarExtra<'a> {
     id: String,
+    attributes: Map<&'a str, &'a str>,
 }
 
-impl<'root> Renderer<'root, MjNavbar, MjNavbarExtra> {
+impl<'root> Renderer<'root, MjNavbar, MjNavbarExtra<'root>> {
     fn set_style_input<'t>(&self, tag: Tag<'t>) -> Tag<'t> {
         tag.add_style("display", "none !important")
             .add_style("max-height", "0")
@@ -37,7 +39,7 @@ impl<'root> Renderer<'root, MjNavbar, MjNavbarExtra> {
             .add_style("user-select", "none")
             .maybe_add_style("color", self.attribute("ico-color"))
             .maybe_add_style("font-size", self.attribute("ico-font-size"))
-            .maybe_add_style("font-family", self.attribute("ico-font-family"))
+            .maybe_add_style("font-family", self.attribute_font_family("ico-font-family"))
             .maybe_add_style("text-transform", self.attribute("ico-text-transform"))
             .maybe_add_style("text-decoration", self.attribute("ico-text-decoration"))
             .maybe_add_style("line-height", self.attribute("ico-line-height"))
@@ -77,12 +79,13 @@ impl<'root> Renderer<'root, MjNavbar, MjNavbarExtra> {
             .is_some()
     }
 
+    fn toggle(&self) -> Toggle<'_> {
+        Toggle::checkbox("mj-menu-checkbox").with_id(self.extra.id.as_str())
+    }
+
     fn render_hamburger(&self, buf: &mut RenderBuffer) -> Result<(), Error> {
-        let input = self
-            .set_style_input(Tag::new("input"))
-            .add_class("mj-menu-checkbox")
-            .add_attribute("id", self.extra.id.clone())
-            .add_attribute("type", "checkbox");
+        let toggle = self.toggle();
+        let input = self.set_style_input(toggle.input());
         let div = self
             .set_style_trigger(Tag::div())
             .add_class("mj-menu-trigger");
@@ -90,7 +93,11 @@ impl<'root> Renderer<'root, MjNavbar, MjNavbarExtra> {
             .set_style_label(Tag::new("label"))
             .maybe_add_attribute("align", self.attribute("ico-align"))
             .add_class("mj-menu-label")
-            .add_attribute("for", self.extra.id.clone());
+            .maybe_add_attribute("for", toggle.id().map(str::to_string))
+            .maybe_add_attribute(
+                "aria-label",
+                self.context.options.aria.navbar_toggle_label.as_deref(),
+            );
         let span_open = self
             .set_style_ico_open(Tag::new("span"))
             .add_class("mj-menu-icon-open");
@@ -98,9 +105,7 @@ impl<'root> Renderer<'root, MjNavbar, MjNavbarExtra> {
             .set_style_ico_close(Tag::new("span"))
             .add_class("mj-menu-icon-close");
 
-        buf.start_mso_negation_conditional_tag();
-        input.render_closed(buf)?;
-        buf.end_negation_conditional_tag();
+        toggle.render_input(&input, buf, InputGate::NotMso)?;
 
         div.render_open(buf)?;
         label.render_open(buf)?;
@@ -125,7 +130,7 @@ impl<'root> Renderer<'root, MjNavbar, MjNavbarExtra> {
     fn render_style(&self) -> String {
         format!(
             r#"
-        noinput.mj-menu-checkbox {{ display:block!important; max-height:none!important; visibility:visible!important; }}
+        {}
         @media only screen and (max-width:{}) {{
           .mj-menu-checkbox[type="checkbox"] ~ .mj-inline-links {{ display:none!important; }}
           .mj-menu-checkbox[type="checkbox"]:checked ~ .mj-inline-links,
@@ -135,12 +140,15 @@ impl<'root> Renderer<'root, MjNavbar, MjNavbarExtra> {
           .mj-menu-checkbox[type="checkbox"]:checked ~ .mj-menu-trigger .mj-menu-icon-open {{ display:none!important; }}
         }}
         "#,
+            self.toggle().no_input_rule(
+                "display:block!important; max-height:none!important; visibility:visible!important;"
+            ),
             self.context.header.breakpoint().lower()
         )
     }
 }
 
-impl<'root> Render<'root> for Renderer<'root, MjNavbar, MjNavbarExtra> {
+impl<'root> Render<'root> for Renderer<'root, MjNavbar, MjNavbarExtra<'root>> {
     fn default_attribute(&self, name: &str) -> Option<&'static str> {
         match name {
             "align" => Some("center"),
@@ -162,6 +170,18 @@ impl<'root> Render<'root> for Renderer<'root, MjNavbar, MjNavbarExtra> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
+    fn add_extra_attribute(&mut self, key: &'root str, value: &'root str) {
+        self.extra.attributes.insert(key, value);
+    }
+
+    fn raw_extra_attribute(&self, key: &str) -> Option<&'root str> {
+        self.extra.attributes.get(key).copied()
+    }
+
     fn tag(&self) -> Option<&str> {
         Some(NAME)
     }
@@ -189,7 +209,12 @@ impl<'root> Render<'root> for Renderer<'root, MjNavbar, MjNavbarExtra> {
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         cursor.header.add_style(self.render_style());
 
-        let div = Tag::div().add_class("mj-inline-links");
+        let navbar_label = self.context.options.aria.navbar_label.as_deref();
+        let div = Tag::div()
+            .add_class("mj-inline-links")
+            .maybe_add_attribute("role", navbar_label.map(|_| "navigation"))
+            .maybe_add_attribute("aria-label", navbar_label);
+        let div = self.passthrough_attributes(div);
         let table = Tag::table_presentation().maybe_add_attribute("align", self.attribute("align"));
         let tr = Tag::tr();
         let base_url = self.attribute("base-url");
@@ -204,7 +229,11 @@ impl<'root> Render<'root> for Renderer<'root, MjNavbar, MjNavbarExtra> {
         tr.render_open(&mut cursor.buffer)?;
         cursor.buffer.end_conditional_tag();
 
-        for child in self.element.children.iter() {
+        let mut children = self.element.children.iter().collect::<Vec<_>>();
+        if self.attribute_equals("direction", "rtl") {
+            children.reverse();
+        }
+        for child in children {
             let mut renderer = child.renderer(self.context());
             renderer.maybe_add_extra_attribute("navbar-base-url", base_url);
             renderer.render(cursor)?;
@@ -226,7 +255,11 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjNavbar {
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
         let id = context.generator.next_id();
-        Box::new(Renderer::new(context, self, MjNavbarExtra { id }))
+        let extra = MjNavbarExtra {
+            id,
+            attributes: Map::new(),
+        };
+        Box::new(Renderer::new(context, self, extra))
     }
 }
 
@@ -235,4 +268,49 @@ mod tests {
     crate::should_render!(basic, "mj-navbar");
     crate::should_render!(align_class, "mj-navbar-align-class");
     crate::should_render!(ico, "mj-navbar-ico");
+
+    #[test]
+    fn should_add_aria_labels() {
+        let opts = crate::prelude::render::RenderOptions {
+            aria: crate::prelude::render::AriaOptions {
+                navbar_label: Some("Main menu".into()),
+                navbar_toggle_label: Some("Toggle the menu".into()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let template = r#"<mjml><mj-body><mj-navbar hamburger="hamburger">
+  <mj-navbar-link href="/gettings-started-onboard">Getting started</mj-navbar-link>
+</mj-navbar></mj-body></mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&opts).unwrap();
+        assert!(result.contains(r#"role="navigation""#));
+        assert!(result.contains(r#"aria-label="Main menu""#));
+        assert!(result.contains(r#"aria-label="Toggle the menu""#));
+    }
+
+    #[test]
+    fn should_reverse_links_in_rtl_column() {
+        let template = r#"<mjml><mj-body><mj-section><mj-column direction="rtl"><mj-navbar>
+  <mj-navbar-link href="/first">First</mj-navbar-link>
+  <mj-navbar-link href="/second">Second</mj-navbar-link>
+</mj-navbar></mj-column></mj-section></mj-body></mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        assert!(result.find("/second").unwrap() < result.find("/first").unwrap());
+    }
+
+    #[test]
+    fn should_render_link_hover_style() {
+        let template = r##"<mjml><mj-body><mj-navbar>
+  <mj-navbar-link href="/first" hover-color="#ff0000">First</mj-navbar-link>
+  <mj-navbar-link href="/second">Second</mj-navbar-link>
+</mj-navbar></mj-body></mjml>"##;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        assert!(result.contains(":focus-within {"));
+        assert!(result.contains("color: #ff0000 !important;"));
+        assert!(result.contains(r#"class="mj-link mj-link-hover-"#));
+        assert_eq!(result.matches("mj-link-hover-").count(), 3);
+    }
 }
@@ -1,5 +1,6 @@
 use super::{MjNavbarLink, NAME};
 use crate::helper::size::Pixel;
+use crate::mj_button::render_hover_style;
 use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
@@ -23,7 +24,7 @@ impl<'root> Renderer<'root, MjNavbarLink, MjNavbarLinkExtra<'root>> {
     {
         tag.add_style("display", "inline-block")
             .maybe_add_style("color", self.attribute("color"))
-            .maybe_add_style("font-family", self.attribute("font-family"))
+            .maybe_add_style("font-family", self.attribute_font_family("font-family"))
             .maybe_add_style("font-size", self.attribute("font-size"))
             .maybe_add_style("font-style", self.attribute("font-style"))
             .maybe_add_style("font-weight", self.attribute("font-weight"))
@@ -58,15 +59,39 @@ impl<'root> Renderer<'root, MjNavbarLink, MjNavbarLinkExtra<'root>> {
         })
     }
 
+    /// Class of the link, generated when it has a hover style.
+    fn hover_class(&self) -> Option<String> {
+        if self.attribute_exists("hover-background-color") || self.attribute_exists("hover-color") {
+            Some(format!(
+                "mj-link-hover-{}",
+                self.context.generator.next_id()
+            ))
+        } else {
+            None
+        }
+    }
+
     fn render_content(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let hover_class = self.hover_class();
+        if let Some(class) = hover_class.as_deref() {
+            cursor.header.add_style(render_hover_style(
+                class,
+                &[""],
+                self.attribute("hover-background-color"),
+                self.attribute("hover-color"),
+            ));
+        }
+
         let link = self
             .set_style_a(Tag::new("a"))
             .add_class("mj-link")
+            .maybe_add_class(hover_class)
             .maybe_add_class(self.attribute("css-class"))
             .maybe_add_attribute("href", self.get_link())
             .maybe_add_attribute("rel", self.attribute("rel"))
             .maybe_add_attribute("target", self.attribute("target"))
             .maybe_add_attribute("name", self.attribute("name"));
+        let link = self.passthrough_attributes(link);
 
         link.render_open(&mut cursor.buffer)?;
         for child in self.element.children.iter() {
@@ -107,6 +132,10 @@ impl<'root> Render<'root> for Renderer<'root, MjNavbarLink, MjNavbarLinkExtra<'r
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
     fn tag(&self) -> Option<&str> {
         Some(NAME)
     }
@@ -0,0 +1,20 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_not_mso::MjNotMso;
+
+    #[test]
+    fn serialize() {
+        let elt = MjNotMso::default();
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r#"{"type":"mj-not-mso"}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{"type":"mj-not-mso","children":[{"type":"mj-text","children":["Hello"]}]}"#;
+        let res: MjNotMso = serde_json::from_str(json).unwrap();
+        assert_eq!(res.children.len(), 1);
+    }
+}
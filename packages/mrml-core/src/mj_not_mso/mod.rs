@@ -0,0 +1,29 @@
+use std::marker::PhantomData;
+
+use crate::mj_body::MjBodyChild;
+use crate::prelude::hash::Map;
+use crate::prelude::{Component, StaticTag};
+
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "parse")]
+mod parse;
+#[cfg(feature = "print")]
+mod print;
+#[cfg(feature = "render")]
+mod render;
+
+pub const NAME: &str = "mj-not-mso";
+
+pub struct MjNotMsoTag;
+
+impl StaticTag for MjNotMsoTag {
+    fn static_tag() -> &'static str {
+        NAME
+    }
+}
+
+/// Content hidden from outlook, wrapped in a `<!--[if !mso]><!-->`
+/// conditional comment. It contains sections, in the body, or the content of
+/// a column.
+pub type MjNotMso = Component<PhantomData<MjNotMsoTag>, Map<String, String>, Vec<MjBodyChild>>;
@@ -0,0 +1,11 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_not_mso::MjNotMso;
+
+    crate::should_parse!(self_closing, MjNotMso, "<mj-not-mso />");
+    crate::should_parse!(
+        with_children,
+        MjNotMso,
+        "<mj-not-mso><mj-text>Hello</mj-text><mj-image src=\"a.png\" /></mj-not-mso>"
+    );
+}
@@ -0,0 +1,14 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::print::Printable;
+
+    #[test]
+    fn empty() {
+        let mut item = crate::mj_not_mso::MjNotMso::default();
+        item.children.push(crate::mj_text::MjText::default().into());
+        assert_eq!(
+            "<mj-not-mso><mj-text /></mj-not-mso>",
+            item.print_dense().unwrap()
+        );
+    }
+}
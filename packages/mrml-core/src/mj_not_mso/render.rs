@@ -0,0 +1,64 @@
+use super::{MjNotMso, NAME};
+use crate::helper::size::Pixel;
+use crate::mj_msooffice::render_conditional;
+use crate::prelude::render::*;
+
+impl<'root> Render<'root> for Renderer<'root, MjNotMso, ()> {
+    fn raw_attribute(&self, key: &str) -> Option<&'root str> {
+        self.element.attributes.get(key).map(|v| v.as_str())
+    }
+
+    fn tag(&self) -> Option<&str> {
+        Some(NAME)
+    }
+
+    fn set_container_width(&mut self, width: Option<Pixel>) {
+        self.container_width = width;
+    }
+
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.context
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        render_conditional(
+            self.context,
+            &self.element.children,
+            self.container_width,
+            false,
+            cursor,
+        )
+    }
+}
+
+impl<'render, 'root: 'render> Renderable<'render, 'root> for MjNotMso {
+    fn is_raw(&'root self) -> bool {
+        true
+    }
+
+    fn renderer(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        Box::new(Renderer::new(context, self, ()))
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use crate::mjml::Mjml;
+
+    #[test]
+    fn should_hide_content_from_outlook() {
+        let template = r#"<mjml><mj-body><mj-section><mj-column>
+  <mj-not-mso><mj-button href="https://example.com">Other clients</mj-button></mj-not-mso>
+</mj-column></mj-section></mj-body></mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        let start = html.find("<!--[if !mso]><!--><tr><td").unwrap();
+        let end = start + html[start..].find("<!--<![endif]-->").unwrap();
+        let block = &html[start..end];
+        assert!(block.contains("https://example.com"));
+        assert!(!block[4..].contains("<!--[if"));
+    }
+}
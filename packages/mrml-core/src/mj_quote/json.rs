@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_body::MjBodyChild;
+    use crate::mj_quote::MjQuote;
+    use crate::text::Text;
+
+    #[test]
+    fn serialize() {
+        let mut elt = MjQuote::default();
+        elt.attributes.insert("cite".into(), "Ada Lovelace".into());
+        elt.children.push(MjBodyChild::Text(Text::from("Great!")));
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r#"{"type":"mj-quote","attributes":{"cite":"Ada Lovelace"},"children":["Great!"]}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let json = r##"{"type":"mj-quote","attributes":{"accent-color":"#27ae60"},"children":[{"type":"comment","children":"testimonial"},"Great!"]}"##;
+        let res: MjQuote = serde_json::from_str(json).unwrap();
+        assert_eq!(res.attributes.len(), 1);
+        assert_eq!(res.children.len(), 2);
+    }
+}
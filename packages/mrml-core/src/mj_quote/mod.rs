@@ -0,0 +1,43 @@
+//! Module containing the `mj-quote` element, rendering a quotation next to
+//! an accent bar, followed by its author, like a testimonial.
+//!
+//! ```rust
+//! let template = r##"<mjml><mj-body><mj-section><mj-column>
+//!   <mj-quote cite="Ada Lovelace, Analytical Engines" accent-color="#2e86de">
+//!     The best newsletter I've ever read.
+//!   </mj-quote>
+//! </mj-column></mj-section></mj-body></mjml>"##;
+//! let root = mrml::parse(template).expect("parse template");
+//! let opts = mrml::prelude::render::Options::default();
+//! match root.element.render(&opts) {
+//!     Ok(content) => println!("{content}"),
+//!     Err(_) => println!("couldn't render mjml template"),
+//! };
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::mj_body::MjBodyChild;
+use crate::prelude::hash::Map;
+use crate::prelude::{Component, StaticTag};
+
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "parse")]
+mod parse;
+#[cfg(feature = "print")]
+mod print;
+#[cfg(feature = "render")]
+mod render;
+
+pub const NAME: &str = "mj-quote";
+
+pub struct MjQuoteTag;
+
+impl StaticTag for MjQuoteTag {
+    fn static_tag() -> &'static str {
+        NAME
+    }
+}
+
+pub type MjQuote = Component<PhantomData<MjQuoteTag>, Map<String, String>, Vec<MjBodyChild>>;
@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_quote::MjQuote;
+
+    crate::should_parse!(
+        success,
+        MjQuote,
+        r#"<mj-quote cite="Ada Lovelace">
+    <!-- testimonial -->
+    The <b>best</b> newsletter
+</mj-quote>"#
+    );
+
+    crate::should_async_parse!(
+        async_success,
+        MjQuote,
+        r#"<mj-quote cite="Ada Lovelace">
+    <!-- testimonial -->
+    The <b>best</b> newsletter
+</mj-quote>"#
+    );
+}
@@ -0,0 +1,18 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::print::Printable;
+
+    #[test]
+    fn empty() {
+        let mut item = crate::mj_quote::MjQuote::default();
+        item.attributes.insert("cite".to_string(), "Ada".into());
+        item.children
+            .push(crate::mj_body::MjBodyChild::Text(crate::text::Text::from(
+                "Great!".to_string(),
+            )));
+        assert_eq!(
+            "<mj-quote cite=\"Ada\">Great!</mj-quote>",
+            item.print_dense().unwrap()
+        );
+    }
+}
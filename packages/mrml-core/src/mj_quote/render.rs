@@ -0,0 +1,161 @@
+use super::{MjQuote, NAME};
+use crate::prelude::hash::Map;
+use crate::prelude::render::*;
+
+impl<'root> Renderer<'root, MjQuote, ()> {
+    fn render_children(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        for child in self.element.children.iter() {
+            let renderer = child.renderer(self.context());
+            renderer.render(cursor)?;
+        }
+        Ok(())
+    }
+
+    /// Cell filled with the accent color, its content being hidden so that
+    /// it keeps its width in every client.
+    fn set_style_accent<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 'a,
+        'a: 't,
+    {
+        tag.maybe_add_style("background", self.attribute("accent-color"))
+            .add_style("font-size", "0")
+            .add_style("line-height", "0")
+            .maybe_add_style("width", self.attribute("accent-width"))
+    }
+
+    fn set_style_content<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 'a,
+        'a: 't,
+    {
+        tag.maybe_add_style("background", self.attribute("background-color"))
+            .maybe_add_style("padding", self.attribute("inner-padding"))
+    }
+
+    fn set_style_text<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 'a,
+        'a: 't,
+    {
+        tag.maybe_add_style("color", self.attribute("color"))
+            .maybe_add_style("font-family", self.attribute_font_family("font-family"))
+            .maybe_add_style("font-size", self.attribute("font-size"))
+            .maybe_add_style("font-style", self.attribute("font-style"))
+            .maybe_add_style("line-height", self.attribute("line-height"))
+            .maybe_add_style("text-align", self.attribute("align"))
+    }
+
+    fn set_style_cite<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 'a,
+        'a: 't,
+    {
+        tag.maybe_add_style("color", self.attribute("cite-color"))
+            .maybe_add_style("font-family", self.attribute_font_family("font-family"))
+            .maybe_add_style("font-size", self.attribute("cite-font-size"))
+            .add_style("font-style", "normal")
+            .maybe_add_style("line-height", self.attribute("line-height"))
+            .maybe_add_style("padding-top", self.attribute("cite-spacing"))
+            .maybe_add_style("text-align", self.attribute("align"))
+    }
+}
+
+impl<'root> Render<'root> for Renderer<'root, MjQuote, ()> {
+    fn default_attribute(&self, key: &str) -> Option<&'static str> {
+        match key {
+            "accent-color" => Some("#414141"),
+            "accent-width" => Some("4px"),
+            "align" => Some("left"),
+            "cite-color" => Some("#777777"),
+            "cite-font-size" => Some("13px"),
+            "cite-spacing" => Some("8px"),
+            "color" => Some("#000000"),
+            "font-family" => Some("Ubuntu, Helvetica, Arial, sans-serif"),
+            "font-size" => Some("16px"),
+            "font-style" => Some("italic"),
+            "inner-padding" => Some("10px 20px"),
+            "line-height" => Some("1.5"),
+            "padding" => Some("10px 25px"),
+            _ => None,
+        }
+    }
+
+    fn raw_attribute(&self, key: &str) -> Option<&'root str> {
+        self.element.attributes.get(key).map(|v| v.as_str())
+    }
+
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
+    fn tag(&self) -> Option<&str> {
+        Some(NAME)
+    }
+
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.context
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        cursor
+            .header
+            .maybe_add_font_families(self.attribute("font-family"));
+
+        let table = Tag::table_presentation()
+            .add_attribute("width", "100%")
+            .add_style("border-collapse", "collapse");
+        let tbody = Tag::tbody();
+        let tr = Tag::tr();
+        let accent = self
+            .set_style_accent(Tag::td())
+            .maybe_add_attribute("bgcolor", self.attribute("accent-color"))
+            .maybe_add_attribute(
+                "width",
+                self.attribute_as_pixel("accent-width")
+                    .map(|width| width.value().to_string()),
+            );
+        let content = self
+            .set_style_content(Tag::td())
+            .maybe_add_attribute("bgcolor", self.attribute("background-color"));
+        let blockquote = self.passthrough_attributes(
+            self.set_style_text(Tag::new("blockquote"))
+                .add_style("margin", "0"),
+        );
+
+        table.render_open(&mut cursor.buffer)?;
+        tbody.render_open(&mut cursor.buffer)?;
+        tr.render_open(&mut cursor.buffer)?;
+        accent.render_text(&mut cursor.buffer, "&nbsp;")?;
+        content.render_open(&mut cursor.buffer)?;
+        blockquote.render_open(&mut cursor.buffer)?;
+        self.render_children(cursor)?;
+        blockquote.render_close(&mut cursor.buffer);
+        if let Some(cite) = self.attribute("cite") {
+            self.set_style_cite(Tag::div())
+                .add_class("mj-quote-cite")
+                .render_text(&mut cursor.buffer, &format!("&mdash;&nbsp;{cite}"))?;
+        }
+        content.render_close(&mut cursor.buffer);
+        tr.render_close(&mut cursor.buffer);
+        tbody.render_close(&mut cursor.buffer);
+        table.render_close(&mut cursor.buffer);
+
+        Ok(())
+    }
+}
+
+impl<'render, 'root: 'render> Renderable<'render, 'root> for MjQuote {
+    fn renderer(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        Box::new(Renderer::new(context, self, ()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::should_render!(basic, "mj-quote");
+    crate::should_render!(without_cite, "mj-quote-without-cite");
+}
@@ -1,3 +1,19 @@
+use super::MjRawAttributes;
+use crate::prelude::json::JsonAttributes;
+
+impl JsonAttributes for MjRawAttributes {
+    fn has_attributes(&self) -> bool {
+        self.position.is_some()
+    }
+
+    fn try_from_serde<Err: serde::de::Error>(this: Option<Self>) -> Result<Self, Err>
+    where
+        Self: Sized,
+    {
+        Ok(this.unwrap_or_default())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::mj_raw::{MjRaw, MjRawChild};
@@ -22,4 +38,13 @@ mod tests {
         let next = serde_json::to_string(&res).unwrap();
         assert_eq!(next, json);
     }
+
+    #[test]
+    fn should_keep_the_position() {
+        let json =
+            r#"{"type":"mj-raw","attributes":{"position":"head-start"},"children":["Hello"]}"#;
+        let res: MjRaw = serde_json::from_str(json).unwrap();
+        assert_eq!(res.position(), crate::mj_raw::MjRawPosition::HeadStart);
+        assert_eq!(serde_json::to_string(&res).unwrap(), json);
+    }
 }
@@ -16,6 +16,40 @@ use crate::prelude::{Component, StaticTag};
 
 pub const NAME: &str = "mj-raw";
 
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+pub struct MjRawAttributes {
+    /// Where the content is written in the generated `head`, see
+    /// [`MjRawPosition`].
+    #[cfg_attr(feature = "json", serde(skip_serializing_if = "Option::is_none"))]
+    pub position: Option<String>,
+}
+
+/// Position of the content of a `mj-raw` element of the `mj-head` in the
+/// generated `head`, to place meta tags or directives of the emailing
+/// services precisely.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MjRawPosition {
+    /// Right after the opening `head` tag, before the `title`.
+    HeadStart,
+    /// After the `meta` tags, before the styles.
+    BeforeStyles,
+    /// At the end of the `head`.
+    #[default]
+    HeadEnd,
+}
+
+impl MjRawPosition {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "head-start" => Some(Self::HeadStart),
+            "before-styles" => Some(Self::BeforeStyles),
+            "head-end" => Some(Self::HeadEnd),
+            _ => None,
+        }
+    }
+}
+
 pub struct MjRawTag;
 
 impl StaticTag for MjRawTag {
@@ -24,4 +58,16 @@ impl StaticTag for MjRawTag {
     }
 }
 
-pub type MjRaw = Component<PhantomData<MjRawTag>, (), Vec<MjRawChild>>;
+pub type MjRaw = Component<PhantomData<MjRawTag>, MjRawAttributes, Vec<MjRawChild>>;
+
+impl MjRaw {
+    /// Position in the generated `head`, defaulting to its end when missing
+    /// or unknown.
+    pub fn position(&self) -> MjRawPosition {
+        self.attributes
+            .position
+            .as_deref()
+            .and_then(MjRawPosition::parse)
+            .unwrap_or_default()
+    }
+}
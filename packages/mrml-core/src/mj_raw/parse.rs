@@ -1,6 +1,6 @@
 use xmlparser::StrSpan;
 
-use super::MjRawChild;
+use super::{MjRawAttributes, MjRawChild};
 use crate::comment::Comment;
 use crate::node::Node;
 use crate::prelude::is_void_element;
@@ -8,9 +8,44 @@ use crate::prelude::is_void_element;
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
     Error, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren, ParseElement,
+    WarningKind,
 };
 use crate::text::Text;
 
+#[inline]
+fn parse_attributes(cursor: &mut MrmlCursor<'_>) -> Result<MjRawAttributes, Error> {
+    let mut result = MjRawAttributes::default();
+    while let Some(attr) = cursor.next_attribute()? {
+        if attr.local.as_str() == "position" {
+            result.position = Some(attr.value.to_string());
+        } else {
+            cursor.add_warning(WarningKind::UnexpectedAttribute, attr.span);
+        }
+    }
+    Ok(result)
+}
+
+impl<'opts> ParseAttributes<MjRawAttributes> for MrmlParser<'opts> {
+    fn parse_attributes(
+        &self,
+        cursor: &mut MrmlCursor<'_>,
+        _tag: &StrSpan<'_>,
+    ) -> Result<MjRawAttributes, Error> {
+        parse_attributes(cursor)
+    }
+}
+
+#[cfg(feature = "async")]
+impl ParseAttributes<MjRawAttributes> for AsyncMrmlParser {
+    fn parse_attributes(
+        &self,
+        cursor: &mut MrmlCursor<'_>,
+        _tag: &StrSpan<'_>,
+    ) -> Result<MjRawAttributes, Error> {
+        parse_attributes(cursor)
+    }
+}
+
 impl<'opts> ParseElement<Node<MjRawChild>> for MrmlParser<'opts> {
     fn parse<'a>(
         &self,
@@ -20,6 +55,7 @@ impl<'opts> ParseElement<Node<MjRawChild>> for MrmlParser<'opts> {
         let attributes = self.parse_attributes(cursor, &tag)?;
         let ending = cursor.assert_element_end()?;
         if ending.empty || is_void_element(tag.as_str()) {
+            cursor.end_void_element(tag.as_str(), &ending)?;
             return Ok(Node {
                 tag: tag.to_string(),
                 attributes,
@@ -50,6 +86,7 @@ impl AsyncParseElement<Node<MjRawChild>> for AsyncMrmlParser {
         let attributes = self.parse_attributes(cursor, &tag)?;
         let ending = cursor.assert_element_end()?;
         if ending.empty || is_void_element(tag.as_str()) {
+            cursor.end_void_element(tag.as_str(), &ending)?;
             return Ok(Node {
                 tag: tag.to_string(),
                 attributes,
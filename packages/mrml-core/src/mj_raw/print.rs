@@ -1,3 +1,14 @@
+use crate::prelude::print::PrintableAttributes;
+
+impl PrintableAttributes for super::MjRawAttributes {
+    fn print<P: crate::prelude::print::Printer>(&self, printer: &mut P) -> std::fmt::Result {
+        if let Some(ref position) = self.position {
+            printer.push_attribute("position", position.as_str())?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::print::Printable;
@@ -3,14 +3,26 @@ use std::convert::TryFrom;
 
 use super::{MjSection, NAME};
 use crate::helper::size::{Percent, Pixel};
+use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
 fn is_horizontal_position(value: &str) -> bool {
-    value == "left" || value == "right" || value == "center"
+    value == "left" || value == "right"
 }
 
 fn is_vertical_position(value: &str) -> bool {
-    value == "top" || value == "bottom" || value == "center"
+    value == "top" || value == "bottom"
+}
+
+/// Coordinate of the VML fill, from a percentage position. Like mjml, only
+/// the integer part of the percentage is used.
+fn vfill_coordinate(position: Percent, repeat: bool) -> f32 {
+    let value = position.value().trunc();
+    if repeat {
+        value * 0.01
+    } else {
+        (value - 50.0) * 0.01
+    }
 }
 
 pub trait WithMjSectionBackground<'root>: Render<'root> {
@@ -24,21 +36,19 @@ pub trait WithMjSectionBackground<'root>: Render<'root> {
     {
         // can be unwraped because has default value
         let position = self.attribute("background-position").unwrap();
-        let mut positions = position.split_whitespace();
-        if let Some(first) = positions.next() {
-            if let Some(second) = positions.next() {
-                if is_vertical_position(first) && is_horizontal_position(second) {
-                    (second, first)
-                } else {
-                    (first, second)
-                }
-            } else if is_vertical_position(first) {
-                ("center", first)
-            } else {
-                (first, "center")
+        let positions = position.split_whitespace().collect::<Vec<_>>();
+        match positions.as_slice() {
+            [first] if is_vertical_position(first) => ("center", *first),
+            [first] => (*first, "center"),
+            [first, second]
+                if is_vertical_position(first)
+                    || (*first == "center" && is_horizontal_position(second)) =>
+            {
+                (*second, *first)
             }
-        } else {
-            ("center", "top")
+            [first, second] => (*first, *second),
+            // more than 2 values isn't supported, like in mjml
+            _ => ("center", "top"),
         }
     }
 
@@ -131,27 +141,15 @@ pub trait WithMjSectionBackground<'root>: Render<'root> {
                 }
             }
         };
-        let position_x = if let Ok(position) = Percent::try_from(bg_position_x) {
-            if bg_repeat {
-                position.value() * 0.01
-            } else {
-                (position.value() - 50.0) * 0.01
-            }
-        } else if bg_repeat {
-            0.5
-        } else {
-            0.0
+        let position_x = match Percent::try_from(bg_position_x) {
+            Ok(position) => vfill_coordinate(position, bg_repeat),
+            Err(_) if bg_repeat => 0.5,
+            Err(_) => 0.0,
         };
-        let position_y = if let Ok(position) = Percent::try_from(bg_position_y) {
-            if bg_repeat {
-                position.value() * 0.01
-            } else {
-                (position.value() - 50.0) * 0.01
-            }
-        } else if bg_repeat {
-            0.5
-        } else {
-            0.0
+        let position_y = match Percent::try_from(bg_position_y) {
+            Ok(position) => vfill_coordinate(position, bg_repeat),
+            Err(_) if bg_repeat => 0.0,
+            Err(_) => -0.5,
         };
         (
             format!("{position_x}, {position_y}").into(),
@@ -211,14 +209,33 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
     fn children(&self) -> &Vec<crate::mj_body::MjBodyChild>;
 
     fn is_full_width(&self) -> bool {
-        self.attribute_exists("full-width")
+        self.attribute_equals("full-width", "full-width")
+    }
+
+    /// With `full-width-background="false"`, a full width element keeps its
+    /// background in the boxed content instead of spanning the whole width.
+    fn has_full_width_background(&self) -> bool {
+        self.is_full_width() && !self.attribute_equals("full-width-background", "false")
+    }
+
+    /// Hides the content overflowing the rounded corners, like the
+    /// backgrounds of the nested sections.
+    fn clips_children(&self) -> bool {
+        false
+    }
+
+    /// Outlook doesn't display a VML shape nested in the one of the wrapper,
+    /// so the background image of a section in a wrapper with a background
+    /// image falls back to the `background` attribute of its table.
+    fn has_vml_background(&self) -> bool {
+        self.has_background() && self.raw_extra_attribute("wrapper-background").is_none()
     }
 
     fn render_with_background<F>(&self, cursor: &mut RenderCursor, content: F) -> Result<(), Error>
     where
         F: Fn(&mut RenderCursor) -> Result<(), Error>,
     {
-        let full_width = self.is_full_width();
+        let full_width = self.has_full_width_background();
         let vrect = Tag::new("v:rect")
             .maybe_add_attribute(
                 "mso-width-percent",
@@ -257,13 +274,21 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
         'root: 'a,
         'a: 't,
     {
-        let base = if self.is_full_width() {
+        let base = if self.has_full_width_background() {
             tag
         } else {
             self.set_background_style(tag)
         };
         base.add_style("margin", "0px auto")
             .maybe_add_style("border-radius", self.attribute("border-radius"))
+            .maybe_add_style(
+                "overflow",
+                if self.clips_children() {
+                    Some("hidden")
+                } else {
+                    None
+                },
+            )
             .maybe_add_style(
                 "max-width",
                 self.container_width().as_ref().map(|item| item.to_string()),
@@ -318,6 +343,7 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
     fn render_wrapped_children(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         let siblings = self.get_siblings();
         let raw_siblings = self.get_raw_siblings();
+        let is_rtl = self.attribute_equals("direction", "rtl");
         let tr = Tag::tr();
 
         tr.render_open(&mut cursor.buffer)?;
@@ -326,6 +352,9 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
             renderer.set_siblings(siblings);
             renderer.set_raw_siblings(raw_siblings);
             renderer.set_container_width(*self.container_width());
+            if is_rtl {
+                renderer.add_extra_attribute("section-direction", "rtl");
+            }
             if child.is_raw() {
                 cursor.buffer.end_conditional_tag();
                 renderer.render(cursor)?;
@@ -356,7 +385,7 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
         'root: 'a,
         'a: 't,
     {
-        let base = if self.is_full_width() {
+        let base = if self.has_full_width_background() {
             tag
         } else {
             self.set_background_style(tag)
@@ -387,6 +416,7 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
 
     fn render_section(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         let is_full_width = self.is_full_width();
+        let full_width_background = self.has_full_width_background();
         let div = self
             .set_style_section_div(Tag::div())
             .maybe_add_class(if is_full_width {
@@ -394,13 +424,17 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
             } else {
                 self.attribute("css-class")
             });
+        let div = match is_full_width {
+            true => div,
+            false => self.passthrough_attributes(div),
+        };
         let inner_div = self.set_style_section_inner_div(Tag::div());
         let table = self.set_style_section_table(
             Tag::table_presentation()
                 .add_attribute("align", "center")
                 .maybe_add_attribute(
                     "background",
-                    if is_full_width {
+                    if full_width_background {
                         None
                     } else {
                         self.attribute("background-url")
@@ -443,7 +477,7 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
         'root: 'a,
         'a: 't,
     {
-        let base = if self.is_full_width() {
+        let base = if self.has_full_width_background() {
             self.set_background_style(tag)
         } else {
             tag
@@ -456,10 +490,19 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
     where
         'root: 'a,
     {
-        self.set_style_table_full_width(Tag::table_presentation())
+        let table = self
+            .set_style_table_full_width(Tag::table_presentation())
             .add_attribute("align", "center")
             .maybe_add_class(self.attribute("css-class"))
-            .maybe_add_attribute("background", self.attribute("background-url"))
+            .maybe_add_attribute(
+                "background",
+                if self.has_full_width_background() {
+                    self.attribute("background-url")
+                } else {
+                    None
+                },
+            );
+        self.passthrough_attributes(table)
     }
 
     fn render_full_width(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
@@ -473,7 +516,9 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
         tr.render_open(&mut cursor.buffer)?;
         td.render_open(&mut cursor.buffer)?;
         //
-        if self.has_background() {
+        if !self.has_full_width_background() {
+            self.render_simple(cursor)?;
+        } else if self.has_vml_background() {
             self.render_with_background(cursor, |cursor| {
                 self.render_wrap(cursor, |cursor| {
                     cursor.buffer.end_conditional_tag();
@@ -501,7 +546,7 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
 
     fn render_simple(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         self.render_wrap(cursor, |cursor| {
-            if self.has_background() {
+            if self.has_vml_background() {
                 self.render_with_background(cursor, |cursor| self.render_section(cursor))?;
             } else {
                 cursor.buffer.end_conditional_tag();
@@ -513,8 +558,12 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
     }
 }
 
-impl<'root> WithMjSectionBackground<'root> for Renderer<'root, MjSection, ()> {}
-impl<'root> SectionLikeRender<'root> for Renderer<'root, MjSection, ()> {
+struct MjSectionExtra<'a> {
+    attributes: Map<&'a str, &'a str>,
+}
+
+impl<'root> WithMjSectionBackground<'root> for Renderer<'root, MjSection, MjSectionExtra<'root>> {}
+impl<'root> SectionLikeRender<'root> for Renderer<'root, MjSection, MjSectionExtra<'root>> {
     fn children(&self) -> &Vec<crate::mj_body::MjBodyChild> {
         &self.element.children
     }
@@ -524,7 +573,7 @@ impl<'root> SectionLikeRender<'root> for Renderer<'root, MjSection, ()> {
     }
 }
 
-impl<'root> Render<'root> for Renderer<'root, MjSection, ()> {
+impl<'root> Render<'root> for Renderer<'root, MjSection, MjSectionExtra<'root>> {
     fn default_attribute(&self, name: &str) -> Option<&'static str> {
         match name {
             "background-position" => Some("top center"),
@@ -542,6 +591,18 @@ impl<'root> Render<'root> for Renderer<'root, MjSection, ()> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
+    fn raw_extra_attribute(&self, key: &str) -> Option<&'root str> {
+        self.extra.attributes.get(key).copied()
+    }
+
+    fn add_extra_attribute(&mut self, key: &'root str, value: &'root str) {
+        self.extra.attributes.insert(key, value);
+    }
+
     fn tag(&self) -> Option<&str> {
         Some(NAME)
     }
@@ -555,11 +616,24 @@ impl<'root> Render<'root> for Renderer<'root, MjSection, ()> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
-        if self.is_full_width() {
-            self.render_full_width(cursor)
-        } else {
-            self.render_simple(cursor)
+        let render = |cursor: &mut RenderCursor| {
+            if self.is_full_width() {
+                self.render_full_width(cursor)
+            } else {
+                self.render_simple(cursor)
+            }
+        };
+        // the html depends on the wrapper, that isn't part of the cache key
+        if self.raw_extra_attribute("wrapper-background").is_some() {
+            return render(cursor);
         }
+        render_cached(
+            self.context,
+            self.element,
+            self.container_width.as_ref(),
+            cursor,
+            render,
+        )
     }
 }
 
@@ -568,7 +642,13 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjSection {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, ()))
+        Box::new(Renderer::new(
+            context,
+            self,
+            MjSectionExtra {
+                attributes: Map::new(),
+            },
+        ))
     }
 }
 
@@ -589,4 +669,48 @@ mod tests {
     crate::should_render!(full_width, "mj-section-full-width");
     crate::should_render!(padding, "mj-section-padding");
     crate::should_render!(text_align, "mj-section-text-align");
+
+    #[cfg(feature = "parse")]
+    fn render_section(attributes: &str) -> String {
+        let template = format!(
+            "<mjml><mj-body><mj-section background-url=\"https://example.com/bg.png\" {attributes}></mj-section></mj-body></mjml>"
+        );
+        crate::parse(template)
+            .unwrap()
+            .element
+            .render(&Default::default())
+            .unwrap()
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_parse_background_position_like_mjml() {
+        let html = render_section(r#"background-position="top 20%""#);
+        assert!(html.contains("background-position:20% top;"));
+        let html = render_section(r#"background-position="center left""#);
+        assert!(html.contains("background-position:left center;"));
+        let html = render_section(r#"background-position="bottom""#);
+        assert!(html.contains("background-position:center bottom;"));
+        let html = render_section(r#"background-position="left top center""#);
+        assert!(html.contains("background-position:center top;"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_vml_background_without_repeat() {
+        let html = render_section(
+            r#"background-position-x="left" background-position-y="100%" background-repeat="no-repeat" background-size="cover""#,
+        );
+        assert!(html.contains("background-position:left 100%;"));
+        assert!(html.contains("background-size:cover;"));
+        assert!(html.contains(r#"<v:fill position="-0.5, 0.5" origin="-0.5, 0.5" src="https://example.com/bg.png" size="1,1" type="frame" aspect="atleast" />"#));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_vml_background_with_repeat() {
+        let html = render_section(r#"background-position="center 25.9%" background-size="200px""#);
+        assert!(html.contains("background-repeat:repeat;"));
+        assert!(html.contains(r#"<v:fill position="0.5, 0.25" origin="0.5, 0.25" src="https://example.com/bg.png" size="200px" type="tile" aspect="atmost" />"#));
+    }
 }
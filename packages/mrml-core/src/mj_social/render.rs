@@ -1,5 +1,6 @@
 use super::{MjSocial, MjSocialChild, NAME};
 use crate::helper::size::{Pixel, Size};
+use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
 impl<'render, 'root: 'render> Renderable<'render, 'root> for MjSocialChild {
@@ -45,7 +46,12 @@ const EXTRA_CHILD_KEY: [&str; 13] = [
     "text-decoration",
 ];
 
-impl<'root> Renderer<'root, MjSocial, ()> {
+#[derive(Default)]
+struct MjSocialExtra<'a> {
+    attributes: Map<&'a str, &'a str>,
+}
+
+impl<'root> Renderer<'root, MjSocial, MjSocialExtra<'root>> {
     fn set_style_table_vertical<'t>(&self, tag: Tag<'t>) -> Tag<'t> {
         tag.add_style("margin", "0px")
     }
@@ -56,14 +62,24 @@ impl<'root> Renderer<'root, MjSocial, ()> {
             .unwrap_or(true)
     }
 
+    /// With a `rtl` direction, the elements are displayed from right to left
+    /// with their text before their icon.
+    fn is_rtl(&self) -> bool {
+        self.attribute_equals("direction", "rtl")
+    }
+
     fn build_child_attributes(&self) -> Vec<(&str, &str)> {
-        EXTRA_CONTAINER_KEY
+        let mut result = EXTRA_CONTAINER_KEY
             .iter()
             .zip(EXTRA_CHILD_KEY.iter())
             .filter_map(|(con_key, child_key)| {
                 self.attribute(con_key).map(|value| (*child_key, value))
             })
-            .collect::<Vec<_>>()
+            .collect::<Vec<_>>();
+        if self.is_rtl() {
+            result.push(("direction", "rtl"));
+        }
+        result
     }
 
     fn render_horizontal(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
@@ -82,7 +98,11 @@ impl<'root> Renderer<'root, MjSocial, ()> {
         tr.render_open(&mut cursor.buffer)?;
         cursor.buffer.end_conditional_tag();
 
-        for (index, child) in self.element.children.iter().enumerate() {
+        let mut children = self.element.children.iter().enumerate().collect::<Vec<_>>();
+        if self.is_rtl() {
+            children.reverse();
+        }
+        for (index, child) in children {
             cursor.buffer.start_conditional_tag();
             td.render_open(&mut cursor.buffer)?;
             cursor.buffer.end_conditional_tag();
@@ -110,6 +130,7 @@ impl<'root> Renderer<'root, MjSocial, ()> {
 
     fn render_vertical(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         let table = self.set_style_table_vertical(Tag::table_presentation());
+        let table = self.passthrough_attributes(table);
         let tbody = Tag::tbody();
         let child_attributes = self.build_child_attributes();
 
@@ -130,7 +151,7 @@ impl<'root> Renderer<'root, MjSocial, ()> {
     }
 }
 
-impl<'root> Render<'root> for Renderer<'root, MjSocial, ()> {
+impl<'root> Render<'root> for Renderer<'root, MjSocial, MjSocialExtra<'root>> {
     fn default_attribute(&self, name: &str) -> Option<&'static str> {
         match name {
             "align" => Some("center"),
@@ -151,6 +172,18 @@ impl<'root> Render<'root> for Renderer<'root, MjSocial, ()> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
+    fn add_extra_attribute(&mut self, key: &'root str, value: &'root str) {
+        self.extra.attributes.insert(key, value);
+    }
+
+    fn raw_extra_attribute(&self, key: &str) -> Option<&'root str> {
+        self.extra.attributes.get(key).copied()
+    }
+
     fn tag(&self) -> Option<&str> {
         Some(NAME)
     }
@@ -192,7 +225,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjSocial {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, ()))
+        Box::new(Renderer::new(context, self, MjSocialExtra::default()))
     }
 }
 
@@ -214,4 +247,35 @@ mod tests {
     crate::should_render!(mode, "mj-social-mode");
     crate::should_render!(padding, "mj-social-padding");
     crate::should_render!(text, "mj-social-text");
+
+    fn render(template: &str) -> String {
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        root.element.render(&Default::default()).unwrap()
+    }
+
+    #[test]
+    fn should_mirror_in_rtl_column() {
+        let social = r#"<mj-social>
+  <mj-social-element name="facebook" href="https://facebook.com">Facebook</mj-social-element>
+  <mj-social-element name="twitter" href="https://twitter.com">Twitter</mj-social-element>
+</mj-social>"#;
+        let ltr = render(&format!(
+            "<mjml><mj-body><mj-section><mj-column>{social}</mj-column></mj-section></mj-body></mjml>"
+        ));
+        assert!(ltr.find("facebook.com").unwrap() < ltr.find("twitter.com").unwrap());
+        assert!(ltr.find("facebook.png").unwrap() < ltr.find(">Facebook<").unwrap());
+        for template in [
+            format!("<mjml><mj-body><mj-section><mj-column direction=\"rtl\">{social}</mj-column></mj-section></mj-body></mjml>"),
+            format!("<mjml><mj-body><mj-section direction=\"rtl\"><mj-column>{social}</mj-column></mj-section></mj-body></mjml>"),
+        ] {
+            let rtl = render(&template);
+            assert!(rtl.find("twitter.com").unwrap() < rtl.find("facebook.com").unwrap());
+            assert!(rtl.find(">Facebook<").unwrap() < rtl.find("facebook.png").unwrap());
+            assert!(rtl.contains("padding:4px 0 4px 4px;"));
+        }
+        let explicit = render(&format!(
+            "<mjml><mj-body><mj-section direction=\"rtl\"><mj-column direction=\"ltr\">{social}</mj-column></mj-section></mj-body></mjml>"
+        ));
+        assert!(explicit.find("facebook.com").unwrap() < explicit.find("twitter.com").unwrap());
+    }
 }
@@ -1,7 +1,7 @@
 #[cfg(feature = "json")]
 mod json;
 #[cfg(feature = "render")]
-mod network;
+pub(crate) mod network;
 #[cfg(feature = "parse")]
 mod parse;
 #[cfg(feature = "print")]
@@ -11,6 +11,9 @@ mod render;
 
 use std::marker::PhantomData;
 
+#[cfg(feature = "render")]
+pub(crate) use render::DEFAULT_ICON_ORIGIN;
+
 use crate::mj_raw::MjRawChild;
 use crate::prelude::hash::Map;
 use crate::prelude::{Component, StaticTag};
@@ -6,7 +6,8 @@ use crate::helper::size::{Pixel, Size};
 use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
-const DEFAULT_ICON_ORIGIN: &str = "https://www.mailjet.com/images/theme/v1/icons/ico-social/";
+pub(crate) const DEFAULT_ICON_ORIGIN: &str =
+    "https://www.mailjet.com/images/theme/v1/icons/ico-social/";
 
 #[derive(Default)]
 struct MjSocialElementExtra<'a> {
@@ -110,6 +111,10 @@ impl<'root> Renderer<'root, MjSocialElement, MjSocialElementExtra<'root>> {
             .maybe_add_style("vertical-align", self.attribute("vertical-align"))
     }
 
+    fn is_rtl(&self) -> bool {
+        self.attribute_equals("direction", "rtl")
+    }
+
     fn set_style_td_text<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
     where
         'root: 'a,
@@ -128,7 +133,7 @@ impl<'root> Renderer<'root, MjSocialElement, MjSocialElementExtra<'root>> {
             .maybe_add_style("font-size", self.attribute("font-size"))
             .maybe_add_style("font-weight", self.attribute("font-weight"))
             .maybe_add_style("font-style", self.attribute("font-style"))
-            .maybe_add_style("font-family", self.attribute("font-family"))
+            .maybe_add_style("font-family", self.attribute_font_family("font-family"))
             .maybe_add_style("line-height", self.attribute("line-height"))
             .maybe_add_style("text-decoration", self.attribute("text-decoration"))
     }
@@ -147,6 +152,15 @@ impl<'root> Renderer<'root, MjSocialElement, MjSocialElementExtra<'root>> {
         })
     }
 
+    /// Name of the network, used to label the icon when it has no text.
+    fn get_aria_label(&self) -> Option<&str> {
+        if !self.context.options.aria.social_labels || !self.element.children.is_empty() {
+            return None;
+        }
+        self.attribute("name")
+            .map(|name| name.trim_end_matches("-noshare"))
+    }
+
     fn render_icon(
         &self,
         href: &Option<Cow<'root, str>>,
@@ -159,7 +173,8 @@ impl<'root> Renderer<'root, MjSocialElement, MjSocialElementExtra<'root>> {
         let a = Tag::new("a")
             .maybe_add_attribute("href", href.clone())
             .maybe_add_attribute("rel", self.attribute("rel"))
-            .maybe_add_attribute("target", self.attribute("target"));
+            .maybe_add_attribute("target", self.attribute("target"))
+            .maybe_add_attribute("aria-label", self.get_aria_label());
         let img = self
             .set_style_img(Tag::new("img"))
             .maybe_add_attribute("alt", self.attribute("alt"))
@@ -225,6 +240,7 @@ impl<'root> Renderer<'root, MjSocialElement, MjSocialElementExtra<'root>> {
 impl<'root> Render<'root> for Renderer<'root, MjSocialElement, MjSocialElementExtra<'root>> {
     fn default_attribute(&self, key: &str) -> Option<&'static str> {
         match key {
+            "align" if self.is_rtl() => Some("right"),
             "align" => Some("left"),
             "color" => Some("#000"),
             "border-radius" => Some("3px"),
@@ -234,6 +250,7 @@ impl<'root> Render<'root> for Renderer<'root, MjSocialElement, MjSocialElementEx
             "padding" => Some("4px"),
             "target" => Some("_blank"),
             "text-decoration" => Some("none"),
+            "text-padding" if self.is_rtl() => Some("4px 0 4px 4px"),
             "text-padding" => Some("4px 4px 4px 0"),
             "vertical-align" => Some("middle"),
             _ => None,
@@ -252,6 +269,10 @@ impl<'root> Render<'root> for Renderer<'root, MjSocialElement, MjSocialElementEx
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
     fn tag(&self) -> Option<&str> {
         Some(NAME)
     }
@@ -267,13 +288,20 @@ impl<'root> Render<'root> for Renderer<'root, MjSocialElement, MjSocialElementEx
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         let href = self.get_href();
         let tr = Tag::tr().maybe_add_class(self.attribute("css-class"));
+        let tr = self.passthrough_attributes(tr);
         let td = self.set_style_td(Tag::td());
 
+        let has_text = !self.element.children.is_empty();
+        let is_rtl = self.is_rtl();
+
         tr.render_open(&mut cursor.buffer)?;
+        if has_text && is_rtl {
+            self.render_text(&href, cursor)?;
+        }
         td.render_open(&mut cursor.buffer)?;
         self.render_icon(&href, cursor)?;
         td.render_close(&mut cursor.buffer);
-        if !self.element.children.is_empty() {
+        if has_text && !is_rtl {
             self.render_text(&href, cursor)?;
         }
         tr.render_close(&mut cursor.buffer);
@@ -298,4 +326,25 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjSocialElement {
 #[cfg(test)]
 mod tests {
     crate::should_render!(render_ending_tag, "mj-social-element-ending");
+
+    #[test]
+    fn should_label_icons_without_text() {
+        let opts = crate::prelude::render::RenderOptions {
+            aria: crate::prelude::render::AriaOptions {
+                social_labels: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let template = r#"<mjml><mj-body><mj-social>
+  <mj-social-element name="facebook-noshare" href="https://facebook.com" />
+  <mj-social-element name="github" href="https://github.com">Github</mj-social-element>
+</mj-social></mj-body></mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap();
+        let result = root.element.render(&opts).unwrap();
+        assert!(result.contains(r#"aria-label="facebook""#));
+        assert!(!result.contains(r#"aria-label="github""#));
+        let result = root.element.render(&Default::default()).unwrap();
+        assert!(!result.contains("aria-label"));
+    }
 }
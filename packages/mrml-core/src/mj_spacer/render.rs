@@ -1,5 +1,6 @@
 use super::{MjSpacer, NAME};
 use crate::helper::size::Pixel;
+use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
 impl<'root> Render<'root> for Renderer<'root, MjSpacer, ()> {
@@ -14,6 +15,10 @@ impl<'root> Render<'root> for Renderer<'root, MjSpacer, ()> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
     fn tag(&self) -> Option<&str> {
         Some(NAME)
     }
@@ -27,9 +32,10 @@ impl<'root> Render<'root> for Renderer<'root, MjSpacer, ()> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
-        Tag::div()
+        let div = Tag::div()
             .maybe_add_style("height", self.attribute("height"))
-            .maybe_add_style("line-height", self.attribute("height"))
+            .maybe_add_style("line-height", self.attribute("height"));
+        self.passthrough_attributes(div)
             .render_text(&mut cursor.buffer, "&#8202;")
             .map_err(Error::from)
     }
@@ -0,0 +1,26 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_body::MjBodyChild;
+    use crate::mj_stat::MjStat;
+    use crate::text::Text;
+
+    #[test]
+    fn serialize() {
+        let mut elt = MjStat::default();
+        elt.attributes.insert("value".into(), "98%".into());
+        elt.children
+            .push(MjBodyChild::Text(Text::from("satisfied customers")));
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r#"{"type":"mj-stat","attributes":{"value":"98%"},"children":["satisfied customers"]}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{"type":"mj-stat","attributes":{"value":"2M","label-color":"gray"},"children":["orders shipped"]}"#;
+        let res: MjStat = serde_json::from_str(json).unwrap();
+        assert_eq!(res.attributes.get("value").unwrap(), "2M");
+        assert_eq!(res.children.len(), 1);
+    }
+}
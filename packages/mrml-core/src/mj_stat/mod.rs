@@ -0,0 +1,28 @@
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "parse")]
+mod parse;
+#[cfg(feature = "print")]
+mod print;
+#[cfg(feature = "render")]
+mod render;
+
+use std::marker::PhantomData;
+
+use crate::mj_body::MjBodyChild;
+use crate::prelude::hash::Map;
+use crate::prelude::{Component, StaticTag};
+
+pub const NAME: &str = "mj-stat";
+
+pub struct MjStatTag;
+
+impl StaticTag for MjStatTag {
+    fn static_tag() -> &'static str {
+        NAME
+    }
+}
+
+/// Figure of a [`MjStats`](crate::mj_stats::MjStats), with the number in its
+/// `value` attribute and its caption as content.
+pub type MjStat = Component<PhantomData<MjStatTag>, Map<String, String>, Vec<MjBodyChild>>;
@@ -0,0 +1,11 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_stat::MjStat;
+
+    crate::should_parse!(self_closing, MjStat, "<mj-stat value=\"98%\" />");
+    crate::should_parse!(
+        with_children,
+        MjStat,
+        "<mj-stat value=\"98%\">satisfied <b>customers</b></mj-stat>"
+    );
+}
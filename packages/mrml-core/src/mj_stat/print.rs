@@ -0,0 +1,16 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::print::Printable;
+
+    #[test]
+    fn with_children() {
+        let mut item = crate::mj_stat::MjStat::default();
+        item.attributes.insert("value".into(), "98%".into());
+        item.children
+            .push(crate::text::Text::from("satisfied customers").into());
+        assert_eq!(
+            "<mj-stat value=\"98%\">satisfied customers</mj-stat>",
+            item.print_dense().unwrap()
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use super::{MjStat, NAME};
+use crate::helper::size::{Pixel, Size};
+use crate::prelude::hash::Map;
+use crate::prelude::render::*;
+
+struct MjStatExtra<'a> {
+    attributes: Map<&'a str, &'a str>,
+}
+
+impl<'root> Renderer<'root, MjStat, MjStatExtra<'root>> {
+    /// Class giving the figure its share of the row above the breakpoint,
+    /// the figures being stacked below.
+    fn get_stat_class(&self) -> (String, Size) {
+        let size = Size::percent(100.0 / self.siblings.max(1) as f32);
+        let classname = format!("mj-stats-per-{}", size.value()).replace('.', "-");
+        (classname, size)
+    }
+
+    /// With the hybrid layout, the figure is limited to its width instead of
+    /// relying on the media queries.
+    fn get_hybrid_max_width(&self) -> Option<String> {
+        if self.context.options.layout == Layout::Hybrid {
+            self.container_width.map(|width| width.to_string())
+        } else {
+            None
+        }
+    }
+
+    fn set_style_value<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 'a,
+        'a: 't,
+    {
+        tag.maybe_add_style("color", self.attribute("value-color"))
+            .maybe_add_style("font-family", self.attribute_font_family("font-family"))
+            .maybe_add_style("font-size", self.attribute("value-font-size"))
+            .maybe_add_style("font-weight", self.attribute("value-font-weight"))
+            .maybe_add_style("line-height", self.attribute("value-line-height"))
+    }
+
+    fn set_style_label<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 'a,
+        'a: 't,
+    {
+        tag.maybe_add_style("color", self.attribute("label-color"))
+            .maybe_add_style("font-family", self.attribute_font_family("font-family"))
+            .maybe_add_style("font-size", self.attribute("label-font-size"))
+            .add_style("line-height", "1.5")
+    }
+
+    fn render_children(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        for child in self.element.children.iter() {
+            let renderer = child.renderer(self.context());
+            renderer.render(cursor)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'root> Render<'root> for Renderer<'root, MjStat, MjStatExtra<'root>> {
+    fn add_extra_attribute(&mut self, key: &'root str, value: &'root str) {
+        self.extra.attributes.insert(key, value);
+    }
+
+    fn raw_extra_attribute(&self, key: &str) -> Option<&'root str> {
+        self.extra.attributes.get(key).copied()
+    }
+
+    fn raw_attribute(&self, key: &str) -> Option<&'root str> {
+        self.element.attributes.get(key).map(|v| v.as_str())
+    }
+
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
+    fn tag(&self) -> Option<&str> {
+        Some(NAME)
+    }
+
+    fn set_container_width(&mut self, width: Option<Pixel>) {
+        self.container_width = width;
+    }
+
+    fn set_index(&mut self, index: usize) {
+        self.index = index;
+    }
+
+    fn set_siblings(&mut self, value: usize) {
+        self.siblings = value;
+    }
+
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.context
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let (classname, size) = self.get_stat_class();
+        cursor.header.add_media_query(classname.clone(), size);
+
+        let div = Tag::div()
+            .add_class("mj-stat")
+            .add_class(classname)
+            .maybe_add_class(self.attribute("css-class"))
+            .add_style("display", "inline-block")
+            .add_style("vertical-align", "top")
+            .add_style("width", "100%")
+            .maybe_add_style("max-width", self.get_hybrid_max_width());
+        let div = self.passthrough_attributes(div);
+        let inner = Tag::div()
+            .maybe_add_style("padding", self.attribute("stat-padding"))
+            .maybe_add_style("text-align", self.attribute("align"));
+        let value = self.set_style_value(Tag::div());
+        let label = self.set_style_label(Tag::div());
+
+        div.render_open(&mut cursor.buffer)?;
+        inner.render_open(&mut cursor.buffer)?;
+        if let Some(content) = self.attribute("value") {
+            value.render_text(&mut cursor.buffer, content)?;
+        }
+        label.render_open(&mut cursor.buffer)?;
+        self.render_children(cursor)?;
+        label.render_close(&mut cursor.buffer);
+        inner.render_close(&mut cursor.buffer);
+        div.render_close(&mut cursor.buffer);
+        Ok(())
+    }
+}
+
+impl<'render, 'root: 'render> Renderable<'render, 'root> for MjStat {
+    fn renderer(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        Box::new(Renderer::new(
+            context,
+            self,
+            MjStatExtra {
+                attributes: Map::new(),
+            },
+        ))
+    }
+}
@@ -0,0 +1,11 @@
+use crate::comment::Comment;
+use crate::mj_stat::MjStat;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json", serde(untagged))]
+#[cfg_attr(feature = "print", enum_dispatch::enum_dispatch)]
+pub enum MjStatsChild {
+    Comment(Comment),
+    MjStat(MjStat),
+}
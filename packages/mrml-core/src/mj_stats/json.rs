@@ -0,0 +1,24 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_stat::MjStat;
+    use crate::mj_stats::{MjStats, MjStatsChild};
+
+    #[test]
+    fn serialize() {
+        let mut elt = MjStats::default();
+        elt.attributes.insert("value-color".into(), "red".into());
+        elt.children.push(MjStatsChild::MjStat(MjStat::default()));
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r#"{"type":"mj-stats","attributes":{"value-color":"red"},"children":[{"type":"mj-stat"}]}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{"type":"mj-stats","attributes":{"value-color":"red"},"children":[{"type":"comment","children":"figures"},{"type":"mj-stat","attributes":{"value":"98%"}}]}"#;
+        let res: MjStats = serde_json::from_str(json).unwrap();
+        assert_eq!(res.attributes.len(), 1);
+        assert!(matches!(res.children[1], MjStatsChild::MjStat(_)));
+    }
+}
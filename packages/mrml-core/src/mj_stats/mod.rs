@@ -0,0 +1,51 @@
+//! Module containing the `mj-stats` element, rendering a row of key figures,
+//! each one being a big number above its caption, like the results of a
+//! campaign in a monthly report.
+//!
+//! The figures share the width of the row and are stacked on the small
+//! screens, without needing a group of columns.
+//!
+//! ```rust
+//! let template = r#"<mjml><mj-body><mj-section><mj-column>
+//!   <mj-stats>
+//!     <mj-stat value="98%">satisfied customers</mj-stat>
+//!     <mj-stat value="2M">orders shipped</mj-stat>
+//!     <mj-stat value="24/7">support</mj-stat>
+//!   </mj-stats>
+//! </mj-column></mj-section></mj-body></mjml>"#;
+//! let root = mrml::parse(template).expect("parse template");
+//! let opts = mrml::prelude::render::Options::default();
+//! match root.element.render(&opts) {
+//!     Ok(content) => println!("{content}"),
+//!     Err(_) => println!("couldn't render mjml template"),
+//! };
+//! ```
+
+mod children;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "parse")]
+mod parse;
+#[cfg(feature = "print")]
+mod print;
+#[cfg(feature = "render")]
+mod render;
+
+use std::marker::PhantomData;
+
+pub use children::MjStatsChild;
+
+use crate::prelude::hash::Map;
+use crate::prelude::{Component, StaticTag};
+
+pub const NAME: &str = "mj-stats";
+
+pub struct MjStatsTag;
+
+impl StaticTag for MjStatsTag {
+    fn static_tag() -> &'static str {
+        NAME
+    }
+}
+
+pub type MjStats = Component<PhantomData<MjStatsTag>, Map<String, String>, Vec<MjStatsChild>>;
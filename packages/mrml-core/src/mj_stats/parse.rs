@@ -0,0 +1,111 @@
+use super::MjStatsChild;
+use crate::comment::Comment;
+use crate::mj_stat::NAME as MJ_STAT;
+#[cfg(feature = "async")]
+use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
+use crate::prelude::parser::{
+    Error, MrmlCursor, MrmlParser, MrmlToken, ParseChildren, ParseElement,
+};
+
+impl<'opts> ParseChildren<Vec<MjStatsChild>> for MrmlParser<'opts> {
+    fn parse_children(&self, cursor: &mut MrmlCursor<'_>) -> Result<Vec<MjStatsChild>, Error> {
+        let mut result = Vec::new();
+
+        loop {
+            match cursor.assert_next()? {
+                MrmlToken::Comment(inner) => {
+                    result.push(MjStatsChild::Comment(Comment::from(inner.text.as_str())));
+                }
+                MrmlToken::ElementStart(inner) => {
+                    if inner.local.as_str() == MJ_STAT {
+                        result.push(MjStatsChild::MjStat(self.parse(cursor, inner.local)?));
+                    } else {
+                        return Err(Error::UnexpectedElement {
+                            origin: cursor.origin(),
+                            position: inner.span.into(),
+                        });
+                    }
+                }
+                MrmlToken::ElementClose(inner) => {
+                    cursor.rewind(MrmlToken::ElementClose(inner));
+                    return Ok(result);
+                }
+                other => {
+                    return Err(Error::UnexpectedToken {
+                        origin: cursor.origin(),
+                        position: other.span(),
+                    })
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl AsyncParseChildren<Vec<MjStatsChild>> for AsyncMrmlParser {
+    async fn async_parse_children<'a>(
+        &self,
+        cursor: &mut MrmlCursor<'a>,
+    ) -> Result<Vec<MjStatsChild>, Error> {
+        let mut result = Vec::new();
+
+        loop {
+            match cursor.assert_next()? {
+                MrmlToken::Comment(inner) => {
+                    result.push(MjStatsChild::Comment(Comment::from(inner.text.as_str())));
+                }
+                MrmlToken::ElementStart(inner) => {
+                    if inner.local.as_str() == MJ_STAT {
+                        result.push(MjStatsChild::MjStat(
+                            self.async_parse(cursor, inner.local).await?,
+                        ));
+                    } else {
+                        return Err(Error::UnexpectedElement {
+                            origin: cursor.origin(),
+                            position: inner.span.into(),
+                        });
+                    }
+                }
+                MrmlToken::ElementClose(inner) => {
+                    cursor.rewind(MrmlToken::ElementClose(inner));
+                    return Ok(result);
+                }
+                other => {
+                    return Err(Error::UnexpectedToken {
+                        origin: cursor.origin(),
+                        position: other.span(),
+                    })
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mj_stats::MjStats;
+
+    crate::should_parse!(
+        should_parse_stats,
+        MjStats,
+        "<mj-stats><!-- comment --><mj-stat value=\"98%\">satisfied customers</mj-stat><mj-stat value=\"2M\" /></mj-stats>"
+    );
+
+    crate::should_sync_parse!(should_work_empty, MjStats, "<mj-stats />");
+
+    crate::should_not_sync_parse!(
+        should_error_with_text,
+        MjStats,
+        "<mj-stats>Hello</mj-stats>",
+        "UnexpectedToken { origin: Root, position: Span { start: 10, end: 15 } }"
+    );
+
+    crate::should_not_sync_parse!(
+        should_error_with_unknown_element,
+        MjStats,
+        "<mj-stats><mj-text /></mj-stats>",
+        "UnexpectedElement { origin: Root, position: Span { start: 10, end: 18 } }"
+    );
+}
@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod tests {
+    use crate::comment::Comment;
+    use crate::mj_stat::MjStat;
+    use crate::mj_stats::{MjStats, MjStatsChild};
+    use crate::prelude::print::Printable;
+
+    #[test]
+    fn empty() {
+        let item = MjStats::default();
+        assert_eq!("<mj-stats />", item.print_dense().unwrap());
+    }
+
+    #[test]
+    fn with_children() {
+        let mut stat = MjStat::default();
+        stat.attributes.insert("value".into(), "98%".into());
+        let item = MjStats::new(
+            Default::default(),
+            vec![
+                MjStatsChild::Comment(Comment::new((), "Hello World!".into())),
+                MjStatsChild::MjStat(stat),
+            ],
+        );
+        assert_eq!(
+            "<mj-stats><!--Hello World!--><mj-stat value=\"98%\" /></mj-stats>",
+            item.print_dense().unwrap()
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use super::{MjStats, MjStatsChild, NAME};
+use crate::helper::size::{Pixel, Size};
+use crate::prelude::hash::Map;
+use crate::prelude::render::*;
+
+const CHILDREN_ATTRIBUTES: [&str; 9] = [
+    "align",
+    "font-family",
+    "label-color",
+    "label-font-size",
+    "stat-padding",
+    "value-color",
+    "value-font-size",
+    "value-font-weight",
+    "value-line-height",
+];
+
+impl<'root> Renderer<'root, MjStats, ()> {
+    /// Width of the content of the row.
+    fn box_width(&self) -> Option<Pixel> {
+        self.container_width
+            .map(|width| Pixel::new(width.value() - self.get_padding_horizontal().value()))
+    }
+
+    fn stats(&self) -> impl Iterator<Item = &'root MjStatsChild> {
+        self.element
+            .children
+            .iter()
+            .filter(|child| matches!(child, MjStatsChild::MjStat(_)))
+    }
+
+    fn render_stats(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let siblings = self.stats().count();
+        let stat_width = self
+            .box_width()
+            .map(|width| Pixel::new(width.value() / siblings as f32));
+        let children_attrs = CHILDREN_ATTRIBUTES
+            .iter()
+            .copied()
+            .filter_map(|key| self.attribute(key).map(|found| (key, found)))
+            .collect::<Vec<_>>();
+
+        for (index, child) in self.stats().enumerate() {
+            let mut renderer = child.renderer(self.context());
+            children_attrs.iter().copied().for_each(|(key, value)| {
+                renderer.add_extra_attribute(key, value);
+            });
+            renderer.set_index(index);
+            renderer.set_siblings(siblings);
+            renderer.set_container_width(stat_width);
+
+            let td = Tag::td()
+                .add_style("vertical-align", "top")
+                .maybe_add_style("width", stat_width.map(|width| width.to_string()));
+            cursor.buffer.start_conditional_tag();
+            td.render_open(&mut cursor.buffer)?;
+            cursor.buffer.end_conditional_tag();
+            renderer.render(cursor)?;
+            cursor.buffer.start_conditional_tag();
+            td.render_close(&mut cursor.buffer);
+            cursor.buffer.end_conditional_tag();
+        }
+        Ok(())
+    }
+}
+
+impl<'root> Render<'root> for Renderer<'root, MjStats, ()> {
+    fn default_attribute(&self, name: &str) -> Option<&'static str> {
+        match name {
+            "align" => Some("center"),
+            "font-family" => Some("Ubuntu, Helvetica, Arial, sans-serif"),
+            "label-color" => Some("#555555"),
+            "label-font-size" => Some("13px"),
+            "padding" => Some("10px 25px"),
+            "stat-padding" => Some("10px"),
+            "value-color" => Some("#000000"),
+            "value-font-size" => Some("32px"),
+            "value-font-weight" => Some("bold"),
+            "value-line-height" => Some("1.2"),
+            _ => None,
+        }
+    }
+
+    fn raw_attribute(&self, key: &str) -> Option<&'root str> {
+        self.element.attributes.get(key).map(|v| v.as_str())
+    }
+
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
+    fn tag(&self) -> Option<&str> {
+        Some(NAME)
+    }
+
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.context
+    }
+
+    fn get_width(&self) -> Option<Size> {
+        self.container_width.as_ref().copied().map(Size::Pixel)
+    }
+
+    fn set_container_width(&mut self, width: Option<Pixel>) {
+        self.container_width = width;
+    }
+
+    fn set_siblings(&mut self, value: usize) {
+        self.siblings = value;
+    }
+
+    fn set_raw_siblings(&mut self, value: usize) {
+        self.raw_siblings = value;
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        cursor
+            .header
+            .maybe_add_font_families(self.attribute("font-family"));
+
+        // the font size removes the gaps between the inline blocks
+        let div = self.passthrough_attributes(
+            Tag::div()
+                .add_class("mj-stats")
+                .add_style("font-size", "0")
+                .maybe_add_style("text-align", self.attribute("align")),
+        );
+        let table = Tag::table_presentation().add_attribute("width", "100%");
+        let tr = Tag::tr();
+
+        div.render_open(&mut cursor.buffer)?;
+        cursor.buffer.start_conditional_tag();
+        table.render_open(&mut cursor.buffer)?;
+        tr.render_open(&mut cursor.buffer)?;
+        cursor.buffer.end_conditional_tag();
+        self.render_stats(cursor)?;
+        cursor.buffer.start_conditional_tag();
+        tr.render_close(&mut cursor.buffer);
+        table.render_close(&mut cursor.buffer);
+        cursor.buffer.end_conditional_tag();
+        div.render_close(&mut cursor.buffer);
+        Ok(())
+    }
+}
+
+impl<'render, 'root: 'render> Renderable<'render, 'root> for MjStats {
+    fn renderer(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        Box::new(Renderer::new(context, self, ()))
+    }
+}
+
+impl<'render, 'root: 'render> Renderable<'render, 'root> for MjStatsChild {
+    fn renderer(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        match self {
+            Self::MjStat(elt) => elt.renderer(context),
+            Self::Comment(elt) => elt.renderer(context),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::should_render!(basic, "mj-stats");
+}
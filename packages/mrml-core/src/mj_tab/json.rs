@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_tab::MjTab;
+
+    #[test]
+    fn serialize() {
+        let mut elt = MjTab::default();
+        elt.attributes.insert("title".into(), "Shoes".into());
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r#"{"type":"mj-tab","attributes":{"title":"Shoes"}}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{"type":"mj-tab","attributes":{"title":"Shoes"},"children":[{"type":"mj-text","children":["Hello"]}]}"#;
+        let res: MjTab = serde_json::from_str(json).unwrap();
+        assert_eq!(res.attributes.get("title").unwrap(), "Shoes");
+        assert_eq!(res.children.len(), 1);
+    }
+}
@@ -0,0 +1,28 @@
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "parse")]
+mod parse;
+#[cfg(feature = "print")]
+mod print;
+#[cfg(feature = "render")]
+mod render;
+
+use std::marker::PhantomData;
+
+use crate::mj_body::MjBodyChild;
+use crate::prelude::hash::Map;
+use crate::prelude::{Component, StaticTag};
+
+pub const NAME: &str = "mj-tab";
+
+pub struct MjTabTag;
+
+impl StaticTag for MjTabTag {
+    fn static_tag() -> &'static str {
+        NAME
+    }
+}
+
+/// Panel of a [`MjTabs`](crate::mj_tabs::MjTabs), with the content of a
+/// column and the label of the tab in its `title` attribute.
+pub type MjTab = Component<PhantomData<MjTabTag>, Map<String, String>, Vec<MjBodyChild>>;
@@ -0,0 +1,11 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_tab::MjTab;
+
+    crate::should_parse!(self_closing, MjTab, "<mj-tab title=\"Shoes\" />");
+    crate::should_parse!(
+        with_children,
+        MjTab,
+        "<mj-tab title=\"Shoes\"><mj-text>Hello</mj-text><mj-image src=\"a.png\" /></mj-tab>"
+    );
+}
@@ -0,0 +1,15 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::print::Printable;
+
+    #[test]
+    fn with_children() {
+        let mut item = crate::mj_tab::MjTab::default();
+        item.attributes.insert("title".into(), "Shoes".into());
+        item.children.push(crate::mj_text::MjText::default().into());
+        assert_eq!(
+            "<mj-tab title=\"Shoes\"><mj-text /></mj-tab>",
+            item.print_dense().unwrap()
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use super::{MjTab, NAME};
+use crate::helper::size::Pixel;
+use crate::mj_column::render_row;
+use crate::prelude::hash::Map;
+use crate::prelude::render::*;
+
+struct MjTabExtra<'a> {
+    attributes: Map<&'a str, &'a str>,
+}
+
+impl<'root> Renderer<'root, MjTab, MjTabExtra<'root>> {
+    fn tabs_id(&self) -> &str {
+        self.extra
+            .attributes
+            .get("tabs-id")
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// The radio of the tab, the first tab being checked by default.
+    fn toggle(&self) -> Toggle<'static> {
+        let id = format!("mj-tabs-{}-radio-{}", self.tabs_id(), self.index + 1);
+        Toggle::radio(id.clone(), format!("mj-tabs-{}", self.tabs_id()))
+            .with_id(id)
+            .checked(self.index == 0)
+    }
+
+    fn render_style(&self) -> String {
+        let toggle = self.toggle();
+        let id = self.tabs_id();
+        let position = self.index + 1;
+        let mut result = format!(
+            "{} {{ display:block !important; }}\n",
+            toggle.checked_sibling_selector(&format!(
+                ".mj-tabs-panels .mj-tabs-{id}-panel-{position}"
+            ))
+        );
+        let label = toggle
+            .checked_sibling_selector(&format!(".mj-tabs-labels .mj-tabs-{id}-label-{position}"));
+        if let Some(color) = self.attribute("tab-active-color") {
+            result.push_str(&format!("{label} {{ color:{color} !important; }}\n"));
+        }
+        if let Some(color) = self.attribute("tab-border-color") {
+            result.push_str(&format!(
+                "{label} {{ border-bottom-color:{color} !important; }}\n"
+            ));
+        }
+        result
+    }
+
+    fn render_radio(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        cursor.header.add_style(self.render_style());
+        let toggle = self.toggle();
+        let input = toggle
+            .input()
+            .add_class("mj-tabs-radio")
+            .add_style("display", "none")
+            .add_style("mso-hide", "all");
+        toggle.render_input(&input, &mut cursor.buffer, InputGate::NotMso)?;
+        Ok(())
+    }
+
+    fn render_label(&self, buf: &mut RenderBuffer) -> Result<(), Error> {
+        let label = self
+            .toggle()
+            .label()
+            .add_class("mj-tabs-label")
+            .add_class(format!(
+                "mj-tabs-{}-label-{}",
+                self.tabs_id(),
+                self.index + 1
+            ))
+            .add_style("display", "inline-block")
+            .add_style("cursor", "pointer")
+            .maybe_add_style("padding", self.attribute("tab-padding"))
+            .maybe_add_style("color", self.attribute("tab-color"))
+            .maybe_add_style("font-family", self.attribute_font_family("font-family"))
+            .maybe_add_style("font-size", self.attribute("font-size"))
+            .add_style("border-bottom", "2px solid transparent");
+        label.render_open(buf)?;
+        buf.push_str(self.attribute("title").unwrap_or_default());
+        label.render_close(buf);
+        Ok(())
+    }
+}
+
+impl<'root> Render<'root> for Renderer<'root, MjTab, MjTabExtra<'root>> {
+    fn add_extra_attribute(&mut self, key: &'root str, value: &'root str) {
+        self.extra.attributes.insert(key, value);
+    }
+
+    fn raw_extra_attribute(&self, key: &str) -> Option<&'root str> {
+        self.extra.attributes.get(key).copied()
+    }
+
+    fn raw_attribute(&self, key: &str) -> Option<&'root str> {
+        self.element.attributes.get(key).map(|v| v.as_str())
+    }
+
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
+    fn tag(&self) -> Option<&str> {
+        Some(NAME)
+    }
+
+    fn set_container_width(&mut self, width: Option<Pixel>) {
+        self.container_width = width;
+    }
+
+    fn set_index(&mut self, index: usize) {
+        self.index = index;
+    }
+
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.context
+    }
+
+    fn render_fragment(&self, name: &str, cursor: &mut RenderCursor) -> Result<(), Error> {
+        match name {
+            "main" => self.render(cursor),
+            "radio" => self.render_radio(cursor),
+            "label" => self.render_label(&mut cursor.buffer),
+            _ => Err(Error::UnknownFragment(name.to_string())),
+        }
+    }
+
+    /// Renders the panel, with its title shown when the panels are stacked.
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let div = Tag::div()
+            .add_class("mj-tabs-panel")
+            .add_class(format!(
+                "mj-tabs-{}-panel-{}",
+                self.tabs_id(),
+                self.index + 1
+            ))
+            .maybe_add_class(self.attribute("css-class"));
+        let div = self.passthrough_attributes(div);
+        let heading = Tag::div()
+            .add_class("mj-tabs-heading")
+            .maybe_add_style("padding", self.attribute("tab-padding"))
+            .maybe_add_style("color", self.attribute("tab-active-color"))
+            .maybe_add_style("font-family", self.attribute_font_family("font-family"))
+            .maybe_add_style("font-size", self.attribute("font-size"))
+            .add_style("font-weight", "bold");
+        let table = Tag::table_presentation().add_attribute("width", "100%");
+        let tbody = Tag::tbody();
+
+        div.render_open(&mut cursor.buffer)?;
+        if let Some(title) = self.attribute("title") {
+            heading.render_open(&mut cursor.buffer)?;
+            cursor.buffer.push_str(title);
+            heading.render_close(&mut cursor.buffer);
+        }
+        table.render_open(&mut cursor.buffer)?;
+        tbody.render_open(&mut cursor.buffer)?;
+        for (index, child) in self.element.children.iter().enumerate() {
+            let mut renderer = child.renderer(self.context());
+            renderer.set_index(index);
+            renderer.set_container_width(self.container_width);
+            if child.is_raw() {
+                renderer.render(cursor)?;
+            } else {
+                render_row(renderer.as_ref(), cursor)?;
+            }
+        }
+        tbody.render_close(&mut cursor.buffer);
+        table.render_close(&mut cursor.buffer);
+        div.render_close(&mut cursor.buffer);
+        Ok(())
+    }
+}
+
+impl<'render, 'root: 'render> Renderable<'render, 'root> for MjTab {
+    fn renderer(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        Box::new(Renderer::new(
+            context,
+            self,
+            MjTabExtra {
+                attributes: Map::new(),
+            },
+        ))
+    }
+}
@@ -1,6 +1,7 @@
 use super::{MjTable, NAME};
 use crate::helper::size::Pixel;
 use crate::mj_section::WithMjSectionBackground;
+use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
 impl<'root> WithMjSectionBackground<'root> for Renderer<'root, MjTable, ()> {}
@@ -12,7 +13,7 @@ impl<'root> Renderer<'root, MjTable, ()> {
         'a: 't,
     {
         tag.maybe_add_style("color", self.attribute("color"))
-            .maybe_add_style("font-family", self.attribute("font-family"))
+            .maybe_add_style("font-family", self.attribute_font_family("font-family"))
             .maybe_add_style("font-size", self.attribute("font-size"))
             .maybe_add_style("line-height", self.attribute("line-height"))
             .maybe_add_style("table-layout", self.attribute("table-layout"))
@@ -43,6 +44,10 @@ impl<'root> Render<'root> for Renderer<'root, MjTable, ()> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
     fn tag(&self) -> Option<&str> {
         Some(NAME)
     }
@@ -65,6 +70,7 @@ impl<'root> Render<'root> for Renderer<'root, MjTable, ()> {
             .maybe_add_attribute("cellpadding", self.attribute("cellpadding"))
             .maybe_add_attribute("cellspacing", self.attribute("cellspacing"))
             .maybe_add_attribute("width", self.attribute("width"));
+        let table = self.passthrough_attributes(table);
         table.render_open(&mut cursor.buffer)?;
         for (index, child) in self.element.children.iter().enumerate() {
             let mut renderer = child.renderer(self.context());
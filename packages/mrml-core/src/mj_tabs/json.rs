@@ -0,0 +1,24 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_tab::MjTab;
+    use crate::mj_tabs::{MjTabs, MjTabsChild};
+
+    #[test]
+    fn serialize() {
+        let mut elt = MjTabs::default();
+        elt.attributes.insert("tab-color".into(), "red".into());
+        elt.children.push(MjTabsChild::MjTab(MjTab::default()));
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r#"{"type":"mj-tabs","attributes":{"tab-color":"red"},"children":[{"type":"mj-tab"}]}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{"type":"mj-tabs","attributes":{"tab-color":"red"},"children":[{"type":"mj-tab","attributes":{"title":"Shoes"}}]}"#;
+        let res: MjTabs = serde_json::from_str(json).unwrap();
+        assert_eq!(res.attributes.len(), 1);
+        assert_eq!(res.children.len(), 1);
+    }
+}
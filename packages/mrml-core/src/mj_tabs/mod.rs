@@ -0,0 +1,50 @@
+//! Module containing the `mj-tabs` element, switching between panels with
+//! tabs, like the categories of products in a promotional email.
+//!
+//! The tabs are radio inputs toggled by their labels. The clients without
+//! interactivity, like Outlook or Gmail, show the panels stacked, each one
+//! with its title.
+//!
+//! ```rust
+//! let template = r#"<mjml><mj-body><mj-section><mj-column>
+//!   <mj-tabs>
+//!     <mj-tab title="Shoes"><mj-text>Our shoes</mj-text></mj-tab>
+//!     <mj-tab title="Bags"><mj-text>Our bags</mj-text></mj-tab>
+//!   </mj-tabs>
+//! </mj-column></mj-section></mj-body></mjml>"#;
+//! let root = mrml::parse(template).expect("parse template");
+//! let opts = mrml::prelude::render::Options::default();
+//! match root.element.render(&opts) {
+//!     Ok(content) => println!("{content}"),
+//!     Err(_) => println!("couldn't render mjml template"),
+//! };
+//! ```
+
+mod children;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "parse")]
+mod parse;
+#[cfg(feature = "print")]
+mod print;
+#[cfg(feature = "render")]
+mod render;
+
+use std::marker::PhantomData;
+
+pub use children::MjTabsChild;
+
+use crate::prelude::hash::Map;
+use crate::prelude::{Component, StaticTag};
+
+pub const NAME: &str = "mj-tabs";
+
+pub struct MjTabsTag;
+
+impl StaticTag for MjTabsTag {
+    fn static_tag() -> &'static str {
+        NAME
+    }
+}
+
+pub type MjTabs = Component<PhantomData<MjTabsTag>, Map<String, String>, Vec<MjTabsChild>>;
@@ -0,0 +1,111 @@
+use super::MjTabsChild;
+use crate::comment::Comment;
+use crate::mj_tab::NAME as MJ_TAB;
+#[cfg(feature = "async")]
+use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
+use crate::prelude::parser::{
+    Error, MrmlCursor, MrmlParser, MrmlToken, ParseChildren, ParseElement,
+};
+
+impl<'opts> ParseChildren<Vec<MjTabsChild>> for MrmlParser<'opts> {
+    fn parse_children(&self, cursor: &mut MrmlCursor<'_>) -> Result<Vec<MjTabsChild>, Error> {
+        let mut result = Vec::new();
+
+        loop {
+            match cursor.assert_next()? {
+                MrmlToken::Comment(inner) => {
+                    result.push(MjTabsChild::Comment(Comment::from(inner.text.as_str())));
+                }
+                MrmlToken::ElementStart(inner) => {
+                    if inner.local.as_str() == MJ_TAB {
+                        result.push(MjTabsChild::MjTab(self.parse(cursor, inner.local)?));
+                    } else {
+                        return Err(Error::UnexpectedElement {
+                            origin: cursor.origin(),
+                            position: inner.span.into(),
+                        });
+                    }
+                }
+                MrmlToken::ElementClose(inner) => {
+                    cursor.rewind(MrmlToken::ElementClose(inner));
+                    return Ok(result);
+                }
+                other => {
+                    return Err(Error::UnexpectedToken {
+                        origin: cursor.origin(),
+                        position: other.span(),
+                    })
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl AsyncParseChildren<Vec<MjTabsChild>> for AsyncMrmlParser {
+    async fn async_parse_children<'a>(
+        &self,
+        cursor: &mut MrmlCursor<'a>,
+    ) -> Result<Vec<MjTabsChild>, Error> {
+        let mut result = Vec::new();
+
+        loop {
+            match cursor.assert_next()? {
+                MrmlToken::Comment(inner) => {
+                    result.push(MjTabsChild::Comment(Comment::from(inner.text.as_str())));
+                }
+                MrmlToken::ElementStart(inner) => {
+                    if inner.local.as_str() == MJ_TAB {
+                        result.push(MjTabsChild::MjTab(
+                            self.async_parse(cursor, inner.local).await?,
+                        ));
+                    } else {
+                        return Err(Error::UnexpectedElement {
+                            origin: cursor.origin(),
+                            position: inner.span.into(),
+                        });
+                    }
+                }
+                MrmlToken::ElementClose(inner) => {
+                    cursor.rewind(MrmlToken::ElementClose(inner));
+                    return Ok(result);
+                }
+                other => {
+                    return Err(Error::UnexpectedToken {
+                        origin: cursor.origin(),
+                        position: other.span(),
+                    })
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mj_tabs::MjTabs;
+
+    crate::should_parse!(
+        should_parse_tabs,
+        MjTabs,
+        "<mj-tabs><!-- comment --><mj-tab title=\"Shoes\"><mj-text>Hello</mj-text></mj-tab><mj-tab title=\"Bags\" /></mj-tabs>"
+    );
+
+    crate::should_sync_parse!(should_work_empty, MjTabs, "<mj-tabs />");
+
+    crate::should_not_sync_parse!(
+        should_error_with_text,
+        MjTabs,
+        "<mj-tabs>Hello</mj-tabs>",
+        "UnexpectedToken { origin: Root, position: Span { start: 9, end: 14 } }"
+    );
+
+    crate::should_not_sync_parse!(
+        should_error_with_unknown_element,
+        MjTabs,
+        "<mj-tabs><mj-text /></mj-tabs>",
+        "UnexpectedElement { origin: Root, position: Span { start: 9, end: 17 } }"
+    );
+}
@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod tests {
+    use crate::comment::Comment;
+    use crate::mj_tab::MjTab;
+    use crate::mj_tabs::{MjTabs, MjTabsChild};
+    use crate::prelude::print::Printable;
+
+    #[test]
+    fn empty() {
+        let item = MjTabs::default();
+        assert_eq!("<mj-tabs />", item.print_dense().unwrap());
+    }
+
+    #[test]
+    fn with_children() {
+        let mut tab = MjTab::default();
+        tab.attributes.insert("title".into(), "Shoes".into());
+        let item = MjTabs::new(
+            Default::default(),
+            vec![
+                MjTabsChild::Comment(Comment::new((), "Hello World!".into())),
+                MjTabsChild::MjTab(tab),
+            ],
+        );
+        assert_eq!(
+            "<mj-tabs><!--Hello World!--><mj-tab title=\"Shoes\" /></mj-tabs>",
+            item.print_dense().unwrap()
+        );
+    }
+}
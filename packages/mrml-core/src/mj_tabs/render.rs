@@ -0,0 +1,212 @@
+use super::{MjTabs, MjTabsChild, NAME};
+use crate::helper::size::{Pixel, Size};
+use crate::prelude::hash::Map;
+use crate::prelude::render::*;
+
+const CHILDREN_ATTRIBUTES: [&str; 6] = [
+    "font-family",
+    "font-size",
+    "tab-active-color",
+    "tab-border-color",
+    "tab-color",
+    "tab-padding",
+];
+
+/// Shows the labels and only the panel of the checked tab when the client
+/// supports the inputs, the panels being stacked otherwise.
+const STYLE: &str = r#"input.mj-tabs-radio:checked~.mj-tabs-labels { display:block !important; max-height:none !important; overflow:visible !important; }
+input.mj-tabs-radio:checked~.mj-tabs-panels .mj-tabs-panel { display:none; }
+input.mj-tabs-radio:checked~.mj-tabs-panels .mj-tabs-heading { display:none !important; }
+"#;
+
+struct MjTabsExtra {
+    id: String,
+}
+
+impl<'root> Renderer<'root, MjTabs, MjTabsExtra> {
+    fn update_header(&self, header: &mut VariableHeader) {
+        header.maybe_add_font_families(self.attribute("font-family"));
+        header.add_style(STYLE);
+    }
+
+    fn set_style_labels<'t>(&self, tag: Tag<'t>) -> Tag<'t> {
+        tag.add_style("display", "none")
+            .add_style("max-height", "0")
+            .add_style("overflow", "hidden")
+            .add_style("mso-hide", "all")
+    }
+
+    /// Width of the content of the panels.
+    fn box_width(&self) -> Option<Pixel> {
+        self.container_width
+            .map(|width| Pixel::new(width.value() - self.get_padding_horizontal().value()))
+    }
+
+    fn tabs(&self) -> impl Iterator<Item = (usize, &'root MjTabsChild)> {
+        self.element
+            .children
+            .iter()
+            .filter(|child| matches!(child, MjTabsChild::MjTab(_)))
+            .enumerate()
+    }
+
+    fn render_tabs<'a>(
+        &'a self,
+        fragment: &str,
+        children_attrs: &[(&'a str, &'a str)],
+        cursor: &mut RenderCursor,
+    ) -> Result<(), Error> {
+        for (index, child) in self.tabs() {
+            let mut renderer = child.renderer(self.context());
+            renderer.add_extra_attribute("tabs-id", &self.extra.id);
+            children_attrs.iter().copied().for_each(|(key, value)| {
+                renderer.add_extra_attribute(key, value);
+            });
+            renderer.set_index(index);
+            renderer.set_container_width(self.box_width());
+            renderer.render_fragment(fragment, cursor)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'root> Render<'root> for Renderer<'root, MjTabs, MjTabsExtra> {
+    fn default_attribute(&self, name: &str) -> Option<&'static str> {
+        match name {
+            "font-family" => Some("Ubuntu, Helvetica, Arial, sans-serif"),
+            "font-size" => Some("13px"),
+            "padding" => Some("10px 25px"),
+            "tab-active-color" => Some("#000000"),
+            "tab-border-color" => Some("#000000"),
+            "tab-color" => Some("#555555"),
+            "tab-padding" => Some("10px 15px"),
+            _ => None,
+        }
+    }
+
+    fn raw_attribute(&self, key: &str) -> Option<&'root str> {
+        self.element.attributes.get(key).map(|v| v.as_str())
+    }
+
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
+    fn tag(&self) -> Option<&str> {
+        Some(NAME)
+    }
+
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.context
+    }
+
+    fn get_width(&self) -> Option<Size> {
+        self.container_width.as_ref().copied().map(Size::Pixel)
+    }
+
+    fn set_container_width(&mut self, width: Option<Pixel>) {
+        self.container_width = width;
+    }
+
+    fn set_siblings(&mut self, value: usize) {
+        self.siblings = value;
+    }
+
+    fn set_raw_siblings(&mut self, value: usize) {
+        self.raw_siblings = value;
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.update_header(&mut cursor.header);
+
+        let div = self.passthrough_attributes(Tag::div().add_class("mj-tabs"));
+        let labels = self
+            .set_style_labels(Tag::div())
+            .add_class("mj-tabs-labels");
+        let panels = Tag::div().add_class("mj-tabs-panels");
+
+        let children_attrs = CHILDREN_ATTRIBUTES
+            .iter()
+            .copied()
+            .filter_map(|key| self.attribute(key).map(|found| (key, found)))
+            .collect::<Vec<_>>();
+
+        div.render_open(&mut cursor.buffer)?;
+        self.render_tabs("radio", &children_attrs, cursor)?;
+        labels.render_open(&mut cursor.buffer)?;
+        self.render_tabs("label", &children_attrs, cursor)?;
+        labels.render_close(&mut cursor.buffer);
+        panels.render_open(&mut cursor.buffer)?;
+        self.render_tabs("main", &children_attrs, cursor)?;
+        panels.render_close(&mut cursor.buffer);
+        div.render_close(&mut cursor.buffer);
+        Ok(())
+    }
+}
+
+impl<'render, 'root: 'render> Renderable<'render, 'root> for MjTabs {
+    fn renderer(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        let extra = MjTabsExtra {
+            id: context.generator.next_id(),
+        };
+        Box::new(Renderer::new(context, self, extra))
+    }
+}
+
+impl<'render, 'root: 'render> Renderable<'render, 'root> for MjTabsChild {
+    fn renderer(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        match self {
+            Self::MjTab(elt) => elt.renderer(context),
+            Self::Comment(elt) => elt.renderer(context),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use crate::mjml::Mjml;
+
+    const TEMPLATE: &str = r##"<mjml><mj-body><mj-section><mj-column>
+  <mj-tabs tab-active-color="#ff0000">
+    <mj-tab title="Shoes"><mj-text>Our shoes</mj-text></mj-tab>
+    <!-- comment -->
+    <mj-tab title="Bags"><mj-image src="https://example.com/bag.png" /></mj-tab>
+  </mj-tabs>
+</mj-column></mj-section></mj-body></mjml>"##;
+
+    #[test]
+    fn should_render_tabs() {
+        let root = Mjml::parse(TEMPLATE).unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        assert_eq!(html.matches(r#"type="radio""#).count(), 2);
+        assert_eq!(html.matches(r#"checked="checked""#).count(), 1);
+        assert_eq!(html.matches("<label").count(), 2);
+        assert!(html.contains(">Shoes</label>"));
+        assert!(html.contains("Our shoes"));
+        assert!(html.contains("https://example.com/bag.png"));
+        assert!(html.contains("color:#ff0000 !important;"));
+    }
+
+    #[test]
+    fn should_stack_the_panels_without_interactivity() {
+        let root = Mjml::parse(TEMPLATE).unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        // the inputs are hidden from outlook
+        let start = html.find("<input").unwrap();
+        assert!(html[..start].ends_with("<!--[if !mso]><!-->"));
+        // the labels are hidden until an input is checked
+        assert!(html.contains(
+            r#"<div class="mj-tabs-labels" style="display:none;max-height:0;overflow:hidden;mso-hide:all;">"#
+        ));
+        // each panel shows its title and none of them is hidden
+        assert_eq!(html.matches(r#"class="mj-tabs-heading""#).count(), 2);
+        let panels = &html[html.find(r#"class="mj-tabs-panels""#).unwrap()..];
+        assert!(!panels.contains("display:none"));
+    }
+}
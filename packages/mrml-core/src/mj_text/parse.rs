@@ -1,7 +1,71 @@
 #[cfg(test)]
 mod tests {
     use crate::mj_text::MjText;
+    use crate::prelude::parser::{MrmlCursor, MrmlParser, ParserOptions, WarningKind};
 
     crate::should_parse!(self_closing, MjText, "<mj-text />");
     crate::should_parse!(normal, MjText, "<mj-text>Hello World!</mj-text>");
+    crate::should_parse!(
+        unbalanced_html_without_validation,
+        MjText,
+        "<mj-text><p>Hello <b>World</p></b></mj-text>"
+    );
+
+    crate::should_parse!(
+        unclosed_void_elements,
+        MjText,
+        "<mj-text>Hello<br>World<BR><img src=\"a.png\"><p>Next</p></mj-text>"
+    );
+    crate::should_parse!(
+        closed_void_elements,
+        MjText,
+        "<mj-text>Hello<br></br>World<Img src=\"a.png\"></IMG></mj-text>"
+    );
+    crate::should_parse!(
+        mixed_case_elements,
+        MjText,
+        "<mj-text><P>Hello <B>World</B></P></mj-text>"
+    );
+    crate::should_parse!(
+        stray_ampersand,
+        MjText,
+        "<mj-text><a href=\"https://example.com/?a=1&b=2\">Tom & Jerry</a></mj-text>"
+    );
+
+    #[test]
+    fn should_keep_elements_after_void_element() {
+        let opts = ParserOptions::default();
+        let parser = MrmlParser::new(&opts);
+        let mut cursor = MrmlCursor::new("<mj-text>Hello<BR>World<br></br>!</mj-text>");
+        let elt: MjText = parser.parse_root(&mut cursor).unwrap();
+        assert_eq!(elt.children.len(), 5);
+    }
+
+    fn parse_validated(template: &str) -> Vec<crate::prelude::parser::Warning> {
+        let opts = ParserOptions {
+            validate_html: true,
+            ..Default::default()
+        };
+        let parser = MrmlParser::new(&opts);
+        let mut cursor = MrmlCursor::new(template);
+        let _: MjText = parser.parse_root(&mut cursor).unwrap();
+        cursor.warnings()
+    }
+
+    #[test]
+    fn should_warn_on_unbalanced_html() {
+        let template = "<mj-text><p>Hello <b>World</p></b></mj-text>";
+        let warnings = parse_validated(template);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].kind, WarningKind::UnbalancedHtml);
+        assert_eq!(warnings[0].span.start, template.find("</p>").unwrap());
+        assert_eq!(warnings[1].span.start, template.find("</b>").unwrap());
+    }
+
+    #[test]
+    fn should_not_warn_on_balanced_html() {
+        let warnings =
+            parse_validated("<mj-text><P>Hello <br><b>World</b></p><img src=\"a.png\"></mj-text>");
+        assert!(warnings.is_empty());
+    }
 }
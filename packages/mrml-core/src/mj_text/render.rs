@@ -1,4 +1,5 @@
 use super::{MjText, NAME};
+use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
 impl<'root> Renderer<'root, MjText, ()> {
@@ -7,7 +8,7 @@ impl<'root> Renderer<'root, MjText, ()> {
         'root: 'a,
         'a: 't,
     {
-        tag.maybe_add_style("font-family", self.attribute("font-family"))
+        tag.maybe_add_style("font-family", self.attribute_font_family("font-family"))
             .maybe_add_style("font-size", self.attribute("font-size"))
             .maybe_add_style("font-style", self.attribute("font-style"))
             .maybe_add_style("font-weight", self.attribute("font-weight"))
@@ -21,7 +22,7 @@ impl<'root> Renderer<'root, MjText, ()> {
     }
 
     fn render_content(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
-        let root = self.set_style_text(Tag::div());
+        let root = self.passthrough_attributes(self.set_style_text(Tag::div()));
         root.render_open(&mut cursor.buffer)?;
         for child in self.element.children.iter() {
             child.renderer(self.context()).render(cursor)?;
@@ -70,6 +71,10 @@ impl<'root> Render<'root> for Renderer<'root, MjText, ()> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
     fn tag(&self) -> Option<&str> {
         Some(NAME)
     }
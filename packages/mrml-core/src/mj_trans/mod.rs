@@ -0,0 +1,108 @@
+//! Translation of the content of the templates with the `mj-trans` element,
+//! so that a single template is rendered in every language.
+//!
+//! The element is replaced by the translation of its `key` attribute in the
+//! [`locale`](crate::prelude::render::RenderOptions::locale) of the render
+//! options, found in the
+//! [`catalog`](crate::prelude::render::RenderOptions::catalog). Its content is
+//! rendered when there is no translation.
+//!
+//! ```rust
+//! # #[cfg(feature = "parse")]
+//! # {
+//! use std::sync::Arc;
+//!
+//! use mrml::mj_trans::MemoryCatalog;
+//! use mrml::prelude::render::RenderOptions;
+//!
+//! let mut catalog = MemoryCatalog::default();
+//! catalog.insert("fr", "welcome.title", "Bienvenue");
+//! let opts = RenderOptions {
+//!     locale: Some("fr-CA".into()),
+//!     catalog: Some(Arc::new(catalog)),
+//!     ..Default::default()
+//! };
+//! let template = r#"<mjml><mj-body><mj-text><mj-trans key="welcome.title">Welcome</mj-trans></mj-text></mj-body></mjml>"#;
+//! let root = mrml::parse(template).unwrap();
+//! assert!(root.element.render(&opts).unwrap().contains("Bienvenue"));
+//! # }
+//! ```
+//!
+//! The element is parsed as an html element, so it's kept when printing the
+//! template or converting it to json.
+
+use std::borrow::Cow;
+use std::fmt::Debug;
+
+use crate::prelude::hash::Map;
+
+#[cfg(feature = "render")]
+pub(crate) mod render;
+
+pub const NAME: &str = "mj-trans";
+
+/// Source of the translations. Implement it on top of a Fluent bundle or of
+/// any other translation system to use it while rendering.
+pub trait Catalog: Debug + Send + Sync {
+    /// Translation of the `key` in the `locale`, as html.
+    fn translate(&self, locale: &str, key: &str) -> Option<Cow<'_, str>>;
+}
+
+/// Translations kept in memory, by locale and key. When a key isn't
+/// translated in a regional locale, like `fr-CA`, the translation of its
+/// language, `fr`, is used.
+///
+/// With the `json` feature, it's deserialized from an object like
+/// `{"fr": {"welcome.title": "Bienvenue"}}`.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "json", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "json", serde(transparent))]
+pub struct MemoryCatalog(Map<String, Map<String, String>>);
+
+impl MemoryCatalog {
+    pub fn insert(
+        &mut self,
+        locale: impl Into<String>,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) {
+        self.0
+            .entry(locale.into())
+            .or_default()
+            .insert(key.into(), value.into());
+    }
+
+    fn get(&self, locale: &str, key: &str) -> Option<&str> {
+        self.0
+            .get(locale)
+            .and_then(|values| values.get(key))
+            .map(String::as_str)
+    }
+}
+
+impl Catalog for MemoryCatalog {
+    fn translate(&self, locale: &str, key: &str) -> Option<Cow<'_, str>> {
+        self.get(locale, key)
+            .or_else(|| {
+                let (language, _) = locale.split_once(['-', '_'])?;
+                self.get(language, key)
+            })
+            .map(Cow::Borrowed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Catalog, MemoryCatalog};
+
+    #[test]
+    fn should_fallback_on_language() {
+        let mut catalog = MemoryCatalog::default();
+        catalog.insert("fr", "hello", "Bonjour");
+        catalog.insert("fr-CA", "hello", "Allô");
+        assert_eq!(catalog.translate("fr-CA", "hello").unwrap(), "Allô");
+        assert_eq!(catalog.translate("fr_BE", "hello").unwrap(), "Bonjour");
+        assert!(catalog.translate("de", "hello").is_none());
+        assert!(catalog.translate("fr", "goodbye").is_none());
+    }
+}
@@ -0,0 +1,86 @@
+use std::borrow::Cow;
+
+use crate::node::Node;
+use crate::prelude::render::*;
+
+/// Marker of the renderer of the `mj-trans` elements.
+pub(crate) struct MjTransExtra;
+
+impl<'root, T> Renderer<'root, Node<T>, MjTransExtra> {
+    fn translation(&self) -> Option<Cow<'root, str>> {
+        let options = self.context.options;
+        let key = self.element.attributes.get("key")?;
+        let locale = options.locale.as_deref()?;
+        options.catalog.as_ref()?.translate(locale, key)
+    }
+}
+
+impl<'render, 'root: 'render, T> Render<'root> for Renderer<'root, Node<T>, MjTransExtra>
+where
+    T: Renderable<'render, 'root>,
+{
+    fn tag(&self) -> Option<&str> {
+        Some(super::NAME)
+    }
+
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.context
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        if let Some(value) = self.translation() {
+            cursor.buffer.push_str(&value);
+            return Ok(());
+        }
+        for (index, child) in self.element.children.iter().enumerate() {
+            let mut renderer = child.renderer(self.context);
+            renderer.set_index(index);
+            renderer.render(cursor)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::mj_trans::MemoryCatalog;
+    use crate::prelude::render::RenderOptions;
+
+    const TEMPLATE: &str = r#"<mjml><mj-body><mj-text><mj-trans key="greeting">Hello <b>friend</b></mj-trans>!</mj-text></mj-body></mjml>"#;
+
+    #[test]
+    fn should_translate() {
+        let mut catalog = MemoryCatalog::default();
+        catalog.insert("fr", "greeting", "Bonjour <b>l'ami</b>");
+        let opts = RenderOptions {
+            locale: Some("fr".into()),
+            catalog: Some(Arc::new(catalog)),
+            ..Default::default()
+        };
+        let root = crate::parse(TEMPLATE).unwrap();
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains("Bonjour <b>l'ami</b>!"));
+        assert!(!html.contains("mj-trans"));
+    }
+
+    #[test]
+    fn should_render_fallback() {
+        let mut catalog = MemoryCatalog::default();
+        catalog.insert("fr", "greeting", "Bonjour");
+        let root = crate::parse(TEMPLATE).unwrap();
+        for opts in [
+            RenderOptions::default(),
+            RenderOptions {
+                locale: Some("de".into()),
+                catalog: Some(Arc::new(catalog)),
+                ..Default::default()
+            },
+        ] {
+            let html = root.element.render(&opts).unwrap();
+            assert!(html.contains("Hello <b>friend</b>!"));
+            assert!(!html.contains("mj-trans"));
+        }
+    }
+}
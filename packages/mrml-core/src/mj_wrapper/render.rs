@@ -1,14 +1,18 @@
 use super::{MjWrapper, NAME};
 use crate::helper::size::Pixel;
+use crate::helper::width;
 use crate::mj_section::{SectionLikeRender, WithMjSectionBackground};
+use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
 impl<'root> Renderer<'root, MjWrapper, ()> {
     fn current_width(&self) -> Option<Pixel> {
-        self.container_width.as_ref().map(|width| {
-            let hborder = self.get_border_horizontal();
-            let hpadding = self.get_padding_horizontal();
-            Pixel::new(width.value() - hborder.value() - hpadding.value())
+        self.container_width.map(|container| {
+            width::box_width(
+                container,
+                self.get_padding_horizontal(),
+                self.get_border_horizontal(),
+            )
         })
     }
 }
@@ -24,6 +28,15 @@ impl<'root> SectionLikeRender<'root> for Renderer<'root, MjWrapper, ()> {
         &self.container_width
     }
 
+    fn clips_children(&self) -> bool {
+        self.attribute_exists("border-radius")
+            && self.children().iter().any(|child| {
+                let renderer = child.renderer(self.context());
+                renderer.attribute_exists("background-color")
+                    || renderer.attribute_exists("background-url")
+            })
+    }
+
     fn render_wrapped_children(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         let tr = Tag::tr();
         let siblings = self.get_siblings();
@@ -35,6 +48,9 @@ impl<'root> SectionLikeRender<'root> for Renderer<'root, MjWrapper, ()> {
             renderer.set_siblings(siblings);
             renderer.set_raw_siblings(raw_siblings);
             renderer.set_container_width(current_width);
+            if self.has_background() {
+                renderer.add_extra_attribute("wrapper-background", "vml");
+            }
             if child.is_raw() {
                 renderer.render(cursor)?;
             } else {
@@ -78,6 +94,10 @@ impl<'root> Render<'root> for Renderer<'root, MjWrapper, ()> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
 
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        Some(&self.element.attributes)
+    }
+
     fn tag(&self) -> Option<&str> {
         Some(NAME)
     }
@@ -111,4 +131,61 @@ mod tests {
     crate::should_render!(border, "mj-wrapper-border");
     crate::should_render!(other, "mj-wrapper-other");
     crate::should_render!(padding, "mj-wrapper-padding");
+
+    #[cfg(feature = "parse")]
+    fn render(template: &str) -> String {
+        crate::parse(template)
+            .unwrap()
+            .element
+            .render(&Default::default())
+            .unwrap()
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_keep_background_in_box() {
+        let html = render(
+            r##"<mjml><mj-body><mj-wrapper full-width="full-width" full-width-background="false" background-color="#abcdef" css-class="brand"><mj-section></mj-section></mj-wrapper></mj-body></mjml>"##,
+        );
+        let start = html.find(r#"class="brand""#).unwrap();
+        let table = &html[start..start + html[start..].find('>').unwrap()];
+        assert!(!table.contains("#abcdef"));
+        assert!(html.contains(r#"<div style="background:#abcdef;background-color:#abcdef;margin:0px auto;max-width:600px;">"#));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_only_accept_full_width_value() {
+        let html = render(
+            r##"<mjml><mj-body><mj-wrapper full-width="false" background-color="#abcdef"><mj-section></mj-section></mj-wrapper></mj-body></mjml>"##,
+        );
+        assert!(html.contains(r#"<div style="background:#abcdef;background-color:#abcdef;margin:0px auto;max-width:600px;">"#));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_clip_nested_backgrounds() {
+        let html = render(
+            r#"<mjml><mj-body><mj-wrapper border-radius="8px"><mj-section background-color="red"></mj-section></mj-wrapper></mj-body></mjml>"#,
+        );
+        assert!(html.contains("border-radius:8px;overflow:hidden;"));
+        let html = render(
+            r#"<mjml><mj-body><mj-wrapper border-radius="8px"><mj-section></mj-section></mj-wrapper></mj-body></mjml>"#,
+        );
+        assert!(!html.contains("overflow:hidden"));
+    }
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_not_nest_vml_backgrounds() {
+        let html = render(
+            r#"<mjml><mj-body><mj-wrapper background-url="https://example.com/a.png"><mj-section background-url="https://example.com/b.png"></mj-section></mj-wrapper></mj-body></mjml>"#,
+        );
+        assert_eq!(html.matches("<v:rect").count(), 1);
+        assert!(html.contains(r#"src="https://example.com/a.png""#));
+        assert!(html.contains(r#"background="https://example.com/b.png""#));
+        let html = render(
+            r#"<mjml><mj-body><mj-wrapper><mj-section background-url="https://example.com/b.png"></mj-section></mj-wrapper></mj-body></mjml>"#,
+        );
+        assert_eq!(html.matches("<v:rect").count(), 1);
+    }
 }
@@ -0,0 +1,202 @@
+use super::Mjml;
+use crate::mj_head::MjHeadChild;
+use crate::mj_include::head::MjIncludeHeadChild;
+use crate::prelude::tree::{TreeElement, BODY_PATH};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AssetKind {
+    Image,
+    Background,
+    Icon,
+    Font,
+}
+
+/// External resource referenced by a template.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Asset {
+    pub kind: AssetKind,
+    pub url: String,
+    /// Tag of the element referencing the asset.
+    pub tag: String,
+    /// Location of the element in the template, like
+    /// `/mjml/mj-body/mj-section[1]/mj-column[1]/mj-image[1]`.
+    pub path: String,
+}
+
+/// Attributes of the body elements containing an url, with the kind of
+/// asset they reference.
+const BODY_ATTRIBUTES: [(&str, &str, AssetKind); 9] = [
+    ("mj-image", "src", AssetKind::Image),
+    ("mj-carousel-image", "src", AssetKind::Image),
+    ("mj-carousel-image", "thumbnails-src", AssetKind::Image),
+    ("mj-carousel", "left-icon", AssetKind::Icon),
+    ("mj-carousel", "right-icon", AssetKind::Icon),
+    ("mj-social-element", "src", AssetKind::Icon),
+    ("mj-accordion-title", "icon-wrapped-url", AssetKind::Icon),
+    ("mj-accordion-title", "icon-unwrapped-url", AssetKind::Icon),
+    ("img", "src", AssetKind::Image),
+];
+
+fn push(result: &mut Vec<Asset>, kind: AssetKind, url: &str, tag: &str, path: &str) {
+    if url.trim().is_empty() {
+        return;
+    }
+    result.push(Asset {
+        kind,
+        url: url.to_string(),
+        tag: tag.to_string(),
+        path: path.to_string(),
+    });
+}
+
+#[cfg(feature = "render")]
+fn default_social_icon(element: &TreeElement<'_>) -> Option<String> {
+    use crate::mj_social_element::network::SocialNetwork;
+    use crate::mj_social_element::DEFAULT_ICON_ORIGIN;
+
+    if element.tag != crate::mj_social_element::NAME || element.attribute("src").is_some() {
+        return None;
+    }
+    element
+        .attribute("name")
+        .and_then(SocialNetwork::find)
+        .map(|network| network.icon_src(DEFAULT_ICON_ORIGIN))
+}
+
+#[cfg(not(feature = "render"))]
+fn default_social_icon(_element: &TreeElement<'_>) -> Option<String> {
+    None
+}
+
+impl Mjml {
+    /// Lists the assets referenced by the template: the images, the
+    /// background images, the icons and the fonts declared with `mj-font`.
+    ///
+    /// Only the values written in the template are considered, except for
+    /// the default icons of the social networks.
+    pub fn extract_assets(&self) -> Vec<Asset> {
+        let mut result = Vec::new();
+        if let Some(head) = self.head() {
+            let (mut fonts, mut includes) = (0, 0);
+            for child in head.children.iter() {
+                match child {
+                    MjHeadChild::MjFont(font) => {
+                        fonts += 1;
+                        let path = format!("/mjml/mj-head/mj-font[{fonts}]");
+                        push(&mut result, AssetKind::Font, font.href(), "mj-font", &path);
+                    }
+                    MjHeadChild::MjInclude(include) => {
+                        includes += 1;
+                        let fonts = include.0.children.iter().filter_map(|item| match item {
+                            MjIncludeHeadChild::MjFont(font) => Some(font),
+                            _ => None,
+                        });
+                        for (index, font) in fonts.enumerate() {
+                            let path = format!(
+                                "/mjml/mj-head/mj-include[{includes}]/mj-font[{}]",
+                                index + 1
+                            );
+                            push(&mut result, AssetKind::Font, font.href(), "mj-font", &path);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        let Some(body) = self.body_tree() else {
+            return result;
+        };
+        body.walk_with_path(BODY_PATH, &mut |element, path, _| {
+            if let Some(url) = element.attribute("background-url") {
+                push(&mut result, AssetKind::Background, url, element.tag, path);
+            }
+            for (tag, name, kind) in BODY_ATTRIBUTES.iter() {
+                if *tag == element.tag {
+                    if let Some(url) = element.attribute(name) {
+                        push(&mut result, *kind, url, element.tag, path);
+                    }
+                }
+            }
+            if let Some(url) = default_social_icon(element) {
+                push(&mut result, AssetKind::Icon, &url, element.tag, path);
+            }
+        });
+        result
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use super::AssetKind;
+    use crate::mjml::Mjml;
+
+    #[test]
+    fn should_extract_assets() {
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-title>Hello</mj-title>
+    <mj-font name="Raleway" href="https://fonts.googleapis.com/css?family=Raleway" />
+  </mj-head>
+  <mj-body>
+    <mj-section background-url="https://example.com/background.png">
+      <mj-column>
+        <mj-image src="https://example.com/logo.png" />
+        <mj-text><img src="https://example.com/inline.png" /></mj-text>
+        <mj-social>
+          <mj-social-element name="custom" src="https://example.com/custom.png" />
+        </mj-social>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let assets = root.element.extract_assets();
+        let summary = assets
+            .iter()
+            .map(|item| (item.kind, item.url.as_str(), item.path.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    AssetKind::Font,
+                    "https://fonts.googleapis.com/css?family=Raleway",
+                    "/mjml/mj-head/mj-font[1]"
+                ),
+                (
+                    AssetKind::Background,
+                    "https://example.com/background.png",
+                    "/mjml/mj-body/mj-section[1]"
+                ),
+                (
+                    AssetKind::Image,
+                    "https://example.com/logo.png",
+                    "/mjml/mj-body/mj-section[1]/mj-column[1]/mj-image[1]"
+                ),
+                (
+                    AssetKind::Image,
+                    "https://example.com/inline.png",
+                    "/mjml/mj-body/mj-section[1]/mj-column[1]/mj-text[1]/img[1]"
+                ),
+                (
+                    AssetKind::Icon,
+                    "https://example.com/custom.png",
+                    "/mjml/mj-body/mj-section[1]/mj-column[1]/mj-social[1]/mj-social-element[1]"
+                ),
+            ]
+        );
+    }
+
+    #[cfg(feature = "render")]
+    #[test]
+    fn should_extract_default_social_icons() {
+        let template = r#"<mjml><mj-body><mj-social>
+  <mj-social-element name="github" href="https://github.com" />
+</mj-social></mj-body></mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let assets = root.element.extract_assets();
+        assert_eq!(assets.len(), 1);
+        assert_eq!(assets[0].kind, AssetKind::Icon);
+        assert!(assets[0].url.ends_with("github.png"));
+    }
+}
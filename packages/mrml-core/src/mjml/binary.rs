@@ -0,0 +1,61 @@
+//! Compact binary serializations of the templates, to store them once parsed
+//! and skip the parsing when rendering them. They follow the structure of the
+//! json serialization, so a template serialized by a version of the crate can
+//! be read by the next ones in the same conditions as the json.
+
+use super::Mjml;
+
+impl Mjml {
+    /// Serializes the template in MessagePack, with the names of the fields.
+    #[cfg(feature = "msgpack")]
+    pub fn to_msgpack(&self) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+        rmp_serde::to_vec_named(self)
+    }
+
+    #[cfg(feature = "msgpack")]
+    pub fn from_msgpack(input: &[u8]) -> Result<Self, rmp_serde::decode::Error> {
+        rmp_serde::from_slice(input)
+    }
+
+    /// Serializes the template in CBOR.
+    #[cfg(feature = "cbor")]
+    pub fn to_cbor(&self) -> Result<Vec<u8>, ciborium::ser::Error<std::io::Error>> {
+        let mut result = Vec::new();
+        ciborium::into_writer(self, &mut result)?;
+        Ok(result)
+    }
+
+    #[cfg(feature = "cbor")]
+    pub fn from_cbor(input: &[u8]) -> Result<Self, ciborium::de::Error<std::io::Error>> {
+        ciborium::from_reader(input)
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use crate::mjml::Mjml;
+
+    const TEMPLATE: &str = include_str!("../../resources/template/air-astana.mjml");
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn should_roundtrip_msgpack() {
+        let root = Mjml::parse(TEMPLATE).unwrap();
+        let bytes = root.element.to_msgpack().unwrap();
+        let json = serde_json::to_string(&root.element).unwrap();
+        assert!(bytes.len() < json.len());
+        let result = Mjml::from_msgpack(&bytes).unwrap();
+        assert_eq!(serde_json::to_string(&result).unwrap(), json);
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn should_roundtrip_cbor() {
+        let root = Mjml::parse(TEMPLATE).unwrap();
+        let bytes = root.element.to_cbor().unwrap();
+        let json = serde_json::to_string(&root.element).unwrap();
+        assert!(bytes.len() < json.len());
+        let result = Mjml::from_cbor(&bytes).unwrap();
+        assert_eq!(serde_json::to_string(&result).unwrap(), json);
+    }
+}
@@ -0,0 +1,302 @@
+//! Conversion of the templates from and to the json format of mjml, used by
+//! the visual editors, where each element is written like
+//! `{"tagName":"mj-section","attributes":{},"children":[]}`.
+//!
+//! The elements containing html, like `mj-text` or `mj-button`, have their
+//! html in a `content` string instead of `children`. The comments and the
+//! texts directly in the other elements can't be written in this format and
+//! are dropped, as well as the content of the included templates, the
+//! `mj-include` being kept.
+//!
+//! ```rust
+//! # #[cfg(feature = "parse")]
+//! # {
+//! use mrml::mjml::Mjml;
+//!
+//! let root = mrml::parse(r#"<mjml lang="en"><mj-body width="500px"><mj-text>Hello <b>World</b></mj-text></mj-body></mjml>"#).unwrap();
+//! let json = root.element.to_editor_json().unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"{"tagName":"mjml","attributes":{"lang":"en"},"children":[{"tagName":"mj-body","attributes":{"width":"500px"},"children":[{"tagName":"mj-text","attributes":{},"content":"Hello <b>World</b>"}]}]}"#
+//! );
+//! let root = Mjml::from_editor_json(&json).unwrap();
+//! assert_eq!(root.element.attributes.lang.as_deref(), Some("en"));
+//! # }
+//! ```
+
+use serde_json::{Map, Value};
+
+/// Elements written with their html in the `content` string.
+const ENDING_TAGS: [&str; 15] = [
+    crate::mj_accordion_text::NAME,
+    crate::mj_accordion_title::NAME,
+    crate::mj_badge::NAME,
+    crate::mj_button::NAME,
+    crate::mj_code::NAME,
+    crate::mj_navbar_link::NAME,
+    crate::mj_preview::NAME,
+    crate::mj_quote::NAME,
+    crate::mj_raw::NAME,
+    crate::mj_social_element::NAME,
+    crate::mj_stat::NAME,
+    crate::mj_style::NAME,
+    crate::mj_table::NAME,
+    crate::mj_text::NAME,
+    crate::mj_title::NAME,
+];
+
+const COMMENT: &str = "comment";
+
+pub(super) fn is_ending_tag(tag: &str) -> bool {
+    ENDING_TAGS.contains(&tag)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum EditorJsonError {
+    #[error("invalid json: {0}")]
+    Json(
+        #[from]
+        #[source]
+        serde_json::Error,
+    ),
+    #[error("invalid element {0}, expected an object with a tagName")]
+    InvalidElement(String),
+    #[cfg(feature = "parse")]
+    #[error("invalid template: {0}")]
+    Parser(
+        #[from]
+        #[source]
+        crate::prelude::parser::Error,
+    ),
+}
+
+fn element_type(value: &Value) -> Option<&str> {
+    value.get("type").and_then(Value::as_str)
+}
+
+fn escape_attribute(value: &str) -> String {
+    value.replace('"', "&quot;")
+}
+
+/// Html of the children of an element, in the json structure of mrml.
+fn push_html(buffer: &mut String, value: &Value) {
+    match value {
+        Value::String(text) => buffer.push_str(text),
+        Value::Array(items) => items.iter().for_each(|item| push_html(buffer, item)),
+        Value::Object(_) if element_type(value) == Some(COMMENT) => {
+            buffer.push_str("<!--");
+            push_html(buffer, &value["children"]);
+            buffer.push_str("-->");
+        }
+        Value::Object(_) => {
+            let Some(tag) = element_type(value) else {
+                return;
+            };
+            buffer.push('<');
+            buffer.push_str(tag);
+            if let Some(attributes) = value.get("attributes").and_then(Value::as_object) {
+                for (name, value) in attributes.iter() {
+                    if let Some(value) = value.as_str() {
+                        buffer.push_str(&format!(" {name}=\"{}\"", escape_attribute(value)));
+                    }
+                }
+            }
+            match value.get("children") {
+                Some(children) => {
+                    buffer.push('>');
+                    push_html(buffer, children);
+                    buffer.push_str(&format!("</{tag}>"));
+                }
+                None => buffer.push_str(" />"),
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Converts an element from the json structure of mrml.
+pub(super) fn to_editor(value: &Value) -> Option<Value> {
+    let tag = element_type(value).filter(|tag| *tag != COMMENT)?;
+    let mut result = Map::new();
+    result.insert("tagName".into(), Value::String(tag.to_string()));
+    result.insert(
+        "attributes".into(),
+        value
+            .get("attributes")
+            .cloned()
+            .unwrap_or_else(|| Value::Object(Map::new())),
+    );
+    let children = value.get("children");
+    if is_ending_tag(tag) {
+        let mut content = String::new();
+        if let Some(children) = children {
+            push_html(&mut content, children);
+        }
+        result.insert("content".into(), Value::String(content));
+    } else if tag != crate::mj_include::NAME {
+        let children = children
+            .and_then(Value::as_array)
+            .map(|items| items.iter().filter_map(to_editor).collect())
+            .unwrap_or_default();
+        result.insert("children".into(), Value::Array(children));
+    }
+    Some(Value::Object(result))
+}
+
+/// Writes an element of the json format of mjml as a template.
+#[cfg(feature = "parse")]
+fn push_template(buffer: &mut String, value: &Value) -> Result<(), EditorJsonError> {
+    let tag = value
+        .get("tagName")
+        .and_then(Value::as_str)
+        .ok_or_else(|| EditorJsonError::InvalidElement(value.to_string()))?;
+    buffer.push('<');
+    buffer.push_str(tag);
+    if let Some(attributes) = value.get("attributes").and_then(Value::as_object) {
+        for (name, value) in attributes.iter() {
+            let value = match value {
+                Value::String(inner) => inner.clone(),
+                Value::Null => continue,
+                other => other.to_string(),
+            };
+            buffer.push_str(&format!(" {name}=\"{}\"", escape_attribute(&value)));
+        }
+    }
+    buffer.push('>');
+    if let Some(content) = value.get("content").and_then(Value::as_str) {
+        buffer.push_str(content);
+    }
+    if let Some(children) = value.get("children").and_then(Value::as_array) {
+        for child in children.iter() {
+            push_template(buffer, child)?;
+        }
+    }
+    buffer.push_str(&format!("</{tag}>"));
+    Ok(())
+}
+
+impl super::Mjml {
+    /// Converts the template to the json format of mjml.
+    pub fn to_editor_json_value(&self) -> Result<Value, serde_json::Error> {
+        let value = serde_json::to_value(self)?;
+        Ok(to_editor(&value).unwrap_or_default())
+    }
+
+    /// Serializes the template in the json format of mjml.
+    pub fn to_editor_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&self.to_editor_json_value()?)
+    }
+
+    /// Builds a template from the json format of mjml, like the documents of
+    /// the visual editors.
+    #[cfg(feature = "parse")]
+    pub fn from_editor_json_value(
+        value: &Value,
+    ) -> Result<crate::prelude::parser::ParseOutput<Self>, EditorJsonError> {
+        let mut template = String::new();
+        push_template(&mut template, value)?;
+        Ok(Self::parse(template)?)
+    }
+
+    /// Deserializes a template in the json format of mjml.
+    #[cfg(feature = "parse")]
+    pub fn from_editor_json(
+        input: &str,
+    ) -> Result<crate::prelude::parser::ParseOutput<Self>, EditorJsonError> {
+        let value: Value = serde_json::from_str(input)?;
+        Self::from_editor_json_value(&value)
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use crate::mjml::Mjml;
+
+    #[test]
+    fn should_convert_to_the_editor_format() {
+        let template = r#"<mjml dir="rtl">
+  <mj-head><mj-title>Hello</mj-title><mj-attributes><mj-all padding="0" /></mj-attributes></mj-head>
+  <mj-body background-color="red">
+    <!-- dropped -->
+    <mj-section><mj-column><mj-button href="https://mrml.io">Go <b>now</b></mj-button><mj-image src="a.png" /></mj-column></mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let value = root.element.to_editor_json_value().unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "tagName": "mjml",
+                "attributes": { "dir": "rtl" },
+                "children": [
+                    {
+                        "tagName": "mj-head",
+                        "attributes": {},
+                        "children": [
+                            { "tagName": "mj-title", "attributes": {}, "content": "Hello" },
+                            {
+                                "tagName": "mj-attributes",
+                                "attributes": {},
+                                "children": [
+                                    { "tagName": "mj-all", "attributes": { "padding": "0" }, "children": [] },
+                                ],
+                            },
+                        ],
+                    },
+                    {
+                        "tagName": "mj-body",
+                        "attributes": { "background-color": "red" },
+                        "children": [{
+                            "tagName": "mj-section",
+                            "attributes": {},
+                            "children": [{
+                                "tagName": "mj-column",
+                                "attributes": {},
+                                "children": [
+                                    {
+                                        "tagName": "mj-button",
+                                        "attributes": { "href": "https://mrml.io" },
+                                        "content": "Go <b>now</b>",
+                                    },
+                                    { "tagName": "mj-image", "attributes": { "src": "a.png" }, "children": [] },
+                                ],
+                            }],
+                        }],
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn should_convert_from_the_editor_format() {
+        let json = r#"{
+  "tagName": "mjml",
+  "attributes": { "lang": "fr", "owa": "desktop" },
+  "children": [{
+    "tagName": "mj-body",
+    "attributes": { "width": "500px" },
+    "children": [{
+      "tagName": "mj-section",
+      "attributes": { "padding-top": 10 },
+      "children": [{
+        "tagName": "mj-column",
+        "attributes": {},
+        "children": [{ "tagName": "mj-text", "attributes": { "font-family": "\"Open Sans\"" }, "content": "<p>Hello</p>" }]
+      }]
+    }]
+  }]
+}"#;
+        let root = Mjml::from_editor_json(json).unwrap().element;
+        assert_eq!(root.attributes.lang.as_deref(), Some("fr"));
+        assert_eq!(root.attributes.owa.as_deref(), Some("desktop"));
+        let body = root.body().unwrap();
+        assert_eq!(body.attributes.get("width").unwrap(), "500px");
+        let value = root.to_editor_json_value().unwrap();
+        let section = &value["children"][0]["children"][0];
+        assert_eq!(section["attributes"]["padding-top"], "10");
+        let text = &section["children"][0]["children"][0];
+        assert_eq!(text["content"], "<p>Hello</p>");
+        assert!(Mjml::from_editor_json(r#"{"attributes":{}}"#).is_err());
+    }
+}
@@ -0,0 +1,294 @@
+//! Conversion of the templates from and to the components of the mjml plugin
+//! of GrapesJS, where each element is written like
+//! `{"type":"mj-section","attributes":{},"components":[]}`.
+//!
+//! The elements containing html, like `mj-text`, are exported with their html
+//! in `content`. When importing, their `components` can also be an html
+//! string or the components GrapesJS builds from it, like
+//! `{"type":"textnode","content":"Hello"}`. The project data, with its
+//! `pages`, is accepted as well.
+//!
+//! ```rust
+//! # #[cfg(feature = "parse")]
+//! # {
+//! use mrml::mjml::Mjml;
+//!
+//! let root = mrml::parse("<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>").unwrap();
+//! let project = root.element.to_grapesjs_project().unwrap();
+//! let root = Mjml::from_grapesjs_value(&project).unwrap();
+//! assert!(root.element.body().is_some());
+//! # }
+//! ```
+
+use serde_json::{Map, Value};
+
+use super::editor::{to_editor, EditorJsonError};
+
+const TEXT_NODE: &str = "textnode";
+const COMMENT: &str = "comment";
+const WRAPPER: &str = "wrapper";
+
+/// Converts an element of the json format of mjml to a component.
+fn to_component(value: &Value) -> Value {
+    let mut result = Map::new();
+    if let Some(tag) = value.get("tagName") {
+        result.insert("type".into(), tag.clone());
+    }
+    if let Some(attributes) = value.get("attributes") {
+        result.insert("attributes".into(), attributes.clone());
+    }
+    if let Some(content) = value.get("content") {
+        result.insert("content".into(), content.clone());
+    }
+    if let Some(children) = value.get("children").and_then(Value::as_array) {
+        result.insert(
+            "components".into(),
+            Value::Array(children.iter().map(to_component).collect()),
+        );
+    }
+    Value::Object(result)
+}
+
+fn component_type(value: &Value) -> Option<&str> {
+    value
+        .get("type")
+        .or_else(|| value.get("tagName"))
+        .and_then(Value::as_str)
+}
+
+/// Html of the components built by GrapesJS from the content of an element.
+fn push_html(buffer: &mut String, value: &Value) {
+    match value {
+        Value::String(html) => buffer.push_str(html),
+        Value::Array(items) => items.iter().for_each(|item| push_html(buffer, item)),
+        Value::Object(_) => {
+            let content = value.get("content").and_then(Value::as_str);
+            let tag = value.get("tagName").and_then(Value::as_str);
+            match (value.get("type").and_then(Value::as_str), tag) {
+                (Some(TEXT_NODE), _) | (None, None) => {
+                    buffer.push_str(content.unwrap_or_default());
+                }
+                (Some(COMMENT), _) => {
+                    buffer.push_str("<!--");
+                    buffer.push_str(content.unwrap_or_default());
+                    buffer.push_str("-->");
+                }
+                (kind, tag) => {
+                    let tag = tag.or(kind).unwrap_or("span");
+                    buffer.push('<');
+                    buffer.push_str(tag);
+                    if let Some(attributes) = value.get("attributes").and_then(Value::as_object) {
+                        for (name, value) in attributes.iter() {
+                            if let Some(value) = value.as_str() {
+                                buffer.push_str(&format!(
+                                    " {name}=\"{}\"",
+                                    value.replace('"', "&quot;")
+                                ));
+                            }
+                        }
+                    }
+                    buffer.push('>');
+                    buffer.push_str(content.unwrap_or_default());
+                    if let Some(components) = value.get("components") {
+                        push_html(buffer, components);
+                    }
+                    buffer.push_str(&format!("</{tag}>"));
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Finds the `mjml` component in the project data or in the wrapper.
+fn find_root(value: &Value) -> Option<&Value> {
+    if let Some(pages) = value.get("pages").and_then(Value::as_array) {
+        let component = pages
+            .first()?
+            .get("frames")?
+            .as_array()?
+            .first()?
+            .get("component")?;
+        return find_root(component);
+    }
+    match component_type(value) {
+        Some(WRAPPER) => value
+            .get("components")?
+            .as_array()?
+            .iter()
+            .find(|item| component_type(item) == Some(super::NAME)),
+        _ => Some(value),
+    }
+}
+
+/// Converts a component to an element of the json format of mjml.
+fn from_component(value: &Value) -> Result<Value, EditorJsonError> {
+    let tag =
+        component_type(value).ok_or_else(|| EditorJsonError::InvalidElement(value.to_string()))?;
+    let mut result = Map::new();
+    result.insert("tagName".into(), Value::String(tag.to_string()));
+    result.insert(
+        "attributes".into(),
+        value
+            .get("attributes")
+            .cloned()
+            .unwrap_or_else(|| Value::Object(Map::new())),
+    );
+    let components = value.get("components");
+    if super::editor::is_ending_tag(tag) {
+        let mut content = value
+            .get("content")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        if let Some(components) = components {
+            push_html(&mut content, components);
+        }
+        result.insert("content".into(), Value::String(content));
+    } else if let Some(components) = components.and_then(Value::as_array) {
+        let children = components
+            .iter()
+            .filter(|item| !matches!(component_type(item), Some(TEXT_NODE | COMMENT)))
+            .map(from_component)
+            .collect::<Result<Vec<_>, _>>()?;
+        result.insert("children".into(), Value::Array(children));
+    }
+    Ok(Value::Object(result))
+}
+
+impl super::Mjml {
+    /// Converts the template to the `mjml` component of GrapesJS.
+    pub fn to_grapesjs_value(&self) -> Result<Value, serde_json::Error> {
+        let value = serde_json::to_value(self)?;
+        Ok(to_editor(&value)
+            .map(|item| to_component(&item))
+            .unwrap_or_default())
+    }
+
+    /// Converts the template to the project data of GrapesJS, loaded with
+    /// `editor.loadProjectData`.
+    pub fn to_grapesjs_project(&self) -> Result<Value, serde_json::Error> {
+        let root = self.to_grapesjs_value()?;
+        Ok(serde_json::json!({
+            "assets": [],
+            "styles": [],
+            "pages": [{
+                "frames": [{
+                    "component": {
+                        "type": WRAPPER,
+                        "components": [root],
+                    },
+                }],
+            }],
+        }))
+    }
+
+    /// Builds a template from the `mjml` component of GrapesJS or from its
+    /// project data.
+    #[cfg(feature = "parse")]
+    pub fn from_grapesjs_value(
+        value: &Value,
+    ) -> Result<crate::prelude::parser::ParseOutput<Self>, EditorJsonError> {
+        let root =
+            find_root(value).ok_or_else(|| EditorJsonError::InvalidElement(value.to_string()))?;
+        Self::from_editor_json_value(&from_component(root)?)
+    }
+
+    /// Deserializes a template exported by GrapesJS.
+    #[cfg(feature = "parse")]
+    pub fn from_grapesjs_json(
+        input: &str,
+    ) -> Result<crate::prelude::parser::ParseOutput<Self>, EditorJsonError> {
+        let value: Value = serde_json::from_str(input)?;
+        Self::from_grapesjs_value(&value)
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use crate::mjml::Mjml;
+
+    #[test]
+    fn should_export_components() {
+        let root = Mjml::parse(
+            r#"<mjml><mj-body><mj-section padding="0"><mj-column><mj-text>Hello <b>World</b></mj-text></mj-column></mj-section></mj-body></mjml>"#,
+        )
+        .unwrap();
+        assert_eq!(
+            root.element.to_grapesjs_value().unwrap(),
+            serde_json::json!({
+                "type": "mjml",
+                "attributes": {},
+                "components": [{
+                    "type": "mj-body",
+                    "attributes": {},
+                    "components": [{
+                        "type": "mj-section",
+                        "attributes": { "padding": "0" },
+                        "components": [{
+                            "type": "mj-column",
+                            "attributes": {},
+                            "components": [{
+                                "type": "mj-text",
+                                "attributes": {},
+                                "content": "Hello <b>World</b>",
+                            }],
+                        }],
+                    }],
+                }],
+            })
+        );
+    }
+
+    #[test]
+    fn should_import_the_project_data() {
+        let project = r##"{
+  "assets": [],
+  "styles": [],
+  "pages": [{
+    "id": "page-1",
+    "frames": [{
+      "component": {
+        "type": "wrapper",
+        "stylable": ["background"],
+        "components": [{
+          "type": "mjml",
+          "components": [{
+            "type": "mj-body",
+            "components": [{
+              "type": "mj-section",
+              "attributes": { "background-color": "#eeeeee" },
+              "components": [{
+                "type": "mj-column",
+                "components": [
+                  {
+                    "type": "mj-text",
+                    "attributes": { "align": "center" },
+                    "components": [
+                      { "type": "textnode", "content": "Hello " },
+                      { "tagName": "b", "components": [{ "type": "textnode", "content": "World" }] }
+                    ]
+                  },
+                  { "type": "mj-button", "attributes": { "href": "https://mrml.io" }, "components": "Go" }
+                ]
+              }]
+            }]
+          }]
+        }]
+      }
+    }]
+  }]
+}"##;
+        let root = Mjml::from_grapesjs_json(project).unwrap().element;
+        let value = root.to_editor_json_value().unwrap();
+        let column = &value["children"][0]["children"][0]["children"][0];
+        assert_eq!(column["children"][0]["content"], "Hello <b>World</b>");
+        assert_eq!(column["children"][0]["attributes"]["align"], "center");
+        assert_eq!(column["children"][1]["content"], "Go");
+
+        let exported = root.to_grapesjs_project().unwrap();
+        let root = Mjml::from_grapesjs_value(&exported).unwrap().element;
+        assert_eq!(root.to_editor_json_value().unwrap(), value);
+        assert!(Mjml::from_grapesjs_json(r#"{"pages":[]}"#).is_err());
+    }
+}
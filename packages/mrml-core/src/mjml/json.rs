@@ -53,7 +53,9 @@ impl Serialize for MjmlChildren {
     where
         S: Serializer,
     {
-        let mut map = serializer.serialize_seq(Some(2))?;
+        let mut map = serializer.serialize_seq(Some(
+            self.head.is_some() as usize + self.body.is_some() as usize,
+        ))?;
         if let Some(ref head) = self.head {
             map.serialize_element(head)?;
         }
@@ -98,6 +100,76 @@ impl<'de> Deserialize<'de> for MjmlChildren {
     }
 }
 
+/// Version of the json structure of the templates, written by
+/// [`Mjml::to_versioned_json`]. It's increased each time the structure
+/// changes, with a migration from the previous version.
+pub const JSON_FORMAT_VERSION: u64 = 1;
+
+/// Migrations of the templates stored in json, the one at the index `n`
+/// converting a template from the version `n` to the version `n + 1`.
+///
+/// The templates stored before the versions were introduced have the
+/// version `0` and the same structure as the version `1`.
+const MIGRATIONS: [fn(serde_json::Value) -> serde_json::Value; JSON_FORMAT_VERSION as usize] =
+    [|value| value];
+
+#[derive(Debug, thiserror::Error)]
+pub enum JsonVersionError {
+    #[error("invalid json: {0}")]
+    Json(
+        #[from]
+        #[source]
+        serde_json::Error,
+    ),
+    #[error("unsupported format version {0}, expected at most {JSON_FORMAT_VERSION}")]
+    UnsupportedVersion(u64),
+}
+
+impl super::Mjml {
+    /// Serializes the template in json, with the version of its structure:
+    /// `{"version":1,"template":{"type":"mjml"}}`.
+    pub fn to_versioned_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&serde_json::json!({
+            "version": JSON_FORMAT_VERSION,
+            "template": self,
+        }))
+    }
+
+    /// Deserializes a template serialized with
+    /// [`Mjml::to_versioned_json`] by this version of the crate or a
+    /// previous one, or serialized without version, migrating it to the
+    /// current structure.
+    ///
+    /// ```rust
+    /// use mrml::mjml::Mjml;
+    ///
+    /// let stored = r#"{"type":"mjml","children":[{"type":"mj-body"}]}"#;
+    /// let root = Mjml::from_json_any_version(stored).unwrap();
+    /// assert!(root.body().is_some());
+    /// let stored = root.to_versioned_json().unwrap();
+    /// assert!(Mjml::from_json_any_version(&stored).unwrap().body().is_some());
+    /// ```
+    pub fn from_json_any_version(input: &str) -> Result<Self, JsonVersionError> {
+        let value: serde_json::Value = serde_json::from_str(input)?;
+        let (version, mut template) = match value {
+            serde_json::Value::Object(mut map) if map.contains_key("version") => {
+                let version = map.get("version").and_then(|v| v.as_u64());
+                let template = map.remove("template").unwrap_or_default();
+                (version, template)
+            }
+            other => (Some(0), other),
+        };
+        let version = version.ok_or(JsonVersionError::UnsupportedVersion(u64::MAX))?;
+        if version > JSON_FORMAT_VERSION {
+            return Err(JsonVersionError::UnsupportedVersion(version));
+        }
+        for migration in MIGRATIONS.iter().skip(version as usize) {
+            template = migration(template);
+        }
+        Ok(serde_json::from_value(template)?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::mjml::Mjml;
@@ -117,4 +189,25 @@ mod tests {
         let next = serde_json::to_string(&res).unwrap();
         assert_eq!(next, json);
     }
+
+    #[test]
+    fn versioned() {
+        let json = r#"{"type":"mjml","attributes":{"lang":"fr"},"children":[{"type":"mj-body","children":["Hello World!"]}]}"#;
+        let res = Mjml::from_json_any_version(json).unwrap();
+        let versioned = res.to_versioned_json().unwrap();
+        assert_eq!(versioned, format!(r#"{{"version":1,"template":{json}}}"#));
+        let res = Mjml::from_json_any_version(&versioned).unwrap();
+        assert_eq!(serde_json::to_string(&res).unwrap(), json);
+    }
+
+    #[test]
+    fn unsupported_version() {
+        let json = r#"{"version":42,"template":{"type":"mjml"}}"#;
+        assert!(matches!(
+            Mjml::from_json_any_version(json),
+            Err(super::JsonVersionError::UnsupportedVersion(42))
+        ));
+        let json = r#"{"version":"one","template":{"type":"mjml"}}"#;
+        assert!(Mjml::from_json_any_version(json).is_err());
+    }
 }
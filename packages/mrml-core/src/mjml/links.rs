@@ -0,0 +1,110 @@
+use super::Mjml;
+use crate::prelude::tree::BODY_PATH;
+
+/// Link found in the body of a template.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Link {
+    /// Value of the `href` attribute, as written in the template.
+    pub href: String,
+    /// Tag of the element holding the link, like `mj-button` or `a`.
+    pub tag: String,
+    /// Location of the element in the template, like
+    /// `/mjml/mj-body/mj-section[1]/mj-column[1]/mj-button[2]`.
+    pub path: String,
+    /// Text of the link, or the `alt` attribute of an image link.
+    pub text: String,
+    /// Text of the element containing the link.
+    pub surrounding_text: String,
+}
+
+impl Mjml {
+    /// Lists the links of the body, with their location and text, without
+    /// having to render the template.
+    pub fn extract_links(&self) -> Vec<Link> {
+        let mut result = Vec::new();
+        let Some(body) = self.body_tree() else {
+            return result;
+        };
+        body.walk_with_path(BODY_PATH, &mut |element, path, parent| {
+            let Some(href) = element.attribute("href") else {
+                return;
+            };
+            let text = element.text_content();
+            result.push(Link {
+                href: href.to_string(),
+                tag: element.tag.to_string(),
+                path: path.to_string(),
+                text: if text.is_empty() {
+                    element.attribute("alt").unwrap_or_default().to_string()
+                } else {
+                    text
+                },
+                surrounding_text: parent.text_content(),
+            });
+        });
+        result
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use crate::mjml::Mjml;
+
+    #[test]
+    fn should_extract_links() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-image src="logo.png" alt="Home" href="https://example.com" />
+        <mj-text>Don't want these emails? <a href="https://example.com/unsubscribe">Unsubscribe</a></mj-text>
+        <mj-button href="https://example.com/shop">Shop</mj-button>
+        <mj-button>No link</mj-button>
+        <mj-button href="https://example.com/blog">Blog</mj-button>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let links = root.element.extract_links();
+        let summary = links
+            .iter()
+            .map(|link| (link.href.as_str(), link.path.as_str(), link.text.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "https://example.com",
+                    "/mjml/mj-body/mj-section[1]/mj-column[1]/mj-image[1]",
+                    "Home"
+                ),
+                (
+                    "https://example.com/unsubscribe",
+                    "/mjml/mj-body/mj-section[1]/mj-column[1]/mj-text[1]/a[1]",
+                    "Unsubscribe"
+                ),
+                (
+                    "https://example.com/shop",
+                    "/mjml/mj-body/mj-section[1]/mj-column[1]/mj-button[1]",
+                    "Shop"
+                ),
+                (
+                    "https://example.com/blog",
+                    "/mjml/mj-body/mj-section[1]/mj-column[1]/mj-button[3]",
+                    "Blog"
+                ),
+            ]
+        );
+        assert_eq!(
+            links[1].surrounding_text,
+            "Don't want these emails? Unsubscribe"
+        );
+    }
+
+    #[test]
+    fn should_extract_nothing_without_body() {
+        let root = Mjml::parse("<mjml></mjml>").unwrap();
+        assert!(root.element.extract_links().is_empty());
+    }
+}
@@ -0,0 +1,150 @@
+use super::Mjml;
+use crate::mj_attributes::{MjAttributes, MjAttributesChild};
+use crate::mj_head::MjHeadChild;
+use crate::mj_include::head::MjIncludeHeadChild;
+
+/// Information about a template, gathered from the `mjml` element and the
+/// `mj-head` children.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Metadata<'a> {
+    pub title: Option<&'a str>,
+    pub preview: Option<&'a str>,
+    pub breakpoint: Option<&'a str>,
+    pub lang: Option<&'a str>,
+    pub dir: Option<&'a str>,
+    /// Font families used by the template, in order of appearance.
+    pub fonts: Vec<&'a str>,
+}
+
+fn push_font_families<'a>(value: &'a str, result: &mut Vec<&'a str>) {
+    for name in value.split(',') {
+        let name = name.trim().trim_matches(|c| c == '"' || c == '\'');
+        if !name.is_empty() && !result.contains(&name) {
+            result.push(name);
+        }
+    }
+}
+
+fn mj_attributes_font_families<'a>(element: &'a MjAttributes, result: &mut Vec<&'a str>) {
+    for child in element.children().iter() {
+        let attributes = match child {
+            MjAttributesChild::MjAttributesAll(inner) => inner.attributes(),
+            MjAttributesChild::MjAttributesClass(inner) => &inner.attributes.others,
+            MjAttributesChild::MjAttributesElement(inner) => inner.attributes(),
+        };
+        if let Some(value) = attributes.get("font-family") {
+            push_font_families(value, result);
+        }
+    }
+}
+
+impl Mjml {
+    pub fn lang(&self) -> Option<&str> {
+        self.attributes.lang.as_deref()
+    }
+
+    pub fn dir(&self) -> Option<&str> {
+        self.attributes.dir.as_deref()
+    }
+
+    pub fn breakpoint(&self) -> Option<&str> {
+        self.head()
+            .and_then(|head| head.breakpoint())
+            .map(|item| item.value())
+    }
+
+    /// Font families used by the template, from the `font-family` attributes
+    /// of the elements and of `mj-attributes`.
+    pub fn font_families(&self) -> Vec<&str> {
+        let mut result = Vec::new();
+        if let Some(head) = self.head() {
+            for child in head.children().iter() {
+                match child {
+                    MjHeadChild::MjAttributes(inner) => {
+                        mj_attributes_font_families(inner, &mut result);
+                    }
+                    MjHeadChild::MjInclude(inner) => {
+                        for item in inner.0.children.iter() {
+                            if let MjIncludeHeadChild::MjAttributes(inner) = item {
+                                mj_attributes_font_families(inner, &mut result);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        if let Some(body) = self.body_tree() {
+            body.walk(&mut |node, _| {
+                if let Some(value) = node
+                    .as_element()
+                    .and_then(|element| element.attribute("font-family"))
+                {
+                    push_font_families(value, &mut result);
+                }
+            });
+        }
+        result
+    }
+
+    pub fn metadata(&self) -> Metadata<'_> {
+        let head = self.head();
+        Metadata {
+            title: head
+                .and_then(|head| head.title())
+                .map(|item| item.content()),
+            preview: head
+                .and_then(|head| head.preview())
+                .map(|item| item.content()),
+            breakpoint: self.breakpoint(),
+            lang: self.lang(),
+            dir: self.dir(),
+            fonts: self.font_families(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use super::Metadata;
+    use crate::mjml::Mjml;
+
+    #[test]
+    fn should_extract_metadata() {
+        let template = r#"<mjml lang="fr" dir="ltr">
+  <mj-head>
+    <mj-title>Bonjour</mj-title>
+    <mj-preview>Les nouveautés</mj-preview>
+    <mj-breakpoint width="480px" />
+    <mj-attributes>
+      <mj-all font-family="Roboto, Arial" />
+    </mj-attributes>
+  </mj-head>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-text font-family="'Open Sans', Arial">Hello</mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        assert_eq!(
+            root.element.metadata(),
+            Metadata {
+                title: Some("Bonjour"),
+                preview: Some("Les nouveautés"),
+                breakpoint: Some("480px"),
+                lang: Some("fr"),
+                dir: Some("ltr"),
+                fonts: vec!["Roboto", "Arial", "Open Sans"],
+            }
+        );
+    }
+
+    #[test]
+    fn should_handle_empty_template() {
+        let root = Mjml::parse("<mjml></mjml>").unwrap();
+        assert_eq!(root.element.metadata(), Metadata::default());
+    }
+}
@@ -6,8 +6,20 @@ use crate::mj_body::MjBody;
 use crate::mj_head::MjHead;
 use crate::prelude::{Component, StaticTag};
 
+mod assets;
+#[cfg(any(feature = "msgpack", feature = "cbor"))]
+mod binary;
+#[cfg(feature = "json")]
+mod editor;
+#[cfg(feature = "json")]
+mod grapesjs;
 #[cfg(feature = "json")]
 mod json;
+mod links;
+#[cfg(feature = "render")]
+mod metadata;
+#[cfg(feature = "render")]
+mod normalize;
 #[cfg(feature = "parse")]
 pub mod parse;
 #[cfg(feature = "print")]
@@ -15,6 +27,17 @@ mod print;
 #[cfg(feature = "render")]
 mod render;
 
+pub use assets::{Asset, AssetKind};
+#[cfg(feature = "json")]
+pub use editor::EditorJsonError;
+#[cfg(feature = "json")]
+pub use json::{JsonVersionError, JSON_FORMAT_VERSION};
+pub use links::Link;
+#[cfg(feature = "render")]
+pub use metadata::Metadata;
+#[cfg(feature = "render")]
+pub use render::{PreparedRender, RenderedParts};
+
 pub const NAME: &str = "mjml";
 
 #[derive(Clone, Debug, Default)]
@@ -0,0 +1,462 @@
+use std::marker::PhantomData;
+
+use super::Mjml;
+use crate::comment::Comment;
+use crate::mj_accordion::MjAccordionChild;
+use crate::mj_accordion_element::MjAccordionElement;
+use crate::mj_body::MjBodyChild;
+use crate::mj_carousel::MjCarouselChild;
+use crate::mj_code::MjCode;
+use crate::mj_head::{MjHead, MjHeadChild};
+use crate::mj_include::body::{MjIncludeBody, MjIncludeBodyChild};
+use crate::mj_include::head::MjIncludeHeadChild;
+use crate::mj_navbar::MjNavbarChild;
+use crate::mj_raw::{MjRaw, MjRawChild};
+use crate::mj_social::MjSocialChild;
+use crate::mj_stats::MjStatsChild;
+use crate::mj_tabs::MjTabsChild;
+use crate::node::Node;
+use crate::prelude::hash::Map;
+use crate::prelude::{Component, StaticTag};
+use crate::text::Text;
+
+/// Elements in which the whitespaces are significant.
+const PREFORMATTED_ELEMENTS: [&str; 4] = ["pre", "textarea", "script", "style"];
+
+type AttributesMap = Map<String, String>;
+
+/// Attributes defined in the `mj-attributes` elements of the head.
+#[derive(Default)]
+struct Defaults {
+    all: AttributesMap,
+    classes: Map<String, AttributesMap>,
+    elements: Map<String, AttributesMap>,
+    /// Attributes of the `mj-attributes` with a `scope`, by scope.
+    scopes: Map<String, Defaults>,
+}
+
+fn to_owned_map(input: &Map<&str, &str>) -> AttributesMap {
+    input
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+fn to_owned_maps(input: Map<&str, Map<&str, &str>>) -> Map<String, AttributesMap> {
+    input
+        .iter()
+        .map(|(name, values)| (name.to_string(), to_owned_map(values)))
+        .collect()
+}
+
+fn extend_missing(attributes: &mut AttributesMap, defaults: Option<&AttributesMap>) {
+    for (key, value) in defaults.into_iter().flat_map(|item| item.iter()) {
+        if !attributes.contains_key(key) {
+            attributes.insert(key.clone(), value.clone());
+        }
+    }
+}
+
+impl Defaults {
+    fn new(head: Option<&MjHead>) -> Self {
+        head.map(|head| Self {
+            all: to_owned_map(&head.build_attributes_all()),
+            classes: to_owned_maps(head.build_attributes_class()),
+            elements: to_owned_maps(head.build_attributes_element()),
+            scopes: head
+                .attributes_scopes()
+                .into_iter()
+                .map(|scope| (scope.to_string(), Self::scoped(head, scope)))
+                .collect(),
+        })
+        .unwrap_or_default()
+    }
+
+    fn scoped(head: &MjHead, scope: &str) -> Self {
+        Self {
+            all: to_owned_map(&head.build_scoped_attributes_all(Some(scope))),
+            classes: to_owned_maps(head.build_scoped_attributes_class(Some(scope))),
+            elements: to_owned_maps(head.build_scoped_attributes_element(Some(scope))),
+            scopes: Map::new(),
+        }
+    }
+
+    /// Lists the classes by order of priority, like the renderer: the last
+    /// listed class first, each class followed by the ones of its own
+    /// `mj-class`.
+    fn expand_classes<'a>(&'a self, value: &'a str, result: &mut Vec<&'a str>) {
+        for name in value.split_whitespace().rev() {
+            if result.contains(&name) {
+                continue;
+            }
+            result.push(name);
+            if let Some(parents) = self.classes.get(name).and_then(|item| item.get("mj-class")) {
+                self.expand_classes(parents, result);
+            }
+        }
+    }
+
+    /// Adds the attributes the element inherits, following the same
+    /// precedence as the renderer: `mj-class`, then the element defaults,
+    /// then `mj-all`.
+    fn resolve(&self, tag: &str, attributes: &mut AttributesMap) {
+        if let Some(classes) = attributes.remove("mj-class") {
+            let mut names = Vec::new();
+            self.expand_classes(&classes, &mut names);
+            for (key, value) in names
+                .into_iter()
+                .filter_map(|name| self.classes.get(name))
+                .flat_map(|item| item.iter())
+            {
+                // the parent classes are already expanded in the names
+                if key != "mj-class" && !attributes.contains_key(key) {
+                    attributes.insert(key.clone(), value.clone());
+                }
+            }
+        }
+        extend_missing(attributes, self.elements.get(tag));
+        extend_missing(attributes, Some(&self.all));
+        attributes.sort_keys();
+    }
+}
+
+fn collapse_whitespaces(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut previous_whitespace = false;
+    for c in value.chars() {
+        if c.is_whitespace() {
+            if !previous_whitespace {
+                result.push(' ');
+            }
+            previous_whitespace = true;
+        } else {
+            result.push(c);
+            previous_whitespace = false;
+        }
+    }
+    result
+}
+
+/// Collapses the whitespaces of the texts and, when `trim` is set, removes
+/// the ones at the beginning and the end of the content.
+fn normalize_children<C: Normalize>(children: &mut Vec<C>, defaults: &Defaults, trim: bool) {
+    for child in children.iter_mut() {
+        child.normalize(defaults);
+    }
+    if trim {
+        if let Some(first) = children.first_mut() {
+            first.trim_text(true, false);
+        }
+        if let Some(last) = children.last_mut() {
+            last.trim_text(false, true);
+        }
+    }
+    children.retain(|child| !child.is_empty_text());
+}
+
+trait Normalize {
+    fn normalize(&mut self, defaults: &Defaults);
+
+    fn trim_text(&mut self, _start: bool, _end: bool) {}
+
+    fn is_empty_text(&self) -> bool {
+        false
+    }
+}
+
+impl<T: StaticTag, C: Normalize> Normalize for Component<PhantomData<T>, AttributesMap, Vec<C>> {
+    fn normalize(&mut self, defaults: &Defaults) {
+        defaults.resolve(T::static_tag(), &mut self.attributes);
+        normalize_children(&mut self.children, defaults, true);
+    }
+}
+
+impl<T: StaticTag> Normalize for Component<PhantomData<T>, AttributesMap, ()> {
+    fn normalize(&mut self, defaults: &Defaults) {
+        defaults.resolve(T::static_tag(), &mut self.attributes);
+    }
+}
+
+impl<T, C: Normalize> Normalize for Component<PhantomData<T>, (), Vec<C>> {
+    fn normalize(&mut self, defaults: &Defaults) {
+        normalize_children(&mut self.children, defaults, false);
+    }
+}
+
+/// The whitespaces of the code are kept.
+impl Normalize for MjCode {
+    fn normalize(&mut self, defaults: &Defaults) {
+        defaults.resolve(crate::mj_code::NAME, &mut self.attributes);
+    }
+}
+
+impl Normalize for MjRaw {
+    fn normalize(&mut self, defaults: &Defaults) {
+        normalize_children(&mut self.children, defaults, false);
+    }
+}
+
+impl<C: Normalize> Normalize for Node<C> {
+    fn normalize(&mut self, defaults: &Defaults) {
+        self.attributes.sort_keys();
+        if !PREFORMATTED_ELEMENTS
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case(&self.tag))
+        {
+            normalize_children(&mut self.children, defaults, false);
+        }
+    }
+}
+
+impl Normalize for Comment {
+    fn normalize(&mut self, _defaults: &Defaults) {}
+}
+
+impl Normalize for Text {
+    fn normalize(&mut self, _defaults: &Defaults) {
+        *self = Text::from(collapse_whitespaces(self.inner_str()));
+    }
+
+    fn trim_text(&mut self, start: bool, end: bool) {
+        let value = self.inner_str();
+        let value = if start { value.trim_start() } else { value };
+        let value = if end { value.trim_end() } else { value };
+        *self = Text::from(value);
+    }
+
+    fn is_empty_text(&self) -> bool {
+        self.inner_str().is_empty()
+    }
+}
+
+impl Normalize for MjAccordionElement {
+    fn normalize(&mut self, defaults: &Defaults) {
+        defaults.resolve(crate::mj_accordion_element::NAME, &mut self.attributes);
+        if let Some(ref mut title) = self.children.title {
+            title.normalize(defaults);
+        }
+        if let Some(ref mut text) = self.children.text {
+            text.normalize(defaults);
+        }
+    }
+}
+
+impl Normalize for MjIncludeBody {
+    fn normalize(&mut self, defaults: &Defaults) {
+        match self.0.attributes.scope {
+            Some(ref scope) => {
+                let empty = Defaults::default();
+                let scoped = defaults.scopes.get(scope).unwrap_or(&empty);
+                normalize_children(&mut self.0.children, scoped, false);
+            }
+            None => normalize_children(&mut self.0.children, defaults, false),
+        }
+    }
+}
+
+macro_rules! normalize_enum {
+    ($name:ident, $($variant:ident),+) => {
+        impl Normalize for $name {
+            fn normalize(&mut self, defaults: &Defaults) {
+                match self {
+                    $(Self::$variant(inner) => inner.normalize(defaults),)+
+                }
+            }
+
+            fn trim_text(&mut self, start: bool, end: bool) {
+                match self {
+                    $(Self::$variant(inner) => inner.trim_text(start, end),)+
+                }
+            }
+
+            fn is_empty_text(&self) -> bool {
+                match self {
+                    $(Self::$variant(inner) => inner.is_empty_text(),)+
+                }
+            }
+        }
+    };
+}
+
+normalize_enum!(
+    MjBodyChild,
+    Comment,
+    MjAccordion,
+    MjAvatar,
+    MjBadge,
+    MjButton,
+    MjCalendarButton,
+    MjCarousel,
+    MjCode,
+    MjColumn,
+    MjDivider,
+    MjFooter,
+    MjGroup,
+    MjHero,
+    MjInclude,
+    MjImage,
+    MjMap,
+    MjMsoOffice,
+    MjNavbar,
+    MjNotMso,
+    MjQuote,
+    MjRaw,
+    MjSection,
+    MjSocial,
+    MjSpacer,
+    MjStats,
+    MjTable,
+    MjTabs,
+    MjText,
+    MjWrapper,
+    Node,
+    Text
+);
+normalize_enum!(
+    MjIncludeBodyChild,
+    Comment,
+    MjAccordion,
+    MjAvatar,
+    MjBadge,
+    MjButton,
+    MjCalendarButton,
+    MjCarousel,
+    MjCode,
+    MjColumn,
+    MjDivider,
+    MjFooter,
+    MjGroup,
+    MjHero,
+    MjImage,
+    MjMap,
+    MjMsoOffice,
+    MjNavbar,
+    MjNotMso,
+    MjQuote,
+    MjRaw,
+    MjSection,
+    MjSocial,
+    MjSpacer,
+    MjStats,
+    MjTable,
+    MjTabs,
+    MjText,
+    MjWrapper,
+    Node,
+    Text
+);
+normalize_enum!(MjAccordionChild, Comment, MjAccordionElement);
+normalize_enum!(MjCarouselChild, Comment, MjCarouselImage);
+normalize_enum!(MjNavbarChild, Comment, MjNavbarLink);
+normalize_enum!(MjRawChild, Comment, Node, Text);
+normalize_enum!(MjSocialChild, Comment, MjSocialElement);
+normalize_enum!(MjStatsChild, Comment, MjStat);
+normalize_enum!(MjTabsChild, Comment, MjTab);
+
+impl Mjml {
+    /// Rewrites the template in a canonical form, to compare templates
+    /// regardless of how they are written: the attributes defined in
+    /// `mj-attributes` and `mj-class` are copied on each element, the
+    /// attributes are sorted and the whitespaces of the texts are collapsed
+    /// and trimmed at the edges of the components.
+    ///
+    /// The rendered html stays the same, except for the whitespaces.
+    pub fn normalize(&mut self) {
+        let defaults = Defaults::new(self.children.head.as_ref());
+        if let Some(ref mut head) = self.children.head {
+            head.children
+                .retain(|child| !matches!(child, MjHeadChild::MjAttributes(_)));
+            for child in head.children.iter_mut() {
+                if let MjHeadChild::MjInclude(inner) = child {
+                    inner
+                        .0
+                        .children
+                        .retain(|item| !matches!(item, MjIncludeHeadChild::MjAttributes(_)));
+                }
+            }
+        }
+        if let Some(ref mut body) = self.children.body {
+            body.normalize(&defaults);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "parse", feature = "print"))]
+mod tests {
+    use crate::mjml::Mjml;
+    use crate::prelude::print::Printable;
+
+    fn normalize(template: &str) -> String {
+        let mut root = Mjml::parse(template).unwrap().element;
+        root.normalize();
+        root.print_dense().unwrap()
+    }
+
+    #[test]
+    fn should_expand_attributes() {
+        let result = normalize(
+            r#"<mjml>
+  <mj-head>
+    <mj-attributes>
+      <mj-all font-family="Arial" />
+      <mj-text color="red" padding="0" />
+      <mj-class name="big" font-size="20px" color="blue" />
+    </mj-attributes>
+  </mj-head>
+  <mj-body>
+    <mj-text mj-class="big" padding="10px">Hello</mj-text>
+  </mj-body>
+</mjml>"#,
+        );
+        assert_eq!(
+            result,
+            r#"<mjml><mj-head /><mj-body font-family="Arial"><mj-text color="blue" font-family="Arial" font-size="20px" padding="10px">Hello</mj-text></mj-body></mjml>"#
+        );
+    }
+
+    #[test]
+    fn should_expand_composed_classes() {
+        let result = normalize(
+            r#"<mjml>
+  <mj-head>
+    <mj-attributes>
+      <mj-class name="base" color="gray" padding="0" />
+      <mj-class name="title" mj-class="base" color="black" />
+      <mj-class name="red" color="red" />
+    </mj-attributes>
+  </mj-head>
+  <mj-body>
+    <mj-text mj-class="title">Hello</mj-text>
+    <mj-text mj-class="title red">Hello</mj-text>
+  </mj-body>
+</mjml>"#,
+        );
+        assert_eq!(
+            result,
+            r#"<mjml><mj-head /><mj-body><mj-text color="black" padding="0">Hello</mj-text><mj-text color="red" padding="0">Hello</mj-text></mj-body></mjml>"#
+        );
+    }
+
+    #[test]
+    fn should_compare_equivalent_templates() {
+        let first = normalize(
+            r#"<mjml><mj-body><mj-text align="left" color="red">
+      Hello    <b>World</b>
+    </mj-text></mj-body></mjml>"#,
+        );
+        let second = normalize(
+            r#"<mjml><mj-head><mj-attributes><mj-text color="red" /></mj-attributes></mj-head><mj-body><mj-text align="left">Hello <b>World</b></mj-text></mj-body></mjml>"#,
+        );
+        assert_eq!(
+            first.replace("<mj-head />", ""),
+            second.replace("<mj-head />", "")
+        );
+    }
+
+    #[test]
+    fn should_keep_preformatted_text() {
+        let result =
+            normalize("<mjml><mj-body><mj-text><pre>a\n    b</pre></mj-text></mj-body></mjml>");
+        assert!(result.contains("<pre>a\n    b</pre>"));
+    }
+}
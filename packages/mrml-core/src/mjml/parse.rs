@@ -3,12 +3,12 @@ use xmlparser::StrSpan;
 use super::{Mjml, MjmlAttributes, MjmlChildren};
 use crate::mj_body::NAME as MJ_BODY;
 use crate::mj_head::NAME as MJ_HEAD;
-#[cfg(feature = "async")]
-use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
-    Error, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren, ParseElement,
-    ParseOutput, ParserOptions, WarningKind,
+    mask_raw_tokens, Error, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren,
+    ParseElement, ParseOutput, ParserOptions, WarningKind,
 };
+#[cfg(feature = "async")]
+use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 
 #[inline(always)]
 fn parse_attributes(cursor: &mut MrmlCursor<'_>) -> Result<MjmlAttributes, Error> {
@@ -138,6 +138,7 @@ impl Mjml {
     ///
     /// let options = ParserOptions {
     ///     include_loader: Box::new(MemoryIncludeLoader::default()),
+    ///     ..Default::default()
     /// };
     /// match Mjml::parse_with_options("<mjml><mj-head /><mj-body /></mjml>", &options) {
     ///     Ok(_) => println!("Success!"),
@@ -148,8 +149,12 @@ impl Mjml {
         value: T,
         opts: &ParserOptions,
     ) -> Result<ParseOutput<Self>, Error> {
+        let _span = crate::trace::span!("mrml::parse", size = value.as_ref().len()).entered();
         let parser = MrmlParser::new(opts);
-        let mut cursor = MrmlCursor::new(value.as_ref());
+        // the size of the input is checked before masking it
+        let cursor = MrmlCursor::with_limits(value.as_ref(), opts.limits)?;
+        let masked = mask_raw_tokens(&opts.raw_token_patterns, value.as_ref());
+        let mut cursor = cursor.with_masked_source(value.as_ref(), &masked);
         let element = parser.parse_root(&mut cursor)?;
         Ok(ParseOutput {
             element,
@@ -162,39 +167,32 @@ impl Mjml {
         value: T,
         opts: std::sync::Arc<crate::prelude::parser::AsyncParserOptions>,
     ) -> Result<ParseOutput<Self>, Error> {
-        let parser = AsyncMrmlParser::new(opts);
-        let mut cursor = MrmlCursor::new(value.as_ref());
-        let element = parser.parse_root(&mut cursor).await?;
-        Ok(ParseOutput {
-            element,
-            warnings: cursor.warnings(),
+        let span = crate::trace::span!("mrml::parse", size = value.as_ref().len());
+        crate::trace::instrument(span, async move {
+            let parser = AsyncMrmlParser::new(opts);
+            let cursor = MrmlCursor::with_limits(value.as_ref(), parser.options.limits)?;
+            let masked = mask_raw_tokens(&parser.options.raw_token_patterns, value.as_ref());
+            let mut cursor = cursor.with_masked_source(value.as_ref(), &masked);
+            let element = parser.parse_root(&mut cursor).await?;
+            Ok::<_, Error>(ParseOutput {
+                element,
+                warnings: cursor.warnings(),
+            })
         })
+        .await
     }
 
     /// Function to parse a raw mjml template using the default parsing
     /// [options](crate::prelude::parser::ParserOptions).
     pub fn parse<T: AsRef<str>>(value: T) -> Result<ParseOutput<Self>, Error> {
-        let opts = ParserOptions::default();
-        let parser = MrmlParser::new(&opts);
-        let mut cursor = MrmlCursor::new(value.as_ref());
-        let element = parser.parse_root(&mut cursor)?;
-        Ok(ParseOutput {
-            element,
-            warnings: cursor.warnings(),
-        })
+        Self::parse_with_options(value, &ParserOptions::default())
     }
 
     #[cfg(feature = "async")]
     /// Function to parse a raw mjml template using the default parsing
     /// [options](crate::prelude::parser::ParserOptions).
     pub async fn async_parse<T: AsRef<str>>(value: T) -> Result<ParseOutput<Self>, Error> {
-        let parser = AsyncMrmlParser::default();
-        let mut cursor = MrmlCursor::new(value.as_ref());
-        let element = parser.parse_root(&mut cursor).await?;
-        Ok(ParseOutput {
-            element,
-            warnings: cursor.warnings(),
-        })
+        Self::async_parse_with_options(value, Default::default()).await
     }
 }
 
@@ -284,6 +282,28 @@ mod tests {
         assert_eq!(output.element.attributes.dir.unwrap(), "rtl");
     }
 
+    #[test]
+    fn should_ignore_prolog() {
+        let template = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE mjml>
+<?xml-stylesheet href="style.css"?>
+<mjml lang="fr"><mj-body /></mjml>"#;
+        let output = Mjml::parse(template).unwrap();
+        assert_eq!(output.element.attributes.lang.unwrap(), "fr");
+        assert!(output.element.children.body.is_some());
+        let output = crate::parse(template).unwrap();
+        assert!(output.element.children.body.is_some());
+    }
+
+    #[test]
+    fn should_ignore_doctype_with_entities() {
+        let template = r#"<!DOCTYPE mjml [
+  <!ENTITY company "Example">
+]><mjml><mj-body /></mjml>"#;
+        let output = Mjml::parse(template).unwrap();
+        assert!(output.element.children.body.is_some());
+    }
+
     #[test]
     fn should_not_fail_with_unknown_param() {
         let template = "<mjml unknown=\"true\"></mjml>";
@@ -1,6 +1,15 @@
+use std::fmt::Write;
+
 use super::Mjml;
 use crate::mj_head::MjHead;
 use crate::prelude::render::*;
+use crate::prelude::tree::TreeNode;
+
+/// Approximate size of the head, with the default styles.
+const HEAD_SIZE: usize = 2048;
+/// Approximate size of the html generated for each element of the body,
+/// including the tables and the conditional comments for outlook.
+const ELEMENT_SIZE: usize = 512;
 
 impl<'root> Render<'root> for Renderer<'root, Mjml, ()> {
     fn context(&self) -> &'root RenderContext<'root> {
@@ -11,9 +20,24 @@ impl<'root> Render<'root> for Renderer<'root, Mjml, ()> {
         if let Some(body) = self.element.body() {
             body.renderer(self.context).render(cursor)?;
         } else {
-            cursor.buffer.push_str("<body></body>");
+            self.render_missing_body(cursor)?;
         }
-        let mut body = RenderBuffer::default();
+        self.render_document(cursor).map(|_| ())
+    }
+}
+
+impl<'root> Renderer<'root, Mjml, ()> {
+    fn render_missing_body(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        cursor.buffer.push_str("<body>");
+        crate::mj_body::render_tracking_pixel(self.context.options, &mut cursor.buffer)?;
+        cursor.buffer.push_str("</body>");
+        Ok(())
+    }
+
+    /// Wraps the body, already rendered in the cursor, with the html element
+    /// and the head. Returns the position of the body in the output.
+    fn render_document(&self, cursor: &mut RenderCursor) -> Result<usize, Error> {
+        let mut body = cursor.buffer.empty(cursor.buffer.len() + HEAD_SIZE);
         std::mem::swap(&mut body, &mut cursor.buffer);
         cursor.buffer.push_str("<!doctype html>");
         cursor.buffer.open_tag("html");
@@ -30,14 +54,36 @@ impl<'root> Render<'root> for Renderer<'root, Mjml, ()> {
             .buffer
             .push_attribute("xmlns:o", "urn:schemas-microsoft-com:office:office")?;
         cursor.buffer.close_tag();
+        self.render_head(cursor)?;
+        let offset = cursor.buffer.len();
+        cursor.buffer.append(&body);
+        cursor.buffer.end_tag("html");
+        Ok(offset)
+    }
+
+    fn render_head(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         if let Some(head) = self.element.head() {
-            head.renderer(self.context).render(cursor)?;
+            head.renderer(self.context).render(cursor)
         } else {
-            MjHead::default().renderer(self.context).render(cursor)?;
+            MjHead::default().renderer(self.context).render(cursor)
         }
-        cursor.buffer.push_str(body.as_ref());
-        cursor.buffer.end_tag("html");
-        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_render(
+        &self,
+        cursor: &mut RenderCursor,
+        token: &CancellationToken,
+    ) -> Result<(), Error> {
+        if let Some(body) = self.element.body() {
+            Renderer::new(self.context, body, ())
+                .async_render(cursor, token)
+                .await?;
+        } else {
+            self.render_missing_body(cursor)?;
+        }
+        checkpoint(token).await?;
+        self.render_document(cursor).map(|_| ())
     }
 }
 
@@ -50,13 +96,386 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for Mjml {
     }
 }
 
+/// Checks if the html has to be modified once rendered.
+fn needs_postprocess(opts: &RenderOptions) -> bool {
+    opts.minify
+        || opts.web_url != WebUrl::Keep
+        || opts.entity_strategy != EntityStrategy::Raw
+        || opts.quoted_printable_friendly
+        || opts.max_line_length.is_some()
+        || opts.crlf_line_endings
+}
+
+fn postprocess(mut output: String, opts: &RenderOptions) -> String {
+    if opts.minify {
+        output = minify_html(&output);
+    }
+    if opts.web_url != WebUrl::Keep {
+        output = apply_web_url(&output, &opts.web_url);
+    }
+    if opts.entity_strategy != EntityStrategy::Raw {
+        output = encode_entities(&output, opts.entity_strategy);
+    }
+    if opts.quoted_printable_friendly {
+        output = quoted_printable_friendly(&output);
+    }
+    if let Some(max) = opts.max_line_length {
+        output = wrap_lines(&output, max);
+    }
+    if opts.crlf_line_endings {
+        output = crlf_line_endings(&output);
+    }
+    output
+}
+
+/// Content of the `head` and of the `body` of a rendered template, without
+/// the elements themselves, to be inserted in an existing html document.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RenderedParts {
+    pub head: String,
+    pub body: String,
+}
+
+/// Template ready to be rendered several times with the same options, like
+/// when sending the same email to many recipients. The values computed from
+/// the template, like the attributes of the `mj-attributes` and the size of
+/// the output, are only computed once. It can be shared between threads.
+///
+/// ```rust
+/// # #[cfg(feature = "parse")]
+/// # {
+/// use mrml::prelude::render::RenderOptions;
+///
+/// let root = mrml::parse("<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>").unwrap();
+/// let opts = RenderOptions::default();
+/// let prepared = root.element.prepare(&opts);
+/// for _ in 0..3 {
+///     assert!(prepared.render().unwrap().contains("Hello"));
+/// }
+/// # }
+/// ```
+pub struct PreparedRender<'a> {
+    element: &'a Mjml,
+    options: &'a RenderOptions,
+    header: Header<'a>,
+    capacity: usize,
+}
+
+impl<'a> PreparedRender<'a> {
+    pub fn render(&self) -> Result<String, Error> {
+        let context = RenderContext::new(self.options, self.header.clone());
+        self.element.render_with_capacity(&context, self.capacity)
+    }
+}
+
 impl Mjml {
+    /// Computes, once, what's needed to render the template with the
+    /// `opts`.
+    pub fn prepare<'a>(&'a self, opts: &'a RenderOptions) -> PreparedRender<'a> {
+        let header = Header::new(self.children.head.as_ref(), self.attributes.lang.as_deref());
+        PreparedRender {
+            element: self,
+            options: opts,
+            header,
+            capacity: opts
+                .buffer_capacity
+                .unwrap_or_else(|| self.estimate_render_size()),
+        }
+    }
+
     pub fn render(&self, opts: &RenderOptions) -> Result<String, Error> {
         let header = Header::new(self.children.head.as_ref(), self.attributes.lang.as_deref());
         let context = RenderContext::new(opts, header);
-        let mut cursor = RenderCursor::default();
-        self.renderer(&context).render(&mut cursor)?;
-        Ok(cursor.buffer.into())
+        self.render_with_context(&context)
+    }
+
+    /// Renders the template like [`Mjml::render`], the debug annotations
+    /// giving the line of the `source` defining each element of the body, like
+    /// `<!-- mj-text line 4 -->`. The `source` is the template the element was
+    /// parsed from.
+    #[cfg(feature = "parse")]
+    pub fn render_with_source(&self, opts: &RenderOptions, source: &str) -> Result<String, Error> {
+        let header = Header::new(self.children.head.as_ref(), self.attributes.lang.as_deref());
+        let mut context = RenderContext::new(opts, header);
+        if opts.debug_annotations {
+            context.paths.enable();
+            context.source_lines = source_map::source_lines(source);
+        }
+        self.render_with_context(&context)
+    }
+
+    /// Renders the template like [`Mjml::render`] and returns, next to the
+    /// html, the issues that were worked around while rendering.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parse")]
+    /// # {
+    /// let root = mrml::parse(r#"<mjml><mj-body><mj-image src="logo.png" width="big" /></mj-body></mjml>"#).unwrap();
+    /// let (html, warnings) = root.element.render_with_report(&Default::default()).unwrap();
+    /// for warning in warnings {
+    ///     eprintln!("{warning}");
+    /// }
+    /// # }
+    /// ```
+    pub fn render_with_report(
+        &self,
+        opts: &RenderOptions,
+    ) -> Result<(String, Vec<RenderWarning>), Error> {
+        let header = Header::new(self.children.head.as_ref(), self.attributes.lang.as_deref());
+        let context = RenderContext::new(opts, header);
+        context.paths.enable();
+        context.report.enable();
+        let output = self.render_with_context(&context)?;
+        let mut warnings: Vec<RenderWarning> = context
+            .report
+            .take()
+            .into_iter()
+            .map(|item| RenderWarning {
+                path: item.path,
+                kind: match item.value {
+                    Some(value) => RenderWarningKind::InvalidAttribute {
+                        name: item.name,
+                        value,
+                    },
+                    None => RenderWarningKind::MissingAttribute { name: item.name },
+                },
+            })
+            .collect();
+        if output.len() > GMAIL_CLIP_SIZE {
+            warnings.push(RenderWarning {
+                path: "/mjml".into(),
+                kind: RenderWarningKind::OversizedOutput {
+                    size: output.len(),
+                    limit: GMAIL_CLIP_SIZE,
+                },
+            });
+        }
+        if let Some(max) = opts.max_line_length {
+            warnings.extend(
+                long_lines(&output, max).map(|(line, length)| RenderWarning {
+                    path: "/mjml".into(),
+                    kind: RenderWarningKind::LongLine {
+                        line,
+                        length,
+                        limit: max,
+                    },
+                }),
+            );
+        }
+        Ok((output, warnings))
+    }
+
+    /// Renders the template like [`Mjml::render`] and returns, next to the
+    /// html, the [source map](crate::prelude::render::source_map) of the
+    /// elements of the body. The `source` is the template the element was
+    /// parsed from. The options modifying the html once rendered, like
+    /// `max_line_length` or `entity_strategy`, are ignored to keep the
+    /// positions of the output, and the render cache isn't used.
+    #[cfg(feature = "parse")]
+    pub fn render_with_source_map(
+        &self,
+        opts: &RenderOptions,
+        source: &str,
+    ) -> Result<(String, source_map::SourceMap), Error> {
+        let header = Header::new(self.children.head.as_ref(), self.attributes.lang.as_deref());
+        let mut context = RenderContext::new(opts, header);
+        context.paths.enable();
+        context.source_map.enable();
+        if opts.debug_annotations {
+            context.source_lines = source_map::source_lines(source);
+        }
+        let capacity = opts
+            .buffer_capacity
+            .unwrap_or_else(|| self.estimate_render_size());
+        let mut cursor = RenderCursor::with_capacity(capacity)
+            .with_conditional_comments(opts.conditional_comments.clone());
+        let renderer = Renderer::new(&context, self, ());
+        if let Some(body) = self.body() {
+            body.renderer(&context).render(&mut cursor)?;
+        } else {
+            renderer.render_missing_body(&mut cursor)?;
+        }
+        let offset = renderer.render_document(&mut cursor)?;
+        let source_map = context.source_map.build(source, offset);
+        Ok((cursor.buffer.into(), source_map))
+    }
+
+    fn render_with_context(&self, context: &RenderContext<'_>) -> Result<String, Error> {
+        let capacity = context
+            .options
+            .buffer_capacity
+            .unwrap_or_else(|| self.estimate_render_size());
+        self.render_with_capacity(context, capacity)
+    }
+
+    fn render_with_capacity(
+        &self,
+        context: &RenderContext<'_>,
+        capacity: usize,
+    ) -> Result<String, Error> {
+        let opts = context.options;
+        let span = crate::trace::span!("mrml::render", size = crate::trace::Empty).entered();
+        let mut cursor = RenderCursor::with_capacity(capacity)
+            .with_conditional_comments(opts.conditional_comments.clone());
+        Renderer::new(context, self, ()).render(&mut cursor)?;
+        let output = postprocess(cursor.buffer.into(), opts);
+        span.record("size", output.len());
+        Ok(output)
+    }
+
+    /// Renders the template asynchronously, giving back the control to the
+    /// executor between the sections of the body. The render stops with
+    /// [`Error::Cancelled`] as soon as the token is cancelled or its deadline
+    /// is reached.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parse")]
+    /// # tokio_test::block_on(async {
+    /// use std::time::Duration;
+    /// use mrml::prelude::render::{CancellationToken, RenderOptions};
+    ///
+    /// let root = mrml::parse("<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>").unwrap();
+    /// let token = CancellationToken::default().with_timeout(Duration::from_secs(1));
+    /// match root.element.async_render(&RenderOptions::default(), &token).await {
+    ///     Ok(html) => println!("{html}"),
+    ///     Err(err) => eprintln!("couldn't render template: {err}"),
+    /// }
+    /// # })
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn async_render(
+        &self,
+        opts: &RenderOptions,
+        token: &CancellationToken,
+    ) -> Result<String, Error> {
+        let header = Header::new(self.children.head.as_ref(), self.attributes.lang.as_deref());
+        let context = RenderContext::new(opts, header);
+        let capacity = opts
+            .buffer_capacity
+            .unwrap_or_else(|| self.estimate_render_size());
+        let span = crate::trace::span!("mrml::render", size = crate::trace::Empty);
+        let mut cursor = RenderCursor::with_capacity(capacity)
+            .with_conditional_comments(opts.conditional_comments.clone());
+        crate::trace::instrument(
+            span.clone(),
+            Renderer::new(&context, self, ()).async_render(&mut cursor, token),
+        )
+        .await?;
+        let output = postprocess(cursor.buffer.into(), opts);
+        span.record("size", output.len());
+        Ok(output)
+    }
+
+    /// Renders the template like [`Mjml::render`] and writes the html to the
+    /// `writer`, like a file. The html is stored in chunks while rendering,
+    /// instead of a single string, unless one of the options modifying the
+    /// html once rendered, like `max_line_length`, is used.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parse")]
+    /// # {
+    /// let root = mrml::parse("<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>").unwrap();
+    /// let output = root.element.render_to_writer(&Default::default(), Vec::new()).unwrap();
+    /// assert!(String::from_utf8(output).unwrap().contains("Hello"));
+    /// # }
+    /// ```
+    pub fn render_to_writer<W: std::io::Write>(
+        &self,
+        opts: &RenderOptions,
+        writer: W,
+    ) -> Result<W, Error> {
+        let header = Header::new(self.children.head.as_ref(), self.attributes.lang.as_deref());
+        let context = RenderContext::new(opts, header);
+        let mut cursor = RenderCursor {
+            buffer: RenderBuffer::with_backend(ChunkedBackend::default())
+                .with_conditional_comments(opts.conditional_comments.clone()),
+            header: VariableHeader::default(),
+        };
+        Renderer::new(&context, self, ()).render(&mut cursor)?;
+        let mut writer = IoWriter::new(writer);
+        let result = if needs_postprocess(opts) {
+            writer.write_str(&postprocess(cursor.buffer.into(), opts))
+        } else {
+            cursor.buffer.write_to(&mut writer)
+        };
+        match (result, writer.into_inner()) {
+            (_, Err(error)) => Err(Error::Io(error)),
+            (Err(error), Ok(_)) => Err(Error::Format(error)),
+            (Ok(()), Ok(inner)) => Ok(inner),
+        }
+    }
+
+    /// Renders the content of the `head` and of the `body` separately, to
+    /// insert them in an existing html document. The styles of the `body`
+    /// element, like its `background-color`, are also set on its content.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parse")]
+    /// # {
+    /// let root = mrml::parse("<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>").unwrap();
+    /// let parts = root.element.render_parts(&Default::default()).unwrap();
+    /// let html = format!("<html><head>{}</head><body>{}</body></html>", parts.head, parts.body);
+    /// assert!(html.contains("Hello"));
+    /// # }
+    /// ```
+    pub fn render_parts(&self, opts: &RenderOptions) -> Result<RenderedParts, Error> {
+        let header = Header::new(self.children.head.as_ref(), self.attributes.lang.as_deref());
+        let context = RenderContext::new(opts, header);
+        let capacity = opts
+            .buffer_capacity
+            .unwrap_or_else(|| self.estimate_render_size());
+        let mut cursor = RenderCursor::with_capacity(capacity)
+            .with_conditional_comments(opts.conditional_comments.clone());
+        if let Some(body) = self.body() {
+            Renderer::new(&context, body, ()).render_inner(&mut cursor)?;
+        } else {
+            crate::mj_body::render_tracking_pixel(opts, &mut cursor.buffer)?;
+        }
+        let mut body = cursor.buffer.empty(HEAD_SIZE);
+        std::mem::swap(&mut body, &mut cursor.buffer);
+        Renderer::new(&context, self, ()).render_head(&mut cursor)?;
+        let head: String = cursor.buffer.into();
+        let head = head
+            .strip_prefix("<head>")
+            .and_then(|value| value.strip_suffix("</head>"))
+            .unwrap_or(head.as_str());
+        Ok(RenderedParts {
+            head: postprocess(head.to_string(), opts),
+            body: postprocess(body.into(), opts),
+        })
+    }
+
+    /// Renders the content of the `head`, see [`Mjml::render_parts`].
+    pub fn render_head(&self, opts: &RenderOptions) -> Result<String, Error> {
+        self.render_parts(opts).map(|parts| parts.head)
+    }
+
+    /// Renders the content of the `body`, see [`Mjml::render_parts`].
+    pub fn render_body(&self, opts: &RenderOptions) -> Result<String, Error> {
+        self.render_parts(opts).map(|parts| parts.body)
+    }
+
+    /// Estimates the size, in bytes, of the rendered html, from the number
+    /// of elements and the length of the texts of the body. Used to allocate
+    /// the output once, instead of growing it while rendering.
+    pub fn estimate_render_size(&self) -> usize {
+        let mut result = HEAD_SIZE;
+        if let Some(body) = self.body_tree() {
+            result += ELEMENT_SIZE;
+            body.walk(&mut |node, _| {
+                result += match node {
+                    TreeNode::Element(_) => ELEMENT_SIZE,
+                    TreeNode::Text(value) | TreeNode::Comment(value) => value.len(),
+                };
+            });
+        }
+        result
+    }
+
+    /// Renders the template and converts the result into a plain text
+    /// version, to be used as alternative content next to the html one.
+    pub fn render_plain_text(&self, opts: &RenderOptions) -> Result<String, Error> {
+        self.render(opts).map(|html| html_to_plain_text(&html))
     }
 
     pub fn get_title(&self) -> Option<String> {
@@ -75,10 +494,36 @@ impl Mjml {
 #[cfg(all(test, feature = "parse"))]
 mod tests {
     use crate::mjml::Mjml;
-    use crate::prelude::render::RenderOptions;
+    use crate::prelude::render::{
+        minify_html, quoted_printable_size, EntityStrategy, RenderOptions, WebUrl,
+        SMTP_MAX_LINE_LENGTH,
+    };
 
     crate::should_render!(empty, "mjml");
 
+    #[test]
+    fn should_render_head_and_body_separately() {
+        let template = r##"<mjml lang="fr">
+  <mj-head><mj-title>Hello</mj-title><mj-preview>Preview</mj-preview></mj-head>
+  <mj-body background-color="#eeeeee"><mj-section><mj-column><mj-text font-family="Roboto">World</mj-text></mj-column></mj-section></mj-body>
+</mjml>"##;
+        let root = Mjml::parse(template).unwrap();
+        let opts = RenderOptions::default();
+        let html = root.element.render(&opts).unwrap();
+        let parts = root.element.render_parts(&opts).unwrap();
+        assert!(parts.head.starts_with("<title>Hello</title>"));
+        assert!(parts.head.contains("family=Roboto"));
+        assert!(parts.head.contains("@media"));
+        assert!(!parts.head.contains("<head>"));
+        assert!(parts.body.starts_with("<div style=\"display:none;"));
+        assert!(parts.body.contains("World"));
+        assert!(!parts.body.contains("<body"));
+        assert!(html.contains(&parts.head));
+        assert!(html.contains(&parts.body));
+        assert_eq!(root.element.render_head(&opts).unwrap(), parts.head);
+        assert_eq!(root.element.render_body(&opts).unwrap(), parts.body);
+    }
+
     #[test]
     fn template_amario() {
         let opts = RenderOptions::default();
@@ -96,6 +541,199 @@ mod tests {
         html_compare::assert_similar(expected, root.element.render(&opts).unwrap().as_str());
     }
 
+    #[test]
+    fn render_with_smtp_safe_lines() {
+        let opts = RenderOptions {
+            max_line_length: Some(SMTP_MAX_LINE_LENGTH),
+            crlf_line_endings: true,
+            ..Default::default()
+        };
+        let template = format!(
+            "<mjml><mj-body><mj-text>{}</mj-text></mj-body></mjml>",
+            "Hello World! ".repeat(500)
+        );
+        let root = Mjml::parse(template).unwrap();
+        let result = root.element.render(&opts).unwrap();
+        assert!(result
+            .split("\r\n")
+            .all(|line| line.len() <= SMTP_MAX_LINE_LENGTH && !line.contains('\n')));
+        let expected = root.element.render(&RenderOptions::default()).unwrap();
+        assert_eq!(
+            result.split_whitespace().collect::<String>(),
+            expected.split_whitespace().collect::<String>()
+        );
+    }
+
+    #[test]
+    fn render_minified() {
+        let template = include_str!("../../resources/template/amario.mjml");
+        let root = Mjml::parse(template).unwrap();
+        let expected = root.element.render(&RenderOptions::default()).unwrap();
+        let opts = RenderOptions {
+            minify: true,
+            ..Default::default()
+        };
+        let minified = root.element.render(&opts).unwrap();
+        assert!(minified.len() < expected.len());
+        assert_eq!(minified, minify_html(&expected));
+        // the lines are broken once minified
+        let opts = RenderOptions {
+            minify: true,
+            max_line_length: Some(SMTP_MAX_LINE_LENGTH),
+            crlf_line_endings: true,
+            quoted_printable_friendly: true,
+            ..Default::default()
+        };
+        let result = root.element.render(&opts).unwrap();
+        assert!(result
+            .split("\r\n")
+            .all(|line| line.len() <= SMTP_MAX_LINE_LENGTH && !line.contains('\n')));
+        assert_eq!(
+            result.split_whitespace().collect::<String>(),
+            minified.split_whitespace().collect::<String>()
+        );
+    }
+
+    #[test]
+    fn render_quoted_printable_friendly() {
+        let template = "<mjml><mj-body><mj-text>Prix : 10 € 😀</mj-text></mj-body></mjml>";
+        let root = Mjml::parse(template).unwrap();
+        let expected = root.element.render(&RenderOptions::default()).unwrap();
+        let opts = RenderOptions {
+            quoted_printable_friendly: true,
+            ..Default::default()
+        };
+        let result = root.element.render(&opts).unwrap();
+        assert!(result.contains("10 &#8364; &#128512;"));
+        assert!(quoted_printable_size(&result) < quoted_printable_size(&expected));
+    }
+
+    #[test]
+    fn render_entity_strategy() {
+        let template = "<mjml><mj-head><mj-style>.a::after { content: \"é\"; }</mj-style></mj-head><mj-body><mj-text>Café</mj-text></mj-body></mjml>";
+        let root = Mjml::parse(template).unwrap();
+        let opts = RenderOptions {
+            entity_strategy: EntityStrategy::Named,
+            ..Default::default()
+        };
+        let result = root.element.render(&opts).unwrap();
+        assert!(result.contains("Caf&eacute;"));
+        assert!(result.contains("content: \"é\";"));
+        let opts = RenderOptions {
+            entity_strategy: EntityStrategy::Numeric,
+            ..Default::default()
+        };
+        let result = root.element.render(&opts).unwrap();
+        assert!(result.contains("Caf&#233;"));
+    }
+
+    #[test]
+    fn render_conditional_comments() {
+        use crate::prelude::render::ConditionalComments;
+
+        let template = "<mjml><mj-body><mj-section><mj-column><mj-text>Hello</mj-text></mj-column></mj-section><mj-raw><!--[if mso | IE]><p>raw</p><![endif]--></mj-raw></mj-body></mjml>";
+        let root = Mjml::parse(template).unwrap();
+        let opts = RenderOptions {
+            conditional_comments: ConditionalComments::new("(gte mso 11)&(lte mso 16)", "!mso"),
+            ..Default::default()
+        };
+        let result = root.element.render(&opts).unwrap();
+        assert!(result.contains("<!--[if (gte mso 11)&(lte mso 16)]><table"));
+        assert!(result.contains("<!--[if mso | IE]><p>raw</p><![endif]-->"));
+        assert_eq!(result.matches("<!--[if mso | IE]>").count(), 1);
+    }
+
+    #[test]
+    fn render_with_source_map() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-image src="logo.png" />
+        <mj-text>Hello <b>World</b></mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let (html, source_map) = root
+            .element
+            .render_with_source_map(&RenderOptions::default(), template)
+            .unwrap();
+        assert_eq!(
+            html,
+            root.element.render(&RenderOptions::default()).unwrap()
+        );
+        let paths = source_map
+            .entries
+            .iter()
+            .map(|entry| entry.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                "/mjml/mj-body/mj-section[1]",
+                "/mjml/mj-body/mj-section[1]/mj-column[1]",
+                "/mjml/mj-body/mj-section[1]/mj-column[1]/mj-image[1]",
+                "/mjml/mj-body/mj-section[1]/mj-column[1]/mj-text[1]",
+            ]
+        );
+        let text = source_map.find_output(html.find("Hello").unwrap()).unwrap();
+        assert_eq!(
+            &template[text.source.clone()],
+            "<mj-text>Hello <b>World</b></mj-text>"
+        );
+        assert!(html[text.output.clone()].contains("Hello <b>World</b>"));
+        let image = source_map
+            .find_source(template.find("logo.png").unwrap())
+            .unwrap();
+        assert!(html[image.output.clone()].contains("logo.png"));
+    }
+
+    #[test]
+    fn render_web_url() {
+        let template = r#"<mjml><mj-body>
+  <mj-text><a href="{{__weburl__}}">View in browser</a></mj-text>
+  <mj-button href="{{__weburl__}}">Online version</mj-button>
+</mj-body></mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let result = root.element.render(&RenderOptions::default()).unwrap();
+        assert_eq!(result.matches("{{__weburl__}}").count(), 2);
+        let opts = RenderOptions {
+            web_url: WebUrl::Replace("https://example.com/online".into()),
+            ..Default::default()
+        };
+        let result = root.element.render(&opts).unwrap();
+        assert_eq!(result.matches("https://example.com/online").count(), 2);
+        let opts = RenderOptions {
+            web_url: WebUrl::Strip,
+            ..Default::default()
+        };
+        let result = root.element.render(&opts).unwrap();
+        assert!(!result.contains("{{__weburl__}}"));
+        assert!(!result.contains("View in browser"));
+        assert!(!result.contains("Online version"));
+    }
+
+    #[test]
+    fn render_plain_text() {
+        let opts = RenderOptions::default();
+        let template = r#"<mjml>
+  <mj-head><mj-title>Hello</mj-title></mj-head>
+  <mj-body>
+    <mj-section><mj-column>
+      <mj-text>Hello World!</mj-text>
+      <mj-button href="https://mrml.io">Visit</mj-button>
+    </mj-column></mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let text = root.element.render_plain_text(&opts).unwrap();
+        assert!(text.contains("Hello World!"));
+        assert!(text.contains("Visit (https://mrml.io)"));
+        assert!(!text.contains('<'));
+    }
+
     #[test]
     fn stable_output() {
         let source = "<mjml><mj-body><mj-section><mj-column><mj-text>hi</mj-text></mj-column></mj-section></mj-body></mjml>";
@@ -109,4 +747,224 @@ mod tests {
 
         assert_eq!(output_1, output_2);
     }
+
+    #[test]
+    fn estimate_render_size() {
+        let small = Mjml::parse("<mjml><mj-body><mj-text>hi</mj-text></mj-body></mjml>").unwrap();
+        let template = include_str!("../../resources/template/air-astana.mjml");
+        let large = Mjml::parse(template).unwrap();
+        assert!(small.element.estimate_render_size() < large.element.estimate_render_size());
+        let output = large.element.render(&RenderOptions::default()).unwrap();
+        let estimate = large.element.estimate_render_size();
+        assert!(estimate > output.len() / 4 && estimate < output.len() * 4);
+    }
+
+    #[test]
+    fn render_with_buffer_capacity() {
+        let template = include_str!("../../resources/template/air-astana.mjml");
+        let root = Mjml::parse(template).unwrap();
+        let expected = root.element.render(&RenderOptions::default()).unwrap();
+        let opts = RenderOptions {
+            buffer_capacity: Some(expected.len()),
+            ..Default::default()
+        };
+        assert_eq!(root.element.render(&opts).unwrap(), expected);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_render() {
+        use crate::prelude::render::CancellationToken;
+
+        let template = include_str!("../../resources/template/air-astana.mjml");
+        let root = Mjml::parse(template).unwrap();
+        let opts = RenderOptions::default();
+        let expected = root.element.render(&opts).unwrap();
+        let token = CancellationToken::default();
+        let result = root.element.async_render(&opts, &token).await.unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_render_cancelled() {
+        use std::time::{Duration, Instant};
+
+        use crate::prelude::render::{CancellationToken, Error};
+
+        let template = include_str!("../../resources/template/air-astana.mjml");
+        let root = Mjml::parse(template).unwrap();
+        let opts = RenderOptions::default();
+        let token = CancellationToken::default();
+        token.clone().cancel();
+        assert!(matches!(
+            root.element.async_render(&opts, &token).await,
+            Err(Error::Cancelled)
+        ));
+        let token =
+            CancellationToken::default().with_deadline(Instant::now() - Duration::from_secs(1));
+        assert!(matches!(
+            root.element.async_render(&opts, &token).await,
+            Err(Error::Cancelled)
+        ));
+    }
+
+    #[test]
+    fn render_with_report() {
+        use crate::prelude::render::{RenderWarning, RenderWarningKind};
+
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-attributes><mj-button width="wide" /></mj-attributes>
+  </mj-head>
+  <mj-body>
+    <mj-section padding="0 10px">
+      <mj-column>
+        <mj-image src="logo.png" width="big" />
+        <mj-button>First</mj-button>
+        <mj-button>Second</mj-button>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let (html, warnings) = root
+            .element
+            .render_with_report(&RenderOptions::default())
+            .unwrap();
+        assert_eq!(
+            html,
+            root.element.render(&RenderOptions::default()).unwrap()
+        );
+        assert_eq!(
+            warnings,
+            vec![
+                RenderWarning {
+                    path: "/mjml/mj-body/mj-section[1]/mj-column[1]/mj-image[1]".into(),
+                    kind: RenderWarningKind::InvalidAttribute {
+                        name: "width".into(),
+                        value: "big".into(),
+                    },
+                },
+                RenderWarning {
+                    path: "/mjml/mj-head".into(),
+                    kind: RenderWarningKind::InvalidAttribute {
+                        name: "width".into(),
+                        value: "wide".into(),
+                    },
+                },
+            ]
+        );
+        let image = root
+            .element
+            .element_at(&warnings[0].element_path().unwrap())
+            .unwrap();
+        assert_eq!(image.tag, "mj-image");
+    }
+
+    #[test]
+    fn render_prepared() {
+        let template = include_str!("../../resources/template/air-astana.mjml");
+        let root = Mjml::parse(template).unwrap();
+        let opts = RenderOptions {
+            breakpoint: Some("320px".into()),
+            ..Default::default()
+        };
+        let expected = root.element.render(&opts).unwrap();
+        let prepared = root.element.prepare(&opts);
+        std::thread::scope(|scope| {
+            let handles = (0..4)
+                .map(|_| scope.spawn(|| prepared.render().unwrap()))
+                .collect::<Vec<_>>();
+            for handle in handles {
+                assert_eq!(handle.join().unwrap(), expected);
+            }
+        });
+    }
+
+    #[test]
+    fn render_to_writer() {
+        let template = include_str!("../../resources/compare/success/mj-navbar.mjml");
+        let root = Mjml::parse(template).unwrap();
+        let expected = root.element.render(&RenderOptions::default()).unwrap();
+        let output = root
+            .element
+            .render_to_writer(&RenderOptions::default(), Vec::new())
+            .unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+        let opts = RenderOptions {
+            crlf_line_endings: true,
+            ..Default::default()
+        };
+        let output = root.element.render_to_writer(&opts, Vec::new()).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            root.element.render(&opts).unwrap()
+        );
+    }
+
+    #[test]
+    fn render_amario_with_smtp_safe_lines() {
+        let opts = RenderOptions {
+            max_line_length: Some(SMTP_MAX_LINE_LENGTH),
+            ..Default::default()
+        };
+        let template = include_str!("../../resources/template/amario.mjml");
+        let root = Mjml::parse(template).unwrap();
+        let (html, warnings) = root.element.render_with_report(&opts).unwrap();
+        assert!(html.lines().all(|line| line.len() <= SMTP_MAX_LINE_LENGTH));
+        assert!(warnings.is_empty());
+        let expected = root.element.render(&RenderOptions::default()).unwrap();
+        assert_eq!(
+            html.split_whitespace().collect::<String>(),
+            expected.split_whitespace().collect::<String>()
+        );
+    }
+
+    #[test]
+    fn render_with_report_long_lines() {
+        use crate::prelude::render::RenderWarningKind;
+
+        let opts = RenderOptions {
+            max_line_length: Some(100),
+            ..Default::default()
+        };
+        let template = format!(
+            "<mjml><mj-body><mj-raw><pre>{}</pre></mj-raw></mj-body></mjml>",
+            "Hello World! ".repeat(20)
+        );
+        let root = Mjml::parse(template).unwrap();
+        let (html, warnings) = root.element.render_with_report(&opts).unwrap();
+        let (index, length) = html
+            .lines()
+            .map(str::len)
+            .enumerate()
+            .find(|(_, length)| *length > 100)
+            .unwrap();
+        assert!(html.lines().nth(index).unwrap().contains("<pre>"));
+        assert!(matches!(
+            warnings.as_slice(),
+            [warning] if warning.kind == RenderWarningKind::LongLine { line: index + 1, length, limit: 100 }
+        ));
+    }
+
+    #[test]
+    fn render_with_report_oversized() {
+        use crate::prelude::render::{RenderWarningKind, GMAIL_CLIP_SIZE};
+
+        let template = format!(
+            "<mjml><mj-body><mj-text>{}</mj-text></mj-body></mjml>",
+            "Hello World! ".repeat(10_000)
+        );
+        let root = Mjml::parse(template).unwrap();
+        let (html, warnings) = root
+            .element
+            .render_with_report(&RenderOptions::default())
+            .unwrap();
+        assert!(html.len() > GMAIL_CLIP_SIZE);
+        assert!(matches!(
+            warnings.as_slice(),
+            [warning] if matches!(warning.kind, RenderWarningKind::OversizedOutput { .. })
+        ));
+    }
 }
@@ -15,9 +15,17 @@ where
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        if let Some(style) = self.element.attributes.get("style") {
+            cursor.header.add_style_font_families(style);
+        }
+        if self.element.tag.eq_ignore_ascii_case("font") {
+            cursor
+                .header
+                .maybe_add_font_families(self.element.attributes.get("face"));
+        }
         cursor.buffer.open_tag(&self.element.tag);
         for (key, value) in self.element.attributes.iter() {
-            cursor.buffer.push_attribute(key, value)?;
+            cursor.buffer.push_raw_attribute(key, value)?;
         }
         if self.element.children.is_empty() {
             if is_void_element(self.element.tag.as_str()) {
@@ -47,6 +55,14 @@ impl<'render, 'root: 'render, T: Renderable<'render, 'root>> Renderable<'render,
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
+        #[cfg(feature = "i18n")]
+        if self.tag == crate::mj_trans::NAME {
+            return Box::new(Renderer::new(
+                context,
+                self,
+                crate::mj_trans::render::MjTransExtra,
+            ));
+        }
         Box::new(Renderer::new(context, self, ()))
     }
 }
@@ -76,4 +92,23 @@ mod tests {
         let result = root.element.render(&opts).unwrap();
         assert!(result.contains("<script src=\"http://example.com/hello.js\"></script>"));
     }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_import_fonts_of_inline_styles() {
+        use crate::mjml::Mjml;
+        use crate::prelude::render::RenderOptions;
+
+        let template = r#"<mjml>
+    <mj-body>
+        <mj-raw><p style="font-family: 'Lato', sans-serif">Hello</p></mj-raw>
+        <mj-text font-family="Arial"><span style="font-family:Roboto">World</span> <font face="Ubuntu">!</font></mj-text>
+    </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let result = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(result.contains("family=Lato"));
+        assert!(result.contains("family=Roboto"));
+        assert!(result.contains("family=Ubuntu"));
+    }
 }
@@ -130,7 +130,8 @@ impl<Child: serde::Serialize> serde::Serialize
     where
         S: serde::Serializer,
     {
-        let mut map = serializer.serialize_map(Some(3))?;
+        let len = 1 + !self.attributes.is_empty() as usize + !self.children.is_empty() as usize;
+        let mut map = serializer.serialize_map(Some(len))?;
         map.serialize_entry("type", self.tag.as_str())?;
         if !self.attributes.is_empty() {
             map.serialize_entry("attributes", &self.attributes)?;
@@ -149,7 +150,9 @@ impl<Tag: StaticTag, Attributes: JsonAttributes, Children: JsonChildren> serde::
     where
         S: serde::Serializer,
     {
-        let mut map = serializer.serialize_map(Some(3))?;
+        let len =
+            1 + self.attributes.has_attributes() as usize + self.children.has_children() as usize;
+        let mut map = serializer.serialize_map(Some(len))?;
         map.serialize_entry("type", Tag::static_tag())?;
         if self.attributes.has_attributes() {
             map.serialize_entry("attributes", &self.attributes)?;
@@ -10,6 +10,8 @@ pub mod print;
 pub mod render;
 
 pub mod hash;
+pub mod path;
+pub mod tree;
 
 pub trait StaticTag {
     fn static_tag() -> &'static str;
@@ -45,22 +47,15 @@ impl<T, A, C> Component<PhantomData<T>, A, C> {
 
 // see https://developer.mozilla.org/en-US/docs/Glossary/Void_element
 #[cfg(any(feature = "parse", feature = "print", feature = "render"))]
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Html tag names are case insensitive, `<BR>` is a void element too.
+#[cfg(any(feature = "parse", feature = "print", feature = "render"))]
 pub(crate) fn is_void_element(tag: &str) -> bool {
-    matches!(
-        tag,
-        "area"
-            | "base"
-            | "br"
-            | "col"
-            | "embed"
-            | "hr"
-            | "img"
-            | "input"
-            | "link"
-            | "meta"
-            | "param"
-            | "source"
-            | "track"
-            | "wbr"
-    )
+    VOID_ELEMENTS
+        .iter()
+        .any(|name| name.eq_ignore_ascii_case(tag))
 }
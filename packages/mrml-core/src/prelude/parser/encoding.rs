@@ -0,0 +1,104 @@
+//! Decoding of templates that are not encoded in UTF-8.
+//!
+//! The encoding is detected, by order of priority, from the byte order mark,
+//! the `encoding` of the xml declaration or the charset of a `meta` element.
+//! Without any hint, the template is considered as UTF-8 and falls back to
+//! `windows-1252` (a superset of Latin-1) when it's not valid UTF-8.
+//!
+//! ```rust
+//! let input = b"<mjml><mj-head><mj-raw><meta charset=\"iso-8859-1\" /></mj-raw></mj-head><mj-body><mj-text>Caf\xe9</mj-text></mj-body></mjml>";
+//! let root = mrml::parse_bytes(input).expect("parse template");
+//! ```
+
+use std::borrow::Cow;
+
+use encoding_rs::{Encoding, UTF_8, WINDOWS_1252};
+
+/// Number of bytes inspected when looking for an encoding declaration.
+const SNIFF_LENGTH: usize = 1024;
+
+/// Detects the encoding of the given template.
+pub fn detect(input: &[u8]) -> &'static Encoding {
+    if let Some((encoding, _)) = Encoding::for_bom(input) {
+        return encoding;
+    }
+    let head = &input[..input.len().min(SNIFF_LENGTH)];
+    let head = String::from_utf8_lossy(head).to_ascii_lowercase();
+    if let Some(encoding) = find_declared(&head) {
+        // a template readable as ascii can't be encoded in utf-16
+        if encoding.output_encoding() == encoding {
+            return encoding;
+        }
+    }
+    if std::str::from_utf8(input).is_ok() {
+        UTF_8
+    } else {
+        WINDOWS_1252
+    }
+}
+
+/// Decodes the given template to a string, replacing the malformed sequences
+/// with the replacement character.
+pub fn decode(input: &[u8]) -> Cow<'_, str> {
+    let encoding = detect(input);
+    let (result, _) = encoding.decode_with_bom_removal(input);
+    result
+}
+
+fn find_declared(head: &str) -> Option<&'static Encoding> {
+    ["encoding=", "charset="]
+        .iter()
+        .flat_map(|key| head.match_indices(key).map(move |(idx, _)| idx + key.len()))
+        .filter_map(|start| {
+            let value = head[start..].trim_start_matches(['"', '\'', ' ']);
+            let end = value
+                .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':' | '.')))
+                .unwrap_or(value.len());
+            Encoding::for_label(&value.as_bytes()[..end])
+        })
+        .next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_use_utf8_by_default() {
+        assert_eq!(detect("<mjml>Café</mjml>".as_bytes()), UTF_8);
+        assert_eq!(decode("<mjml>Café</mjml>".as_bytes()), "<mjml>Café</mjml>");
+    }
+
+    #[test]
+    fn should_detect_bom() {
+        let mut input = vec![0xFF, 0xFE];
+        for unit in "<mjml>Café</mjml>".encode_utf16() {
+            input.extend_from_slice(&unit.to_le_bytes());
+        }
+        assert_eq!(decode(&input), "<mjml>Café</mjml>");
+    }
+
+    #[test]
+    fn should_detect_meta_charset() {
+        let input = b"<mjml><mj-head><mj-raw><meta charset=\"ISO-8859-1\"></mj-raw></mj-head>Caf\xe9</mjml>";
+        assert_eq!(detect(input), WINDOWS_1252);
+        assert!(decode(input).ends_with("Café</mjml>"));
+    }
+
+    #[test]
+    fn should_detect_http_equiv_charset() {
+        let input = b"<meta http-equiv=\"Content-Type\" content=\"text/html; charset=koi8-r\">";
+        assert_eq!(detect(input), encoding_rs::KOI8_R);
+    }
+
+    #[test]
+    fn should_detect_xml_declaration() {
+        let input = b"<?xml version=\"1.0\" encoding='windows-1250'?><mjml />";
+        assert_eq!(detect(input), encoding_rs::WINDOWS_1250);
+    }
+
+    #[test]
+    fn should_fallback_on_latin1() {
+        assert_eq!(decode(b"<mjml>Caf\xe9</mjml>"), "<mjml>Café</mjml>");
+    }
+}
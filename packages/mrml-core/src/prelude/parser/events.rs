@@ -0,0 +1,240 @@
+//! Low level parsing of a template as a stream of events, for the tools that
+//! only need to scan or rewrite a template without building the elements.
+//!
+//! The events borrow from the input and their [spans](Span) allow rewriting
+//! the parts of the template they come from. The content isn't validated
+//! against the mjml specification and the `mj-include` elements aren't
+//! resolved. The texts only made of whitespaces starting with a line break
+//! are skipped.
+//!
+//! ```rust
+//! use mrml::prelude::parser::events::{MjmlEvent, MjmlEvents};
+//!
+//! let template = r#"<mjml><mj-body><mj-button href="https://example.com">Go</mj-button></mj-body></mjml>"#;
+//! let mut links = Vec::new();
+//! for event in MjmlEvents::new(template) {
+//!     if let MjmlEvent::Start { attributes, .. } = event.expect("invalid template") {
+//!         links.extend(attributes.iter().filter(|attr| attr.name == "href").map(|attr| attr.value));
+//!     }
+//! }
+//! assert_eq!(links, vec!["https://example.com"]);
+//! ```
+
+use super::{Error, MrmlCursor, MrmlToken, ParserLimits, Span};
+use crate::prelude::is_void_element;
+
+#[derive(Clone, Debug)]
+pub struct EventAttribute<'a> {
+    pub name: &'a str,
+    pub value: &'a str,
+    /// Position of the value, without the quotes.
+    pub value_span: Span,
+}
+
+#[derive(Clone, Debug)]
+pub enum MjmlEvent<'a> {
+    /// Opening of an element, from the `<` to the `>`.
+    Start {
+        tag: &'a str,
+        attributes: Vec<EventAttribute<'a>>,
+        span: Span,
+    },
+    /// Closing of an element. The self closing and the void elements emit an
+    /// `End` event right after their `Start` event, with the span of their
+    /// `/>` or `>`.
+    End {
+        tag: &'a str,
+        span: Span,
+    },
+    Text {
+        text: &'a str,
+        span: Span,
+    },
+    Comment {
+        text: &'a str,
+        span: Span,
+    },
+}
+
+impl<'a> MjmlEvent<'a> {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Start { span, .. }
+            | Self::End { span, .. }
+            | Self::Text { span, .. }
+            | Self::Comment { span, .. } => *span,
+        }
+    }
+}
+
+/// Iterator over the [events](MjmlEvent) of a template. It stops after the
+/// first error.
+pub struct MjmlEvents<'a> {
+    cursor: MrmlCursor<'a>,
+    pending: Option<MjmlEvent<'a>>,
+    failed: bool,
+}
+
+impl<'a> MjmlEvents<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            cursor: MrmlCursor::new(input),
+            pending: None,
+            failed: false,
+        }
+    }
+
+    /// Creates an iterator enforcing the provided limits.
+    pub fn with_limits(input: &'a str, limits: ParserLimits) -> Result<Self, Error> {
+        Ok(Self {
+            cursor: MrmlCursor::with_limits(input, limits)?,
+            pending: None,
+            failed: false,
+        })
+    }
+
+    fn read_start(&mut self, tag: &'a str, start: usize) -> Result<MjmlEvent<'a>, Error> {
+        let mut attributes = Vec::new();
+        while let Some(attr) = self.cursor.next_attribute()? {
+            attributes.push(EventAttribute {
+                name: attr.local.as_str(),
+                value: attr.value.as_str(),
+                value_span: attr.value.into(),
+            });
+        }
+        let ending = self.cursor.assert_element_end()?;
+        if ending.empty || is_void_element(tag) {
+            self.cursor.end_void_element(tag, &ending)?;
+            self.pending = Some(MjmlEvent::End {
+                tag,
+                span: ending.span.into(),
+            });
+        }
+        Ok(MjmlEvent::Start {
+            tag,
+            attributes,
+            span: Span {
+                start,
+                end: ending.span.end(),
+            },
+        })
+    }
+
+    fn read_next(&mut self) -> Option<Result<MjmlEvent<'a>, Error>> {
+        if let Some(event) = self.pending.take() {
+            return Some(Ok(event));
+        }
+        let token = match self.cursor.next_token()? {
+            Ok(token) => token,
+            Err(err) => return Some(Err(err)),
+        };
+        Some(match token {
+            MrmlToken::Comment(inner) => Ok(MjmlEvent::Comment {
+                text: inner.text.as_str(),
+                span: inner.span.into(),
+            }),
+            MrmlToken::Text(inner) => Ok(MjmlEvent::Text {
+                text: inner.text.as_str(),
+                span: inner.text.into(),
+            }),
+            MrmlToken::ElementStart(inner) => {
+                self.read_start(inner.local.as_str(), inner.span.start())
+            }
+            MrmlToken::ElementClose(inner) => Ok(MjmlEvent::End {
+                tag: inner.local.as_str(),
+                span: inner.span.into(),
+            }),
+            other => Err(Error::UnexpectedToken {
+                origin: self.cursor.origin(),
+                position: other.span(),
+            }),
+        })
+    }
+}
+
+impl<'a> Iterator for MjmlEvents<'a> {
+    type Item = Result<MjmlEvent<'a>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let result = self.read_next();
+        self.failed = matches!(result, Some(Err(_)));
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MjmlEvent, MjmlEvents};
+
+    fn describe(template: &str) -> Vec<String> {
+        MjmlEvents::new(template)
+            .map(|event| match event.unwrap() {
+                MjmlEvent::Start { tag, .. } => format!("start {tag}"),
+                MjmlEvent::End { tag, .. } => format!("end {tag}"),
+                MjmlEvent::Text { text, .. } => format!("text {text}"),
+                MjmlEvent::Comment { text, .. } => format!("comment {text}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_emit_events() {
+        let template = "<mjml><mj-body><!-- hi --><mj-text>Hello<br>World</mj-text><mj-spacer /></mj-body></mjml>";
+        assert_eq!(
+            describe(template),
+            vec![
+                "start mjml",
+                "start mj-body",
+                "comment  hi ",
+                "start mj-text",
+                "text Hello",
+                "start br",
+                "end br",
+                "text World",
+                "end mj-text",
+                "start mj-spacer",
+                "end mj-spacer",
+                "end mj-body",
+                "end mjml",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_point_to_input() {
+        let template =
+            r#"<mjml><mj-body><mj-image src="logo.png" /><mj-text>Hi</mj-text></mj-body></mjml>"#;
+        for event in MjmlEvents::new(template) {
+            let event = event.unwrap();
+            let span = event.span();
+            let slice = &template[span.start..span.end];
+            match event {
+                MjmlEvent::Start {
+                    tag, attributes, ..
+                } => {
+                    assert!(slice.starts_with(&format!("<{tag}")));
+                    assert!(slice.ends_with('>'));
+                    for attr in attributes {
+                        assert_eq!(
+                            &template[attr.value_span.start..attr.value_span.end],
+                            attr.value
+                        );
+                    }
+                }
+                MjmlEvent::End { .. } => assert!(slice.ends_with('>')),
+                MjmlEvent::Text { text, .. } => assert_eq!(slice, text),
+                MjmlEvent::Comment { .. } => {}
+            }
+        }
+    }
+
+    #[test]
+    fn should_stop_after_error() {
+        let mut events = MjmlEvents::new("<mjml><mj-body</mjml>");
+        assert!(events.by_ref().any(|event| event.is_err()));
+        assert!(events.next().is_none());
+    }
+}
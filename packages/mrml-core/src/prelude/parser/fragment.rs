@@ -0,0 +1,171 @@
+//! Parsing of a single element, like a `mj-section`, outside of a template,
+//! to store it and insert it later in a document.
+
+use xmlparser::StrSpan;
+
+use super::{
+    mask_raw_tokens, Error, MrmlCursor, MrmlParser, MrmlToken, ParseElement, ParseOutput,
+    ParserOptions,
+};
+use crate::mj_accordion::MjAccordion;
+use crate::mj_accordion_element::MjAccordionElement;
+use crate::mj_body::{MjBody, MjBodyChild};
+use crate::mj_button::MjButton;
+use crate::mj_carousel::MjCarousel;
+use crate::mj_carousel_image::MjCarouselImage;
+use crate::mj_column::MjColumn;
+use crate::mj_divider::MjDivider;
+use crate::mj_group::MjGroup;
+use crate::mj_head::MjHead;
+use crate::mj_hero::MjHero;
+use crate::mj_image::MjImage;
+use crate::mj_navbar::MjNavbar;
+use crate::mj_navbar_link::MjNavbarLink;
+use crate::mj_raw::MjRaw;
+use crate::mj_section::MjSection;
+use crate::mj_social::MjSocial;
+use crate::mj_social_element::MjSocialElement;
+use crate::mj_spacer::MjSpacer;
+use crate::mj_table::MjTable;
+use crate::mj_text::MjText;
+use crate::mj_wrapper::MjWrapper;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Element that can be parsed on its own with
+/// [`parse_fragment`](crate::parse_fragment).
+pub trait Fragment: Sized + private::Sealed {
+    /// Tag of the element, `None` when any element of the body is accepted.
+    fn fragment_tag() -> Option<&'static str>;
+
+    #[doc(hidden)]
+    fn parse_element<'a>(
+        parser: &MrmlParser<'_>,
+        cursor: &mut MrmlCursor<'a>,
+        tag: StrSpan<'a>,
+    ) -> Result<Self, Error>;
+}
+
+macro_rules! fragment {
+    ($element:ty, $tag:expr) => {
+        impl private::Sealed for $element {}
+
+        impl Fragment for $element {
+            fn fragment_tag() -> Option<&'static str> {
+                $tag
+            }
+
+            fn parse_element<'a>(
+                parser: &MrmlParser<'_>,
+                cursor: &mut MrmlCursor<'a>,
+                tag: StrSpan<'a>,
+            ) -> Result<Self, Error> {
+                parser.parse(cursor, tag)
+            }
+        }
+    };
+}
+
+fragment!(MjBodyChild, None);
+fragment!(MjAccordion, Some(crate::mj_accordion::NAME));
+fragment!(MjAccordionElement, Some(crate::mj_accordion_element::NAME));
+fragment!(MjBody, Some(crate::mj_body::NAME));
+fragment!(MjButton, Some(crate::mj_button::NAME));
+fragment!(MjCarousel, Some(crate::mj_carousel::NAME));
+fragment!(MjCarouselImage, Some(crate::mj_carousel_image::NAME));
+fragment!(MjColumn, Some(crate::mj_column::NAME));
+fragment!(MjDivider, Some(crate::mj_divider::NAME));
+fragment!(MjGroup, Some(crate::mj_group::NAME));
+fragment!(MjHead, Some(crate::mj_head::NAME));
+fragment!(MjHero, Some(crate::mj_hero::NAME));
+fragment!(MjImage, Some(crate::mj_image::NAME));
+fragment!(MjNavbar, Some(crate::mj_navbar::NAME));
+fragment!(MjNavbarLink, Some(crate::mj_navbar_link::NAME));
+fragment!(MjRaw, Some(crate::mj_raw::NAME));
+fragment!(MjSection, Some(crate::mj_section::NAME));
+fragment!(MjSocial, Some(crate::mj_social::NAME));
+fragment!(MjSocialElement, Some(crate::mj_social_element::NAME));
+fragment!(MjSpacer, Some(crate::mj_spacer::NAME));
+fragment!(MjTable, Some(crate::mj_table::NAME));
+fragment!(MjText, Some(crate::mj_text::NAME));
+fragment!(MjWrapper, Some(crate::mj_wrapper::NAME));
+
+/// Parses the `input`, that must contain a single element of type `T`.
+pub(crate) fn parse_fragment_with_options<T: Fragment>(
+    input: &str,
+    opts: &ParserOptions,
+) -> Result<ParseOutput<T>, Error> {
+    let parser = MrmlParser::new(opts);
+    let cursor = MrmlCursor::with_limits(input, opts.limits)?;
+    let masked = mask_raw_tokens(&opts.raw_token_patterns, input);
+    let mut cursor = cursor
+        .with_masked_source(input, &masked)
+        .with_fragment(&masked);
+    let start = cursor.assert_element_start()?;
+    if T::fragment_tag().is_some_and(|tag| tag != start.local.as_str()) {
+        return Err(Error::UnexpectedElement {
+            origin: cursor.origin(),
+            position: start.span.into(),
+        });
+    }
+    let element = T::parse_element(&parser, &mut cursor, start.local)?;
+    while let Some(token) = cursor.next_token() {
+        match token? {
+            MrmlToken::Text(inner) if inner.text.as_str().trim().is_empty() => {}
+            MrmlToken::Comment(_) => {}
+            other => {
+                return Err(Error::UnexpectedToken {
+                    origin: cursor.origin(),
+                    position: other.span(),
+                })
+            }
+        }
+    }
+    Ok(ParseOutput {
+        element,
+        warnings: cursor.warnings(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mj_body::MjBodyChild;
+    use crate::mj_section::MjSection;
+    use crate::mj_text::MjText;
+    use crate::prelude::parser::Error;
+
+    #[test]
+    fn should_parse_a_section() {
+        let output = crate::parse_fragment::<MjSection, _>(
+            r#"<mj-section background-color="red"><mj-column><mj-text>Hello</mj-text></mj-column></mj-section>
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            output.element.attributes.get("background-color").unwrap(),
+            "red"
+        );
+        assert_eq!(output.element.children.len(), 1);
+        assert!(output.warnings.is_empty());
+    }
+
+    #[test]
+    fn should_parse_any_element_of_the_body() {
+        let output = crate::parse_fragment::<MjBodyChild, _>("<mj-text>Hello</mj-text>").unwrap();
+        assert!(matches!(output.element, MjBodyChild::MjText(_)));
+    }
+
+    #[test]
+    fn should_reject_other_elements() {
+        assert!(matches!(
+            crate::parse_fragment::<MjText, _>("<mj-section />"),
+            Err(Error::UnexpectedElement { .. })
+        ));
+        assert!(matches!(
+            crate::parse_fragment::<MjText, _>("<mj-text>A</mj-text><mj-text>B</mj-text>"),
+            Err(Error::UnexpectedToken { .. })
+        ));
+    }
+}
@@ -0,0 +1,258 @@
+//! Limits applied by the parser, to protect the application when parsing
+//! untrusted templates.
+//!
+//! ```rust
+//! use mrml::prelude::parser::{Error, ParserLimits, ParserOptions};
+//!
+//! let options = ParserOptions {
+//!     limits: ParserLimits {
+//!         max_depth: Some(2),
+//!         ..Default::default()
+//!     },
+//!     ..Default::default()
+//! };
+//! let template = "<mjml><mj-body><mj-section></mj-section></mj-body></mjml>";
+//! match mrml::parse_with_options(template, &options) {
+//!     Err(Error::LimitExceeded { .. }) => println!("template too deep"),
+//!     _ => unreachable!(),
+//! }
+//! ```
+
+use super::{Error, MrmlCursor};
+
+/// Limits applied while parsing a template. Each limit is disabled when set
+/// to `None`, which is the default.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ParserLimits {
+    /// Maximum size, in bytes, of the template and of each included template.
+    pub max_input_size: Option<usize>,
+    /// Maximum nesting depth of the elements, included templates included.
+    pub max_depth: Option<usize>,
+    /// Maximum number of elements, included templates included.
+    pub max_elements: Option<usize>,
+    /// Maximum number of attributes on a single element.
+    pub max_attributes: Option<usize>,
+}
+
+impl ParserLimits {
+    /// Limits large enough for any real world email, but preventing
+    /// malicious templates from exhausting the memory or the stack.
+    pub fn untrusted() -> Self {
+        Self {
+            max_input_size: Some(1024 * 1024),
+            max_depth: Some(64),
+            max_elements: Some(10_000),
+            max_attributes: Some(64),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LimitKind {
+    InputSize,
+    Depth,
+    Elements,
+    Attributes,
+}
+
+impl std::fmt::Display for LimitKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InputSize => f.write_str("input size"),
+            Self::Depth => f.write_str("nesting depth"),
+            Self::Elements => f.write_str("number of elements"),
+            Self::Attributes => f.write_str("number of attributes"),
+        }
+    }
+}
+
+/// Counters of the resources used while parsing, shared with the cursors of
+/// the included templates.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Usage {
+    pub depth: usize,
+    pub elements: usize,
+    pub attributes: usize,
+}
+
+fn check(value: usize, max: Option<usize>) -> Option<usize> {
+    max.filter(|max| value > *max)
+}
+
+impl<'a> MrmlCursor<'a> {
+    fn limit_exceeded(&self, kind: LimitKind, max: usize) -> Error {
+        Error::LimitExceeded {
+            origin: self.origin(),
+            kind,
+            max,
+        }
+    }
+
+    pub(crate) fn check_input_size(&self, input: &str) -> Result<(), Error> {
+        match check(input.len(), self.limits.max_input_size) {
+            Some(max) => Err(self.limit_exceeded(LimitKind::InputSize, max)),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn count_element_start(&mut self) -> Result<(), Error> {
+        self.usage.elements += 1;
+        self.usage.attributes = 0;
+        match check(self.usage.elements, self.limits.max_elements) {
+            Some(max) => Err(self.limit_exceeded(LimitKind::Elements, max)),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn count_attribute(&mut self) -> Result<(), Error> {
+        self.usage.attributes += 1;
+        match check(self.usage.attributes, self.limits.max_attributes) {
+            Some(max) => Err(self.limit_exceeded(LimitKind::Attributes, max)),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn count_element_open(&mut self) -> Result<(), Error> {
+        self.usage.depth += 1;
+        match check(self.usage.depth, self.limits.max_depth) {
+            Some(max) => Err(self.limit_exceeded(LimitKind::Depth, max)),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn count_element_close(&mut self) {
+        self.usage.depth = self.usage.depth.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LimitKind, ParserLimits};
+    use crate::prelude::parser::{Error, ParserOptions};
+
+    const TEMPLATE: &str = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-text align="center" color="red">Hello</mj-text>
+        <mj-text>World</mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+
+    fn parse(limits: ParserLimits) -> Result<(), Error> {
+        let options = ParserOptions {
+            limits,
+            ..Default::default()
+        };
+        crate::parse_with_options(TEMPLATE, &options).map(|_| ())
+    }
+
+    fn assert_exceeded(limits: ParserLimits, expected: LimitKind) {
+        match parse(limits) {
+            Err(Error::LimitExceeded { kind, .. }) => assert_eq!(kind, expected),
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_parse_within_limits() {
+        parse(ParserLimits::default()).unwrap();
+        parse(ParserLimits::untrusted()).unwrap();
+        parse(ParserLimits {
+            max_input_size: Some(TEMPLATE.len()),
+            max_depth: Some(5),
+            max_elements: Some(6),
+            max_attributes: Some(2),
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn should_not_count_void_elements_in_depth() {
+        let template =
+            "<mjml><mj-body><mj-text>a<br>b<br>c<br></br>d<br/></mj-text></mj-body></mjml>";
+        let options = ParserOptions {
+            limits: ParserLimits {
+                max_depth: Some(4),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        crate::parse_with_options(template, &options).unwrap();
+    }
+
+    #[test]
+    fn should_limit_input_size() {
+        assert_exceeded(
+            ParserLimits {
+                max_input_size: Some(TEMPLATE.len() - 1),
+                ..Default::default()
+            },
+            LimitKind::InputSize,
+        );
+    }
+
+    #[test]
+    fn should_limit_depth() {
+        assert_exceeded(
+            ParserLimits {
+                max_depth: Some(4),
+                ..Default::default()
+            },
+            LimitKind::Depth,
+        );
+    }
+
+    #[test]
+    fn should_limit_elements() {
+        assert_exceeded(
+            ParserLimits {
+                max_elements: Some(5),
+                ..Default::default()
+            },
+            LimitKind::Elements,
+        );
+    }
+
+    #[test]
+    fn should_limit_attributes() {
+        assert_exceeded(
+            ParserLimits {
+                max_attributes: Some(1),
+                ..Default::default()
+            },
+            LimitKind::Attributes,
+        );
+    }
+
+    #[test]
+    fn should_count_included_elements() {
+        use crate::prelude::parser::memory_loader::MemoryIncludeLoader;
+
+        let loader = MemoryIncludeLoader::from(vec![(
+            "partial.mjml",
+            "<mj-section><mj-column><mj-text>Hello</mj-text></mj-column></mj-section>",
+        )]);
+        let template = r#"<mjml><mj-body><mj-include path="partial.mjml" /></mj-body></mjml>"#;
+        let options = ParserOptions {
+            include_loader: Box::new(loader),
+            limits: ParserLimits {
+                max_elements: Some(5),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        match crate::parse_with_options(template, &options).map(|_| ()) {
+            Err(Error::LimitExceeded { kind, origin, .. }) => {
+                assert_eq!(kind, LimitKind::Elements);
+                assert!(matches!(
+                    origin,
+                    crate::prelude::parser::Origin::Include { .. }
+                ));
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+}
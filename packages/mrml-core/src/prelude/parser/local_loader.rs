@@ -30,6 +30,7 @@ use crate::prelude::parser::loader::IncludeLoader;
 /// let resolver = LocalIncludeLoader::new(root);
 /// let opts = ParserOptions {
 ///     include_loader: Box::new(resolver),
+///     ..Default::default()
 /// };
 /// let template = r#"<mjml>
 ///   <mj-body>
@@ -1,23 +1,34 @@
 use std::marker::PhantomData;
 
-use xmlparser::{StrSpan, Tokenizer};
+use xmlparser::{Stream, StrSpan, Tokenizer};
 
 use self::loader::IncludeLoaderError;
 use super::hash::Map;
 
+#[cfg(feature = "encoding")]
+pub mod encoding;
+pub mod events;
+pub mod fragment;
 #[cfg(feature = "http-loader-base")]
 pub mod http_loader;
+mod limits;
 pub mod loader;
 #[cfg(feature = "local-loader")]
 pub mod local_loader;
 pub mod memory_loader;
 pub mod multi_loader;
 pub mod noop_loader;
+#[cfg(feature = "object-store")]
+pub mod object_store_loader;
 
 mod output;
+mod raw_token;
 mod token;
 
+pub use limits::{LimitKind, ParserLimits};
 pub use output::*;
+pub(crate) use raw_token::mask as mask_raw_tokens;
+pub use raw_token::RawTokenPattern;
 pub use token::*;
 
 #[derive(Clone, Debug)]
@@ -73,11 +84,63 @@ pub enum Error {
         #[source]
         source: IncludeLoaderError,
     },
+    /// One of the [limits](ParserLimits) defined in the options was reached.
+    #[error("{kind} limit of {max} exceeded in {origin}")]
+    LimitExceeded {
+        origin: Origin,
+        kind: LimitKind,
+        max: usize,
+    },
+}
+
+impl Error {
+    /// Returns the template in which the error occurred.
+    pub fn origin(&self) -> Option<&Origin> {
+        match self {
+            Self::UnexpectedElement { origin, .. }
+            | Self::UnexpectedToken { origin, .. }
+            | Self::MissingAttribute { origin, .. }
+            | Self::InvalidAttribute { origin, .. }
+            | Self::InvalidFormat { origin, .. }
+            | Self::EndOfStream { origin }
+            | Self::SizeLimit { origin }
+            | Self::ParserError { origin, .. }
+            | Self::IncludeLoaderError { origin, .. }
+            | Self::LimitExceeded { origin, .. } => Some(origin),
+            Self::NoRootNode => None,
+        }
+    }
+
+    /// Returns the position of the error in its origin template, when known.
+    pub fn position(&self) -> Option<Span> {
+        match self {
+            Self::UnexpectedElement { position, .. }
+            | Self::UnexpectedToken { position, .. }
+            | Self::MissingAttribute { position, .. }
+            | Self::InvalidAttribute { position, .. }
+            | Self::InvalidFormat { position, .. }
+            | Self::IncludeLoaderError { position, .. } => Some(*position),
+            Self::EndOfStream { .. }
+            | Self::SizeLimit { .. }
+            | Self::ParserError { .. }
+            | Self::LimitExceeded { .. }
+            | Self::NoRootNode => None,
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct ParserOptions {
     pub include_loader: Box<dyn loader::IncludeLoader>,
+    pub limits: ParserLimits,
+    /// Report a [`WarningKind::UnbalancedHtml`] warning when a closing tag of
+    /// the raw html inside the body (like in `mj-text` or `mj-table`) doesn't
+    /// match the element it closes.
+    pub validate_html: bool,
+    /// Merge tags of the emailing services, like `*|FNAME|*` or `<%= name %>`,
+    /// kept byte-for-byte in the attributes and in the text, even when they
+    /// contain characters like `<` or `"`.
+    pub raw_token_patterns: Vec<RawTokenPattern>,
 }
 
 #[allow(clippy::box_default)]
@@ -85,14 +148,46 @@ impl Default for ParserOptions {
     fn default() -> Self {
         Self {
             include_loader: Box::new(noop_loader::NoopIncludeLoader),
+            limits: ParserLimits::default(),
+            validate_html: false,
+            raw_token_patterns: Vec::new(),
         }
     }
 }
 
+impl ParserOptions {
+    pub fn with_include_loader(mut self, include_loader: Box<dyn loader::IncludeLoader>) -> Self {
+        self.include_loader = include_loader;
+        self
+    }
+
+    pub fn with_limits(mut self, limits: ParserLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// See [`ParserOptions::validate_html`].
+    pub fn with_html_validation(mut self, validate_html: bool) -> Self {
+        self.validate_html = validate_html;
+        self
+    }
+
+    /// See [`ParserOptions::raw_token_patterns`].
+    pub fn with_raw_token_patterns(mut self, raw_token_patterns: Vec<RawTokenPattern>) -> Self {
+        self.raw_token_patterns = raw_token_patterns;
+        self
+    }
+}
+
 #[cfg(feature = "async")]
 #[derive(Debug)]
 pub struct AsyncParserOptions {
     pub include_loader: Box<dyn loader::AsyncIncludeLoader + Send + Sync>,
+    pub limits: ParserLimits,
+    /// See [`ParserOptions::validate_html`].
+    pub validate_html: bool,
+    /// See [`ParserOptions::raw_token_patterns`].
+    pub raw_token_patterns: Vec<RawTokenPattern>,
 }
 
 #[cfg(feature = "async")]
@@ -101,10 +196,41 @@ impl Default for AsyncParserOptions {
     fn default() -> Self {
         Self {
             include_loader: Box::new(noop_loader::NoopIncludeLoader),
+            limits: ParserLimits::default(),
+            validate_html: false,
+            raw_token_patterns: Vec::new(),
         }
     }
 }
 
+#[cfg(feature = "async")]
+impl AsyncParserOptions {
+    pub fn with_include_loader(
+        mut self,
+        include_loader: Box<dyn loader::AsyncIncludeLoader + Send + Sync>,
+    ) -> Self {
+        self.include_loader = include_loader;
+        self
+    }
+
+    pub fn with_limits(mut self, limits: ParserLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// See [`ParserOptions::validate_html`].
+    pub fn with_html_validation(mut self, validate_html: bool) -> Self {
+        self.validate_html = validate_html;
+        self
+    }
+
+    /// See [`ParserOptions::raw_token_patterns`].
+    pub fn with_raw_token_patterns(mut self, raw_token_patterns: Vec<RawTokenPattern>) -> Self {
+        self.raw_token_patterns = raw_token_patterns;
+        self
+    }
+}
+
 pub(crate) trait ParseElement<E> {
     fn parse<'a>(&self, cursor: &mut MrmlCursor<'a>, tag: StrSpan<'a>) -> Result<E, Error>;
 }
@@ -137,36 +263,94 @@ pub(crate) trait AsyncParseChildren<C> {
 
 pub struct MrmlCursor<'a> {
     tokenizer: Tokenizer<'a>,
+    /// The template, when the tokenizer reads a copy with its raw tokens
+    /// masked.
+    source: Option<&'a str>,
     buffer: Vec<MrmlToken<'a>>,
     origin: Origin,
     warnings: Vec<Warning>,
+    limits: ParserLimits,
+    usage: limits::Usage,
 }
 
 impl<'a> MrmlCursor<'a> {
     pub fn new(source: &'a str) -> Self {
         Self {
             tokenizer: Tokenizer::from(source),
+            source: None,
             buffer: Default::default(),
             origin: Origin::Root,
             warnings: Default::default(),
+            limits: Default::default(),
+            usage: Default::default(),
         }
     }
 
+    /// Creates a cursor enforcing the provided limits.
+    pub(crate) fn with_limits(source: &'a str, limits: ParserLimits) -> Result<Self, Error> {
+        let cursor = Self {
+            limits,
+            ..Self::new(source)
+        };
+        cursor.check_input_size(source)?;
+        Ok(cursor)
+    }
+
+    /// Creates the cursor of an included template, sharing the limits and
+    /// the resources already used by this cursor.
     pub(crate) fn new_child<'b, O: Into<String>>(
         &self,
         origin: O,
         source: &'b str,
-    ) -> MrmlCursor<'b> {
-        MrmlCursor {
+    ) -> Result<MrmlCursor<'b>, Error> {
+        let child = MrmlCursor {
             tokenizer: Tokenizer::from(source),
+            source: None,
             buffer: Default::default(),
             origin: Origin::Include {
                 path: origin.into(),
             },
             warnings: Default::default(),
+            limits: self.limits,
+            usage: self.usage,
+        };
+        child.check_input_size(source)?;
+        Ok(child)
+    }
+
+    /// Reads the tokens from `masked`, a copy of the template with its raw
+    /// tokens masked, while taking their values from the template.
+    pub(crate) fn with_masked_source(mut self, source: &'a str, masked: &'a str) -> Self {
+        if !std::ptr::eq(source, masked) {
+            self.tokenizer = Tokenizer::from(masked);
+            self.source = Some(source);
+        }
+        self
+    }
+
+    /// Reads `text`, the content being tokenized, as a fragment that can have
+    /// several elements at its root, after the leading whitespaces.
+    pub(crate) fn with_fragment(mut self, text: &'a str) -> Self {
+        let start = text.len() - text.trim_start().len();
+        self.tokenizer = Tokenizer::from_fragment(text, start..text.len());
+        self
+    }
+
+    /// Gives the span of the template matching the span of the masked copy.
+    pub(crate) fn unmask(&self, span: StrSpan<'a>) -> StrSpan<'a> {
+        match self.source {
+            Some(source) => Stream::from_substr(source, span.range()).slice_tail(),
+            None => span,
         }
     }
 
+    /// Takes back the warnings and the resources used by the cursor of an
+    /// included template.
+    pub(crate) fn merge_child(&mut self, child: MrmlCursor<'_>) {
+        self.usage.elements = child.usage.elements;
+        self.warnings.extend(child.warnings);
+    }
+
     pub(crate) fn origin(&self) -> Origin {
         self.origin.clone()
     }
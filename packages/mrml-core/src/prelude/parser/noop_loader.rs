@@ -18,6 +18,7 @@ use crate::prelude::parser::loader::IncludeLoader;
 /// // This could be done using `ParserOptions::default()`.
 /// let opts = ParserOptions {
 ///     include_loader: Box::new(NoopIncludeLoader::default()),
+///     ..Default::default()
 /// };
 /// let json = r#"<mjml>
 ///   <mj-body>
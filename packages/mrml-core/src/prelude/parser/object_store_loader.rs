@@ -0,0 +1,182 @@
+//! Module containing a loader where all the possible files are stored in an
+//! object store (S3, GCS, Azure Blob Storage...) through the
+//! [`object_store`] crate.
+
+use std::io::ErrorKind;
+use std::sync::Arc;
+
+use object_store::path::Path;
+use object_store::ObjectStore;
+
+use super::loader::{AsyncIncludeLoader, IncludeLoaderError};
+
+#[derive(Debug)]
+/// This struct is an
+/// [`AsyncIncludeLoader`](crate::prelude::parser::loader::AsyncIncludeLoader)
+/// where you can read a template from any store implementing
+/// [`ObjectStore`] and be able to use it with
+/// [`mj-include`](crate::mj_include).
+///
+/// # Example
+/// ```rust
+/// # tokio_test::block_on(async {
+/// use std::sync::Arc;
+/// use mrml::prelude::parser::object_store_loader::ObjectStoreIncludeLoader;
+/// use mrml::prelude::parser::AsyncParserOptions;
+/// use object_store::memory::InMemory;
+/// use object_store::path::Path;
+/// use object_store::ObjectStore;
+///
+/// let store = Arc::new(InMemory::new());
+/// store
+///     .put(&Path::from("partials/basic.mjml"), "<mj-button>Hello</mj-button>".into())
+///     .await
+///     .unwrap();
+/// let resolver = ObjectStoreIncludeLoader::new(store).with_prefix("partials");
+/// let opts = Arc::new(AsyncParserOptions {
+///     include_loader: Box::new(resolver),
+///     ..Default::default()
+/// });
+/// let template = r#"<mjml>
+///   <mj-body>
+///     <mj-include path="basic.mjml" />
+///   </mj-body>
+/// </mjml>"#;
+/// match mrml::async_parse_with_options(template, opts).await {
+///     Ok(_) => println!("Success!"),
+///     Err(err) => eprintln!("Couldn't parse template: {err:?}"),
+/// }
+/// # })
+/// ```
+///
+/// About the security: the paths containing relative segments like `..` are
+/// rejected, so a template cannot go fetch an object outside of the prefix.
+pub struct ObjectStoreIncludeLoader {
+    store: Arc<dyn ObjectStore>,
+    prefix: Option<Path>,
+}
+
+impl ObjectStoreIncludeLoader {
+    pub fn new(store: Arc<dyn ObjectStore>) -> Self {
+        Self {
+            store,
+            prefix: None,
+        }
+    }
+
+    /// Every path provided by the `mj-include` elements will be resolved
+    /// relatively to this prefix.
+    pub fn with_prefix<P: Into<Path>>(mut self, prefix: P) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    pub fn set_prefix<P: Into<Path>>(&mut self, prefix: P) {
+        self.prefix = Some(prefix.into());
+    }
+
+    fn build_path(&self, path: &str) -> Result<Path, IncludeLoaderError> {
+        let relative = Path::parse(path.trim_start_matches('/')).map_err(|err| {
+            IncludeLoaderError::new(path, ErrorKind::InvalidInput)
+                .with_message("unable to parse the provided path")
+                .with_cause(Arc::new(err))
+        })?;
+        Ok(match self.prefix {
+            Some(ref prefix) => prefix.parts().chain(relative.parts()).collect(),
+            None => relative,
+        })
+    }
+}
+
+fn into_include_loader_error(path: &str, err: object_store::Error) -> IncludeLoaderError {
+    let reason = match err {
+        object_store::Error::NotFound { .. } => ErrorKind::NotFound,
+        object_store::Error::PermissionDenied { .. }
+        | object_store::Error::Unauthenticated { .. } => ErrorKind::PermissionDenied,
+        _ => ErrorKind::Other,
+    };
+    IncludeLoaderError::new(path, reason)
+        .with_message("unable to fetch template")
+        .with_cause(Arc::new(err))
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl AsyncIncludeLoader for ObjectStoreIncludeLoader {
+    async fn async_resolve(&self, path: &str) -> Result<String, IncludeLoaderError> {
+        let location = self.build_path(path)?;
+        let result = self
+            .store
+            .get(&location)
+            .await
+            .map_err(|err| into_include_loader_error(path, err))?;
+        let bytes = result
+            .bytes()
+            .await
+            .map_err(|err| into_include_loader_error(path, err))?;
+        String::from_utf8(bytes.to_vec()).map_err(|err| {
+            IncludeLoaderError::new(path, ErrorKind::InvalidData)
+                .with_message("unable to convert remote template as string")
+                .with_cause(Arc::new(err))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::ErrorKind;
+    use std::sync::Arc;
+
+    use object_store::memory::InMemory;
+    use object_store::path::Path;
+    use object_store::ObjectStore;
+
+    use super::ObjectStoreIncludeLoader;
+    use crate::prelude::parser::loader::AsyncIncludeLoader;
+
+    async fn store() -> Arc<InMemory> {
+        let store = Arc::new(InMemory::new());
+        store
+            .put(
+                &Path::from("templates/partials/button.mjml"),
+                "<mj-button>Hello</mj-button>".into(),
+            )
+            .await
+            .unwrap();
+        store
+    }
+
+    #[tokio::test]
+    async fn should_resolve_without_prefix() {
+        let loader = ObjectStoreIncludeLoader::new(store().await);
+        let content = loader
+            .async_resolve("templates/partials/button.mjml")
+            .await
+            .unwrap();
+        assert_eq!(content, "<mj-button>Hello</mj-button>");
+    }
+
+    #[tokio::test]
+    async fn should_resolve_with_prefix() {
+        let loader = ObjectStoreIncludeLoader::new(store().await).with_prefix("templates");
+        let content = loader.async_resolve("/partials/button.mjml").await.unwrap();
+        assert_eq!(content, "<mj-button>Hello</mj-button>");
+    }
+
+    #[tokio::test]
+    async fn should_not_find() {
+        let loader = ObjectStoreIncludeLoader::new(store().await).with_prefix("templates");
+        let err = loader.async_resolve("unknown.mjml").await.unwrap_err();
+        assert_eq!(err.reason, ErrorKind::NotFound);
+    }
+
+    #[tokio::test]
+    async fn should_reject_parent_directory() {
+        let loader = ObjectStoreIncludeLoader::new(store().await).with_prefix("templates/partials");
+        let err = loader
+            .async_resolve("../partials/button.mjml")
+            .await
+            .unwrap_err();
+        assert_eq!(err.reason, ErrorKind::InvalidInput);
+    }
+}
@@ -6,11 +6,15 @@ pub struct ParseOutput<E> {
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum WarningKind {
     UnexpectedAttribute,
+    UnbalancedHtml,
 }
 
 impl WarningKind {
     pub const fn as_str(&self) -> &'static str {
-        "unexpected-attribute"
+        match self {
+            Self::UnexpectedAttribute => "unexpected-attribute",
+            Self::UnbalancedHtml => "unbalanced-html",
+        }
     }
 }
 
@@ -18,6 +22,7 @@ impl std::fmt::Display for WarningKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::UnexpectedAttribute => f.write_str("unexpected attribute"),
+            Self::UnbalancedHtml => f.write_str("unbalanced html element"),
         }
     }
 }
@@ -41,10 +46,6 @@ impl<'a> super::MrmlCursor<'a> {
     pub(crate) fn warnings(self) -> Vec<Warning> {
         self.warnings
     }
-
-    pub(crate) fn with_warnings(&mut self, others: Vec<Warning>) {
-        self.warnings.extend(others);
-    }
 }
 
 impl std::fmt::Display for Warning {
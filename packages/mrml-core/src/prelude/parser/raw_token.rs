@@ -0,0 +1,199 @@
+//! Merge tags of the emailing services, like `*|FNAME|*` or `<%= name %>`,
+//! kept as they are in the attributes and in the text.
+//!
+//! The characters of the tokens that the xml tokenizer would interpret, like
+//! `<` or `"`, are masked before tokenizing. The values are then read from the
+//! original template, so the tokens are unchanged.
+
+use std::borrow::Cow;
+
+/// Delimiters of a merge tag.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawTokenPattern {
+    pub start: Cow<'static, str>,
+    pub end: Cow<'static, str>,
+}
+
+impl RawTokenPattern {
+    /// Merge tags of Mailchimp, like `*|FNAME|*`.
+    pub const MAILCHIMP: Self = Self::from_static("*|", "|*");
+    /// Variables of Handlebars, Liquid or Braze, like `{{ first_name }}`.
+    pub const HANDLEBARS: Self = Self::from_static("{{", "}}");
+    /// Tags of Liquid or Jinja, like `{% if vip %}`.
+    pub const LIQUID_TAG: Self = Self::from_static("{%", "%}");
+    /// Tags of ERB or SendGrid, like `<%= name %>` or
+    /// `<%asm_group_unsubscribe_raw_url%>`.
+    pub const ERB: Self = Self::from_static("<%", "%>");
+
+    pub const fn from_static(start: &'static str, end: &'static str) -> Self {
+        Self {
+            start: Cow::Borrowed(start),
+            end: Cow::Borrowed(end),
+        }
+    }
+
+    pub fn new<S: Into<Cow<'static, str>>, E: Into<Cow<'static, str>>>(start: S, end: E) -> Self {
+        Self {
+            start: start.into(),
+            end: end.into(),
+        }
+    }
+
+    /// The patterns of the common emailing services.
+    pub fn common() -> Vec<Self> {
+        vec![
+            Self::MAILCHIMP,
+            Self::HANDLEBARS,
+            Self::LIQUID_TAG,
+            Self::ERB,
+        ]
+    }
+}
+
+fn is_masked(value: u8) -> bool {
+    matches!(value, b'<' | b'>' | b'&' | b'"' | b'\'')
+}
+
+/// Replaces the characters of the tokens that the xml tokenizer would
+/// interpret. The result has the same length than the source, so the
+/// positions of the tokenizer match the source.
+pub(crate) fn mask<'s>(patterns: &[RawTokenPattern], source: &'s str) -> Cow<'s, str> {
+    if patterns.is_empty() {
+        return Cow::Borrowed(source);
+    }
+    let bytes = source.as_bytes();
+    let mut result: Option<Vec<u8>> = None;
+    // the patterns without any end left in the source, that can't match
+    // anymore
+    let mut exhausted = vec![false; patterns.len()];
+    let mut index = 0;
+    while index < bytes.len() {
+        let Some((position, pattern)) = patterns
+            .iter()
+            .enumerate()
+            .filter(|(position, item)| {
+                !exhausted[*position] && !item.start.is_empty() && !item.end.is_empty()
+            })
+            .find(|(_, item)| bytes[index..].starts_with(item.start.as_bytes()))
+        else {
+            index += 1;
+            continue;
+        };
+        let content = index + pattern.start.len();
+        let Some(offset) = source[content..].find(pattern.end.as_ref()) else {
+            exhausted[position] = true;
+            index += 1;
+            continue;
+        };
+        let end = content + offset + pattern.end.len();
+        if bytes[index..end].iter().any(|item| is_masked(*item)) {
+            let buffer = result.get_or_insert_with(|| bytes.to_vec());
+            for item in buffer[index..end]
+                .iter_mut()
+                .filter(|item| is_masked(**item))
+            {
+                *item = b'_';
+            }
+        }
+        index = end;
+    }
+    match result {
+        // only ascii characters are replaced by ascii characters
+        Some(buffer) => Cow::Owned(String::from_utf8(buffer).expect("valid utf8")),
+        None => Cow::Borrowed(source),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{mask, RawTokenPattern};
+
+    #[test]
+    fn should_mask_the_tokens() {
+        let patterns = RawTokenPattern::common();
+        assert!(matches!(
+            mask(&patterns, "<p>Hello *|FNAME|*</p>"),
+            Cow::Borrowed(_)
+        ));
+        let source = r#"<a href="<%= url "home" %>">{{ a < b }} é</a>"#;
+        let masked = mask(&patterns, source);
+        assert_eq!(masked, r#"<a href="_%= url _home_ %_">{{ a _ b }} é</a>"#);
+        assert_eq!(masked.len(), source.len());
+        assert_eq!(
+            mask(&patterns, "<p>{{ unclosed < </p>"),
+            "<p>{{ unclosed < </p>"
+        );
+    }
+
+    #[test]
+    fn should_parse_unclosed_tokens_in_linear_time() {
+        let template = format!(
+            "<mjml><mj-body><mj-text>{}</mj-text></mj-body></mjml>",
+            "{{ *| <% ".repeat(50_000)
+        );
+        assert!(matches!(
+            mask(&RawTokenPattern::common(), &template),
+            Cow::Borrowed(_)
+        ));
+        let opts = crate::prelude::parser::ParserOptions {
+            limits: crate::prelude::parser::ParserLimits::untrusted(),
+            raw_token_patterns: RawTokenPattern::common(),
+            ..Default::default()
+        };
+        let start = std::time::Instant::now();
+        let _ = crate::mjml::Mjml::parse_with_options(&template, &opts);
+        assert!(start.elapsed() < std::time::Duration::from_secs(5));
+    }
+
+    #[test]
+    fn should_check_the_size_before_masking() {
+        let template = format!("<mjml>{}</mjml>", "{{ ".repeat(1024 * 1024));
+        let opts = crate::prelude::parser::ParserOptions {
+            limits: crate::prelude::parser::ParserLimits::untrusted(),
+            raw_token_patterns: RawTokenPattern::common(),
+            ..Default::default()
+        };
+        let result = crate::mjml::Mjml::parse_with_options(&template, &opts);
+        assert!(matches!(
+            result,
+            Err(crate::prelude::parser::Error::LimitExceeded { .. })
+        ));
+    }
+
+    #[test]
+    fn should_keep_the_tokens_byte_for_byte() {
+        let template = r#"<mjml><mj-body><mj-section><mj-column>
+<mj-button href="<%asm_group_unsubscribe_raw_url%>">{{ name | default: "there" }}</mj-button>
+<mj-text><a title="{{ title | default: "Hello" }}" href="*|UNSUB|*">Unsubscribe <% if x < 1 %>now<% end %></a></mj-text>
+</mj-column></mj-section></mj-body></mjml>"#;
+        let opts = crate::prelude::parser::ParserOptions {
+            raw_token_patterns: RawTokenPattern::common(),
+            ..Default::default()
+        };
+        let root = crate::mjml::Mjml::parse_with_options(template, &opts).unwrap();
+        let result = root.element.render(&Default::default()).unwrap();
+        assert!(result.contains(r#"href="<%asm_group_unsubscribe_raw_url%>""#));
+        assert!(result.contains(r#"{{ name | default: "there" }}"#));
+        assert!(result.contains(r#"title='{{ title | default: "Hello" }}'"#));
+        assert!(result.contains(r#"href="*|UNSUB|*""#));
+        assert!(result.contains("Unsubscribe <% if x < 1 %>now<% end %>"));
+
+        assert!(crate::mjml::Mjml::parse(template).is_err());
+    }
+
+    #[cfg(feature = "print")]
+    #[test]
+    fn should_print_the_tokens_byte_for_byte() {
+        use crate::prelude::print::Printable;
+
+        let template = r#"<mjml><mj-body><mj-text title="*|FNAME|*" href="{{ url }}">Hello *|FNAME|*, {{ x < 1 }} &amp; <%= name %></mj-text></mj-body></mjml>"#;
+        let opts = crate::prelude::parser::ParserOptions {
+            raw_token_patterns: RawTokenPattern::common(),
+            ..Default::default()
+        };
+        let root = crate::mjml::Mjml::parse_with_options(template, &opts).unwrap();
+        assert_eq!(root.element.print_dense().unwrap(), template);
+    }
+}
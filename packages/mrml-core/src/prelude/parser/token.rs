@@ -77,10 +77,13 @@ impl<'a> MrmlToken<'a> {
             } => Ok(MrmlToken::Attribute(Attribute {
                 prefix,
                 local,
-                value,
-                span,
+                value: cursor.unmask(value),
+                span: cursor.unmask(span),
+            })),
+            Token::Comment { text, span } => Ok(MrmlToken::Comment(Comment {
+                span: cursor.unmask(span),
+                text: cursor.unmask(text),
             })),
-            Token::Comment { text, span } => Ok(MrmlToken::Comment(Comment { span, text })),
             Token::ElementEnd {
                 end: xmlparser::ElementEnd::Close(prefix, local),
                 span,
@@ -106,7 +109,9 @@ impl<'a> MrmlToken<'a> {
                 local,
                 span,
             })),
-            Token::Text { text } => Ok(MrmlToken::Text(Text { text })),
+            Token::Text { text } => Ok(MrmlToken::Text(Text {
+                text: cursor.unmask(text),
+            })),
             other => Err(super::Error::UnexpectedToken {
                 origin: cursor.origin(),
                 position: other.into(),
@@ -171,10 +176,24 @@ pub(crate) struct Text<'a> {
     pub text: StrSpan<'a>,
 }
 
+/// The xml declaration, the doctype and the processing instructions that
+/// some tools add to the templates are ignored.
+fn is_ignored(token: &Token<'_>) -> bool {
+    matches!(
+        token,
+        Token::Declaration { .. }
+            | Token::DtdStart { .. }
+            | Token::DtdEnd { .. }
+            | Token::EmptyDtd { .. }
+            | Token::EntityDeclaration { .. }
+            | Token::ProcessingInstruction { .. }
+    )
+}
+
 impl<'a> super::MrmlCursor<'a> {
     fn read_next_token(&mut self) -> Option<Result<MrmlToken<'a>, super::Error>> {
         self.tokenizer
-            .next()
+            .find(|res| !res.as_ref().is_ok_and(is_ignored))
             .map(|res| {
                 res.map_err(|source| super::Error::ParserError {
                     origin: self.origin(),
@@ -188,10 +207,24 @@ impl<'a> super::MrmlCursor<'a> {
                 {
                     self.read_next_token()
                 }
+                Ok(token) => Some(self.count_token(&token).map(|_| token)),
                 other => Some(other),
             })
     }
 
+    fn count_token(&mut self, token: &MrmlToken<'a>) -> Result<(), super::Error> {
+        match token {
+            MrmlToken::ElementStart(_) => self.count_element_start(),
+            MrmlToken::Attribute(_) => self.count_attribute(),
+            MrmlToken::ElementEnd(ElementEnd { empty: false, .. }) => self.count_element_open(),
+            MrmlToken::ElementClose(_) => {
+                self.count_element_close();
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
     pub(crate) fn next_token(&mut self) -> Option<Result<MrmlToken<'a>, super::Error>> {
         if let Some(item) = self.buffer.pop() {
             Some(Ok(item))
@@ -271,6 +304,35 @@ impl<'a> super::MrmlCursor<'a> {
         }
     }
 
+    /// Ends a void element. Their closing tag is optional, like in html with
+    /// `<br>`, or explicit, like in xml with `<br></br>`.
+    pub(crate) fn end_void_element(
+        &mut self,
+        tag: &str,
+        ending: &ElementEnd<'a>,
+    ) -> Result<(), super::Error> {
+        if ending.empty {
+            return Ok(());
+        }
+        match self.next_token() {
+            Some(Ok(MrmlToken::ElementClose(inner)))
+                if inner.local.as_str().eq_ignore_ascii_case(tag) =>
+            {
+                Ok(())
+            }
+            Some(Ok(other)) => {
+                self.rewind(other);
+                self.count_element_close();
+                Ok(())
+            }
+            Some(Err(inner)) => Err(inner),
+            None => {
+                self.count_element_close();
+                Ok(())
+            }
+        }
+    }
+
     pub(crate) fn next_text(&mut self) -> Result<Option<Text<'a>>, super::Error> {
         match self.next_token() {
             Some(Ok(MrmlToken::Text(inner))) => Ok(Some(inner)),
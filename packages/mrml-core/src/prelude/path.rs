@@ -0,0 +1,280 @@
+//! Address of an element of a template, like
+//! `/mjml/mj-body/mj-section[1]/mj-column[2]/mj-button[1]`, shared by the
+//! diagnostics of the linter, the changes of the diffs and the source maps.
+//!
+//! The index of a segment counts the siblings with the same tag, starting at
+//! 1, so a path stays the same when the template is printed and parsed
+//! again.
+//!
+//! ```rust
+//! # #[cfg(feature = "parse")]
+//! # {
+//! use mrml::prelude::path::ElementPath;
+//!
+//! let root = mrml::parse(
+//!     r#"<mjml><mj-body><mj-section><mj-column /><mj-column><mj-button>Go</mj-button></mj-column></mj-section></mj-body></mjml>"#,
+//! )
+//! .unwrap();
+//! let path: ElementPath = "/mjml/mj-body/mj-section[1]/mj-column[2]/mj-button[1]"
+//!     .parse()
+//!     .unwrap();
+//! let button = root.element.element_at(&path).unwrap();
+//! assert_eq!(button.text_content(), "Go");
+//! # }
+//! ```
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::tree::{TreeElement, TreeNode};
+
+/// Elements that can only appear once in their parent, written without
+/// index.
+const UNIQUE_TAGS: [&str; 3] = ["mjml", "mj-head", "mj-body"];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PathSegment {
+    pub tag: String,
+    /// Position of the element among the siblings with the same tag,
+    /// starting at 1.
+    pub index: usize,
+}
+
+impl PathSegment {
+    pub fn new<T: Into<String>>(tag: T, index: usize) -> Self {
+        Self {
+            tag: tag.into(),
+            index,
+        }
+    }
+}
+
+impl Display for PathSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.index == 1 && UNIQUE_TAGS.contains(&self.tag.as_str()) {
+            f.write_str(&self.tag)
+        } else {
+            write!(f, "{}[{}]", self.tag, self.index)
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ElementPath(Vec<PathSegment>);
+
+impl ElementPath {
+    /// Path of the `mj-body`, `/mjml/mj-body`.
+    pub fn body() -> Self {
+        Self(vec![
+            PathSegment::new("mjml", 1),
+            PathSegment::new("mj-body", 1),
+        ])
+    }
+
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+
+    /// Tag of the element.
+    pub fn tag(&self) -> Option<&str> {
+        self.0.last().map(|item| item.tag.as_str())
+    }
+
+    /// Path of the `index`th child with the given tag, starting at 1.
+    pub fn child<T: Into<String>>(&self, tag: T, index: usize) -> Self {
+        let mut result = self.clone();
+        result.0.push(PathSegment::new(tag, index));
+        result
+    }
+
+    pub fn parent(&self) -> Option<Self> {
+        self.0.split_last().map(|(_, parent)| Self(parent.to_vec()))
+    }
+
+    pub fn starts_with(&self, other: &Self) -> bool {
+        self.0.starts_with(&other.0)
+    }
+
+    /// Identifier built from the path, that can be used as an html `id` or a
+    /// key by the tools. Like the path, it doesn't change when the template is
+    /// printed and parsed again.
+    pub fn id(&self) -> String {
+        // fnv-1a, to stay the same between the versions of rust
+        let hash = self.to_string().bytes().fold(0x811c9dc5u32, |hash, byte| {
+            (hash ^ byte as u32).wrapping_mul(0x01000193)
+        });
+        format!("mj-{hash:08x}")
+    }
+}
+
+impl From<Vec<PathSegment>> for ElementPath {
+    fn from(value: Vec<PathSegment>) -> Self {
+        Self(value)
+    }
+}
+
+impl Display for ElementPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            return f.write_str("/");
+        }
+        for segment in self.0.iter() {
+            write!(f, "/{segment}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidElementPath(pub String);
+
+impl Display for InvalidElementPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid element path {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidElementPath {}
+
+fn parse_segment(value: &str) -> Option<PathSegment> {
+    let Some((tag, rest)) = value.split_once('[') else {
+        return (!value.is_empty()).then(|| PathSegment::new(value, 1));
+    };
+    let index = rest.strip_suffix(']')?.parse::<usize>().ok()?;
+    (!tag.is_empty() && index > 0).then(|| PathSegment::new(tag, index))
+}
+
+impl FromStr for ElementPath {
+    type Err = InvalidElementPath;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let Some(rest) = value.strip_prefix('/') else {
+            return Err(InvalidElementPath(value.to_string()));
+        };
+        rest.split('/')
+            .filter(|item| !item.is_empty())
+            .map(parse_segment)
+            .collect::<Option<Vec<_>>>()
+            .map(Self)
+            .ok_or_else(|| InvalidElementPath(value.to_string()))
+    }
+}
+
+#[cfg(feature = "json")]
+impl serde::Serialize for ElementPath {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'a> TreeElement<'a> {
+    /// Finds the descendant of the element at the given segments, relative to
+    /// the element.
+    pub fn descendant(&self, segments: &[PathSegment]) -> Option<&TreeElement<'a>> {
+        let Some((first, rest)) = segments.split_first() else {
+            return Some(self);
+        };
+        self.children
+            .iter()
+            .filter_map(TreeNode::as_element)
+            .filter(|child| child.tag == first.tag)
+            .nth(first.index.checked_sub(1)?)?
+            .descendant(rest)
+    }
+
+    /// Calls `visitor` on each descendant element, depth first, with its path
+    /// and its ancestors, starting with this element, which is at `path`.
+    pub fn walk_elements<F>(&self, path: &ElementPath, visitor: &mut F)
+    where
+        F: FnMut(&TreeElement<'a>, &ElementPath, &[&TreeElement<'a>]),
+    {
+        self.walk_elements_with(path, visitor, &mut Vec::new());
+    }
+
+    fn walk_elements_with<'n, F>(
+        &'n self,
+        path: &ElementPath,
+        visitor: &mut F,
+        ancestors: &mut Vec<&'n TreeElement<'a>>,
+    ) where
+        F: FnMut(&TreeElement<'a>, &ElementPath, &[&TreeElement<'a>]),
+    {
+        ancestors.push(self);
+        for (child, index) in self.indexed_children() {
+            let child_path = path.child(child.tag, index);
+            visitor(child, &child_path, ancestors);
+            child.walk_elements_with(&child_path, visitor, ancestors);
+        }
+        ancestors.pop();
+    }
+}
+
+impl crate::mjml::Mjml {
+    /// Finds the element of the body at the given path, like the ones of the
+    /// diagnostics or of the source maps.
+    pub fn element_at(&self, path: &ElementPath) -> Option<TreeElement<'_>> {
+        let body = ElementPath::body();
+        if !path.starts_with(&body) {
+            return None;
+        }
+        let tree = self.body_tree()?;
+        tree.descendant(&path.segments()[body.segments().len()..])
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ElementPath, InvalidElementPath, PathSegment};
+
+    #[test]
+    fn should_parse_and_print() {
+        let value = "/mjml/mj-body/mj-section[1]/mj-column[2]";
+        let path: ElementPath = value.parse().unwrap();
+        assert_eq!(path.to_string(), value);
+        assert_eq!(path.tag(), Some("mj-column"));
+        assert_eq!(path.segments()[3], PathSegment::new("mj-column", 2));
+        assert!(path.starts_with(&ElementPath::body()));
+        assert_eq!(
+            path.parent().unwrap(),
+            ElementPath::body().child("mj-section", 1)
+        );
+        assert_eq!(
+            "/mjml[1]".parse::<ElementPath>().unwrap().to_string(),
+            "/mjml"
+        );
+        assert_eq!(
+            "/mjml/mj-section[0]".parse::<ElementPath>(),
+            Err(InvalidElementPath("/mjml/mj-section[0]".into()))
+        );
+        assert!("mjml".parse::<ElementPath>().is_err());
+    }
+
+    #[test]
+    fn should_generate_stable_ids() {
+        let first = ElementPath::body().child("mj-section", 1);
+        let second = ElementPath::body().child("mj-section", 2);
+        assert_eq!(first.id(), first.clone().id());
+        assert_ne!(first.id(), second.id());
+        assert_eq!(first.id().len(), 11);
+    }
+
+    #[cfg(all(feature = "parse", feature = "print"))]
+    #[test]
+    fn should_resolve_after_print_and_parse() {
+        use crate::prelude::print::Printable;
+
+        let template = r#"<mjml><mj-body><mj-section><mj-column><mj-text>A</mj-text><mj-text>B</mj-text></mj-column></mj-section></mj-body></mjml>"#;
+        let path: ElementPath = "/mjml/mj-body/mj-section[1]/mj-column[1]/mj-text[2]"
+            .parse()
+            .unwrap();
+        let root = crate::parse(template).unwrap();
+        let printed = root.element.print_pretty().unwrap();
+        let root = crate::parse(printed).unwrap();
+        assert_eq!(root.element.element_at(&path).unwrap().text_content(), "B");
+        assert!(root
+            .element
+            .element_at(&path.parent().unwrap().child("mj-text", 3))
+            .is_none());
+    }
+}
@@ -77,30 +77,43 @@ use crate::mj_attributes::{MjAttributes, MjAttributesChild};
 use crate::mj_attributes_all::MjAttributesAll;
 use crate::mj_attributes_class::MjAttributesClass;
 use crate::mj_attributes_element::MjAttributesElement;
+use crate::mj_avatar::MjAvatar;
+use crate::mj_badge::MjBadge;
 use crate::mj_body::MjBodyChild;
 use crate::mj_breakpoint::MjBreakpoint;
 use crate::mj_button::MjButton;
+use crate::mj_calendar_button::MjCalendarButton;
 use crate::mj_carousel::{MjCarousel, MjCarouselChild};
 use crate::mj_carousel_image::MjCarouselImage;
+use crate::mj_code::MjCode;
 use crate::mj_column::MjColumn;
 use crate::mj_divider::MjDivider;
 use crate::mj_font::MjFont;
+use crate::mj_footer::MjFooter;
 use crate::mj_group::MjGroup;
 use crate::mj_head::MjHeadChild;
 use crate::mj_hero::MjHero;
 use crate::mj_image::MjImage;
 use crate::mj_include::body::MjIncludeBody;
 use crate::mj_include::head::MjIncludeHead;
+use crate::mj_map::MjMap;
+use crate::mj_msooffice::MjMsoOffice;
 use crate::mj_navbar::{MjNavbar, MjNavbarChild};
 use crate::mj_navbar_link::MjNavbarLink;
+use crate::mj_not_mso::MjNotMso;
 use crate::mj_preview::MjPreview;
+use crate::mj_quote::MjQuote;
 use crate::mj_raw::{MjRaw, MjRawChild};
 use crate::mj_section::MjSection;
 use crate::mj_social::{MjSocial, MjSocialChild};
 use crate::mj_social_element::MjSocialElement;
 use crate::mj_spacer::MjSpacer;
+use crate::mj_stat::MjStat;
+use crate::mj_stats::{MjStats, MjStatsChild};
 use crate::mj_style::MjStyle;
+use crate::mj_tab::MjTab;
 use crate::mj_table::MjTable;
+use crate::mj_tabs::{MjTabs, MjTabsChild};
 use crate::mj_text::MjText;
 use crate::mj_title::MjTitle;
 use crate::mj_wrapper::MjWrapper;
@@ -115,7 +128,9 @@ use crate::text::Text;
     MjHeadChild,
     MjNavbarChild,
     MjRawChild,
-    MjSocialChild
+    MjSocialChild,
+    MjStatsChild,
+    MjTabsChild
 )]
 pub trait Printable {
     fn print<P: Printer>(&self, printer: &mut P) -> std::fmt::Result;
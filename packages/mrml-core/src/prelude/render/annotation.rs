@@ -0,0 +1,166 @@
+use super::{Error, Render, RenderContext, RenderCursor, Tag};
+use crate::helper::size::{Pixel, Size};
+
+/// Renderer surrounding the html of an element with comments naming it, and
+/// giving the line of the template defining it when known, when the `debug_annotations` option is enabled, and following its path
+/// when building a source map or a report.
+struct Annotated<'root, 'render> {
+    inner: Box<dyn Render<'root> + 'render>,
+}
+
+impl<'root, 'render> Render<'root> for Annotated<'root, 'render> {
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.inner.context()
+    }
+
+    fn tag(&self) -> Option<&str> {
+        self.inner.tag()
+    }
+
+    fn raw_attribute(&self, key: &str) -> Option<&'root str> {
+        self.inner.raw_attribute(key)
+    }
+
+    fn raw_extra_attribute(&self, key: &str) -> Option<&'root str> {
+        self.inner.raw_extra_attribute(key)
+    }
+
+    fn get_width(&self) -> Option<Size> {
+        self.inner.get_width()
+    }
+
+    fn default_attribute(&self, key: &str) -> Option<&'static str> {
+        self.inner.default_attribute(key)
+    }
+
+    fn set_style<'a, 't>(&'a self, name: &str, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 'a,
+        'a: 't,
+    {
+        self.inner.set_style(name, tag)
+    }
+
+    fn set_container_width(&mut self, width: Option<Pixel>) {
+        self.inner.set_container_width(width);
+    }
+
+    fn set_index(&mut self, index: usize) {
+        self.inner.set_index(index);
+    }
+
+    fn set_siblings(&mut self, count: usize) {
+        self.inner.set_siblings(count);
+    }
+
+    fn set_raw_siblings(&mut self, count: usize) {
+        self.inner.set_raw_siblings(count);
+    }
+
+    fn add_extra_attribute(&mut self, key: &'root str, value: &'root str) {
+        self.inner.add_extra_attribute(key, value);
+    }
+
+    fn render_fragment(&self, name: &str, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.inner.render_fragment(name, cursor)
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let context = self.inner.context();
+        let tag = self.inner.tag().unwrap_or_default();
+        let debug = context.options.debug_annotations;
+        let path = context.paths.enter(tag);
+        if debug {
+            cursor.buffer.push_str("<!-- ");
+            cursor.buffer.push_str(tag);
+            if let Some(line) = path
+                .as_ref()
+                .and_then(|path| context.source_lines.get(path))
+            {
+                cursor.buffer.push_str(" line ");
+                cursor.buffer.push_str(&line.to_string());
+            }
+            cursor.buffer.push_str(" -->");
+        }
+        let entry = path.and_then(|path| context.source_map.enter(path));
+        let start = cursor.buffer.len();
+        self.inner.render(cursor)?;
+        if let Some(index) = entry {
+            context.source_map.leave(index, start..cursor.buffer.len());
+        }
+        context.paths.leave();
+        if debug {
+            cursor.buffer.push_str("<!-- /");
+            cursor.buffer.push_str(tag);
+            cursor.buffer.push_str(" -->");
+        }
+        Ok(())
+    }
+}
+
+/// Surrounds the html of the element with comments naming it, when enabled
+/// in the options, and follows its path when building a source map or a
+/// report.
+pub(crate) fn annotate<'render, 'root: 'render>(
+    context: &'root RenderContext<'root>,
+    inner: Box<dyn Render<'root> + 'render>,
+) -> Box<dyn Render<'root> + 'render> {
+    let enabled = context.options.debug_annotations || context.paths.is_enabled();
+    if enabled && inner.tag().is_some() {
+        Box::new(Annotated { inner })
+    } else {
+        inner
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use crate::prelude::render::RenderOptions;
+
+    #[test]
+    fn should_annotate_elements() {
+        let root = crate::parse(
+            "<mjml><mj-body><mj-section><mj-column><mj-text>Hello</mj-text></mj-column></mj-section></mj-body></mjml>",
+        )
+        .unwrap();
+        let html = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(!html.contains("<!-- mj-text -->"));
+        let opts = RenderOptions {
+            debug_annotations: true,
+            disable_comments: true,
+            ..Default::default()
+        };
+        let html = root.element.render(&opts).unwrap();
+        for tag in ["mj-section", "mj-column", "mj-text"] {
+            let start = html.find(&format!("<!-- {tag} -->")).unwrap();
+            let end = html.find(&format!("<!-- /{tag} -->")).unwrap();
+            assert!(start < end);
+        }
+        let text =
+            &html[html.find("<!-- mj-text -->").unwrap()..html.find("<!-- /mj-text -->").unwrap()];
+        assert!(text.contains("Hello"));
+    }
+
+    #[test]
+    fn should_annotate_elements_with_source_lines() {
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-text>Hello</mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let opts = RenderOptions {
+            debug_annotations: true,
+            ..Default::default()
+        };
+        let html = root.element.render_with_source(&opts, template).unwrap();
+        assert!(html.contains("<!-- mj-section line 3 -->"));
+        assert!(html.contains("<!-- mj-column line 4 -->"));
+        assert!(html.contains("<!-- mj-text line 5 -->"));
+        assert!(html.contains("<!-- /mj-text -->"));
+    }
+}
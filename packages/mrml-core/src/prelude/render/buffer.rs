@@ -1,28 +1,234 @@
+use std::borrow::Cow;
 use std::fmt::{Debug, Display, Write};
 
-#[derive(Debug, Default)]
-pub struct RenderBuffer {
-    inner: String,
+/// Storage of the html written by the renderers in a [`RenderBuffer`].
+///
+/// The html is only read once rendered, so a backend doesn't need to keep
+/// it in a single string.
+pub trait BufferBackend: Debug + Send {
+    fn push_str(&mut self, value: &str);
+    fn len(&self) -> usize;
+    fn capacity(&self) -> usize;
+    /// Writes the content to the `writer`, in as many pieces as needed.
+    fn write_to(&self, writer: &mut dyn Write) -> std::fmt::Result;
+    /// Gathers the content in a single string.
+    fn into_string(self: Box<Self>) -> String;
+    /// Empty backend of the same kind, used to render a part of the html
+    /// apart. The `capacity` is a hint of the size of its content.
+    fn empty(&self, capacity: usize) -> Box<dyn BufferBackend>;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
-impl std::fmt::Write for RenderBuffer {
+impl BufferBackend for String {
+    #[inline]
+    fn push_str(&mut self, value: &str) {
+        String::push_str(self, value);
+    }
+
     #[inline]
-    fn write_fmt(&mut self, args: std::fmt::Arguments<'_>) -> std::fmt::Result {
-        self.inner.write_fmt(args)
+    fn len(&self) -> usize {
+        String::len(self)
     }
 
     #[inline]
+    fn capacity(&self) -> usize {
+        String::capacity(self)
+    }
+
+    fn write_to(&self, writer: &mut dyn Write) -> std::fmt::Result {
+        writer.write_str(self)
+    }
+
+    fn into_string(self: Box<Self>) -> String {
+        *self
+    }
+
+    fn empty(&self, capacity: usize) -> Box<dyn BufferBackend> {
+        Box::new(String::with_capacity(capacity))
+    }
+}
+
+/// Backend storing the html in chunks of a fixed size, to avoid allocating
+/// and copying a single string for big outputs. The values bigger than a
+/// chunk are kept in their own chunk.
+#[derive(Debug)]
+pub struct ChunkedBackend {
+    chunks: Vec<String>,
+    chunk_size: usize,
+    len: usize,
+}
+
+impl ChunkedBackend {
+    pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+    pub fn new(chunk_size: usize) -> Self {
+        Self {
+            chunks: Vec::new(),
+            chunk_size: chunk_size.max(1),
+            len: 0,
+        }
+    }
+
+    pub fn chunks(&self) -> impl Iterator<Item = &str> {
+        self.chunks.iter().map(String::as_str)
+    }
+}
+
+impl Default for ChunkedBackend {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CHUNK_SIZE)
+    }
+}
+
+impl BufferBackend for ChunkedBackend {
+    fn push_str(&mut self, value: &str) {
+        let fits = self
+            .chunks
+            .last()
+            .is_some_and(|last| last.capacity() - last.len() >= value.len());
+        if !fits {
+            self.chunks
+                .push(String::with_capacity(self.chunk_size.max(value.len())));
+        }
+        if let Some(last) = self.chunks.last_mut() {
+            last.push_str(value);
+        }
+        self.len += value.len();
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn capacity(&self) -> usize {
+        self.chunks.iter().map(String::capacity).sum()
+    }
+
+    fn write_to(&self, writer: &mut dyn Write) -> std::fmt::Result {
+        self.chunks
+            .iter()
+            .try_for_each(|chunk| writer.write_str(chunk))
+    }
+
+    fn into_string(self: Box<Self>) -> String {
+        self.chunks.concat()
+    }
+
+    fn empty(&self, _capacity: usize) -> Box<dyn BufferBackend> {
+        Box::new(Self::new(self.chunk_size))
+    }
+}
+
+/// Adapter writing the html to an [`std::io::Write`], like a file or a
+/// socket, keeping the first error.
+///
+/// ```rust
+/// use std::fmt::Write;
+/// use mrml::prelude::render::IoWriter;
+///
+/// let mut writer = IoWriter::new(Vec::new());
+/// writer.write_str("<p>Hello</p>").unwrap();
+/// assert_eq!(writer.into_inner().unwrap(), b"<p>Hello</p>");
+/// ```
+#[derive(Debug)]
+pub struct IoWriter<W> {
+    inner: W,
+    error: Option<std::io::Error>,
+}
+
+impl<W: std::io::Write> IoWriter<W> {
+    pub fn new(inner: W) -> Self {
+        Self { inner, error: None }
+    }
+
+    /// Gives back the writer, or the error that interrupted the writes.
+    pub fn into_inner(self) -> Result<W, std::io::Error> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.inner),
+        }
+    }
+}
+
+impl<W: std::io::Write> Write for IoWriter<W> {
     fn write_str(&mut self, s: &str) -> std::fmt::Result {
-        self.inner.write_str(s)
+        if self.error.is_some() {
+            return Err(std::fmt::Error);
+        }
+        self.inner.write_all(s.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            std::fmt::Error
+        })
     }
+}
+
+#[derive(Debug)]
+pub struct RenderBuffer {
+    inner: Box<dyn BufferBackend>,
+    comments: ConditionalComments,
+}
+
+impl Default for RenderBuffer {
+    fn default() -> Self {
+        Self::with_backend(String::new())
+    }
+}
 
+impl std::fmt::Write for RenderBuffer {
     #[inline]
-    fn write_char(&mut self, c: char) -> std::fmt::Result {
-        self.inner.write_char(c)
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.inner.push_str(s);
+        Ok(())
     }
 }
 
 impl RenderBuffer {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_backend(String::with_capacity(capacity))
+    }
+
+    pub fn with_backend<B: BufferBackend + 'static>(backend: B) -> Self {
+        Self {
+            inner: Box::new(backend),
+            comments: ConditionalComments::default(),
+        }
+    }
+
+    /// Writes the given openings of the conditional comments instead of the
+    /// default ones.
+    pub fn with_conditional_comments(mut self, comments: ConditionalComments) -> Self {
+        self.comments = comments;
+        self
+    }
+
+    /// Empty buffer with the same kind of backend and the same conditional
+    /// comments, to render a part of the html apart before adding it with
+    /// [`RenderBuffer::append`].
+    pub fn empty(&self, capacity: usize) -> Self {
+        Self {
+            inner: self.inner.empty(capacity),
+            comments: self.comments.clone(),
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
     #[inline]
     pub fn push_str(&mut self, value: &str) {
         self.inner.push_str(value);
@@ -30,7 +236,19 @@ impl RenderBuffer {
 
     #[inline]
     pub fn push(&mut self, value: char) {
-        self.inner.push(value);
+        self.inner.push_str(value.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Adds the content of `other` at the end of the buffer.
+    pub fn append(&mut self, other: &RenderBuffer) {
+        // the write can't fail, the buffer accepting every value
+        let _ = other.inner.write_to(self);
+    }
+
+    /// Writes the content to the `writer`, in as many pieces as the backend
+    /// stores.
+    pub fn write_to(&self, writer: &mut dyn Write) -> std::fmt::Result {
+        self.inner.write_to(writer)
     }
 
     #[inline]
@@ -39,30 +257,42 @@ impl RenderBuffer {
         key: &K,
         value: &V,
     ) -> std::fmt::Result {
-        write!(&mut self.inner, " {key}={value:?}")
+        write!(self, " {key}={value:?}")
+    }
+
+    /// Pushes an attribute between single quotes when its value contains
+    /// double quotes, like the merge tag `{{ name | default: "there" }}`, to
+    /// keep it unchanged.
+    #[inline]
+    pub fn push_raw_attribute(&mut self, key: &str, value: &str) -> std::fmt::Result {
+        if value.contains('"') && !value.contains('\'') {
+            write!(self, " {key}='{value}'")
+        } else {
+            self.push_attribute(key, value)
+        }
     }
 
     #[inline]
     pub fn open_tag(&mut self, tag: &str) {
-        self.inner.push('<');
-        self.inner.push_str(tag);
+        self.push('<');
+        self.push_str(tag);
     }
 
     #[inline]
     pub fn closed_tag(&mut self) {
-        self.inner.push_str(" />");
+        self.push_str(" />");
     }
 
     #[inline]
     pub fn close_tag(&mut self) {
-        self.inner.push('>');
+        self.push('>');
     }
 
     #[inline]
     pub fn end_tag(&mut self, tag: &str) {
-        self.inner.push_str("</");
-        self.inner.push_str(tag);
-        self.inner.push('>');
+        self.push_str("</");
+        self.push_str(tag);
+        self.push('>');
     }
 }
 
@@ -76,12 +306,12 @@ const END_NEGATION_CONDITIONAL_TAG: &str = "<!--<![endif]-->";
 impl RenderBuffer {
     #[inline]
     pub fn start_conditional_tag(&mut self) {
-        self.inner.push_str(START_CONDITIONAL_TAG);
+        self.inner.push_str(&self.comments.start);
     }
 
     #[inline]
     pub fn start_negation_conditional_tag(&mut self) {
-        self.inner.push_str(START_NEGATION_CONDITIONAL_TAG);
+        self.inner.push_str(&self.comments.start_negation);
     }
 
     #[inline]
@@ -105,14 +335,188 @@ impl RenderBuffer {
     }
 }
 
-impl AsRef<str> for RenderBuffer {
-    fn as_ref(&self) -> &str {
-        self.inner.as_str()
+/// Openings of the conditional comments written by
+/// [`RenderBuffer::start_conditional_tag`], wrapping the content only visible
+/// in outlook, and by [`RenderBuffer::start_negation_conditional_tag`],
+/// wrapping the content hidden from outlook. The closings are fixed by the
+/// syntax of the conditional comments.
+///
+/// The openings are written by the buffer, the conditional comments of a
+/// `mj-raw` element are kept as is.
+///
+/// ```rust
+/// use mrml::prelude::render::ConditionalComments;
+///
+/// let comments = ConditionalComments::new("(gte mso 11)&(lte mso 16)", "!mso");
+/// assert_eq!(comments.start, "<!--[if (gte mso 11)&(lte mso 16)]>");
+/// assert_eq!(comments.start_negation, "<!--[if !mso]><!-->");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConditionalComments {
+    pub start: Cow<'static, str>,
+    pub start_negation: Cow<'static, str>,
+}
+
+impl Default for ConditionalComments {
+    fn default() -> Self {
+        Self {
+            start: Cow::Borrowed(START_CONDITIONAL_TAG),
+            start_negation: Cow::Borrowed(START_NEGATION_CONDITIONAL_TAG),
+        }
+    }
+}
+
+impl ConditionalComments {
+    /// Builds the openings from the conditions of the content visible in
+    /// outlook, like `mso`, and of the content hidden from outlook, like
+    /// `!mso`.
+    pub fn new(condition: &str, negation: &str) -> Self {
+        Self {
+            start: Cow::Owned(format!("<!--[if {condition}]>")),
+            start_negation: Cow::Owned(format!("<!--[if {negation}]><!-->")),
+        }
     }
 }
 
 impl From<RenderBuffer> for String {
     fn from(value: RenderBuffer) -> Self {
-        value.inner
+        value.inner.into_string()
+    }
+}
+
+/// Removes the conditional comments of the html rendered in a block only
+/// visible in outlook, when `mso` is `true`, or hidden from outlook, since
+/// the conditional comments can't be nested. The content targeting the
+/// other clients is removed.
+pub(crate) fn flatten_conditionals(input: &str, mso: bool) -> String {
+    const MARKERS: [(&str, &str, bool); 4] = [
+        (START_CONDITIONAL_TAG, END_CONDITIONAL_TAG, true),
+        (START_MSO_CONDITIONAL_TAG, END_CONDITIONAL_TAG, true),
+        (
+            START_NEGATION_CONDITIONAL_TAG,
+            END_NEGATION_CONDITIONAL_TAG,
+            false,
+        ),
+        (
+            START_MSO_NEGATION_CONDITIONAL_TAG,
+            END_NEGATION_CONDITIONAL_TAG,
+            false,
+        ),
+    ];
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+    loop {
+        let next = MARKERS
+            .iter()
+            .filter_map(|marker| rest.find(marker.0).map(|index| (index, marker)))
+            .min_by_key(|(index, _)| *index);
+        let Some((index, (start, end, for_mso))) = next else {
+            break;
+        };
+        output.push_str(&rest[..index]);
+        let content = &rest[index + start.len()..];
+        let (inner, after) = match content.find(end) {
+            Some(position) => (&content[..position], &content[position + end.len()..]),
+            None => (content, ""),
+        };
+        if *for_mso == mso {
+            output.push_str(inner);
+        }
+        rest = after;
+    }
+    output.push_str(rest);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+
+    use super::{
+        flatten_conditionals, ChunkedBackend, ConditionalComments, IoWriter, RenderBuffer,
+    };
+
+    const INPUT: &str = "<div><!--[if mso | IE]><table><tr><td><![endif]--><p>Hello</p><!--[if mso | IE]></td></tr></table><![endif]--><!--[if !mso | IE]><!--><img /><!--<![endif]--></div>";
+
+    #[test]
+    fn should_flatten_for_mso() {
+        assert_eq!(
+            flatten_conditionals(INPUT, true),
+            "<div><table><tr><td><p>Hello</p></td></tr></table></div>"
+        );
+    }
+
+    #[test]
+    fn should_flatten_for_other_clients() {
+        assert_eq!(
+            flatten_conditionals(INPUT, false),
+            "<div><p>Hello</p><img /></div>"
+        );
+    }
+
+    #[test]
+    fn should_write_conditional_comments() {
+        let comments = ConditionalComments::new("(gte mso 11)&(lte mso 16)", "!mso");
+        let mut buffer = RenderBuffer::default().with_conditional_comments(comments);
+        buffer.start_conditional_tag();
+        buffer.push_str("<table>");
+        buffer.end_conditional_tag();
+        let mut part = buffer.empty(0);
+        part.start_negation_conditional_tag();
+        part.push_str("<img />");
+        part.end_negation_conditional_tag();
+        buffer.append(&part);
+        assert_eq!(
+            String::from(buffer),
+            "<!--[if (gte mso 11)&(lte mso 16)]><table><![endif]--><!--[if !mso]><!--><img /><!--<![endif]-->"
+        );
+    }
+
+    #[test]
+    fn should_store_in_chunks() {
+        let mut buffer = RenderBuffer::with_backend(ChunkedBackend::new(8));
+        buffer.open_tag("div");
+        buffer.push_attribute("class", "content").unwrap();
+        buffer.close_tag();
+        buffer.push_str("Hello World!");
+        buffer.end_tag("div");
+        let mut part = buffer.empty(0);
+        part.push_str("<p>Bye</p>");
+        buffer.append(&part);
+        assert_eq!(buffer.len(), 49);
+        assert_eq!(
+            String::from(buffer),
+            r#"<div class="content">Hello World!</div><p>Bye</p>"#
+        );
+    }
+
+    #[test]
+    fn should_write_to_io() {
+        let mut buffer = RenderBuffer::with_backend(ChunkedBackend::new(4));
+        buffer.push_str("<p>Hello</p>");
+        let mut writer = IoWriter::new(Vec::new());
+        buffer.write_to(&mut writer).unwrap();
+        assert_eq!(writer.into_inner().unwrap(), b"<p>Hello</p>");
+    }
+
+    #[test]
+    fn should_keep_io_error() {
+        #[derive(Debug)]
+        struct Failing;
+
+        impl std::io::Write for Failing {
+            fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("closed"))
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut writer = IoWriter::new(Failing);
+        assert!(writer.write_str("<p>").is_err());
+        assert!(writer.write_str("</p>").is_err());
+        assert_eq!(writer.into_inner().unwrap_err().to_string(), "closed");
     }
 }
@@ -0,0 +1,262 @@
+//! Cache of the html rendered for the sections, reused when the same section
+//! is rendered again with the same head and container width, like a header
+//! or a footer shared by all the emails of a newsletter.
+//!
+//! ```rust
+//! # #[cfg(feature = "parse")]
+//! # {
+//! use std::sync::Arc;
+//!
+//! use mrml::prelude::render::{MemoryRenderCache, RenderOptions};
+//!
+//! let opts = RenderOptions {
+//!     cache: Some(Arc::new(MemoryRenderCache::default())),
+//!     ..Default::default()
+//! };
+//! for name in ["Alice", "Bob"] {
+//!     let template = format!(
+//!         "<mjml><mj-body><mj-section><mj-column><mj-text>Hello {name}</mj-text></mj-column></mj-section><mj-section><mj-column><mj-text>Footer</mj-text></mj-column></mj-section></mj-body></mjml>"
+//!     );
+//!     let root = mrml::parse(template).expect("couldn't parse template");
+//!     println!("{}", root.element.render(&opts).expect("couldn't render template"));
+//! }
+//! # }
+//! ```
+//!
+//! The cache should only be shared between renders using the same options,
+//! the options not being part of the key.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hasher;
+use std::sync::{Arc, Mutex};
+
+use super::{Error, RenderContext, RenderCursor, VariableHeader};
+use crate::helper::size::Pixel;
+
+/// Html rendered for a section, with the fonts, media queries and styles it
+/// requires in the head.
+#[derive(Clone, Debug)]
+pub struct CachedRender {
+    pub html: String,
+    pub header: VariableHeader,
+}
+
+/// Storage of the rendered sections. The key is a hash of the section, the
+/// attributes defined in the head and the width of the container. It's
+/// only stable for a given version of MRML.
+pub trait RenderCache: Debug + Send + Sync {
+    fn get(&self, key: u64) -> Option<Arc<CachedRender>>;
+    fn set(&self, key: u64, value: Arc<CachedRender>);
+}
+
+/// Cache keeping the rendered sections in memory, without any eviction.
+#[derive(Default)]
+pub struct MemoryRenderCache(Mutex<HashMap<u64, Arc<CachedRender>>>);
+
+impl MemoryRenderCache {
+    pub fn len(&self) -> usize {
+        self.0.lock().map(|inner| inner.len()).unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut inner) = self.0.lock() {
+            inner.clear();
+        }
+    }
+}
+
+impl Debug for MemoryRenderCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MemoryRenderCache")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl RenderCache for MemoryRenderCache {
+    fn get(&self, key: u64) -> Option<Arc<CachedRender>> {
+        self.0
+            .lock()
+            .ok()
+            .and_then(|inner| inner.get(&key).cloned())
+    }
+
+    fn set(&self, key: u64, value: Arc<CachedRender>) {
+        if let Ok(mut inner) = self.0.lock() {
+            inner.insert(key, value);
+        }
+    }
+}
+
+/// Feeds the formatted values to the hasher, without allocating them.
+struct HashWriter<H>(H);
+
+impl<H: Hasher> std::fmt::Write for HashWriter<H> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.0.write(s.as_bytes());
+        Ok(())
+    }
+}
+
+fn cache_key<E: Debug>(
+    context: &RenderContext<'_>,
+    element: &E,
+    container_width: Option<&Pixel>,
+) -> Result<u64, Error> {
+    use std::fmt::Write;
+
+    let mut writer = HashWriter(std::collections::hash_map::DefaultHasher::new());
+    context.header.write_fingerprint(&mut writer)?;
+    context.scopes.with_current(|scope| {
+        write!(
+            writer,
+            "{:?}{scope:?}{container_width:?}{element:?}",
+            context.options.conditional_comments
+        )
+    })?;
+    Ok(writer.0.finish())
+}
+
+/// Renders the element with the `render` function, unless it's available in
+/// the cache defined in the options. The elements generating ids aren't
+/// cached, to keep the ids unique in the document. The cache is bypassed when
+/// recording a report or a source map, the cached html not replaying them.
+pub(crate) fn render_cached<E, F>(
+    context: &RenderContext<'_>,
+    element: &E,
+    container_width: Option<&Pixel>,
+    cursor: &mut RenderCursor,
+    render: F,
+) -> Result<(), Error>
+where
+    E: Debug,
+    F: FnOnce(&mut RenderCursor) -> Result<(), Error>,
+{
+    let Some(ref cache) = context.options.cache else {
+        return render(cursor);
+    };
+    if context.paths.is_enabled() || context.report.is_enabled() || context.source_map.is_enabled()
+    {
+        return render(cursor);
+    }
+    let key = cache_key(context, element, container_width)?;
+    if let Some(found) = cache.get(key) {
+        cursor.buffer.push_str(&found.html);
+        cursor.header.merge(&found.header);
+        return Ok(());
+    }
+    let ids = context.generator.current();
+    let mut inner = RenderCursor {
+        buffer: cursor.buffer.empty(0),
+        header: Default::default(),
+    };
+    render(&mut inner)?;
+    cursor.buffer.append(&inner.buffer);
+    cursor.header.merge(&inner.header);
+    if context.generator.current() == ids {
+        cache.set(
+            key,
+            Arc::new(CachedRender {
+                html: inner.buffer.into(),
+                header: inner.header,
+            }),
+        );
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use std::sync::Arc;
+
+    use super::MemoryRenderCache;
+    use crate::prelude::render::RenderOptions;
+
+    const HEADER: &str = r#"<mj-section><mj-column><mj-text font-family="Lato">Header</mj-text></mj-column></mj-section>"#;
+
+    fn template(head: &str, content: &str) -> String {
+        format!(
+            "<mjml><mj-head>{head}</mj-head><mj-body>{HEADER}<mj-section><mj-column><mj-text>{content}</mj-text></mj-column></mj-section></mj-body></mjml>"
+        )
+    }
+
+    fn render(template: &str, opts: &RenderOptions) -> String {
+        crate::parse(template)
+            .unwrap()
+            .element
+            .render(opts)
+            .unwrap()
+    }
+
+    #[test]
+    fn should_reuse_sections() {
+        let cache = Arc::new(MemoryRenderCache::default());
+        let opts = RenderOptions {
+            cache: Some(cache.clone()),
+            ..Default::default()
+        };
+        for content in ["Hello", "World", "Hello"] {
+            let template = template("", content);
+            assert_eq!(
+                render(&template, &opts),
+                render(&template, &RenderOptions::default())
+            );
+        }
+        assert_eq!(cache.len(), 3);
+    }
+
+    #[test]
+    fn should_depend_on_head() {
+        let cache = Arc::new(MemoryRenderCache::default());
+        let opts = RenderOptions {
+            cache: Some(cache.clone()),
+            ..Default::default()
+        };
+        render(&template("", "Hello"), &opts);
+        let template = template(
+            r#"<mj-attributes><mj-text color="red" /></mj-attributes>"#,
+            "Hello",
+        );
+        let result = render(&template, &opts);
+        assert!(result.contains("color:red"));
+        assert_eq!(cache.len(), 4);
+    }
+
+    #[test]
+    fn should_not_cache_generated_ids() {
+        let cache = Arc::new(MemoryRenderCache::default());
+        let opts = RenderOptions {
+            cache: Some(cache.clone()),
+            ..Default::default()
+        };
+        let template = r#"<mjml><mj-body><mj-section><mj-column><mj-navbar hamburger="hamburger"><mj-navbar-link href="/">Home</mj-navbar-link></mj-navbar></mj-column></mj-section></mj-body></mjml>"#;
+        render(template, &opts);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn should_report_with_warm_cache() {
+        let cache = Arc::new(MemoryRenderCache::default());
+        let opts = RenderOptions {
+            cache: Some(cache.clone()),
+            ..Default::default()
+        };
+        let template = r#"<mjml><mj-body><mj-section><mj-column><mj-image src="logo.png" width="foo" /></mj-column></mj-section></mj-body></mjml>"#;
+        let root = crate::parse(template).unwrap();
+        root.element.render(&opts).unwrap();
+        assert_eq!(cache.len(), 1);
+        let (cold, cold_warnings) = root
+            .element
+            .render_with_report(&RenderOptions::default())
+            .unwrap();
+        let (warm, warm_warnings) = root.element.render_with_report(&opts).unwrap();
+        assert_eq!(warm, cold);
+        assert!(!warm_warnings.is_empty());
+        assert_eq!(warm_warnings, cold_warnings);
+    }
+}
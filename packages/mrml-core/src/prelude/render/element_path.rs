@@ -0,0 +1,85 @@
+use std::sync::Mutex;
+
+use crate::prelude::path::ElementPath;
+
+pub(crate) struct Frame {
+    pub path: ElementPath,
+    counters: Vec<(String, usize)>,
+}
+
+impl Frame {
+    pub fn new(path: ElementPath) -> Self {
+        Self {
+            path,
+            counters: Vec::new(),
+        }
+    }
+
+    /// Path of the next child with the given tag, counting the siblings with
+    /// the same tag.
+    pub fn child_path(&mut self, tag: &str) -> ElementPath {
+        let index = match self.counters.iter_mut().find(|(name, _)| name == tag) {
+            Some((_, count)) => {
+                *count += 1;
+                *count
+            }
+            None => {
+                self.counters.push((tag.to_string(), 1));
+                1
+            }
+        };
+        self.path.child(tag, index)
+    }
+}
+
+/// Path of the element of the body being rendered, like
+/// `/mjml/mj-body/mj-section[1]/mj-column[2]`, followed by the renderers when
+/// a source map or a report is requested.
+#[derive(Default)]
+pub(crate) struct ElementPathRecorder(Mutex<Option<Vec<Frame>>>);
+
+impl std::fmt::Debug for ElementPathRecorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ElementPathRecorder")
+            .field("enabled", &self.is_enabled())
+            .finish()
+    }
+}
+
+impl ElementPathRecorder {
+    pub fn enable(&self) {
+        if let Ok(mut inner) = self.0.lock() {
+            *inner = Some(vec![Frame::new(ElementPath::body())]);
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.0.lock().map(|inner| inner.is_some()).unwrap_or(false)
+    }
+
+    /// Starts rendering a child of the current element, returns its path.
+    pub fn enter(&self, tag: &str) -> Option<ElementPath> {
+        let mut inner = self.0.lock().ok()?;
+        let frames = inner.as_mut()?;
+        let path = frames.last_mut()?.child_path(tag);
+        frames.push(Frame::new(path.clone()));
+        Some(path)
+    }
+
+    pub fn leave(&self) {
+        if let Ok(mut inner) = self.0.lock() {
+            if let Some(frames) = inner.as_mut() {
+                frames.pop();
+            }
+        }
+    }
+
+    /// Path of the element being rendered.
+    pub fn current(&self) -> ElementPath {
+        self.0
+            .lock()
+            .ok()
+            .and_then(|inner| inner.as_ref()?.last().map(|frame| frame.path.clone()))
+            .unwrap_or_else(ElementPath::body)
+    }
+}
@@ -0,0 +1,247 @@
+/// Elements where the entities are not decoded by the browsers.
+const RAW_TEXT_ELEMENTS: [&str; 2] = ["script", "style"];
+
+/// Named entities supported by every email client, the html 4 ones.
+const NAMED_ENTITIES: [(char, &str); 114] = [
+    ('\u{a0}', "nbsp"),
+    ('¡', "iexcl"),
+    ('¢', "cent"),
+    ('£', "pound"),
+    ('¤', "curren"),
+    ('¥', "yen"),
+    ('¦', "brvbar"),
+    ('§', "sect"),
+    ('¨', "uml"),
+    ('©', "copy"),
+    ('ª', "ordf"),
+    ('«', "laquo"),
+    ('¬', "not"),
+    ('\u{ad}', "shy"),
+    ('®', "reg"),
+    ('¯', "macr"),
+    ('°', "deg"),
+    ('±', "plusmn"),
+    ('²', "sup2"),
+    ('³', "sup3"),
+    ('´', "acute"),
+    ('µ', "micro"),
+    ('¶', "para"),
+    ('·', "middot"),
+    ('¸', "cedil"),
+    ('¹', "sup1"),
+    ('º', "ordm"),
+    ('»', "raquo"),
+    ('¼', "frac14"),
+    ('½', "frac12"),
+    ('¾', "frac34"),
+    ('¿', "iquest"),
+    ('À', "Agrave"),
+    ('Á', "Aacute"),
+    ('Â', "Acirc"),
+    ('Ã', "Atilde"),
+    ('Ä', "Auml"),
+    ('Å', "Aring"),
+    ('Æ', "AElig"),
+    ('Ç', "Ccedil"),
+    ('È', "Egrave"),
+    ('É', "Eacute"),
+    ('Ê', "Ecirc"),
+    ('Ë', "Euml"),
+    ('Ì', "Igrave"),
+    ('Í', "Iacute"),
+    ('Î', "Icirc"),
+    ('Ï', "Iuml"),
+    ('Ð', "ETH"),
+    ('Ñ', "Ntilde"),
+    ('Ò', "Ograve"),
+    ('Ó', "Oacute"),
+    ('Ô', "Ocirc"),
+    ('Õ', "Otilde"),
+    ('Ö', "Ouml"),
+    ('×', "times"),
+    ('Ø', "Oslash"),
+    ('Ù', "Ugrave"),
+    ('Ú', "Uacute"),
+    ('Û', "Ucirc"),
+    ('Ü', "Uuml"),
+    ('Ý', "Yacute"),
+    ('Þ', "THORN"),
+    ('ß', "szlig"),
+    ('à', "agrave"),
+    ('á', "aacute"),
+    ('â', "acirc"),
+    ('ã', "atilde"),
+    ('ä', "auml"),
+    ('å', "aring"),
+    ('æ', "aelig"),
+    ('ç', "ccedil"),
+    ('è', "egrave"),
+    ('é', "eacute"),
+    ('ê', "ecirc"),
+    ('ë', "euml"),
+    ('ì', "igrave"),
+    ('í', "iacute"),
+    ('î', "icirc"),
+    ('ï', "iuml"),
+    ('ð', "eth"),
+    ('ñ', "ntilde"),
+    ('ò', "ograve"),
+    ('ó', "oacute"),
+    ('ô', "ocirc"),
+    ('õ', "otilde"),
+    ('ö', "ouml"),
+    ('÷', "divide"),
+    ('ø', "oslash"),
+    ('ù', "ugrave"),
+    ('ú', "uacute"),
+    ('û', "ucirc"),
+    ('ü', "uuml"),
+    ('ý', "yacute"),
+    ('þ', "thorn"),
+    ('ÿ', "yuml"),
+    ('Œ', "OElig"),
+    ('œ', "oelig"),
+    ('Š', "Scaron"),
+    ('š', "scaron"),
+    ('Ÿ', "Yuml"),
+    ('–', "ndash"),
+    ('—', "mdash"),
+    ('‘', "lsquo"),
+    ('’', "rsquo"),
+    ('‚', "sbquo"),
+    ('“', "ldquo"),
+    ('”', "rdquo"),
+    ('„', "bdquo"),
+    ('•', "bull"),
+    ('…', "hellip"),
+    ('‰', "permil"),
+    ('€', "euro"),
+    ('™', "trade"),
+];
+
+/// Encoding of the non ascii characters of the output.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EntityStrategy {
+    /// The characters are written as is, in UTF-8.
+    #[default]
+    Raw,
+    /// The characters are replaced by numeric entities, like `&#233;`.
+    Numeric,
+    /// The characters are replaced by named entities, like `&eacute;`, or by
+    /// numeric entities when they don't have a name.
+    Named,
+}
+
+fn raw_text_element(rest: &str) -> Option<&'static str> {
+    RAW_TEXT_ELEMENTS.iter().copied().find(|name| {
+        rest.get(1..name.len() + 1)
+            .is_some_and(|value| value.eq_ignore_ascii_case(name))
+            && rest[name.len() + 1..].starts_with(|c: char| c == '>' || c.is_ascii_whitespace())
+    })
+}
+
+/// Calls `push` for each non ascii character of the output, outside of the
+/// content of the `style` and `script` elements.
+pub(crate) fn replace_non_ascii<F>(input: &str, push: F) -> String
+where
+    F: Fn(&mut String, char),
+{
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some((index, c)) = rest
+        .char_indices()
+        .find(|(_, c)| *c == '<' || !c.is_ascii())
+    {
+        output.push_str(&rest[..index]);
+        rest = &rest[index..];
+        if c != '<' {
+            push(&mut output, c);
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+        let Some(name) = raw_text_element(rest) else {
+            output.push('<');
+            rest = &rest[1..];
+            continue;
+        };
+        let closing = format!("</{name}");
+        let end = rest
+            .to_ascii_lowercase()
+            .find(&closing)
+            .unwrap_or(rest.len());
+        output.push_str(&rest[..end]);
+        rest = &rest[end..];
+        if !rest.is_empty() {
+            output.push_str(&rest[..closing.len()]);
+            rest = &rest[closing.len()..];
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+fn push_numeric(output: &mut String, c: char) {
+    output.push_str("&#");
+    output.push_str(&(c as u32).to_string());
+    output.push(';');
+}
+
+fn push_named(output: &mut String, c: char) {
+    match NAMED_ENTITIES.iter().find(|(value, _)| *value == c) {
+        Some((_, name)) => {
+            output.push('&');
+            output.push_str(name);
+            output.push(';');
+        }
+        None => push_numeric(output, c),
+    }
+}
+
+/// Encodes the non ascii characters of the output following the given
+/// strategy. The content of the `style` and `script` elements is left
+/// untouched.
+pub fn encode_entities(input: &str, strategy: EntityStrategy) -> String {
+    match strategy {
+        EntityStrategy::Raw => input.to_string(),
+        EntityStrategy::Numeric => replace_non_ascii(input, push_numeric),
+        EntityStrategy::Named => replace_non_ascii(input, push_named),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_entities, EntityStrategy};
+
+    #[test]
+    fn should_keep_raw() {
+        assert_eq!(
+            encode_entities("<p>café</p>", EntityStrategy::Raw),
+            "<p>café</p>"
+        );
+    }
+
+    #[test]
+    fn should_encode_numeric() {
+        assert_eq!(
+            encode_entities("<p>café 😀</p>", EntityStrategy::Numeric),
+            "<p>caf&#233; &#128512;</p>"
+        );
+    }
+
+    #[test]
+    fn should_encode_named() {
+        assert_eq!(
+            encode_entities("<p>café — 10 € 😀</p>", EntityStrategy::Named),
+            "<p>caf&eacute; &mdash; 10 &euro; &#128512;</p>"
+        );
+    }
+
+    #[test]
+    fn should_not_encode_raw_text() {
+        let input = r#"<style>.a::after { content: "é"; }</style><p>é</p>"#;
+        assert_eq!(
+            encode_entities(input, EntityStrategy::Named),
+            r#"<style>.a::after { content: "é"; }</style><p>&eacute;</p>"#
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! Loaders of the font stylesheets, used to write them in the head instead of
+//! linking them, for the clients blocking the requests to the fonts CDN.
+//!
+//! The stylesheets still reference the font files with their url, unless the
+//! loader returns a stylesheet embedding them.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+/// The stylesheet of a font couldn't be loaded.
+#[derive(Clone, Debug, thiserror::Error)]
+#[error("unable to load the font {name} from {href}: {message}")]
+pub struct FontLoaderError {
+    pub name: String,
+    pub href: String,
+    pub message: String,
+}
+
+impl FontLoaderError {
+    pub fn new<N: Into<String>, H: Into<String>, M: Into<String>>(
+        name: N,
+        href: H,
+        message: M,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            href: href.into(),
+            message: message.into(),
+        }
+    }
+}
+
+pub trait FontLoader: Debug + Send + Sync {
+    /// Returns the content of the stylesheet of the font `name`, defined at
+    /// `href`.
+    fn load(&self, name: &str, href: &str) -> Result<String, FontLoaderError>;
+}
+
+/// How the stylesheets of the fonts are added to the head.
+#[derive(Clone, Debug, Default)]
+pub enum FontStrategy {
+    /// The stylesheets are referenced with a `link` element and an `@import`
+    /// rule. This is what mjml does.
+    #[default]
+    Link,
+    /// The stylesheets are loaded while rendering and written in a `style`
+    /// element.
+    Inline(Arc<dyn FontLoader>),
+}
+
+/// Loader with the stylesheets in memory, by font name or by url.
+///
+/// ```rust
+/// use std::sync::Arc;
+///
+/// use mrml::prelude::render::{FontStrategy, MemoryFontLoader, RenderOptions};
+///
+/// let loader = MemoryFontLoader::default()
+///     .with_font("Lato", "@font-face { font-family: 'Lato'; src: url(lato.woff2); }");
+/// let opts = RenderOptions {
+///     font_strategy: FontStrategy::Inline(Arc::new(loader)),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MemoryFontLoader(HashMap<String, String>);
+
+impl MemoryFontLoader {
+    /// Adds the stylesheet of a font, `key` being its name or its url.
+    pub fn with_font<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.0.insert(key.into(), value.into());
+        self
+    }
+}
+
+impl From<HashMap<String, String>> for MemoryFontLoader {
+    fn from(value: HashMap<String, String>) -> Self {
+        Self(value)
+    }
+}
+
+impl FontLoader for MemoryFontLoader {
+    fn load(&self, name: &str, href: &str) -> Result<String, FontLoaderError> {
+        self.0
+            .get(name)
+            .or_else(|| self.0.get(href))
+            .cloned()
+            .ok_or_else(|| FontLoaderError::new(name, href, "stylesheet not found"))
+    }
+}
+
+/// Loader fetching the stylesheets with the
+/// [`HttpFetcher`](crate::prelude::parser::http_loader::HttpFetcher) of the
+/// http include loader.
+///
+/// Google Fonts returns a stylesheet depending on the user agent, so setting a
+/// recent one gives the `woff2` files.
+#[cfg(feature = "http-loader-base")]
+#[derive(Debug, Default)]
+pub struct HttpFontLoader<F> {
+    fetcher: F,
+    headers: HashMap<String, String>,
+}
+
+#[cfg(feature = "http-loader-base")]
+impl<F: crate::prelude::parser::http_loader::HttpFetcher> HttpFontLoader<F> {
+    pub fn with_header<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.headers.insert(key.into(), value.into());
+        self
+    }
+}
+
+#[cfg(feature = "http-loader-base")]
+impl<F> FontLoader for HttpFontLoader<F>
+where
+    F: crate::prelude::parser::http_loader::HttpFetcher + Send + Sync,
+{
+    fn load(&self, name: &str, href: &str) -> Result<String, FontLoaderError> {
+        self.fetcher
+            .fetch(href, &self.headers)
+            .map_err(|err| FontLoaderError::new(name, href, err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FontLoader, MemoryFontLoader};
+
+    #[test]
+    fn should_load_by_name_or_url() {
+        let loader = MemoryFontLoader::default()
+            .with_font("Lato", "lato")
+            .with_font("https://example.com/roboto.css", "roboto");
+        assert_eq!(
+            loader.load("Lato", "https://example.com/lato.css").unwrap(),
+            "lato"
+        );
+        assert_eq!(
+            loader
+                .load("Roboto", "https://example.com/roboto.css")
+                .unwrap(),
+            "roboto"
+        );
+        let err = loader
+            .load("Ubuntu", "https://example.com/ubuntu.css")
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unable to load the font Ubuntu from https://example.com/ubuntu.css: stylesheet not found"
+        );
+    }
+}
@@ -5,10 +5,11 @@ use crate::helper::size::{Pixel, Size};
 use crate::mj_head::MjHead;
 use crate::prelude::hash::{Map, Set};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct VariableHeader {
     used_font_families: Set<String>,
     media_queries: Map<String, Size>,
+    tablet_media_queries: Map<String, Size>,
     styles: Set<Cow<'static, str>>,
 }
 
@@ -17,6 +18,7 @@ impl Default for VariableHeader {
         Self {
             used_font_families: Default::default(),
             media_queries: Map::new(),
+            tablet_media_queries: Map::new(),
             styles: Set::new(),
         }
     }
@@ -48,6 +50,28 @@ impl VariableHeader {
         }
     }
 
+    /// Adds the families of the `font-family` declarations of an inline
+    /// style, like the `style` attribute of the html elements.
+    pub fn add_style_font_families(&mut self, style: &str) {
+        for (name, value) in style
+            .split(';')
+            .filter_map(|declaration| declaration.split_once(':'))
+        {
+            if !name.trim().eq_ignore_ascii_case("font-family") {
+                continue;
+            }
+            let value = value.trim();
+            let value = value.strip_suffix("!important").unwrap_or(value);
+            for family in value
+                .split(',')
+                .map(|item| item.trim().trim_matches(|c| c == '\'' || c == '"'))
+                .filter(|item| !item.is_empty())
+            {
+                self.add_used_font_family(family);
+            }
+        }
+    }
+
     pub fn media_queries(&self) -> &Map<String, Size> {
         &self.media_queries
     }
@@ -56,6 +80,16 @@ impl VariableHeader {
         self.media_queries.insert(classname, size);
     }
 
+    /// Width classes applied between the breakpoint and the tablet
+    /// breakpoint.
+    pub fn tablet_media_queries(&self) -> &Map<String, Size> {
+        &self.tablet_media_queries
+    }
+
+    pub fn add_tablet_media_query(&mut self, classname: String, size: Size) {
+        self.tablet_media_queries.insert(classname, size);
+    }
+
     pub fn styles(&self) -> &Set<Cow<'static, str>> {
         &self.styles
     }
@@ -69,14 +103,163 @@ impl VariableHeader {
             self.add_style(value);
         }
     }
+
+    /// Adds the fonts, media queries and styles of `other`.
+    pub fn merge(&mut self, other: &VariableHeader) {
+        for name in other.used_font_families.iter() {
+            self.add_used_font_family(name);
+        }
+        for (classname, size) in other.media_queries.iter() {
+            self.add_media_query(classname.clone(), *size);
+        }
+        for (classname, size) in other.tablet_media_queries.iter() {
+            self.add_tablet_media_query(classname.clone(), *size);
+        }
+        for style in other.styles.iter() {
+            self.add_style(style.clone());
+        }
+    }
+}
+
+/// Parses the list of breakpoints, sorted by width. The first one is the
+/// mobile breakpoint, the second one the tablet breakpoint.
+fn parse_breakpoints<'a, I: Iterator<Item = &'a str>>(values: I) -> Vec<Pixel> {
+    let mut result: Vec<Pixel> = values
+        .filter_map(|value| Pixel::try_from(value).ok())
+        .collect();
+    result.sort_by(|a, b| {
+        a.value()
+            .partial_cmp(&b.value())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    result
+}
+
+/// Values defined in the `mj-attributes` elements sharing the same scope.
+#[derive(Clone, Default)]
+pub struct HeaderAttributes<'h> {
+    all: Map<&'h str, &'h str>,
+    class: Map<&'h str, Map<&'h str, &'h str>>,
+    element: Map<&'h str, Map<&'h str, &'h str>>,
+    class_chains: Map<&'h str, Vec<&'h str>>,
+}
+
+impl<'h> HeaderAttributes<'h> {
+    fn new(head: &'h MjHead, scope: Option<&'h str>) -> Self {
+        let class = head.build_scoped_attributes_class(scope);
+        Self {
+            all: head.build_scoped_attributes_all(scope),
+            class_chains: build_class_chains(&class),
+            class,
+            element: head.build_scoped_attributes_element(scope),
+        }
+    }
+
+    fn write_fingerprint<W: std::fmt::Write>(&self, writer: &mut W) -> std::fmt::Result {
+        for (key, value) in self.all.iter() {
+            writeln!(writer, "all {key}={value}")?;
+        }
+        for (name, values) in self.class.iter() {
+            for (key, value) in values.iter() {
+                writeln!(writer, "class {name} {key}={value}")?;
+            }
+        }
+        for (name, values) in self.element.iter() {
+            for (key, value) in values.iter() {
+                writeln!(writer, "element {name} {key}={value}")?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn all(&self, key: &str) -> Option<&str> {
+        self.all.get(key).copied()
+    }
+
+    pub fn class(&self, name: &str, key: &str) -> Option<&str> {
+        self.class
+            .get(name)
+            .and_then(|class_map| class_map.get(key))
+            .copied()
+    }
+
+    pub fn element(&self, name: &str, key: &str) -> Option<&str> {
+        self.element.get(name).and_then(|elt| elt.get(key)).copied()
+    }
+
+    /// Names of the classes to look at for an `mj-class` value, by order of
+    /// priority. Like in mjml, the last listed class overrides the previous
+    /// ones. A class can list other classes in its own `mj-class` attribute,
+    /// that come right after it, and each class is only listed once.
+    pub fn expand_classes<'a>(&'a self, value: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        let previous = move |count: usize| {
+            value
+                .split_whitespace()
+                .rev()
+                .take(count)
+                .flat_map(move |name| self.class_chain(name).iter().copied())
+        };
+        value
+            .split_whitespace()
+            .rev()
+            .enumerate()
+            .flat_map(move |(index, name)| {
+                self.class_chain(name)
+                    .iter()
+                    .copied()
+                    .filter(move |class| !previous(index).any(|item| item == *class))
+            })
+    }
+
+    fn class_chain(&self, name: &str) -> &[&'h str] {
+        self.class_chains
+            .get(name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+/// Expands each class with the classes listed in its `mj-class` attribute,
+/// recursively, so that the render doesn't walk them on each lookup.
+fn build_class_chains<'h>(
+    class: &Map<&'h str, Map<&'h str, &'h str>>,
+) -> Map<&'h str, Vec<&'h str>> {
+    class
+        .keys()
+        .map(|name| {
+            let mut chain = Vec::new();
+            push_class_chain(class, name, &mut chain);
+            (*name, chain)
+        })
+        .collect()
 }
 
+fn push_class_chain<'h>(
+    class: &Map<&'h str, Map<&'h str, &'h str>>,
+    name: &'h str,
+    result: &mut Vec<&'h str>,
+) {
+    if result.contains(&name) {
+        return;
+    }
+    result.push(name);
+    if let Some(parents) = class.get(name).and_then(|item| item.get("mj-class")) {
+        for parent in parents.split_whitespace().rev() {
+            push_class_chain(class, parent, result);
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct Header<'h> {
-    attributes_all: Map<&'h str, &'h str>,
-    attributes_class: Map<&'h str, Map<&'h str, &'h str>>,
-    attributes_element: Map<&'h str, Map<&'h str, &'h str>>,
+    attributes: HeaderAttributes<'h>,
+    scoped_attributes: Map<&'h str, HeaderAttributes<'h>>,
     breakpoint: Pixel,
+    tablet_breakpoint: Option<Pixel>,
+    breakpoint_class: Option<&'h str>,
     font_families: Map<&'h str, &'h str>,
+    font_displays: Map<&'h str, &'h str>,
+    font_fallbacks: Map<&'h str, &'h str>,
     title: Option<&'h str>,
     preview: Option<&'h str>,
     lang: Option<&'h str>,
@@ -84,60 +267,159 @@ pub struct Header<'h> {
 
 impl<'h> Header<'h> {
     pub fn new(head: Option<&'h MjHead>, lang: Option<&'h str>) -> Self {
+        let breakpoints = head
+            .and_then(|h| h.breakpoint())
+            .map(|b| parse_breakpoints(b.values()))
+            .unwrap_or_default();
         Self {
-            attributes_all: head
-                .as_ref()
-                .map(|h| h.build_attributes_all())
+            attributes: head
+                .map(|h| HeaderAttributes::new(h, None))
                 .unwrap_or_default(),
-            attributes_class: head
-                .as_ref()
-                .map(|h| h.build_attributes_class())
-                .unwrap_or_default(),
-            attributes_element: head
-                .as_ref()
-                .map(|h| h.build_attributes_element())
+            scoped_attributes: head
+                .map(|h| {
+                    h.attributes_scopes()
+                        .into_iter()
+                        .map(|scope| (scope, HeaderAttributes::new(h, Some(scope))))
+                        .collect()
+                })
                 .unwrap_or_default(),
-            breakpoint: head
-                .as_ref()
-                .and_then(|h| h.breakpoint())
-                .and_then(|s| Pixel::try_from(s.value()).ok())
+            breakpoint: breakpoints
+                .first()
+                .copied()
                 .unwrap_or_else(|| Pixel::new(480.0)),
+            tablet_breakpoint: breakpoints.get(1).copied(),
+            breakpoint_class: head
+                .and_then(|h| h.breakpoint())
+                .and_then(|b| b.attributes.css_class.as_deref()),
             font_families: head
                 .as_ref()
                 .map(|h| h.build_font_families())
                 .unwrap_or_default(),
+            font_displays: head
+                .as_ref()
+                .map(|h| h.build_font_displays())
+                .unwrap_or_default(),
+            font_fallbacks: head
+                .as_ref()
+                .map(|h| h.build_font_fallbacks())
+                .unwrap_or_default(),
             title: head.and_then(|h| h.title().map(|t| t.content())),
             preview: head.and_then(|h| h.preview().map(|t| t.content())),
             lang,
         }
     }
 
+    /// Writes the values used to resolve the attributes of the elements.
+    pub(crate) fn write_fingerprint<W: std::fmt::Write>(&self, writer: &mut W) -> std::fmt::Result {
+        writeln!(writer, "{:?}", self.lang)?;
+        writeln!(writer, "{:?}", self.breakpoint)?;
+        writeln!(writer, "{:?}", self.tablet_breakpoint)?;
+        self.attributes.write_fingerprint(writer)?;
+        for (scope, attributes) in self.scoped_attributes.iter() {
+            writeln!(writer, "scope {scope}")?;
+            attributes.write_fingerprint(writer)?;
+        }
+        for (name, value) in self.font_families.iter() {
+            writeln!(writer, "font {name}={value}")?;
+        }
+        for (name, value) in self.font_fallbacks.iter() {
+            writeln!(writer, "font-fallback {name}={value}")?;
+        }
+        Ok(())
+    }
+
     pub fn attribute_all(&self, key: &str) -> Option<&str> {
-        self.attributes_all.get(key).copied()
+        self.attributes.all(key)
     }
 
     pub fn attribute_class(&self, name: &str, key: &str) -> Option<&str> {
-        self.attributes_class
-            .get(name)
-            .and_then(|class_map| class_map.get(key))
-            .copied()
+        self.attributes.class(name, key)
     }
 
     pub fn attribute_element(&self, name: &str, key: &str) -> Option<&str> {
-        self.attributes_element
-            .get(name)
-            .and_then(|elt| elt.get(key))
-            .copied()
+        self.attributes.element(name, key)
+    }
+
+    /// Values of the `mj-attributes` applying in the scope, the ones without
+    /// scope when `None`. A scope without `mj-attributes` has no values.
+    pub fn scoped_attributes(&self, scope: Option<&str>) -> Option<&HeaderAttributes<'h>> {
+        match scope {
+            Some(name) => self.scoped_attributes.get(name),
+            None => Some(&self.attributes),
+        }
     }
 
     pub fn breakpoint(&self) -> &Pixel {
         &self.breakpoint
     }
 
+    pub fn tablet_breakpoint(&self) -> Option<&Pixel> {
+        self.tablet_breakpoint.as_ref()
+    }
+
+    /// Class of the `style` elements containing the media queries, defined
+    /// with the `css-class` of the `mj-breakpoint` element.
+    pub fn breakpoint_class(&self) -> Option<&str> {
+        self.breakpoint_class
+    }
+
+    /// Replaces the breakpoints defined by the `mj-breakpoint` element, when
+    /// the value contains a valid width.
+    pub(crate) fn override_breakpoints(&mut self, value: &str) {
+        let breakpoints = parse_breakpoints(
+            value
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|item| !item.is_empty()),
+        );
+        if let Some(first) = breakpoints.first() {
+            self.breakpoint = *first;
+            self.tablet_breakpoint = breakpoints.get(1).copied();
+        }
+    }
+
     pub fn font_families(&self) -> &Map<&str, &str> {
         &self.font_families
     }
 
+    /// Value of the `font-display` descriptor of a font, defined in its
+    /// `mj-font` element.
+    pub fn font_display(&self, name: &str) -> Option<&str> {
+        self.font_displays.get(name).copied()
+    }
+
+    /// Appends the fallback stacks of the `mj-font` elements to a
+    /// `font-family` value, skipping the families it already contains.
+    pub fn with_font_fallbacks<'a>(&self, value: &'a str) -> Cow<'a, str> {
+        if self.font_fallbacks.is_empty() {
+            return Cow::Borrowed(value);
+        }
+        let mut families = value
+            .split(',')
+            .map(|item| item.trim())
+            .filter(|item| !item.is_empty())
+            .map(String::from)
+            .collect::<Vec<_>>();
+        let count = families.len();
+        for index in 0..count {
+            let name = families[index]
+                .trim_matches(|c| c == '\'' || c == '"')
+                .to_string();
+            let Some(fallback) = self.font_fallbacks.get(name.as_str()) else {
+                continue;
+            };
+            for item in fallback.split(',').map(|item| item.trim()) {
+                if !item.is_empty() && !families.iter().any(|family| family == item) {
+                    families.push(item.to_string());
+                }
+            }
+        }
+        if families.len() == count {
+            Cow::Borrowed(value)
+        } else {
+            Cow::Owned(families.join(", "))
+        }
+    }
+
     pub fn lang(&self) -> Option<&str> {
         self.lang
     }
@@ -150,3 +432,21 @@ impl<'h> Header<'h> {
         self.preview
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::VariableHeader;
+
+    #[test]
+    fn should_add_style_font_families() {
+        let mut header = VariableHeader::default();
+        header.add_style_font_families(
+            "color: red; FONT-FAMILY: 'Open Sans', \"Lato\", sans-serif !important;",
+        );
+        header.add_style_font_families("font-size:12px");
+        assert_eq!(
+            header.used_font_families().iter().collect::<Vec<_>>(),
+            vec!["Open Sans", "Lato", "sans-serif"]
+        );
+    }
+}
@@ -0,0 +1,190 @@
+//! Building blocks of the interactive elements, like the accordions or the
+//! navbars, made of a hidden input toggled by a label and of css rules
+//! matching its `:checked` state, without javascript.
+//!
+//! The input is hidden from the clients that can't toggle it, the content
+//! having to be readable without it, and the `noinput` rules are used by the
+//! clients removing the inputs, like Gmail, to show the fallback.
+//!
+//! ```rust
+//! use mrml::prelude::render::{InputGate, RenderBuffer, Toggle};
+//!
+//! let toggle = Toggle::checkbox("acme-modal-checkbox").with_id("acme-modal-1");
+//! let mut buffer = RenderBuffer::default();
+//! toggle
+//!     .render_input(&toggle.input().add_style("display", "none"), &mut buffer, InputGate::NotMso)
+//!     .unwrap();
+//! toggle.label().render_open(&mut buffer).unwrap();
+//! let style = toggle.no_input_rule("display: block !important;");
+//! assert_eq!(style, "noinput.acme-modal-checkbox { display: block !important; }");
+//! ```
+
+use std::borrow::Cow;
+
+use super::{RenderBuffer, Tag};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToggleKind {
+    /// Input toggled independently, like the element of an accordion.
+    Checkbox,
+    /// Input of a group where a single one is checked, like the images of a
+    /// carousel.
+    Radio,
+}
+
+impl ToggleKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Checkbox => "checkbox",
+            Self::Radio => "radio",
+        }
+    }
+}
+
+/// Clients from which the input is hidden, with conditional comments.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputGate {
+    /// Hidden from Outlook on Windows, `<!--[if !mso]><!-->`.
+    NotMso,
+    /// Hidden from Outlook on Windows and the old Internet Explorer,
+    /// `<!--[if !mso | IE]><!-->`.
+    NotMsoNorIe,
+}
+
+/// Hidden input and the labels toggling it.
+#[derive(Clone, Debug)]
+pub struct Toggle<'a> {
+    kind: ToggleKind,
+    class: Cow<'a, str>,
+    id: Option<Cow<'a, str>>,
+    name: Option<Cow<'a, str>>,
+    checked: bool,
+}
+
+impl<'a> Toggle<'a> {
+    pub fn new<C: Into<Cow<'a, str>>>(kind: ToggleKind, class: C) -> Self {
+        Self {
+            kind,
+            class: class.into(),
+            id: None,
+            name: None,
+            checked: false,
+        }
+    }
+
+    pub fn checkbox<C: Into<Cow<'a, str>>>(class: C) -> Self {
+        Self::new(ToggleKind::Checkbox, class)
+    }
+
+    /// Radio of the group `name`.
+    pub fn radio<C: Into<Cow<'a, str>>, N: Into<Cow<'a, str>>>(class: C, name: N) -> Self {
+        let mut result = Self::new(ToggleKind::Radio, class);
+        result.name = Some(name.into());
+        result
+    }
+
+    /// Identifier of the input, needed by the labels that are not around it.
+    /// The ids generated by the [`Generator`](super::Generator) of the context
+    /// are unique in the email.
+    pub fn with_id<I: Into<Cow<'a, str>>>(mut self, id: I) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn checked(mut self, value: bool) -> Self {
+        self.checked = value;
+        self
+    }
+
+    pub fn kind(&self) -> ToggleKind {
+        self.kind
+    }
+
+    pub fn class(&self) -> &str {
+        &self.class
+    }
+
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// The input, without style, hiding it being up to the element.
+    pub fn input<'t>(&self) -> Tag<'t> {
+        Tag::new("input")
+            .add_class(self.class.to_string())
+            .maybe_add_attribute("id", self.id.as_ref().map(|id| id.to_string()))
+            .maybe_add_attribute("name", self.name.as_ref().map(|name| name.to_string()))
+            .maybe_add_attribute("checked", self.checked.then_some("checked"))
+            .add_attribute("type", self.kind.as_str())
+    }
+
+    /// A label toggling the input, pointing to it when it has an id.
+    pub fn label<'t>(&self) -> Tag<'t> {
+        Tag::new("label").maybe_add_attribute("for", self.id.as_ref().map(|id| id.to_string()))
+    }
+
+    /// Renders the `input` built with [`Toggle::input`], hidden from the
+    /// clients of the `gate`.
+    pub fn render_input(
+        &self,
+        input: &Tag<'_>,
+        buffer: &mut RenderBuffer,
+        gate: InputGate,
+    ) -> std::fmt::Result {
+        match gate {
+            InputGate::NotMso => buffer.start_mso_negation_conditional_tag(),
+            InputGate::NotMsoNorIe => buffer.start_negation_conditional_tag(),
+        }
+        input.render_closed(buffer)?;
+        buffer.end_negation_conditional_tag();
+        Ok(())
+    }
+
+    /// Css rule applied when the client removed the input.
+    pub fn no_input_rule(&self, content: &str) -> String {
+        format!("noinput.{} {{ {content} }}", self.class)
+    }
+
+    /// Selector of the `target` when the input is checked, the target being
+    /// in the next sibling of the input.
+    pub fn checked_selector(&self, target: &str) -> String {
+        format!("input.{}:checked+* {target}", self.class)
+    }
+
+    /// Selector of the `target` when the input is checked, the target being
+    /// in one of the following siblings of the input, like the panels of
+    /// tabs following all their inputs.
+    pub fn checked_sibling_selector(&self, target: &str) -> String {
+        format!("input.{}:checked~{target}", self.class)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InputGate, Toggle};
+    use crate::prelude::render::RenderBuffer;
+
+    #[test]
+    fn should_render_the_input() {
+        let toggle = Toggle::radio("tabs-radio", "tabs-1")
+            .with_id("tabs-1-2")
+            .checked(true);
+        let mut buffer = RenderBuffer::default();
+        toggle
+            .render_input(&toggle.input(), &mut buffer, InputGate::NotMsoNorIe)
+            .unwrap();
+        toggle.label().render_open(&mut buffer).unwrap();
+        assert_eq!(
+            String::from(buffer),
+            r#"<!--[if !mso | IE]><!--><input id="tabs-1-2" name="tabs-1" checked="checked" type="radio" class="tabs-radio" /><!--<![endif]--><label for="tabs-1-2">"#
+        );
+        assert_eq!(
+            toggle.checked_selector(".tabs-panel"),
+            "input.tabs-radio:checked+* .tabs-panel"
+        );
+        assert_eq!(
+            toggle.checked_sibling_selector(".tabs-panels .tabs-panel"),
+            "input.tabs-radio:checked~.tabs-panels .tabs-panel"
+        );
+    }
+}
@@ -0,0 +1,379 @@
+/// Maximum length of a line, without the line ending, allowed by the SMTP
+/// protocol (RFC 5322).
+pub const SMTP_MAX_LINE_LENGTH: usize = 998;
+
+/// Elements in which the whitespaces are kept as is by the browsers, or
+/// where a line break could change the meaning of the content.
+const PRESERVED_ELEMENTS: [&str; 4] = ["pre", "textarea", "script", "style"];
+/// Elements whose content is not html, only their closing tag is looked for.
+const RAW_TEXT_ELEMENTS: [&str; 3] = ["textarea", "script", "style"];
+/// Elements after which the browsers ignore a line break, when it's followed
+/// by another tag, because the text can't be displayed there.
+const BREAK_AFTER_OPENING: [&str; 7] = ["html", "head", "table", "thead", "tbody", "tfoot", "tr"];
+/// Same as [`BREAK_AFTER_OPENING`] for the closing tags.
+const BREAK_AFTER_CLOSING: [&str; 7] = ["head", "thead", "tbody", "tfoot", "tr", "td", "th"];
+
+enum State {
+    Text,
+    Tag { start: usize, quote: Option<u8> },
+    Comment { start: usize },
+}
+
+/// Where a line can be broken.
+#[derive(Clone, Copy)]
+enum Candidate {
+    /// The whitespace at this position can be replaced by a line break.
+    Replace(usize),
+    /// A line break can be inserted at this position.
+    Insert(usize),
+}
+
+/// Element in which the whitespaces must be kept, with the number of nested
+/// elements with the same name.
+struct Preserved {
+    name: String,
+    depth: usize,
+    /// The quote of the string being read in a `style` element.
+    quote: Option<u8>,
+}
+
+impl Preserved {
+    fn is_raw_text(&self) -> bool {
+        RAW_TEXT_ELEMENTS.contains(&self.name.as_str())
+    }
+
+    /// The whitespaces of a stylesheet are all alike, outside of the strings.
+    fn accepts_break(&self) -> bool {
+        self.name == "style" && self.quote.is_none()
+    }
+}
+
+fn tag_name(tag: &[u8]) -> String {
+    tag.iter()
+        .skip_while(|c| matches!(c, b'<' | b'/'))
+        .take_while(|c| c.is_ascii_alphanumeric() || **c == b'-' || **c == b'!')
+        .map(|c| c.to_ascii_lowercase() as char)
+        .collect()
+}
+
+fn preserves_whitespaces(name: &str, tag: &[u8]) -> bool {
+    if PRESERVED_ELEMENTS.contains(&name) {
+        return true;
+    }
+    let tag = tag
+        .iter()
+        .filter(|c| !c.is_ascii_whitespace())
+        .map(|c| c.to_ascii_lowercase())
+        .collect::<Vec<_>>();
+    tag.windows(15).any(|window| window == b"white-space:pre")
+}
+
+/// Updates the preserved element once the tag is closed, and tells if a line
+/// break can follow the tag.
+fn visit_tag(tag: &[u8], preserved: &mut Option<Preserved>, in_head: &mut bool) -> bool {
+    let name = tag_name(tag);
+    let closing = tag.starts_with(b"</");
+    let self_closing = tag.ends_with(b"/>");
+    match preserved {
+        Some(inner) if inner.name == name => {
+            if closing {
+                inner.depth -= 1;
+                if inner.depth == 0 {
+                    *preserved = None;
+                }
+            } else if !self_closing {
+                inner.depth += 1;
+            }
+        }
+        Some(_) => {}
+        None if !closing && !self_closing && preserves_whitespaces(&name, tag) => {
+            *preserved = Some(Preserved {
+                name: name.clone(),
+                depth: 1,
+                quote: None,
+            });
+        }
+        None => {}
+    }
+    if name == "head" {
+        *in_head = !closing;
+    }
+    if closing {
+        *in_head || BREAK_AFTER_CLOSING.contains(&name.as_str())
+    } else {
+        *in_head || BREAK_AFTER_OPENING.contains(&name.as_str())
+    }
+}
+
+fn is_conditional_comment(comment: &[u8]) -> bool {
+    comment.starts_with(b"<!--[if") || comment.ends_with(b"<![endif]-->")
+}
+
+/// Breaks the lines longer than `max` bytes, when it can be done without
+/// changing the meaning of the html.
+///
+/// A line is broken by replacing a whitespace of the text or between the
+/// attributes of a tag, or by adding a line break between two tags where
+/// the browsers don't display it: in the `head`, between the rows and the
+/// cells of a table, and around the conditional comments. The strings,
+/// the comments and the elements keeping their whitespaces (like `pre`)
+/// aren't broken, so a line can still be longer than `max`. No line break
+/// is added between two inline elements, where it would add a visible space.
+pub fn wrap_lines(input: &str, max: usize) -> String {
+    let max = max.max(1);
+    let input = input.as_bytes();
+    let mut output = Vec::with_capacity(input.len() + input.len() / max);
+    let mut state = State::Text;
+    let mut preserved: Option<Preserved> = None;
+    let mut in_head = false;
+    let mut line_start = 0;
+    let mut candidate: Option<Candidate> = None;
+    for (index, c) in input.iter().copied().enumerate() {
+        let position = output.len();
+        let next_is_tag = input.get(index + 1) == Some(&b'<');
+        output.push(c);
+        if c == b'\n' {
+            line_start = position + 1;
+            candidate = None;
+        }
+        match state {
+            State::Text => match c {
+                b'<' if preserved.as_ref().is_some_and(Preserved::is_raw_text)
+                    && !input[index..].starts_with(b"</") => {}
+                b'<' if input[index..].starts_with(b"<!--") => {
+                    if preserved.is_none()
+                        && input[index..].starts_with(b"<!--[if")
+                        && index > 0
+                        && input[index - 1] == b'>'
+                    {
+                        candidate = Some(Candidate::Insert(position));
+                    }
+                    state = State::Comment { start: index };
+                }
+                b'<' => {
+                    state = State::Tag {
+                        start: index,
+                        quote: None,
+                    }
+                }
+                b'"' | b'\'' => {
+                    if let Some(inner) = preserved.as_mut().filter(|inner| inner.name == "style") {
+                        match inner.quote {
+                            Some(quote) if quote == c => inner.quote = None,
+                            Some(_) => {}
+                            None => inner.quote = Some(c),
+                        }
+                    }
+                }
+                b' ' | b'\t'
+                    if position > line_start
+                        && preserved.as_ref().is_none_or(Preserved::accepts_break) =>
+                {
+                    candidate = Some(Candidate::Replace(position));
+                }
+                _ => {}
+            },
+            State::Tag {
+                start,
+                quote: Some(quote),
+            } => {
+                if c == quote {
+                    state = State::Tag { start, quote: None };
+                }
+            }
+            State::Tag { start, quote: None } => match c {
+                b'"' | b'\'' => {
+                    state = State::Tag {
+                        start,
+                        quote: Some(c),
+                    }
+                }
+                b' ' | b'\t' if position > line_start => {
+                    candidate = Some(Candidate::Replace(position));
+                }
+                b'>' => {
+                    let breakable = visit_tag(&input[start..=index], &mut preserved, &mut in_head);
+                    if breakable && next_is_tag && preserved.is_none() {
+                        candidate = Some(Candidate::Insert(position + 1));
+                    }
+                    state = State::Text;
+                }
+                _ => {}
+            },
+            State::Comment { start } => {
+                if c == b'>' && input[..=index].ends_with(b"-->") {
+                    let comment = &input[start..=index];
+                    if next_is_tag
+                        && preserved.is_none()
+                        && (in_head || is_conditional_comment(comment))
+                    {
+                        candidate = Some(Candidate::Insert(position + 1));
+                    }
+                    state = State::Text;
+                }
+            }
+        }
+        if output.len() - line_start > max {
+            match candidate.take() {
+                Some(Candidate::Replace(position)) => {
+                    output[position] = b'\n';
+                    line_start = position + 1;
+                }
+                Some(Candidate::Insert(position)) => {
+                    output.insert(position, b'\n');
+                    line_start = position + 1;
+                }
+                None => {}
+            }
+        }
+    }
+    // only ascii line breaks have been added
+    String::from_utf8(output).expect("valid utf8")
+}
+
+/// Lines longer than `max` bytes, that [`wrap_lines`] couldn't break, with
+/// their number, starting at 1, and their length.
+pub fn long_lines(input: &str, max: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+    input
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line).len())
+        .enumerate()
+        .filter(move |(_, length)| *length > max)
+        .map(|(index, length)| (index + 1, length))
+}
+
+/// Converts all the line endings to CRLF, as expected by SMTP.
+pub fn crlf_line_endings(input: &str) -> String {
+    let mut output = String::with_capacity(input.len() + input.len() / 40);
+    for (index, line) in input.split('\n').enumerate() {
+        if index > 0 {
+            output.push_str("\r\n");
+        }
+        output.push_str(line.strip_suffix('\r').unwrap_or(line));
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{crlf_line_endings, wrap_lines};
+
+    #[test]
+    fn should_keep_short_lines() {
+        let input = "<div>Hello World</div>\n<p>Goodbye</p>";
+        assert_eq!(wrap_lines(input, 998), input);
+    }
+
+    #[test]
+    fn should_break_on_whitespace_in_text() {
+        assert_eq!(
+            wrap_lines("<p>Hello World and more</p>", 16),
+            "<p>Hello World\nand more</p>"
+        );
+    }
+
+    #[test]
+    fn should_not_break_between_inline_tags() {
+        let input = "<a>Hello</a><span>World</span><b>Again</b><br><img>";
+        assert_eq!(wrap_lines(input, 20), input);
+    }
+
+    #[test]
+    fn should_not_break_in_preformatted_text() {
+        let input = "<pre>Hello <b>World</b> and more</pre>";
+        assert_eq!(wrap_lines(input, 10), input);
+        let input = "<textarea>Hello World and more</textarea>";
+        assert_eq!(wrap_lines(input, 10), input);
+        let input = "<script>if (a <b && c) { alert(\"Hello World\"); }</script>";
+        assert_eq!(wrap_lines(input, 10), input);
+        let input =
+            r#"<div style="white-space: pre-wrap">Hello <div>World</div> and</div> more text"#;
+        assert_eq!(
+            wrap_lines(input, 10),
+            "<div\nstyle=\"white-space: pre-wrap\">Hello <div>World</div> and</div>\nmore text"
+        );
+    }
+
+    #[test]
+    fn should_break_between_table_tags() {
+        let input = "<table><tbody><tr><td>Hello World</td><td><b>A</b></td></tr></tbody></table>";
+        assert_eq!(
+            wrap_lines(input, 16),
+            "<table><tbody>\n<tr><td>Hello\nWorld</td>\n<td><b>A</b></td>\n</tr></tbody>\n</table>"
+        );
+    }
+
+    #[test]
+    fn should_break_in_head() {
+        let input = r#"<head><title></title><meta charset="utf-8"><style>.a { content: "a b c d"; }</style></head><body><p>Text</p></body>"#;
+        assert_eq!(
+            wrap_lines(input, 20),
+            "<head><title></title>\n<meta\ncharset=\"utf-8\">\n<style>.a { content:\n\"a b c d\"; }</style>\n</head>\n<body><p>Text</p></body>"
+        );
+    }
+
+    #[test]
+    fn should_break_around_conditional_comments() {
+        let input = "<div><!--[if mso]><table><![endif]--><div>A</div><!--[if mso]></table><![endif]--></div>";
+        assert_eq!(
+            wrap_lines(input, 20),
+            "<div>\n<!--[if mso]><table><![endif]-->\n<div>A</div>\n<!--[if mso]></table><![endif]-->\n</div>"
+        );
+    }
+
+    #[test]
+    fn should_keep_preformatted_text_over_several_lines() {
+        let input = "<pre>Hello World\nand more text</pre> after the pre";
+        assert_eq!(
+            wrap_lines(input, 10),
+            "<pre>Hello World\nand more text</pre>\nafter the\npre"
+        );
+    }
+
+    #[test]
+    fn should_only_break_between_attributes() {
+        let input = r#"<a href="https://example.com/some path">Link</a>"#;
+        assert_eq!(
+            wrap_lines(input, 20),
+            "<a\nhref=\"https://example.com/some path\">Link</a>"
+        );
+        let input = r#"<img alt="an image with spaces" src="https://example.com/image.png">"#;
+        assert_eq!(
+            wrap_lines(input, 20),
+            "<img\nalt=\"an image with spaces\"\nsrc=\"https://example.com/image.png\">"
+        );
+        let input = r#"<a title="a = b" href='https://example.com/?a=b c'>Link</a>"#;
+        assert_eq!(
+            wrap_lines(input, 20),
+            "<a title=\"a = b\"\nhref='https://example.com/?a=b c'>Link</a>"
+        );
+        let input = r#"<td align=center title="some title">Text</td>"#;
+        assert_eq!(
+            wrap_lines(input, 20),
+            "<td align=center\ntitle=\"some title\">Text</td>"
+        );
+    }
+
+    #[test]
+    fn should_keep_lines_under_limit() {
+        let input = format!("<p>{}</p>", "word ".repeat(1000));
+        let output = wrap_lines(&input, 998);
+        assert!(output.lines().all(|line| line.len() <= 998));
+        assert_eq!(output.replace('\n', " "), input);
+    }
+
+    #[test]
+    fn should_not_break_in_comments() {
+        let input = "<!--[if mso | IE]><table role=\"presentation\"><![endif]--> some text";
+        assert_eq!(
+            wrap_lines(input, 20),
+            "<!--[if mso | IE]><table role=\"presentation\"><![endif]-->\nsome text"
+        );
+    }
+
+    #[test]
+    fn should_convert_line_endings() {
+        assert_eq!(crlf_line_endings("a\nb\r\nc"), "a\r\nb\r\nc");
+        assert_eq!(crlf_line_endings("a"), "a");
+    }
+}
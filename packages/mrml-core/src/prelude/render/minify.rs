@@ -0,0 +1,132 @@
+//! Minification of the rendered html, removing the whitespaces used to indent
+//! the tags.
+
+/// Elements whose content is kept as is when minifying.
+const PRESERVED_ELEMENTS: [&str; 2] = ["pre", "textarea"];
+
+/// Removes the whitespaces between two tags when they contain a line break,
+/// leaving the inline content untouched. The comments, like the conditional
+/// comments only read by outlook, and the `pre` and `textarea` elements are
+/// kept as is. The content of the `mj-raw` elements is kept as well, since
+/// the parser drops the whitespaces with a line break between its tags.
+pub fn minify_html(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    let mut pending = String::new();
+    let mut after_tag = false;
+    let mut rest = input;
+    while let Some(c) = rest.chars().next() {
+        if c == '<' {
+            if let Some(length) = comment_length(rest).or_else(|| preserved_length(rest)) {
+                flush_whitespaces(&mut result, &mut pending, true);
+                result.push_str(&rest[..length]);
+                after_tag = true;
+                rest = &rest[length..];
+                continue;
+            }
+        }
+        rest = &rest[c.len_utf8()..];
+        if after_tag && c.is_whitespace() {
+            pending.push(c);
+            continue;
+        }
+        flush_whitespaces(&mut result, &mut pending, c == '<');
+        after_tag = c == '>';
+        result.push(c);
+    }
+    result.push_str(&pending);
+    result
+}
+
+/// Writes the whitespaces found after a tag, unless they contain a line break
+/// and are followed by another tag.
+fn flush_whitespaces(result: &mut String, pending: &mut String, before_tag: bool) {
+    if !before_tag || !pending.contains('\n') {
+        result.push_str(pending);
+    }
+    pending.clear();
+}
+
+/// Length of the comment, like a conditional comment, starting the input.
+fn comment_length(input: &str) -> Option<usize> {
+    let content = input.strip_prefix("<!--")?;
+    let end = content
+        .find("-->")
+        .map_or(content.len(), |index| index + "-->".len());
+    Some("<!--".len() + end)
+}
+
+/// Length of the `pre` or `textarea` element starting the input, closing tag
+/// included.
+fn preserved_length(input: &str) -> Option<usize> {
+    PRESERVED_ELEMENTS.iter().find_map(|name| {
+        let after_name = input.get(1 + name.len()..)?;
+        let opening = input[1..1 + name.len()].eq_ignore_ascii_case(name)
+            && after_name.starts_with(|c: char| c == '>' || c.is_whitespace());
+        if !opening {
+            return None;
+        }
+        let closing = format!("</{name}>");
+        let end = input.to_ascii_lowercase().find(&closing)?;
+        Some(end + closing.len())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::minify_html;
+
+    #[test]
+    fn should_minify_between_tags() {
+        assert_eq!(
+            minify_html("<div>\n  <p>Hello <b>World</b> !</p>\n</div>"),
+            "<div><p>Hello <b>World</b> !</p></div>"
+        );
+    }
+
+    #[test]
+    fn should_keep_preformatted_content() {
+        assert_eq!(
+            minify_html("<div>\n  <pre>\n  <b>a</b>\n  <i>b</i>\n</pre>\n</div>"),
+            "<div><pre>\n  <b>a</b>\n  <i>b</i>\n</pre></div>"
+        );
+        assert_eq!(
+            minify_html("<div>\n  <TEXTAREA>\n  <b>a</b>\n</TEXTAREA>\n</div>"),
+            "<div><TEXTAREA>\n  <b>a</b>\n</TEXTAREA></div>"
+        );
+    }
+
+    #[test]
+    fn should_keep_comments() {
+        assert_eq!(
+            minify_html(concat!(
+                "<div>\n  <!--[if mso | IE]><table>\n<tr>\n<td><![endif]-->\n",
+                "  <!--[if !mso]><!-->\n  <p>Hello</p>\n  <!--<![endif]-->\n</div>",
+            )),
+            concat!(
+                "<div><!--[if mso | IE]><table>\n<tr>\n<td><![endif]-->",
+                "<!--[if !mso]><!--><p>Hello</p><!--<![endif]--></div>",
+            )
+        );
+        assert_eq!(
+            minify_html("<div>\n  <!-- mj-comment -->\n</div>"),
+            "<div><!-- mj-comment --></div>"
+        );
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_keep_raw_content() {
+        let template = concat!(
+            "<mjml><mj-body><mj-raw><!-- mj-user -->",
+            "<!--[if mso]><b>a</b>\n<i>b</i><![endif]-->",
+            "</mj-raw></mj-body></mjml>",
+        );
+        let root = crate::parse(template).unwrap();
+        let opts = crate::prelude::render::RenderOptions {
+            minify: true,
+            ..Default::default()
+        };
+        let output = root.element.render(&opts).unwrap();
+        assert!(output.contains("<!-- mj-user --><!--[if mso]><b>a</b>\n<i>b</i><![endif]-->"));
+    }
+}
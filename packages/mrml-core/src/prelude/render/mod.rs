@@ -1,18 +1,80 @@
+//! Rendering of the templates to html.
+//!
+//! The `css-class` attribute of the elements is written on:
+//! - `mj-body`: the `div` wrapping the content,
+//! - `mj-section` and `mj-wrapper`: the `div` of the section, or the `table`
+//!   spanning the whole width when `full-width` is set, and, with the
+//!   `-outlook` suffix, on the outlook `table` and `td`,
+//! - `mj-column` and `mj-group`: the `div` of the column, after the width
+//!   classes,
+//! - `mj-hero`: the outer `div`,
+//! - the elements in a column or a hero, like `mj-text` or `mj-image`: the `td`
+//!   containing the element,
+//! - `mj-accordion-element` and `mj-social-element`: their `tr`,
+//! - `mj-accordion-title` and `mj-accordion-text`: their `td`,
+//! - `mj-carousel-image`: its `div`, and its thumbnail with the `-thumbnail`
+//!   suffix,
+//! - `mj-navbar-link`: its link, and its outlook `td` with the `-outlook`
+//!   suffix,
+//! - `mj-breakpoint`: the `style` elements containing the media queries.
+
+use std::borrow::Cow;
 use std::convert::TryFrom;
 use std::sync::atomic::{AtomicU16, Ordering};
 
 use crate::helper::size::{Pixel, Size};
 use crate::helper::spacing::Spacing;
+use crate::prelude::hash::Map;
+use crate::prelude::path::ElementPath;
 
+mod annotation;
 mod buffer;
+mod cache;
+#[cfg(feature = "async")]
+mod cancellation;
+mod element_path;
+mod entities;
+mod font_loader;
 mod header;
+mod interactive;
+mod line_length;
+mod minify;
 mod options;
+mod plain_text;
+mod quoted_printable;
+mod report;
+mod resolved;
+mod scope;
+pub mod source_map;
 mod tag;
+mod web_url;
 
+pub(crate) use annotation::annotate;
 pub use buffer::*;
+pub(crate) use cache::render_cached;
+pub use cache::{CachedRender, MemoryRenderCache, RenderCache};
+#[cfg(feature = "async")]
+pub(crate) use cancellation::checkpoint;
+#[cfg(feature = "async")]
+pub use cancellation::CancellationToken;
+pub(crate) use element_path::ElementPathRecorder;
+pub use entities::{encode_entities, EntityStrategy};
+#[cfg(feature = "http-loader-base")]
+pub use font_loader::HttpFontLoader;
+pub use font_loader::{FontLoader, FontLoaderError, FontStrategy, MemoryFontLoader};
 pub use header::*;
+pub use interactive::{InputGate, Toggle, ToggleKind};
+pub use line_length::*;
+pub use minify::minify_html;
 pub use options::*;
+pub use plain_text::*;
+pub use quoted_printable::*;
+pub use report::{RenderWarning, RenderWarningKind, GMAIL_CLIP_SIZE};
+pub(crate) use report::{Report, HEAD_PATH};
+pub use resolved::{AttributeCandidate, AttributeSource, ResolvedAttributes};
+pub(crate) use scope::ScopeStack;
 pub use tag::*;
+pub use web_url::*;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -20,6 +82,14 @@ pub enum Error {
     UnknownFragment(String),
     #[error("unable to format {0}")]
     Format(#[from] std::fmt::Error),
+    #[error("unable to write the output: {0}")]
+    Io(#[from] std::io::Error),
+    /// The render was aborted with a
+    /// [`CancellationToken`](crate::prelude::render::CancellationToken).
+    #[error("render cancelled")]
+    Cancelled,
+    #[error(transparent)]
+    FontLoader(#[from] FontLoaderError),
 }
 
 #[derive(Debug, Default)]
@@ -30,6 +100,10 @@ impl Generator {
         let id = self.0.fetch_add(1, Ordering::SeqCst);
         format!("{id:0>8}")
     }
+
+    pub(crate) fn current(&self) -> u16 {
+        self.0.load(Ordering::SeqCst)
+    }
 }
 
 #[deprecated = "use mrml::prelude::render::RenderOptions instead"]
@@ -39,16 +113,38 @@ pub struct RenderContext<'h> {
     pub options: &'h RenderOptions,
     pub header: Header<'h>,
     pub generator: Generator,
+    pub(crate) report: Report,
+    pub(crate) source_map: source_map::SourceMapRecorder,
+    pub(crate) paths: ElementPathRecorder,
+    /// Line of the template defining each element of the body, given in the
+    /// debug annotations when known.
+    pub(crate) source_lines: Map<ElementPath, usize>,
+    pub(crate) scopes: ScopeStack,
 }
 
 impl<'h> RenderContext<'h> {
-    pub fn new(options: &'h RenderOptions, header: Header<'h>) -> Self {
+    pub fn new(options: &'h RenderOptions, mut header: Header<'h>) -> Self {
+        if let Some(ref breakpoint) = options.breakpoint {
+            header.override_breakpoints(breakpoint);
+        }
         Self {
             options,
             header,
             generator: Generator::default(),
+            report: Report::default(),
+            source_map: Default::default(),
+            paths: Default::default(),
+            source_lines: Default::default(),
+            scopes: Default::default(),
         }
     }
+
+    /// Values of the `mj-attributes` applying to the element being rendered,
+    /// in the scope of the `mj-include` it comes from.
+    pub(crate) fn scoped_attributes(&self) -> Option<&HeaderAttributes<'h>> {
+        self.scopes
+            .with_current(|scope| self.header.scoped_attributes(scope))
+    }
 }
 
 #[derive(Debug, Default)]
@@ -57,6 +153,22 @@ pub struct RenderCursor {
     pub header: VariableHeader,
 }
 
+impl RenderCursor {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: RenderBuffer::with_capacity(capacity),
+            header: VariableHeader::default(),
+        }
+    }
+
+    /// Writes the given openings of the conditional comments, see
+    /// [`RenderBuffer::with_conditional_comments`].
+    pub fn with_conditional_comments(mut self, comments: ConditionalComments) -> Self {
+        self.buffer = self.buffer.with_conditional_comments(comments);
+        self
+    }
+}
+
 pub(crate) struct Renderer<'root, Element, Extra> {
     pub context: &'root RenderContext<'root>,
     pub element: &'root Element,
@@ -97,23 +209,83 @@ pub trait Render<'root> {
         None
     }
 
+    /// Every attribute defined on the element.
+    fn raw_attributes(&self) -> Option<&'root Map<String, String>> {
+        None
+    }
+
+    /// Forwards the `data-*` and `aria-*` attributes of the element to the
+    /// main html element it renders.
+    fn passthrough_attributes<'t>(&self, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 't,
+    {
+        match self.raw_attributes() {
+            Some(attributes) => tag.add_passthrough_attributes(attributes),
+            None => tag,
+        }
+    }
+
     fn raw_extra_attribute(&self, _: &str) -> Option<&'root str> {
         None
     }
 
     fn attribute_as_pixel(&self, name: &str) -> Option<Pixel> {
-        self.attribute(name)
-            .and_then(|value| Pixel::try_from(value).ok())
+        let value = self.attribute(name)?;
+        let result = Pixel::try_from(value).ok();
+        if result.is_none() {
+            self.report_invalid_attribute(name, value);
+        }
+        result
     }
 
     fn attribute_as_size(&self, name: &str) -> Option<Size> {
-        self.attribute(name)
-            .and_then(|value| Size::try_from(value).ok())
+        let value = self.attribute(name)?;
+        let result = Size::try_from(value).ok();
+        if result.is_none() {
+            self.report_invalid_attribute(name, value);
+        }
+        result
     }
 
     fn attribute_as_spacing(&self, name: &str) -> Option<Spacing> {
-        self.attribute(name)
-            .and_then(|value| Spacing::try_from(value).ok())
+        let value = self.attribute(name)?;
+        let result = Spacing::try_from(value).ok();
+        if result.is_none() {
+            self.report_invalid_attribute(name, value);
+        }
+        result
+    }
+
+    /// Reports the invalid value of an attribute, on the element or on the
+    /// `mj-attributes` defining it. The default values aren't reported.
+    fn report_invalid_attribute(&self, name: &str, value: &str) {
+        let context = self.context();
+        if !context.report.is_enabled() {
+            return;
+        }
+        let mut source = None;
+        self.visit_attribute(name, &mut |origin, _| {
+            source = Some(origin);
+            true
+        });
+        let path = match source {
+            None | Some(AttributeSource::Default) => return,
+            Some(
+                AttributeSource::MjClass(_)
+                | AttributeSource::MjAttributesElement
+                | AttributeSource::MjAttributesAll,
+            ) => HEAD_PATH.to_string(),
+            Some(_) => context.paths.current().to_string(),
+        };
+        context.report.invalid_attribute(name, value, path);
+    }
+
+    fn report_missing_attribute(&self, name: &str) {
+        let context = self.context();
+        context
+            .report
+            .missing_attribute(name, context.paths.current().to_string());
     }
 
     fn attribute_equals(&self, key: &str, value: &str) -> bool {
@@ -125,12 +297,14 @@ pub trait Render<'root> {
     }
 
     fn get_border_left(&self) -> Option<Pixel> {
-        self.attribute_as_pixel("border-left")
+        self.attribute("border-left")
+            .and_then(|value| Pixel::try_from(value).ok())
             .or_else(|| self.attribute("border").and_then(Pixel::from_border))
     }
 
     fn get_border_right(&self) -> Option<Pixel> {
-        self.attribute_as_pixel("border-right")
+        self.attribute("border-right")
+            .and_then(|value| Pixel::try_from(value).ok())
             .or_else(|| self.attribute("border").and_then(Pixel::from_border))
     }
 
@@ -141,39 +315,51 @@ pub trait Render<'root> {
     }
 
     fn get_inner_border_left(&self) -> Option<Pixel> {
-        self.attribute_as_pixel("inner-border-left").or_else(|| {
-            self.attribute_as_spacing("inner-border")
-                .map(|s| s.into_left())
-        })
+        self.attribute("inner-border-left")
+            .and_then(|value| Pixel::try_from(value).ok())
+            .or_else(|| {
+                self.attribute("inner-border")
+                    .and_then(|value| Spacing::try_from(value).ok())
+                    .and_then(|s| s.into_left().into_pixel())
+            })
     }
 
     fn get_inner_border_right(&self) -> Option<Pixel> {
-        self.attribute_as_pixel("inner-border-right").or_else(|| {
-            self.attribute_as_spacing("inner-border")
-                .map(|s| s.into_right())
-        })
+        self.attribute("inner-border-right")
+            .and_then(|value| Pixel::try_from(value).ok())
+            .or_else(|| {
+                self.attribute("inner-border")
+                    .and_then(|value| Spacing::try_from(value).ok())
+                    .and_then(|s| s.into_right().into_pixel())
+            })
     }
 
     fn get_padding_top(&self) -> Option<Pixel> {
-        self.attribute_as_pixel("padding-top")
-            .or_else(|| self.attribute_as_spacing("padding").map(|s| s.into_top()))
+        self.attribute_as_pixel("padding-top").or_else(|| {
+            self.attribute_as_spacing("padding")
+                .and_then(|s| s.into_top().into_pixel())
+        })
     }
 
     fn get_padding_bottom(&self) -> Option<Pixel> {
         self.attribute_as_pixel("padding-bottom").or_else(|| {
             self.attribute_as_spacing("padding")
-                .map(|s| s.into_bottom())
+                .and_then(|s| s.into_bottom().into_pixel())
         })
     }
 
     fn get_padding_left(&self) -> Option<Pixel> {
-        self.attribute_as_pixel("padding-left")
-            .or_else(|| self.attribute_as_spacing("padding").map(|s| s.into_left()))
+        self.attribute_as_pixel("padding-left").or_else(|| {
+            self.attribute_as_spacing("padding")
+                .and_then(|s| s.into_left().into_pixel())
+        })
     }
 
     fn get_padding_right(&self) -> Option<Pixel> {
-        self.attribute_as_pixel("padding-right")
-            .or_else(|| self.attribute_as_spacing("padding").map(|s| s.into_right()))
+        self.attribute_as_pixel("padding-right").or_else(|| {
+            self.attribute_as_spacing("padding")
+                .and_then(|s| s.into_right().into_pixel())
+        })
     }
 
     fn get_padding_horizontal(&self) -> Pixel {
@@ -196,34 +382,100 @@ pub trait Render<'root> {
         None
     }
 
-    fn attribute<'a>(&'a self, key: &str) -> Option<&'a str>
-    where
+    /// Calls the visitor with each value defined for an attribute, by order
+    /// of priority, until it returns `true`.
+    fn visit_attribute<'a>(
+        &'a self,
+        key: &str,
+        visitor: &mut dyn FnMut(AttributeSource<'a>, &'a str) -> bool,
+    ) where
         'root: 'a,
     {
         if let Some(value) = self.raw_attribute(key) {
-            return Some(value);
+            if visitor(AttributeSource::Element, value) {
+                return;
+            }
         }
         if let Some(value) = self.raw_extra_attribute(key) {
-            return Some(value);
+            if visitor(AttributeSource::Parent, value) {
+                return;
+            }
+        }
+        if let Some(attributes) = self.context().scoped_attributes() {
+            if let Some(mj_classes) = self.raw_attribute("mj-class") {
+                for mj_class in attributes.expand_classes(mj_classes) {
+                    if let Some(value) = attributes.class(mj_class, key) {
+                        if visitor(AttributeSource::MjClass(mj_class), value) {
+                            return;
+                        }
+                    }
+                }
+            }
+            if let Some(tag) = self.tag() {
+                if let Some(value) = attributes.element(tag, key) {
+                    if visitor(AttributeSource::MjAttributesElement, value) {
+                        return;
+                    }
+                }
+            }
+            if let Some(value) = attributes.all(key) {
+                if visitor(AttributeSource::MjAttributesAll, value) {
+                    return;
+                }
+            }
         }
-        if let Some(value) = self.raw_attribute("mj-class").and_then(|mj_classes| {
-            mj_classes
-                .split(' ')
-                .map(|mj_class| mj_class.trim())
-                .filter_map(|mj_class| self.context().header.attribute_class(mj_class, key))
-                .next()
-        }) {
-            return Some(value);
+        let defaults = &self.context().options.default_attributes;
+        if let Some(value) = self
+            .tag()
+            .and_then(|tag| defaults.get(tag))
+            .and_then(|attributes| attributes.get(key))
+        {
+            if visitor(AttributeSource::OptionsElement, value) {
+                return;
+            }
         }
-        if let Some(tag) = self.tag() {
-            if let Some(value) = self.context().header.attribute_element(tag, key) {
-                return Some(value);
+        if let Some(value) = defaults
+            .get(crate::mj_attributes_all::NAME)
+            .and_then(|attributes| attributes.get(key))
+        {
+            if visitor(AttributeSource::OptionsAll, value) {
+                return;
             }
         }
-        if let Some(value) = self.context().header.attribute_all(key) {
-            return Some(value);
+        if let Some(value) = self.default_attribute(key) {
+            visitor(AttributeSource::Default, value);
         }
-        self.default_attribute(key)
+    }
+
+    /// Resolves the value of an attribute, looking at the element, the
+    /// `mj-class`, the `mj-attributes` of the head and the default values.
+    ///
+    /// The returned value is borrowed from the template, so resolving an
+    /// attribute doesn't allocate.
+    fn attribute<'a>(&'a self, key: &str) -> Option<&'a str>
+    where
+        'root: 'a,
+    {
+        let mut result = None;
+        self.visit_attribute(key, &mut |_, value| {
+            result = Some(value);
+            true
+        });
+        result
+    }
+
+    /// Lists every value defined for an attribute, with their origin, to
+    /// understand why an attribute has a given value.
+    fn resolve_attribute<'a>(&'a self, key: &str) -> ResolvedAttributes<'a>
+    where
+        'root: 'a,
+    {
+        let mut result = ResolvedAttributes::default();
+        self.visit_attribute(key, &mut |source, value| {
+            result.candidates.push(AttributeCandidate { source, value });
+            false
+        });
+        result
     }
 
     fn attribute_size(&self, key: &str) -> Option<Size> {
@@ -236,6 +488,16 @@ pub trait Render<'root> {
             .and_then(|value| Pixel::try_from(value).ok())
     }
 
+    /// Value of a `font-family` attribute, followed by the fallback stacks
+    /// of the `mj-font` elements.
+    fn attribute_font_family<'a>(&'a self, key: &str) -> Option<Cow<'a, str>>
+    where
+        'root: 'a,
+    {
+        self.attribute(key)
+            .map(|value| self.context().header.with_font_fallbacks(value))
+    }
+
     fn set_style<'a, 't>(&'a self, _name: &str, tag: Tag<'t>) -> Tag<'t>
     where
         'root: 'a,
@@ -308,6 +570,245 @@ macro_rules! should_render {
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_write_css_class_of_every_element() {
+        let elements = [
+            r#"<mj-accordion css-class="c-accordion"><mj-accordion-element css-class="c-accordion-element"><mj-accordion-title css-class="c-accordion-title">Title</mj-accordion-title><mj-accordion-text css-class="c-accordion-text">Text</mj-accordion-text></mj-accordion-element></mj-accordion>"#,
+            r#"<mj-button css-class="c-button">Button</mj-button>"#,
+            r#"<mj-carousel css-class="c-carousel"><mj-carousel-image css-class="c-carousel-image" src="https://example.com/a.png" /></mj-carousel>"#,
+            r#"<mj-divider css-class="c-divider" />"#,
+            r#"<mj-image css-class="c-image" src="https://example.com/a.png" />"#,
+            r#"<mj-navbar css-class="c-navbar"><mj-navbar-link css-class="c-navbar-link" href="/">Home</mj-navbar-link></mj-navbar>"#,
+            r#"<mj-social css-class="c-social"><mj-social-element css-class="c-social-element" name="github" href="https://github.com">GitHub</mj-social-element></mj-social>"#,
+            r#"<mj-spacer css-class="c-spacer" />"#,
+            r#"<mj-table css-class="c-table"><tr><td>Cell</td></tr></mj-table>"#,
+            r#"<mj-text css-class="c-text">Text</mj-text>"#,
+        ];
+        let template = format!(
+            r#"<mjml><mj-head><mj-breakpoint width="480px" css-class="c-breakpoint" /></mj-head><mj-body css-class="c-body"><mj-wrapper css-class="c-wrapper"><mj-section css-class="c-section"><mj-group css-class="c-group"><mj-column css-class="c-column">{}</mj-column></mj-group></mj-section></mj-wrapper><mj-hero css-class="c-hero"><mj-text css-class="c-hero-text">Hero</mj-text></mj-hero></mj-body></mjml>"#,
+            elements.join("")
+        );
+        let root = crate::parse(template).unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        let mut found = Vec::new();
+        root.element.body_tree().unwrap().walk(&mut |node, _| {
+            if let Some(class) = node
+                .as_element()
+                .and_then(|element| element.attribute("css-class"))
+            {
+                found.push(class);
+            }
+        });
+        assert!(found.len() > elements.len());
+        for class in found.into_iter().chain(["c-breakpoint"]) {
+            assert!(html.contains(class), "{} is missing", class);
+        }
+        assert!(html.contains(r#"<style type="text/css" class="c-breakpoint">@media"#));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_forward_data_and_aria_attributes() {
+        let elements = [
+            r#"<mj-accordion data-id="accordion"><mj-accordion-element data-id="accordion-element"><mj-accordion-title data-id="accordion-title">Title</mj-accordion-title><mj-accordion-text data-id="accordion-text">Text</mj-accordion-text></mj-accordion-element></mj-accordion>"#,
+            r#"<mj-button data-id="button">Button</mj-button>"#,
+            r#"<mj-carousel data-id="carousel"><mj-carousel-image data-id="carousel-image" src="https://example.com/a.png" /></mj-carousel>"#,
+            r#"<mj-divider data-id="divider" />"#,
+            r#"<mj-image data-id="image" aria-describedby="caption" src="https://example.com/a.png" />"#,
+            r#"<mj-navbar data-id="navbar"><mj-navbar-link data-id="navbar-link" href="/">Home</mj-navbar-link></mj-navbar>"#,
+            r#"<mj-social data-id="social" mode="vertical"><mj-social-element data-id="social-element" name="github" href="https://github.com">GitHub</mj-social-element></mj-social>"#,
+            r#"<mj-spacer data-id="spacer" />"#,
+            r#"<mj-table data-id="table"><tr><td>Cell</td></tr></mj-table>"#,
+            r#"<mj-text data-id="text">Text</mj-text>"#,
+        ];
+        let template = format!(
+            r#"<mjml><mj-body data-id="body"><mj-wrapper data-id="wrapper"><mj-section data-id="section"><mj-group data-id="group"><mj-column data-id="column">{}</mj-column></mj-group></mj-section></mj-wrapper><mj-section full-width="full-width" data-id="full-width"></mj-section><mj-hero data-id="hero"><mj-text>Hero</mj-text></mj-hero></mj-body></mjml>"#,
+            elements.join("")
+        );
+        let root = crate::parse(template).unwrap();
+        let html = root.element.render(&Default::default()).unwrap();
+        let mut found = Vec::new();
+        root.element.body_tree().unwrap().walk(&mut |node, _| {
+            if let Some(value) = node
+                .as_element()
+                .and_then(|element| element.attribute("data-id"))
+            {
+                found.push(value);
+            }
+        });
+        assert!(found.len() > elements.len());
+        for value in found {
+            assert!(
+                html.contains(&format!("data-id=\"{value}\"")),
+                "{} is missing",
+                value
+            );
+        }
+        assert!(html.contains(r#"data-id="body""#));
+        assert!(html.contains(r#" aria-describedby="caption""#));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn attribute_should_borrow_from_template() {
+        use super::{Header, RenderContext, RenderOptions, Renderable};
+        use crate::mj_body::MjBodyChild;
+
+        let root = crate::parse(
+            r#"<mjml><mj-head><mj-attributes><mj-text color="red" /></mj-attributes></mj-head><mj-body><mj-text align="left" /></mj-body></mjml>"#,
+        )
+        .unwrap()
+        .element;
+        let head = root.head();
+        let body = root.body().unwrap();
+        let MjBodyChild::MjText(text) = &body.children[0] else {
+            panic!("expected mj-text");
+        };
+        let opts = RenderOptions::default();
+        let context = RenderContext::new(&opts, Header::new(head, None));
+        let renderer = text.renderer(&context);
+
+        let align = renderer.attribute("align").unwrap();
+        assert_eq!(align.as_ptr(), text.attributes["align"].as_ptr());
+        let color = renderer.attribute("color").unwrap();
+        assert_eq!(
+            color.as_ptr(),
+            context
+                .header
+                .attribute_element("mj-text", "color")
+                .unwrap()
+                .as_ptr()
+        );
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn attribute_should_fallback_on_options_defaults() {
+        use std::iter::FromIterator;
+
+        use super::RenderOptions;
+        use crate::prelude::hash::Map;
+
+        let opts = RenderOptions {
+            default_attributes: Map::from_iter([
+                (
+                    "mj-text".to_string(),
+                    Map::from_iter([
+                        ("color".to_string(), "#123456".to_string()),
+                        ("align".to_string(), "right".to_string()),
+                    ]),
+                ),
+                (
+                    "mj-all".to_string(),
+                    Map::from_iter([("font-family".to_string(), "Brand".to_string())]),
+                ),
+            ]),
+            ..Default::default()
+        };
+        let root = crate::parse(
+            r#"<mjml><mj-head><mj-attributes><mj-text align="center" /></mj-attributes></mj-head><mj-body><mj-text>Hello</mj-text><mj-text color="red">World</mj-text></mj-body></mjml>"#,
+        )
+        .unwrap();
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains("color:#123456"));
+        assert!(html.contains("color:red"));
+        assert!(html.contains("text-align:center"));
+        assert!(!html.contains("text-align:right"));
+        assert!(html.contains("font-family:Brand"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_resolve_attribute_with_sources() {
+        use super::{AttributeSource, Header, RenderContext, RenderOptions, Renderable};
+
+        let root = crate::parse(
+            r#"<mjml><mj-head><mj-attributes><mj-class name="big" padding="20px" /><mj-text padding="10px" /><mj-all padding="5px" /></mj-attributes></mj-head><mj-body><mj-text mj-class="big">Hello</mj-text></mj-body></mjml>"#,
+        )
+        .unwrap()
+        .element;
+        let opts = RenderOptions::default();
+        let context = RenderContext::new(&opts, Header::new(root.head(), None));
+        let child = &root.body().unwrap().children[0];
+        let renderer = child.renderer(&context);
+        let resolved = renderer.resolve_attribute("padding");
+        let sources = resolved
+            .candidates
+            .iter()
+            .map(|item| (item.source, item.value))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sources,
+            vec![
+                (AttributeSource::MjClass("big"), "20px"),
+                (AttributeSource::MjAttributesElement, "10px"),
+                (AttributeSource::MjAttributesAll, "5px"),
+                (AttributeSource::Default, "10px 25px"),
+            ]
+        );
+        assert_eq!(renderer.attribute("padding"), resolved.value());
+        assert!(renderer.resolve_attribute("unknown").value().is_none());
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_apply_the_last_mj_class_first() {
+        use super::{AttributeSource, Header, RenderContext, RenderOptions, Renderable};
+
+        let root = crate::parse(
+            r#"<mjml><mj-head><mj-attributes><mj-class name="red" color="red" /><mj-class name="blue" color="blue" /></mj-attributes></mj-head><mj-body><mj-text mj-class="red  blue">Hello</mj-text><mj-text mj-class="blue red">Hello</mj-text></mj-body></mjml>"#,
+        )
+        .unwrap()
+        .element;
+        let opts = RenderOptions::default();
+        let context = RenderContext::new(&opts, Header::new(root.head(), None));
+        let children = &root.body().unwrap().children;
+        let renderer = children[0].renderer(&context);
+        assert_eq!(renderer.attribute("color"), Some("blue"));
+        let sources = renderer
+            .resolve_attribute("color")
+            .candidates
+            .iter()
+            .map(|item| item.source)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sources[..2],
+            [
+                AttributeSource::MjClass("blue"),
+                AttributeSource::MjClass("red")
+            ]
+        );
+        let renderer = children[1].renderer(&context);
+        assert_eq!(renderer.attribute("color"), Some("red"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_compose_mj_classes() {
+        use super::{AttributeSource, Header, RenderContext, RenderOptions, Renderable};
+
+        let root = crate::parse(
+            r#"<mjml><mj-head><mj-attributes>
+  <mj-class name="base" color="gray" font-size="12px" padding="0" />
+  <mj-class name="title" mj-class="base" color="black" font-weight="bold" />
+  <mj-class name="loop" mj-class="loop title" />
+</mj-attributes></mj-head><mj-body><mj-text mj-class="loop" font-size="20px">Hello</mj-text></mj-body></mjml>"#,
+        )
+        .unwrap()
+        .element;
+        let opts = RenderOptions::default();
+        let context = RenderContext::new(&opts, Header::new(root.head(), None));
+        let renderer = root.body().unwrap().children[0].renderer(&context);
+        assert_eq!(renderer.attribute("color"), Some("black"));
+        assert_eq!(renderer.attribute("font-weight"), Some("bold"));
+        assert_eq!(renderer.attribute("font-size"), Some("20px"));
+        assert_eq!(
+            renderer.resolve_attribute("padding").source(),
+            Some(AttributeSource::MjClass("base"))
+        );
+    }
+
     #[test]
     fn header_should_increase() {
         let gen = super::Generator::default();
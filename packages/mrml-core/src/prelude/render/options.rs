@@ -1,5 +1,9 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::{ConditionalComments, EntityStrategy, FontStrategy, RenderCache, WebUrl};
+use crate::prelude::hash::Map;
 
 pub fn default_fonts() -> HashMap<String, Cow<'static, str>> {
     HashMap::from([
@@ -26,11 +30,183 @@ pub fn default_fonts() -> HashMap<String, Cow<'static, str>> {
     ])
 }
 
+/// Accessibility improvements of the output. They're all disabled by default
+/// to keep the output identical to the one of mjml.
+#[derive(Clone, Debug, Default)]
+pub struct AriaOptions {
+    /// Adds `role="presentation"` on the layout tables that mjml renders
+    /// without it (`mj-accordion` and the outlook table of `mj-hero`).
+    pub presentation_tables: bool,
+    /// Label of the `mj-navbar` links, also adding `role="navigation"`.
+    pub navbar_label: Option<Cow<'static, str>>,
+    /// Label of the button opening the `mj-navbar` hamburger menu.
+    pub navbar_toggle_label: Option<Cow<'static, str>>,
+    /// Labels the links of the `mj-social-element` icons without text with
+    /// the name of the network.
+    pub social_labels: bool,
+    /// Label of the control showing the previous image of a `mj-carousel`.
+    pub carousel_previous_label: Option<Cow<'static, str>>,
+    /// Label of the control showing the next image of a `mj-carousel`.
+    pub carousel_next_label: Option<Cow<'static, str>>,
+    /// Copies the `alt` of the linked `mj-image` elements in the `title` and
+    /// `aria-label` of their link.
+    pub image_link_labels: bool,
+    /// Reports the linked `mj-image` elements without `alt` nor `title`,
+    /// with [`Mjml::render_with_report`](crate::mjml::Mjml::render_with_report).
+    pub report_unlabelled_image_links: bool,
+}
+
+impl AriaOptions {
+    pub(crate) fn presentation_role(&self) -> Option<&'static str> {
+        self.presentation_tables.then_some("presentation")
+    }
+}
+
+/// Order of the styles in the head. The last ones win when several rules
+/// have the same specificity.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StyleOrder {
+    /// The styles of the components are written before the ones of the
+    /// `mj-style` elements, so the template can override them. This is the
+    /// order used by mjml.
+    #[default]
+    ComponentsFirst,
+    /// The styles of the `mj-style` elements are written first.
+    TemplateFirst,
+}
+
+/// Block of the generated `head`, written after the `title` in the order of
+/// [`RenderOptions::head_sections`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeadSection {
+    /// The `meta` tags defining the compatibility mode, the charset and the
+    /// viewport, followed by the `mj-raw` elements placed `before-styles`.
+    Meta,
+    /// The reset styles and the outlook settings.
+    BaseStyles,
+    /// The stylesheets of the fonts.
+    Fonts,
+    /// The media queries sizing the columns.
+    MediaQueries,
+    /// The styles of the components and of the `mj-style` elements, in the
+    /// order defined by [`RenderOptions::style_order`].
+    Styles,
+    /// The `mj-raw` elements placed at the end of the head.
+    Raw,
+}
+
+impl HeadSection {
+    /// Order used by mjml.
+    pub const DEFAULT_ORDER: [Self; 6] = [
+        Self::Meta,
+        Self::BaseStyles,
+        Self::Fonts,
+        Self::MediaQueries,
+        Self::Styles,
+        Self::Raw,
+    ];
+}
+
+/// Strategy used to size the columns.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Layout {
+    /// The widths of the columns are defined in media queries. This is the
+    /// layout used by mjml.
+    #[default]
+    Classic,
+    /// The columns take the full width, up to their own width, so they're
+    /// displayed side by side when there is enough room and stacked
+    /// otherwise, without media queries. This works in the clients
+    /// ignoring the styles of the head, like Gmail with IMAP accounts.
+    /// Outlook keeps the widths of the cells of the ghost tables, set as
+    /// attributes too.
+    Hybrid,
+}
+
 #[derive(Debug)]
 pub struct RenderOptions {
     pub disable_comments: bool,
     pub social_icon_origin: Option<Cow<'static, str>>,
     pub fonts: HashMap<String, Cow<'static, str>>,
+    /// Value of the `font-display` descriptor, like `swap`, added to the url
+    /// of the font stylesheets that don't define one. The `display`
+    /// attribute of the `mj-font` elements takes precedence.
+    pub font_display: Option<String>,
+    /// Adds `<link rel="preconnect">` elements for the hosts of the fonts,
+    /// so the client connects to them before parsing the stylesheets.
+    pub font_preconnect: bool,
+    /// Whether the font stylesheets are linked or written in the head.
+    pub font_strategy: FontStrategy,
+    /// Removes the whitespaces with a line break between the tags of the
+    /// output, see [`minify_html`](crate::prelude::render::minify_html). It's
+    /// applied before the other changes made to the html once rendered, like
+    /// `max_line_length`.
+    pub minify: bool,
+    /// When defined, the lines of the output longer than this value are
+    /// broken where the line break isn't visible, see
+    /// [`wrap_lines`](crate::prelude::render::wrap_lines). Use
+    /// [`SMTP_MAX_LINE_LENGTH`](crate::prelude::render::SMTP_MAX_LINE_LENGTH)
+    /// to stay under the SMTP limit. The lines that couldn't be broken are
+    /// reported by [`Mjml::render_with_report`](crate::mjml::Mjml::render_with_report).
+    pub max_line_length: Option<usize>,
+    /// Converts the line endings of the output to CRLF.
+    pub crlf_line_endings: bool,
+    /// Replaces the non ascii characters by html entities when it reduces the
+    /// size of the output once encoded in quoted-printable. Combined with a
+    /// `max_line_length` of 76, it also avoids the soft line breaks.
+    pub quoted_printable_friendly: bool,
+    /// Encoding of the non ascii characters of the output, outside of the
+    /// `style` and `script` elements.
+    pub entity_strategy: EntityStrategy,
+    pub aria: AriaOptions,
+    /// Url of an image, used to track the opening of the email, added at the
+    /// end of the body.
+    pub tracking_pixel: Option<String>,
+    /// Handling of the
+    /// [`WEB_URL_PLACEHOLDER`](crate::prelude::render::WEB_URL_PLACEHOLDER)
+    /// used for the "view in browser" links.
+    pub web_url: WebUrl,
+    /// Capacity, in bytes, allocated for the output before rendering. When
+    /// rendering the same template several times, the size of the previous
+    /// output avoids growing the buffer. When `None`, the capacity is
+    /// estimated from the template.
+    pub buffer_capacity: Option<usize>,
+    /// Cache of the rendered sections, reused between renders.
+    pub cache: Option<Arc<dyn RenderCache>>,
+    /// Order of the styles of the components and of the `mj-style`
+    /// elements. The identical `mj-style` blocks are only written once, at
+    /// the position of the last one.
+    pub style_order: StyleOrder,
+    /// Blocks of the generated `head`, in the order they're written. The
+    /// missing ones are omitted.
+    pub head_sections: Vec<HeadSection>,
+    /// Default attributes of the elements, by tag, like the ones defined in
+    /// `mj-attributes`. The attributes of `mj-all` apply to every element.
+    /// They're used when neither the element nor the `mj-attributes` of the
+    /// template define the attribute.
+    pub default_attributes: Map<String, Map<String, String>>,
+    /// Breakpoints replacing the ones of the `mj-breakpoint` element, like
+    /// `480px` or `480px 768px` to add a tablet breakpoint.
+    pub breakpoint: Option<String>,
+    /// Strategy used to size the columns and the groups, the media queries
+    /// of mjml by default.
+    pub layout: Layout,
+    /// Surrounds the html of each element of the body with comments naming
+    /// it, like `<!-- mj-text -->` and `<!-- /mj-text -->`, to find the
+    /// element of the template generating a part of the output. Rendered
+    /// with [`Mjml::render_with_source`](crate::mjml::Mjml::render_with_source),
+    /// the comments also give the line of the template, like
+    /// `<!-- mj-text line 4 -->`.
+    pub debug_annotations: bool,
+    /// Openings of the conditional comments targeting outlook.
+    pub conditional_comments: ConditionalComments,
+    /// Locale of the translations of the `mj-trans` elements, like `fr` or
+    /// `fr-CA`.
+    #[cfg(feature = "i18n")]
+    pub locale: Option<String>,
+    /// Translations of the `mj-trans` elements.
+    #[cfg(feature = "i18n")]
+    pub catalog: Option<Arc<dyn crate::mj_trans::Catalog>>,
 }
 
 impl Default for RenderOptions {
@@ -39,6 +215,30 @@ impl Default for RenderOptions {
             disable_comments: false,
             social_icon_origin: None,
             fonts: default_fonts(),
+            font_display: None,
+            font_preconnect: false,
+            font_strategy: FontStrategy::default(),
+            minify: false,
+            max_line_length: None,
+            crlf_line_endings: false,
+            quoted_printable_friendly: false,
+            entity_strategy: EntityStrategy::default(),
+            aria: AriaOptions::default(),
+            tracking_pixel: None,
+            web_url: WebUrl::Keep,
+            buffer_capacity: None,
+            cache: None,
+            style_order: StyleOrder::default(),
+            head_sections: HeadSection::DEFAULT_ORDER.to_vec(),
+            default_attributes: Map::new(),
+            breakpoint: None,
+            layout: Layout::default(),
+            debug_annotations: false,
+            conditional_comments: ConditionalComments::default(),
+            #[cfg(feature = "i18n")]
+            locale: None,
+            #[cfg(feature = "i18n")]
+            catalog: None,
         }
     }
 }
@@ -0,0 +1,203 @@
+/// Tags whose content is never displayed as text.
+const HIDDEN_TAGS: [&str; 4] = ["head", "script", "style", "title"];
+/// Tags that should be followed by a line break.
+const BLOCK_TAGS: [&str; 13] = [
+    "div", "h1", "h2", "h3", "h4", "h5", "h6", "li", "p", "table", "td", "tr", "ul",
+];
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "nbsp" => Some(' '),
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        _ => {
+            let code = entity.strip_prefix('#')?;
+            let code = match code.strip_prefix(|c| c == 'x' || c == 'X') {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse::<u32>().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+fn push_text(output: &mut String, text: &str) {
+    let mut rest = text;
+    while let Some(index) = rest.find(|c: char| c == '&' || c.is_whitespace()) {
+        output.push_str(&rest[..index]);
+        rest = &rest[index..];
+        if rest.starts_with('&') {
+            let decoded = rest
+                .find(';')
+                .filter(|end| *end < 10)
+                .and_then(|end| decode_entity(&rest[1..end]).map(|c| (c, end)));
+            if let Some((c, end)) = decoded {
+                output.push(c);
+                rest = &rest[end + 1..];
+            } else {
+                output.push('&');
+                rest = &rest[1..];
+            }
+        } else {
+            if !output.ends_with(&[' ', '\n'][..]) && !output.is_empty() {
+                output.push(' ');
+            }
+            rest = rest.trim_start();
+        }
+    }
+    output.push_str(rest);
+}
+
+fn attribute_value<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let lower = tag.to_ascii_lowercase();
+    let start = lower.find(&format!(" {name}="))? + name.len() + 2;
+    let value = &tag[start..];
+    match value.chars().next()? {
+        quote @ ('"' | '\'') => value[1..].split(quote).next(),
+        _ => value.split(&[' ', '>'][..]).next(),
+    }
+}
+
+/// Converts a rendered html document into a readable plain text version,
+/// that can be used as an alternative content for email clients not
+/// supporting html.
+///
+/// The hidden elements (like the head, styles and comments) are removed, the
+/// block elements are separated with line breaks and the links are followed
+/// by their url.
+pub fn html_to_plain_text(html: &str) -> String {
+    let mut output = String::with_capacity(html.len() / 4);
+    let mut links: Vec<Option<&str>> = Vec::new();
+    let mut rest = html;
+    while let Some(index) = rest.find('<') {
+        push_text(&mut output, &rest[..index]);
+        rest = &rest[index..];
+        if rest.starts_with("<!--") {
+            rest = rest.find("-->").map(|end| &rest[end + 3..]).unwrap_or("");
+            continue;
+        }
+        let Some(end) = rest.find('>') else {
+            rest = "";
+            break;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        if !closing && HIDDEN_TAGS.contains(&name.as_str()) {
+            let lower = rest.to_ascii_lowercase();
+            rest = lower
+                .find(&format!("</{name}"))
+                .and_then(move |start| rest[start..].find('>').map(|end| &rest[start + end + 1..]))
+                .unwrap_or("");
+        } else if name == "br" {
+            output.push('\n');
+        } else if name == "a" && closing {
+            match links.pop().flatten() {
+                Some(href) if !output.trim_end().ends_with(href) => {
+                    let trimmed = output.trim_end().len();
+                    output.truncate(trimmed);
+                    output.push_str(" (");
+                    output.push_str(href);
+                    output.push(')');
+                }
+                _ => {}
+            }
+        } else if name == "a" {
+            links.push(
+                attribute_value(tag, "href")
+                    .filter(|href| href.starts_with("http://") || href.starts_with("https://")),
+            );
+        } else if BLOCK_TAGS.contains(&name.as_str()) {
+            output.push('\n');
+        }
+    }
+    push_text(&mut output, rest);
+
+    // cleanup the lines and avoid having more than one empty line in a row
+    let mut result = String::with_capacity(output.len());
+    let mut empty_lines = 0;
+    for line in output.lines().map(str::trim) {
+        if line.is_empty() {
+            empty_lines += 1;
+            continue;
+        }
+        if !result.is_empty() {
+            result.push_str(if empty_lines > 1 { "\n\n" } else { "\n" });
+        }
+        empty_lines = 0;
+        result.push_str(line);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::html_to_plain_text;
+
+    #[test]
+    fn should_remove_hidden_content() {
+        assert_eq!(
+            html_to_plain_text(
+                "<html><head><title>Title</title><style>p { color: red; }</style></head><body><!--[if mso]><table><![endif]--><p>Hello</p></body></html>"
+            ),
+            "Hello"
+        );
+    }
+
+    #[test]
+    fn should_split_blocks() {
+        assert_eq!(
+            html_to_plain_text("<div><p>Hello</p><p>World<br/>and more</p></div>"),
+            "Hello\nWorld\nand more"
+        );
+        assert_eq!(
+            html_to_plain_text("<p>Hello</p><div></div><div></div><p>World</p>"),
+            "Hello\n\nWorld"
+        );
+    }
+
+    #[test]
+    fn should_collapse_whitespaces() {
+        assert_eq!(
+            html_to_plain_text("<p>\n    Hello   <b>World</b>\n  !</p>"),
+            "Hello World !"
+        );
+    }
+
+    #[test]
+    fn should_decode_entities() {
+        assert_eq!(
+            html_to_plain_text("<p>Fish&nbsp;&amp;&#32;chips &#x2764; &unknown; &</p>"),
+            "Fish & chips \u{2764} &unknown; &"
+        );
+    }
+
+    #[test]
+    fn should_display_links() {
+        assert_eq!(
+            html_to_plain_text(
+                r#"<p><a href="https://mrml.io" target="_blank">Click here</a></p>"#
+            ),
+            "Click here (https://mrml.io)"
+        );
+        assert_eq!(
+            html_to_plain_text(r#"<p><a href="https://mrml.io">https://mrml.io</a></p>"#),
+            "https://mrml.io"
+        );
+        assert_eq!(
+            html_to_plain_text(r##"<p><a href="#top">Top</a></p>"##),
+            "Top"
+        );
+    }
+}
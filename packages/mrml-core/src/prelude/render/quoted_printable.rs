@@ -0,0 +1,97 @@
+/// Maximum length of a line encoded in quoted-printable, including the `=`
+/// of a soft line break (RFC 2045).
+const QP_MAX_LINE_LENGTH: usize = 76;
+
+fn encoded_line_size(line: &str) -> usize {
+    let bytes = line.as_bytes();
+    let size = bytes
+        .iter()
+        .enumerate()
+        .map(|(index, c)| match c {
+            // the whitespaces are only encoded at the end of a line
+            b' ' | b'\t' if index + 1 == bytes.len() => 3,
+            b' ' | b'\t' => 1,
+            b'=' => 3,
+            33..=126 => 1,
+            _ => 3,
+        })
+        .sum::<usize>();
+    // each soft line break adds `=` and a CRLF
+    let soft_breaks = if size > QP_MAX_LINE_LENGTH {
+        (size - QP_MAX_LINE_LENGTH).div_ceil(QP_MAX_LINE_LENGTH - 1)
+    } else {
+        0
+    };
+    size + soft_breaks * 3
+}
+
+/// Predicts the size, in bytes, of the input once encoded in
+/// quoted-printable with CRLF line endings.
+pub fn quoted_printable_size(input: &str) -> usize {
+    let lines = input
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .collect::<Vec<_>>();
+    let line_endings = (lines.len() - 1) * 2;
+    lines.into_iter().map(encoded_line_size).sum::<usize>() + line_endings
+}
+
+fn push_char(output: &mut String, c: char) {
+    let entity = format!("&#{};", c as u32);
+    // in quoted-printable, each byte of a non ascii character takes 3 bytes
+    if entity.len() < c.len_utf8() * 3 {
+        output.push_str(&entity);
+    } else {
+        output.push(c);
+    }
+}
+
+/// Replaces the non ascii characters by their html entity when it takes less
+/// space once encoded in quoted-printable. The content of the `style` and
+/// `script` elements is left untouched.
+pub fn quoted_printable_friendly(input: &str) -> String {
+    super::entities::replace_non_ascii(input, push_char)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{quoted_printable_friendly, quoted_printable_size};
+
+    #[test]
+    fn should_predict_size() {
+        assert_eq!(quoted_printable_size("Hello"), 5);
+        assert_eq!(quoted_printable_size("a=b"), 5);
+        assert_eq!(quoted_printable_size("Hello \nWorld"), 15);
+        assert_eq!(quoted_printable_size("€"), 9);
+        assert_eq!(quoted_printable_size(&"a".repeat(76)), 76);
+        assert_eq!(quoted_printable_size(&"a".repeat(77)), 80);
+    }
+
+    #[test]
+    fn should_replace_when_smaller() {
+        assert_eq!(
+            quoted_printable_friendly("<p>10 €</p>"),
+            "<p>10 &#8364;</p>"
+        );
+        assert_eq!(quoted_printable_friendly("<p>café</p>"), "<p>café</p>");
+        assert_eq!(quoted_printable_friendly("😀"), "&#128512;");
+    }
+
+    #[test]
+    fn should_not_replace_in_raw_text() {
+        let input = r#"<style>.price::after { content: "€"; }</style><p>€</p>"#;
+        assert_eq!(
+            quoted_printable_friendly(input),
+            r#"<style>.price::after { content: "€"; }</style><p>&#8364;</p>"#
+        );
+        let input = "<STYLE type=\"text/css\">€</STYLE><script>'€'";
+        assert_eq!(quoted_printable_friendly(input), input);
+    }
+
+    #[test]
+    fn should_reduce_encoded_size() {
+        let input = "<p>Prix : 10 € — « soldes » 😀</p>";
+        let output = quoted_printable_friendly(input);
+        assert!(quoted_printable_size(&output) < quoted_printable_size(input));
+    }
+}
@@ -0,0 +1,153 @@
+use std::convert::TryFrom;
+use std::sync::Mutex;
+
+use crate::helper::size::Size;
+use crate::prelude::path::ElementPath;
+
+/// Size after which Gmail clips the html of an email and displays a link to
+/// see the whole message.
+pub const GMAIL_CLIP_SIZE: usize = 102 * 1024;
+
+/// Path of the warnings about the values coming from the `mj-attributes`.
+pub(crate) const HEAD_PATH: &str = "/mjml/mj-head";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RenderWarningKind {
+    /// The value of the attribute couldn't be parsed, the default value was
+    /// used instead.
+    InvalidAttribute { name: String, value: String },
+    /// The attribute is needed for the element to be accessible.
+    MissingAttribute { name: String },
+    /// The generated html is bigger than the [`GMAIL_CLIP_SIZE`].
+    OversizedOutput { size: usize, limit: usize },
+    /// The line couldn't be broken to respect the
+    /// [`max_line_length`](crate::prelude::render::RenderOptions::max_line_length),
+    /// like in a `pre` element. The lines are numbered from 1.
+    LongLine {
+        line: usize,
+        length: usize,
+        limit: usize,
+    },
+}
+
+/// Issue noticed while rendering a template, that didn't prevent the
+/// rendering. The path is the one of the element, like
+/// `/mjml/mj-body/mj-section[1]/mj-column[2]`, or `/mjml/mj-head` when the
+/// value comes from the `mj-attributes`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenderWarning {
+    pub path: String,
+    pub kind: RenderWarningKind,
+}
+
+impl RenderWarning {
+    /// Path of the element, to find it with
+    /// [`Mjml::element_at`](crate::mjml::Mjml::element_at).
+    pub fn element_path(&self) -> Option<ElementPath> {
+        self.path.parse().ok()
+    }
+}
+
+impl std::fmt::Display for RenderWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            RenderWarningKind::InvalidAttribute {
+                ref name,
+                ref value,
+            } => write!(
+                f,
+                "{}: invalid value {value:?} for attribute {name}, fallback applied",
+                self.path
+            ),
+            RenderWarningKind::MissingAttribute { ref name } => {
+                write!(f, "{}: missing attribute {name}", self.path)
+            }
+            RenderWarningKind::OversizedOutput { size, limit } => write!(
+                f,
+                "{}: output of {size} bytes exceeds {limit} bytes",
+                self.path
+            ),
+            RenderWarningKind::LongLine {
+                line,
+                length,
+                limit,
+            } => write!(
+                f,
+                "{}: line {line} of {length} bytes exceeds {limit} bytes",
+                self.path
+            ),
+        }
+    }
+}
+
+/// Invalid or missing attribute noticed while rendering, the `value` being
+/// `None` when the attribute is missing.
+#[derive(Debug)]
+pub(crate) struct ReportedAttribute {
+    pub name: String,
+    pub value: Option<String>,
+    pub path: String,
+}
+
+/// Invalid and missing attributes collected by the renderers, when a report
+/// is requested.
+#[derive(Debug, Default)]
+pub(crate) struct Report(Mutex<Option<Vec<ReportedAttribute>>>);
+
+/// Checks if the value is made of lengths, even if not in the expected unit,
+/// like `0` for a padding. Those values aren't reported, the renderers
+/// expecting them being lenient.
+fn is_lengths(value: &str) -> bool {
+    value
+        .split_whitespace()
+        .all(|item| Size::try_from(item).is_ok())
+}
+
+impl Report {
+    pub fn enable(&self) {
+        if let Ok(mut inner) = self.0.lock() {
+            *inner = Some(Vec::new());
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.0.lock().map(|inner| inner.is_some()).unwrap_or(false)
+    }
+
+    pub fn invalid_attribute(&self, name: &str, value: &str, path: String) {
+        if is_lengths(value) {
+            return;
+        }
+        self.push(name, Some(value), path);
+    }
+
+    pub fn missing_attribute(&self, name: &str, path: String) {
+        self.push(name, None, path);
+    }
+
+    fn push(&self, name: &str, value: Option<&str>, path: String) {
+        if let Ok(mut inner) = self.0.lock() {
+            let Some(items) = inner.as_mut() else {
+                return;
+            };
+            if !items
+                .iter()
+                .any(|item| item.path == path && item.name == name)
+            {
+                items.push(ReportedAttribute {
+                    name: name.to_string(),
+                    value: value.map(String::from),
+                    path,
+                });
+            }
+        }
+    }
+
+    pub fn take(&self) -> Vec<ReportedAttribute> {
+        self.0
+            .lock()
+            .ok()
+            .and_then(|mut inner| inner.take())
+            .unwrap_or_default()
+    }
+}
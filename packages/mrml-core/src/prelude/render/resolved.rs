@@ -0,0 +1,70 @@
+/// Where the value of an attribute comes from, by order of priority.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttributeSource<'a> {
+    /// The attribute is defined on the element.
+    Element,
+    /// The attribute is given by the parent element, like the attributes of
+    /// `mj-social` applied to its `mj-social-element`.
+    Parent,
+    /// The attribute is defined by the named `mj-class`, either listed on the
+    /// element or in the `mj-class` of another class.
+    MjClass(&'a str),
+    /// The attribute is defined for the tag of the element in
+    /// `mj-attributes`.
+    MjAttributesElement,
+    /// The attribute is defined in the `mj-all` of `mj-attributes`.
+    MjAttributesAll,
+    /// The attribute is defined for the tag of the element in the
+    /// `default_attributes` of the render options.
+    OptionsElement,
+    /// The attribute is defined for `mj-all` in the `default_attributes` of
+    /// the render options.
+    OptionsAll,
+    /// Default value of the element.
+    Default,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AttributeCandidate<'a> {
+    pub source: AttributeSource<'a>,
+    pub value: &'a str,
+}
+
+/// Every value defined for an attribute of an element, from the one used
+/// while rendering to the one with the lowest priority.
+///
+/// ```rust
+/// # #[cfg(feature = "parse")]
+/// # {
+/// use mrml::prelude::render::{AttributeSource, Header, RenderContext, RenderOptions, Renderable};
+///
+/// let template = r#"<mjml>
+///   <mj-head><mj-attributes><mj-text padding="10px" /></mj-attributes></mj-head>
+///   <mj-body><mj-text>Hello</mj-text></mj-body>
+/// </mjml>"#;
+/// let root = mrml::parse(template).unwrap().element;
+/// let opts = RenderOptions::default();
+/// let context = RenderContext::new(&opts, Header::new(root.head(), None));
+/// let text = &root.body().unwrap().children[0];
+/// let renderer = text.renderer(&context);
+/// let resolved = renderer.resolve_attribute("padding");
+/// assert_eq!(resolved.value(), Some("10px"));
+/// assert_eq!(resolved.source(), Some(AttributeSource::MjAttributesElement));
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ResolvedAttributes<'a> {
+    pub candidates: Vec<AttributeCandidate<'a>>,
+}
+
+impl<'a> ResolvedAttributes<'a> {
+    /// The value used while rendering.
+    pub fn value(&self) -> Option<&'a str> {
+        self.candidates.first().map(|item| item.value)
+    }
+
+    /// The origin of the value used while rendering.
+    pub fn source(&self) -> Option<AttributeSource<'a>> {
+        self.candidates.first().map(|item| item.source)
+    }
+}
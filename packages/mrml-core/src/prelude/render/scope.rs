@@ -0,0 +1,21 @@
+use std::cell::RefCell;
+
+/// Scopes of the `mj-include` elements being rendered, the innermost last.
+/// The `mj-attributes` of the innermost scope apply to their content.
+#[derive(Debug, Default)]
+pub(crate) struct ScopeStack(RefCell<Vec<String>>);
+
+impl ScopeStack {
+    pub fn enter(&self, scope: &str) {
+        self.0.borrow_mut().push(scope.to_string());
+    }
+
+    pub fn leave(&self) {
+        self.0.borrow_mut().pop();
+    }
+
+    /// Calls `func` with the scope of the element being rendered.
+    pub fn with_current<R>(&self, func: impl FnOnce(Option<&str>) -> R) -> R {
+        func(self.0.borrow().last().map(String::as_str))
+    }
+}
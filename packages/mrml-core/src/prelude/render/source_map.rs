@@ -0,0 +1,226 @@
+//! Correlation between the elements of the template and the html they
+//! generate, to find the element of the template from a position in the
+//! output and the other way around.
+//!
+//! ```rust
+//! # #[cfg(feature = "parse")]
+//! # {
+//! let template = "<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>";
+//! let root = mrml::parse(template).unwrap();
+//! let (html, source_map) = root
+//!     .element
+//!     .render_with_source_map(&Default::default(), template)
+//!     .unwrap();
+//! let text = source_map.find_output(html.find("Hello").unwrap()).unwrap();
+//! assert_eq!(&template[text.source.clone()], "<mj-text>Hello</mj-text>");
+//! let element = root.element.element_at(&text.element_path().unwrap()).unwrap();
+//! assert_eq!(element.tag, "mj-text");
+//! # }
+//! ```
+
+use std::ops::Range;
+use std::sync::Mutex;
+
+#[cfg(feature = "parse")]
+use super::element_path::Frame;
+#[cfg(feature = "parse")]
+use crate::prelude::hash::Map;
+#[cfg(feature = "parse")]
+use crate::prelude::parser::events::{MjmlEvent, MjmlEvents};
+use crate::prelude::path::ElementPath;
+
+/// Element of the body, with the range of the template it's defined in and
+/// the range of the html it generated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
+pub struct SourceMapEntry {
+    /// Path of the element, like `/mjml/mj-body/mj-section[1]/mj-column[2]`.
+    pub path: String,
+    pub source: Range<usize>,
+    pub output: Range<usize>,
+}
+
+impl SourceMapEntry {
+    /// Path of the element, to find it with
+    /// [`Mjml::element_at`](crate::mjml::Mjml::element_at).
+    pub fn element_path(&self) -> Option<ElementPath> {
+        self.path.parse().ok()
+    }
+}
+
+/// Entries of the elements of the body, ordered by position in the output,
+/// the parents before their children.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
+pub struct SourceMap {
+    pub entries: Vec<SourceMapEntry>,
+}
+
+impl SourceMap {
+    /// Finds the deepest element that generated the html at the given
+    /// position of the output.
+    pub fn find_output(&self, position: usize) -> Option<&SourceMapEntry> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| entry.output.contains(&position))
+    }
+
+    /// Finds the deepest element defined at the given position of the
+    /// template.
+    pub fn find_source(&self, position: usize) -> Option<&SourceMapEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.source.contains(&position))
+            .max_by_key(|entry| entry.source.start)
+    }
+
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+/// Path and output range of the rendered elements, by order of start.
+type Recording = Vec<(ElementPath, Range<usize>)>;
+
+/// Output ranges of the elements of the body, collected by the renderers
+/// when a source map is requested.
+#[derive(Default)]
+pub(crate) struct SourceMapRecorder(Mutex<Option<Recording>>);
+
+impl std::fmt::Debug for SourceMapRecorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SourceMapRecorder")
+            .field("enabled", &self.is_enabled())
+            .finish()
+    }
+}
+
+impl SourceMapRecorder {
+    #[cfg(feature = "parse")]
+    pub fn enable(&self) {
+        if let Ok(mut inner) = self.0.lock() {
+            *inner = Some(Vec::new());
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.0.lock().map(|inner| inner.is_some()).unwrap_or(false)
+    }
+
+    /// Starts recording the element at `path`, returns the index of its
+    /// entry.
+    pub fn enter(&self, path: ElementPath) -> Option<usize> {
+        let mut inner = self.0.lock().ok()?;
+        let entries = inner.as_mut()?;
+        entries.push((path, 0..0));
+        Some(entries.len() - 1)
+    }
+
+    pub fn leave(&self, index: usize, output: Range<usize>) {
+        if let Ok(mut inner) = self.0.lock() {
+            if let Some(entry) = inner.as_mut().and_then(|entries| entries.get_mut(index)) {
+                entry.1 = output;
+            }
+        }
+    }
+
+    #[cfg(feature = "parse")]
+    /// Builds the source map, shifting the output ranges by `offset`, the
+    /// position of the body in the output.
+    pub fn build(&self, source: &str, offset: usize) -> SourceMap {
+        let entries = self
+            .0
+            .lock()
+            .ok()
+            .and_then(|mut inner| inner.take())
+            .unwrap_or_default();
+        let sources = source_ranges(source);
+        SourceMap {
+            entries: entries
+                .into_iter()
+                .filter_map(|(path, output)| {
+                    let source = sources.get(&path)?.clone();
+                    Some(SourceMapEntry {
+                        path: path.to_string(),
+                        source,
+                        output: (output.start + offset)..(output.end + offset),
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "parse")]
+/// Range of the template defining each element of the body, by path.
+fn source_ranges(source: &str) -> Map<ElementPath, Range<usize>> {
+    let mut result = Map::new();
+    let body = ElementPath::body();
+    let mut frames: Vec<(Frame, usize)> = Vec::new();
+    for event in MjmlEvents::new(source) {
+        match event {
+            Ok(MjmlEvent::Start { tag, span, .. }) => {
+                let path = match frames.last_mut() {
+                    None => ElementPath::default().child(tag, 1),
+                    Some((parent, _)) => parent.child_path(tag),
+                };
+                frames.push((Frame::new(path), span.start));
+            }
+            Ok(MjmlEvent::End { span, .. }) => {
+                if let Some((frame, start)) = frames.pop() {
+                    if frame.path.starts_with(&body) && frame.path != body {
+                        result.insert(frame.path, start..span.end);
+                    }
+                }
+            }
+            Ok(_) => {}
+            Err(_) => break,
+        }
+    }
+    result
+}
+
+#[cfg(feature = "parse")]
+/// Line of the template, starting at 1, where each element of the body is
+/// defined, by path.
+pub(crate) fn source_lines(source: &str) -> Map<ElementPath, usize> {
+    let breaks: Vec<usize> = source.match_indices('\n').map(|(index, _)| index).collect();
+    source_ranges(source)
+        .iter()
+        .map(|(path, range)| {
+            let line = breaks.partition_point(|index| *index < range.start) + 1;
+            (path.clone(), line)
+        })
+        .collect()
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use super::{source_lines, source_ranges};
+    use crate::prelude::path::ElementPath;
+
+    #[test]
+    fn should_find_source_ranges() {
+        let template = "<mjml><mj-head /><mj-body><mj-section><mj-column><mj-text>A</mj-text><mj-text>B<br></mj-text></mj-column></mj-section></mj-body></mjml>";
+        let ranges = source_ranges(template);
+        let path: ElementPath = "/mjml/mj-body/mj-section[1]/mj-column[1]/mj-text[2]"
+            .parse()
+            .unwrap();
+        let range = ranges.get(&path).unwrap();
+        assert_eq!(&template[range.clone()], "<mj-text>B<br></mj-text>");
+        let head: ElementPath = "/mjml/mj-head".parse().unwrap();
+        assert!(!ranges.contains_key(&head));
+    }
+
+    #[test]
+    fn should_find_source_lines() {
+        let template = "<mjml>\n  <mj-body>\n    <mj-text>A</mj-text>\n\n    <mj-text>B</mj-text>\n  </mj-body>\n</mjml>";
+        let lines = source_lines(template);
+        let first: ElementPath = "/mjml/mj-body/mj-text[1]".parse().unwrap();
+        let second: ElementPath = "/mjml/mj-body/mj-text[2]".parse().unwrap();
+        assert_eq!(lines.get(&first), Some(&3));
+        assert_eq!(lines.get(&second), Some(&5));
+    }
+}
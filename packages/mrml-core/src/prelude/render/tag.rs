@@ -110,6 +110,17 @@ impl<'a> Tag<'a> {
         self
     }
 
+    /// Copies the `data-*` and `aria-*` attributes of an element, used by the
+    /// emailing services, the web views or the screen readers.
+    pub fn add_passthrough_attributes(self, attributes: &'a Map<String, String>) -> Self {
+        attributes
+            .iter()
+            .filter(|(name, _)| name.starts_with("data-") || name.starts_with("aria-"))
+            .fold(self, |tag, (name, value)| {
+                tag.add_attribute(name.as_str(), value.as_str())
+            })
+    }
+
     pub fn maybe_add_attribute<K: Into<Cow<'a, str>>, V: Into<Cow<'a, str>>>(
         self,
         name: K,
@@ -149,7 +160,7 @@ impl<'a> Tag<'a> {
         b.push('<');
         b.push_str(&self.name);
         for (key, value) in self.attributes.iter() {
-            b.push_attribute(key, value)?;
+            b.push_raw_attribute(key, value)?;
         }
         if !self.classes.0.is_empty() {
             b.push_attribute("class", &self.classes)?;
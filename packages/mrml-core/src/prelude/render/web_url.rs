@@ -0,0 +1,108 @@
+/// Placeholder of the url of the online version of the email, to be used in
+/// the templates, like `<a href="{{__weburl__}}">View in browser</a>`.
+pub const WEB_URL_PLACEHOLDER: &str = "{{__weburl__}}";
+
+/// What to do with the [`WEB_URL_PLACEHOLDER`] when rendering.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum WebUrl {
+    /// The placeholder is kept, to be replaced later by the email service
+    /// provider.
+    #[default]
+    Keep,
+    /// The placeholder is replaced by the url.
+    Replace(String),
+    /// The links to the placeholder are removed, with their content. When
+    /// outside of a link, only the placeholder is removed.
+    Strip,
+}
+
+fn escape_attribute(value: &str) -> String {
+    value.replace('"', "&quot;")
+}
+
+/// Finds the link containing the placeholder at `index` in its opening tag,
+/// returning its start and end positions.
+fn find_link(input: &str, index: usize) -> Option<(usize, usize)> {
+    let start = input[..index].rfind('<')?;
+    let tag = &input[start..index];
+    let is_link = tag.len() > 2
+        && tag[1..2].eq_ignore_ascii_case("a")
+        && tag[2..].starts_with(|c: char| c.is_ascii_whitespace())
+        && !tag.contains('>');
+    if !is_link {
+        return None;
+    }
+    let end = input[index..]
+        .to_ascii_lowercase()
+        .find("</a>")
+        .map(|position| index + position + 4)?;
+    Some((start, end))
+}
+
+fn strip(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(index) = rest.find(WEB_URL_PLACEHOLDER) {
+        match find_link(rest, index) {
+            Some((start, end)) => {
+                output.push_str(&rest[..start]);
+                rest = &rest[end..];
+            }
+            None => {
+                output.push_str(&rest[..index]);
+                rest = &rest[index + WEB_URL_PLACEHOLDER.len()..];
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Applies the [`WebUrl`] strategy to the rendered html.
+pub fn apply_web_url(input: &str, strategy: &WebUrl) -> String {
+    match strategy {
+        WebUrl::Keep => input.to_string(),
+        WebUrl::Replace(url) => input.replace(WEB_URL_PLACEHOLDER, &escape_attribute(url)),
+        WebUrl::Strip => strip(input),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_web_url, WebUrl};
+
+    const INPUT: &str =
+        r#"<p>Hello <a href="{{__weburl__}}" target="_blank">View <b>online</b></a>.</p>"#;
+
+    #[test]
+    fn should_keep() {
+        assert_eq!(apply_web_url(INPUT, &WebUrl::Keep), INPUT);
+    }
+
+    #[test]
+    fn should_replace() {
+        assert_eq!(
+            apply_web_url(
+                INPUT,
+                &WebUrl::Replace("https://example.com/?id=\"42\"".into())
+            ),
+            r#"<p>Hello <a href="https://example.com/?id=&quot;42&quot;" target="_blank">View <b>online</b></a>.</p>"#
+        );
+    }
+
+    #[test]
+    fn should_strip() {
+        assert_eq!(apply_web_url(INPUT, &WebUrl::Strip), "<p>Hello .</p>");
+        assert_eq!(
+            apply_web_url("<p>Online: {{__weburl__}}</p>", &WebUrl::Strip),
+            "<p>Online: </p>"
+        );
+        assert_eq!(
+            apply_web_url(
+                r#"<A HREF="{{__weburl__}}">Web</A><a href="/">Home</a>"#,
+                &WebUrl::Strip
+            ),
+            r#"<a href="/">Home</a>"#
+        );
+    }
+}
@@ -0,0 +1,437 @@
+//! Borrowed and uniform representation of the body of a template, to inspect
+//! it without having to match every kind of component.
+//!
+//! ```rust
+//! # #[cfg(feature = "parse")]
+//! # {
+//! let root = mrml::parse(r#"<mjml><mj-body><mj-image src="logo.png" /></mj-body></mjml>"#)
+//!     .expect("couldn't parse template");
+//! let body = root.element.body_tree().expect("template without body");
+//! body.walk(&mut |node, _ancestors| {
+//!     if let Some(element) = node.as_element() {
+//!         println!("{} {:?}", element.tag, element.attribute("src"));
+//!     }
+//! });
+//! # }
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::comment::Comment;
+use crate::mj_accordion::MjAccordionChild;
+use crate::mj_accordion_element::MjAccordionElement;
+use crate::mj_body::MjBodyChild;
+use crate::mj_carousel::MjCarouselChild;
+use crate::mj_code::MjCode;
+use crate::mj_include::body::{MjIncludeBody, MjIncludeBodyChild};
+use crate::mj_navbar::MjNavbarChild;
+use crate::mj_raw::{MjRaw, MjRawChild};
+use crate::mj_social::MjSocialChild;
+use crate::mj_stats::MjStatsChild;
+use crate::mj_tabs::MjTabsChild;
+use crate::mjml::Mjml;
+use crate::node::Node;
+use crate::prelude::hash::Map;
+use crate::prelude::{Component, StaticTag};
+use crate::text::Text;
+
+#[derive(Clone, Debug)]
+pub struct TreeElement<'a> {
+    pub tag: &'a str,
+    pub attributes: Vec<(&'a str, &'a str)>,
+    pub children: Vec<TreeNode<'a>>,
+}
+
+impl<'a> TreeElement<'a> {
+    fn new(tag: &'a str) -> Self {
+        Self {
+            tag,
+            attributes: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    fn with_attributes(mut self, attributes: &'a Map<String, String>) -> Self {
+        self.attributes = attributes
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect();
+        self
+    }
+
+    fn with_children<C: AsTree>(mut self, children: &'a [C]) -> Self {
+        self.children = children.iter().map(AsTree::as_tree).collect();
+        self
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&'a str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| *value)
+    }
+
+    /// Concatenation of all the text contained in the element and its
+    /// descendants, with the whitespaces collapsed.
+    pub fn text_content(&self) -> String {
+        let mut result = String::new();
+        for child in self.children.iter() {
+            child.walk(&mut |node, _| {
+                if let TreeNode::Text(value) = node {
+                    result.push_str(value);
+                    result.push(' ');
+                }
+            });
+        }
+        result.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    /// Child elements, with their position among the siblings with the same
+    /// tag, starting at 1.
+    pub(crate) fn indexed_children(&self) -> Vec<(&TreeElement<'a>, usize)> {
+        let mut counters: Vec<(&str, usize)> = Vec::new();
+        self.children
+            .iter()
+            .filter_map(TreeNode::as_element)
+            .map(|child| {
+                let index = match counters.iter_mut().find(|(tag, _)| *tag == child.tag) {
+                    Some((_, count)) => {
+                        *count += 1;
+                        *count
+                    }
+                    None => {
+                        counters.push((child.tag, 1));
+                        1
+                    }
+                };
+                (child, index)
+            })
+            .collect()
+    }
+
+    /// Calls `visitor` on each descendant element, depth first, with its path
+    /// and its parent. The path of a child is built from the one of the
+    /// element, like `/mjml/mj-body/mj-section[1]/mj-column[2]`, where the
+    /// index counts the siblings with the same tag, starting at 1.
+    pub fn walk_with_path<F>(&self, path: &str, visitor: &mut F)
+    where
+        F: FnMut(&TreeElement<'a>, &str, &TreeElement<'a>),
+    {
+        for (child, index) in self.indexed_children() {
+            let child_path = format!("{path}/{}[{index}]", child.tag);
+            visitor(child, &child_path, self);
+            child.walk_with_path(&child_path, visitor);
+        }
+    }
+
+    /// Calls `visitor` on each descendant of the element, depth first, with
+    /// its list of ancestors, starting with this element.
+    pub fn walk<F>(&self, visitor: &mut F)
+    where
+        F: FnMut(&TreeNode<'a>, &[&TreeElement<'a>]),
+    {
+        let mut ancestors = vec![self];
+        for child in self.children.iter() {
+            child.walk_with(visitor, &mut ancestors);
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum TreeNode<'a> {
+    Element(TreeElement<'a>),
+    Text(&'a str),
+    Comment(&'a str),
+}
+
+impl<'a> TreeNode<'a> {
+    pub fn as_element(&self) -> Option<&TreeElement<'a>> {
+        match self {
+            Self::Element(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    fn walk_with<'n, F>(&'n self, visitor: &mut F, ancestors: &mut Vec<&'n TreeElement<'a>>)
+    where
+        F: FnMut(&TreeNode<'a>, &[&TreeElement<'a>]),
+    {
+        visitor(self, ancestors);
+        if let Self::Element(element) = self {
+            ancestors.push(element);
+            for child in element.children.iter() {
+                child.walk_with(visitor, ancestors);
+            }
+            ancestors.pop();
+        }
+    }
+
+    /// Calls `visitor` on this node and each of its descendants, depth first,
+    /// with the list of their ancestors.
+    pub fn walk<F>(&self, visitor: &mut F)
+    where
+        F: FnMut(&TreeNode<'a>, &[&TreeElement<'a>]),
+    {
+        self.walk_with(visitor, &mut Vec::new());
+    }
+}
+
+pub(crate) trait AsTree {
+    fn as_tree(&self) -> TreeNode<'_>;
+}
+
+impl<T: StaticTag, C: AsTree> AsTree for Component<PhantomData<T>, Map<String, String>, Vec<C>> {
+    fn as_tree(&self) -> TreeNode<'_> {
+        TreeNode::Element(
+            TreeElement::new(T::static_tag())
+                .with_attributes(&self.attributes)
+                .with_children(&self.children),
+        )
+    }
+}
+
+impl<T: StaticTag> AsTree for Component<PhantomData<T>, Map<String, String>, ()> {
+    fn as_tree(&self) -> TreeNode<'_> {
+        TreeNode::Element(TreeElement::new(T::static_tag()).with_attributes(&self.attributes))
+    }
+}
+
+impl<T: StaticTag, C: AsTree> AsTree for Component<PhantomData<T>, (), Vec<C>> {
+    fn as_tree(&self) -> TreeNode<'_> {
+        TreeNode::Element(TreeElement::new(T::static_tag()).with_children(&self.children))
+    }
+}
+
+impl<C: AsTree> AsTree for Node<C> {
+    fn as_tree(&self) -> TreeNode<'_> {
+        TreeNode::Element(
+            TreeElement::new(self.tag.as_str())
+                .with_attributes(&self.attributes)
+                .with_children(&self.children),
+        )
+    }
+}
+
+impl AsTree for Comment {
+    fn as_tree(&self) -> TreeNode<'_> {
+        TreeNode::Comment(self.children.as_str())
+    }
+}
+
+impl AsTree for Text {
+    fn as_tree(&self) -> TreeNode<'_> {
+        TreeNode::Text(self.inner_str())
+    }
+}
+
+impl AsTree for MjAccordionElement {
+    fn as_tree(&self) -> TreeNode<'_> {
+        let mut element =
+            TreeElement::new(crate::mj_accordion_element::NAME).with_attributes(&self.attributes);
+        if let Some(ref title) = self.children.title {
+            element.children.push(title.as_tree());
+        }
+        if let Some(ref text) = self.children.text {
+            element.children.push(text.as_tree());
+        }
+        TreeNode::Element(element)
+    }
+}
+
+impl AsTree for MjIncludeBody {
+    fn as_tree(&self) -> TreeNode<'_> {
+        let mut element = TreeElement::new(crate::mj_include::NAME).with_children(&self.0.children);
+        element
+            .attributes
+            .push(("path", self.0.attributes.path.as_str()));
+        if let Some(ref scope) = self.0.attributes.scope {
+            element.attributes.push(("scope", scope.as_str()));
+        }
+        TreeNode::Element(element)
+    }
+}
+
+impl AsTree for MjCode {
+    fn as_tree(&self) -> TreeNode<'_> {
+        let mut element = TreeElement::new(crate::mj_code::NAME).with_attributes(&self.attributes);
+        if !self.children.is_empty() {
+            element
+                .children
+                .push(TreeNode::Text(self.children.as_str()));
+        }
+        TreeNode::Element(element)
+    }
+}
+
+impl AsTree for MjRaw {
+    fn as_tree(&self) -> TreeNode<'_> {
+        let mut element = TreeElement::new(crate::mj_raw::NAME).with_children(&self.children);
+        if let Some(ref position) = self.attributes.position {
+            element.attributes.push(("position", position.as_str()));
+        }
+        TreeNode::Element(element)
+    }
+}
+
+macro_rules! as_tree_enum {
+    ($name:ident, $($variant:ident),+) => {
+        impl AsTree for $name {
+            fn as_tree(&self) -> TreeNode<'_> {
+                match self {
+                    $(Self::$variant(inner) => inner.as_tree(),)+
+                }
+            }
+        }
+    };
+}
+
+as_tree_enum!(
+    MjBodyChild,
+    Comment,
+    MjAccordion,
+    MjAvatar,
+    MjBadge,
+    MjButton,
+    MjCalendarButton,
+    MjCarousel,
+    MjCode,
+    MjColumn,
+    MjDivider,
+    MjFooter,
+    MjGroup,
+    MjHero,
+    MjInclude,
+    MjImage,
+    MjMap,
+    MjMsoOffice,
+    MjNavbar,
+    MjNotMso,
+    MjQuote,
+    MjRaw,
+    MjSection,
+    MjSocial,
+    MjSpacer,
+    MjStats,
+    MjTable,
+    MjTabs,
+    MjText,
+    MjWrapper,
+    Node,
+    Text
+);
+as_tree_enum!(
+    MjIncludeBodyChild,
+    Comment,
+    MjAccordion,
+    MjAvatar,
+    MjBadge,
+    MjButton,
+    MjCalendarButton,
+    MjCarousel,
+    MjCode,
+    MjColumn,
+    MjDivider,
+    MjFooter,
+    MjGroup,
+    MjHero,
+    MjImage,
+    MjMap,
+    MjMsoOffice,
+    MjNavbar,
+    MjNotMso,
+    MjQuote,
+    MjRaw,
+    MjSection,
+    MjSocial,
+    MjSpacer,
+    MjStats,
+    MjTable,
+    MjTabs,
+    MjText,
+    MjWrapper,
+    Node,
+    Text
+);
+as_tree_enum!(MjAccordionChild, Comment, MjAccordionElement);
+as_tree_enum!(MjCarouselChild, Comment, MjCarouselImage);
+as_tree_enum!(MjNavbarChild, Comment, MjNavbarLink);
+as_tree_enum!(MjRawChild, Comment, Node, Text);
+as_tree_enum!(MjSocialChild, Comment, MjSocialElement);
+as_tree_enum!(MjStatsChild, Comment, MjStat);
+as_tree_enum!(MjTabsChild, Comment, MjTab);
+
+/// Path of the body, used as root by [`TreeElement::walk_with_path`].
+pub const BODY_PATH: &str = "/mjml/mj-body";
+
+impl Mjml {
+    /// Builds a borrowed and uniform representation of the body of the
+    /// template.
+    pub fn body_tree(&self) -> Option<TreeElement<'_>> {
+        self.body().map(|body| match body.as_tree() {
+            TreeNode::Element(element) => element,
+            _ => unreachable!("the body is an element"),
+        })
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use crate::mjml::Mjml;
+
+    const TEMPLATE: &str = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <!-- logo -->
+        <mj-image src="logo.png" alt="Logo" />
+        <mj-text>Hello <a href="https://mrml.io">World</a>!</mj-text>
+        <mj-social>
+          <mj-social-element name="github" href="https://github.com">Github</mj-social-element>
+        </mj-social>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+
+    #[test]
+    fn should_build_tree() {
+        let root = Mjml::parse(TEMPLATE).unwrap();
+        let body = root.element.body_tree().unwrap();
+        assert_eq!(body.tag, "mj-body");
+        let mut tags = Vec::new();
+        body.walk(&mut |node, ancestors| {
+            if let Some(element) = node.as_element() {
+                tags.push((element.tag, ancestors.len()));
+            }
+        });
+        assert_eq!(
+            tags,
+            vec![
+                ("mj-section", 1),
+                ("mj-column", 2),
+                ("mj-image", 3),
+                ("mj-text", 3),
+                ("a", 4),
+                ("mj-social", 3),
+                ("mj-social-element", 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_read_attributes_and_text() {
+        let root = Mjml::parse(TEMPLATE).unwrap();
+        let body = root.element.body_tree().unwrap();
+        let mut found = Vec::new();
+        body.walk(&mut |node, _| match node.as_element() {
+            Some(element) if element.tag == "mj-image" => {
+                found.push(element.attribute("alt").unwrap().to_string());
+            }
+            Some(element) if element.tag == "mj-text" => {
+                found.push(element.text_content());
+            }
+            _ => {}
+        });
+        assert_eq!(found, vec!["Logo", "Hello World !"]);
+    }
+}
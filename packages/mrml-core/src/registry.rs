@@ -0,0 +1,431 @@
+//! Module containing a registry that loads all the templates of a directory
+//! and keeps them ready to be rendered.
+//!
+//! ```rust,no_run
+//! use mrml::prelude::render::RenderOptions;
+//! use mrml::registry::TemplateRegistry;
+//!
+//! let registry = TemplateRegistry::new("./templates")
+//!     .expect("couldn't load templates")
+//!     .watch()
+//!     .expect("couldn't watch templates");
+//! let html = registry
+//!     .render("emails/welcome", &RenderOptions::default())
+//!     .expect("couldn't render template");
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::mjml::Mjml;
+use crate::prelude::hash::Map;
+use crate::prelude::parser::local_loader::LocalIncludeLoader;
+use crate::prelude::parser::ParserOptions;
+use crate::prelude::render::RenderOptions;
+
+const EXTENSION: &str = "mjml";
+
+#[derive(Debug, thiserror::Error)]
+pub enum RegistryError {
+    #[error("unable to read {path:?}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: Arc<std::io::Error>,
+    },
+    #[error("unable to parse template {name:?}")]
+    Parser {
+        name: String,
+        #[source]
+        source: Box<crate::prelude::parser::Error>,
+    },
+    #[error("unable to render template")]
+    Render(#[from] crate::prelude::render::Error),
+    #[error("unable to watch templates")]
+    Watcher(#[from] notify::Error),
+    #[error("unable to find template {0:?}")]
+    NotFound(String),
+}
+
+/// Error preventing a file or a directory from being loaded, kept until the
+/// next reload.
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum LoadError {
+    #[error("unable to read {path:?}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: Arc<std::io::Error>,
+    },
+    #[error("unable to parse template")]
+    Parser(#[source] crate::prelude::parser::Error),
+}
+
+impl LoadError {
+    fn io(path: &Path, source: std::io::Error) -> Self {
+        Self::Io {
+            path: path.to_path_buf(),
+            source: Arc::new(source),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Inner {
+    root: PathBuf,
+    templates: RwLock<Map<String, Arc<Mjml>>>,
+    errors: RwLock<Map<String, LoadError>>,
+}
+
+/// Partials don't have an `mjml` root element, they are only loaded through
+/// `mj-include` and are not registered as templates.
+fn is_template(content: &str) -> bool {
+    let mut content = content.trim_start();
+    while let Some(rest) = content.strip_prefix("<!--") {
+        content = match rest.find("-->") {
+            Some(end) => rest[end + 3..].trim_start(),
+            None => return false,
+        };
+    }
+    content.starts_with("<mjml")
+}
+
+impl Inner {
+    fn template_name(&self, path: &Path) -> Option<String> {
+        let relative = path.strip_prefix(&self.root).ok()?.with_extension("");
+        let parts = relative
+            .components()
+            .map(|item| item.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>();
+        Some(parts.join("/"))
+    }
+
+    /// Collects the templates of `dir`, the subdirectories that can't be
+    /// read being recorded in `errors` and skipped.
+    fn collect_files(
+        &self,
+        dir: &Path,
+        files: &mut Vec<PathBuf>,
+        errors: &mut Map<String, LoadError>,
+    ) -> std::io::Result<()> {
+        for entry in std::fs::read_dir(dir)? {
+            let path = match entry {
+                Ok(entry) => entry.path(),
+                Err(source) => {
+                    if let Some(name) = self.template_name(dir) {
+                        errors.insert(name, LoadError::io(dir, source));
+                    }
+                    continue;
+                }
+            };
+            if path.is_dir() {
+                if let Err(source) = self.collect_files(&path, files, errors) {
+                    if let Some(name) = self.template_name(&path) {
+                        errors.insert(name, LoadError::io(&path, source));
+                    }
+                }
+            } else if path.extension().is_some_and(|ext| ext == EXTENSION) {
+                files.push(path);
+            }
+        }
+        Ok(())
+    }
+
+    fn reload(&self) -> Result<(), RegistryError> {
+        let mut files = Vec::new();
+        let mut errors = Map::new();
+        if let Err(source) = self.collect_files(&self.root, &mut files, &mut errors) {
+            // the templates already loaded are kept and the error is available
+            // under the name of the root directory, an empty string.
+            let source = Arc::new(source);
+            self.errors.write().unwrap().insert(
+                String::new(),
+                LoadError::Io {
+                    path: self.root.clone(),
+                    source: source.clone(),
+                },
+            );
+            return Err(RegistryError::Io {
+                path: self.root.clone(),
+                source,
+            });
+        }
+
+        let options = ParserOptions {
+            include_loader: Box::new(LocalIncludeLoader::new(self.root.clone())),
+            ..Default::default()
+        };
+        let mut templates = Map::new();
+        for path in files {
+            let Some(name) = self.template_name(&path) else {
+                continue;
+            };
+            let content = match std::fs::read_to_string(&path) {
+                Ok(content) => content,
+                Err(source) => {
+                    errors.insert(name, LoadError::io(&path, source));
+                    continue;
+                }
+            };
+            if !is_template(&content) {
+                continue;
+            }
+            match Mjml::parse_with_options(content, &options) {
+                Ok(output) => {
+                    templates.insert(name, Arc::new(output.element));
+                }
+                Err(error) => {
+                    errors.insert(name, LoadError::Parser(error));
+                }
+            }
+        }
+
+        // when a template can't be loaded anymore, we keep serving the previous
+        // version until it gets fixed.
+        let mut current = self.templates.write().unwrap();
+        for name in errors.keys() {
+            if let Some(previous) = current.get(name) {
+                templates.insert(name.clone(), previous.clone());
+            }
+        }
+        *current = templates;
+        *self.errors.write().unwrap() = errors;
+        Ok(())
+    }
+}
+
+/// Registry holding all the templates from a directory, parsed and ready to
+/// be rendered.
+///
+/// The templates are named after their path relative to the root directory,
+/// without the `.mjml` extension (`emails/welcome.mjml` becomes
+/// `emails/welcome`). Files without an `mjml` root element are considered as
+/// partials and are only available through `mj-include`. The `mj-include`
+/// elements are resolved with a [`LocalIncludeLoader`] using the same root
+/// directory.
+///
+/// A template that fails to be read or parsed is reported by
+/// [`TemplateRegistry::errors`] and, when reloading, the last valid version is
+/// kept.
+#[derive(Debug)]
+pub struct TemplateRegistry {
+    inner: Arc<Inner>,
+    watcher: Option<RecommendedWatcher>,
+}
+
+impl TemplateRegistry {
+    /// Creates a registry loading all the templates contained in `root`.
+    pub fn new<P: AsRef<Path>>(root: P) -> Result<Self, RegistryError> {
+        let root = root
+            .as_ref()
+            .canonicalize()
+            .map_err(|source| RegistryError::Io {
+                path: root.as_ref().to_path_buf(),
+                source: Arc::new(source),
+            })?;
+        let inner = Arc::new(Inner {
+            root,
+            templates: RwLock::new(Map::new()),
+            errors: RwLock::new(Map::new()),
+        });
+        inner.reload()?;
+        Ok(Self {
+            inner,
+            watcher: None,
+        })
+    }
+
+    /// Watches the root directory and reloads the templates when a file
+    /// changes. The watcher stops when the registry is dropped.
+    pub fn watch(mut self) -> Result<Self, RegistryError> {
+        let inner = self.inner.clone();
+        let mut watcher =
+            notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+                if let Ok(event) = res {
+                    if event.kind.is_create() || event.kind.is_modify() || event.kind.is_remove() {
+                        // the error is available through the errors of the registry
+                        let _ = inner.reload();
+                    }
+                }
+            })?;
+        watcher.watch(&self.inner.root, RecursiveMode::Recursive)?;
+        self.watcher = Some(watcher);
+        Ok(self)
+    }
+
+    pub fn is_watching(&self) -> bool {
+        self.watcher.is_some()
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.inner.root
+    }
+
+    /// Reloads all the templates from the root directory.
+    pub fn reload(&self) -> Result<(), RegistryError> {
+        self.inner.reload()
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.inner
+            .templates
+            .read()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    pub fn get(&self, name: &str) -> Option<Arc<Mjml>> {
+        self.inner.templates.read().unwrap().get(name).cloned()
+    }
+
+    /// Returns the errors of the last (re)load, by template name. The files
+    /// and directories that couldn't be read are named like the templates,
+    /// the root directory being named with an empty string.
+    pub fn errors(&self) -> Map<String, LoadError> {
+        self.inner.errors.read().unwrap().clone()
+    }
+
+    pub fn render(&self, name: &str, options: &RenderOptions) -> Result<String, RegistryError> {
+        let Some(template) = self.get(name) else {
+            return Err(match self.inner.errors.read().unwrap().get(name) {
+                Some(LoadError::Parser(source)) => RegistryError::Parser {
+                    name: name.to_string(),
+                    source: source.clone().into(),
+                },
+                Some(LoadError::Io { path, source }) => RegistryError::Io {
+                    path: path.clone(),
+                    source: source.clone(),
+                },
+                None => RegistryError::NotFound(name.to_string()),
+            });
+        };
+        Ok(template.render(options)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{is_template, LoadError, RegistryError, TemplateRegistry};
+    use crate::prelude::render::RenderOptions;
+
+    fn create_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("mrml-registry-{name}"));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("emails")).unwrap();
+        std::fs::create_dir_all(root.join("partials")).unwrap();
+        std::fs::write(
+            root.join("emails").join("welcome.mjml"),
+            r#"<mjml><mj-body><mj-include path="file:///partials/button.mjml" /></mj-body></mjml>"#,
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("partials").join("button.mjml"),
+            "<mj-button>Hello</mj-button>",
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    fn should_load_templates() {
+        let root = create_root("load");
+        let registry = TemplateRegistry::new(&root).unwrap();
+        assert!(registry.get("emails/welcome").is_some());
+        assert!(registry.get("partials/button").is_none());
+        assert!(registry.errors().is_empty());
+        let html = registry
+            .render("emails/welcome", &RenderOptions::default())
+            .unwrap();
+        assert!(html.contains("Hello"));
+    }
+
+    #[test]
+    fn should_detect_templates() {
+        assert!(is_template("<mjml></mjml>"));
+        assert!(is_template("  <!-- header --> <mjml></mjml>"));
+        assert!(!is_template("<mj-button>Hello</mj-button>"));
+        assert!(!is_template("<!-- unclosed"));
+    }
+
+    #[test]
+    fn should_fail_rendering_unknown_template() {
+        let root = create_root("unknown");
+        let registry = TemplateRegistry::new(&root).unwrap();
+        let err = registry
+            .render("emails/unknown", &RenderOptions::default())
+            .unwrap_err();
+        assert!(matches!(err, RegistryError::NotFound(_)));
+    }
+
+    #[test]
+    fn should_reload_templates() {
+        let root = create_root("reload");
+        let registry = TemplateRegistry::new(&root).unwrap();
+        std::fs::write(
+            root.join("partials").join("button.mjml"),
+            "<mj-button>Goodbye</mj-button>",
+        )
+        .unwrap();
+        registry.reload().unwrap();
+        let html = registry
+            .render("emails/welcome", &RenderOptions::default())
+            .unwrap();
+        assert!(html.contains("Goodbye"));
+    }
+
+    #[test]
+    fn should_keep_previous_version_on_error() {
+        let root = create_root("previous");
+        let registry = TemplateRegistry::new(&root).unwrap();
+        std::fs::write(root.join("emails").join("welcome.mjml"), "<mjml><mj-body>").unwrap();
+        registry.reload().unwrap();
+        assert!(registry.get("emails/welcome").is_some());
+        assert!(registry.errors().contains_key("emails/welcome"));
+    }
+
+    #[test]
+    fn should_fail_rendering_invalid_template() {
+        let root = create_root("invalid");
+        std::fs::write(root.join("emails").join("broken.mjml"), "<mjml><mj-body>").unwrap();
+        let registry = TemplateRegistry::new(&root).unwrap();
+        let err = registry
+            .render("emails/broken", &RenderOptions::default())
+            .unwrap_err();
+        assert!(matches!(err, RegistryError::Parser { .. }));
+    }
+
+    #[test]
+    fn should_skip_unreadable_files() {
+        let root = create_root("unreadable");
+        std::fs::write(root.join("emails").join("binary.mjml"), [0xff, 0xfe, 0x00]).unwrap();
+        let registry = TemplateRegistry::new(&root).unwrap();
+        assert!(registry.get("emails/welcome").is_some());
+        assert!(matches!(
+            registry.errors().get("emails/binary"),
+            Some(LoadError::Io { .. })
+        ));
+        let err = registry
+            .render("emails/binary", &RenderOptions::default())
+            .unwrap_err();
+        assert!(matches!(err, RegistryError::Io { .. }));
+    }
+
+    #[test]
+    fn should_report_unreadable_root() {
+        let root = create_root("missing-root");
+        let registry = TemplateRegistry::new(&root).unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+        assert!(matches!(registry.reload(), Err(RegistryError::Io { .. })));
+        assert!(matches!(
+            registry.errors().get(""),
+            Some(LoadError::Io { .. })
+        ));
+        assert!(registry.get("emails/welcome").is_some());
+    }
+}
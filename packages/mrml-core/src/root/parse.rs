@@ -1,7 +1,8 @@
 use super::RootChild;
 use crate::comment::Comment;
 use crate::prelude::parser::{
-    Error, MrmlCursor, MrmlParser, MrmlToken, ParseChildren, ParseOutput, ParserOptions,
+    mask_raw_tokens, Error, MrmlCursor, MrmlParser, MrmlToken, ParseChildren, ParseOutput,
+    ParserOptions,
 };
 
 impl<'opts> crate::prelude::parser::ParseChildren<Vec<RootChild>> for MrmlParser<'opts> {
@@ -70,8 +71,11 @@ impl super::Root {
         value: T,
         opts: &ParserOptions,
     ) -> Result<ParseOutput<Self>, Error> {
+        let _span = crate::trace::span!("mrml::parse", size = value.as_ref().len()).entered();
         let parser = MrmlParser::new(opts);
-        let mut cursor = MrmlCursor::new(value.as_ref());
+        let cursor = MrmlCursor::with_limits(value.as_ref(), opts.limits)?;
+        let masked = mask_raw_tokens(&opts.raw_token_patterns, value.as_ref());
+        let mut cursor = cursor.with_masked_source(value.as_ref(), &masked);
         let element = Self(parser.parse_children(&mut cursor)?);
         Ok(ParseOutput {
             element,
@@ -86,12 +90,18 @@ impl super::Root {
     ) -> Result<ParseOutput<Self>, Error> {
         use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren};
 
-        let parser = AsyncMrmlParser::new(opts);
-        let mut cursor = MrmlCursor::new(value.as_ref());
-        let element = Self(parser.async_parse_children(&mut cursor).await?);
-        Ok(ParseOutput {
-            element,
-            warnings: cursor.warnings(),
+        let span = crate::trace::span!("mrml::parse", size = value.as_ref().len());
+        crate::trace::instrument(span, async move {
+            let parser = AsyncMrmlParser::new(opts);
+            let cursor = MrmlCursor::with_limits(value.as_ref(), parser.options.limits)?;
+            let masked = mask_raw_tokens(&parser.options.raw_token_patterns, value.as_ref());
+            let mut cursor = cursor.with_masked_source(value.as_ref(), &masked);
+            let element = Self(parser.async_parse_children(&mut cursor).await?);
+            Ok::<_, Error>(ParseOutput {
+                element,
+                warnings: cursor.warnings(),
+            })
         })
+        .await
     }
 }
@@ -0,0 +1,642 @@
+//! Removes the dangerous html from a template, when rendering templates
+//! written by untrusted users.
+//!
+//! The `script` elements, the elements able to load or embed other
+//! documents, the event handlers (`onclick`...) and the urls with a
+//! `javascript:`, `vbscript:` or non image `data:` scheme are removed from
+//! the components, from the default attributes of `mj-attributes` and from
+//! the html they contain (`mj-raw`, `mj-text`...). The styles running a
+//! script, in a `style` attribute, a `style` element or an `mj-style`, are
+//! removed as well.
+//!
+//! ```rust
+//! let mut root = mrml::parse(r#"<mjml><mj-body><mj-text>Hello<script>alert(1)</script></mj-text></mj-body></mjml>"#)
+//!     .expect("couldn't parse template")
+//!     .element;
+//! root.sanitize();
+//! let html = root.render(&Default::default()).expect("couldn't render template");
+//! assert!(!html.contains("alert(1)"));
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::comment::Comment;
+use crate::mj_accordion::MjAccordionChild;
+use crate::mj_accordion_element::MjAccordionElement;
+use crate::mj_attributes::{MjAttributes, MjAttributesChild};
+use crate::mj_attributes_class::MjAttributesClass;
+use crate::mj_attributes_element::MjAttributesElement;
+use crate::mj_body::MjBodyChild;
+use crate::mj_carousel::MjCarouselChild;
+use crate::mj_code::MjCode;
+use crate::mj_font::MjFont;
+use crate::mj_head::MjHeadChild;
+use crate::mj_include::body::{MjIncludeBody, MjIncludeBodyChild};
+use crate::mj_include::head::MjIncludeHeadChild;
+use crate::mj_navbar::MjNavbarChild;
+use crate::mj_raw::{MjRaw, MjRawChild};
+use crate::mj_social::MjSocialChild;
+use crate::mj_stats::MjStatsChild;
+use crate::mj_style::MjStyle;
+use crate::mj_tabs::MjTabsChild;
+use crate::mjml::Mjml;
+use crate::node::Node;
+use crate::prelude::hash::Map;
+use crate::prelude::Component;
+use crate::text::Text;
+
+/// Elements removed with their content.
+const FORBIDDEN_ELEMENTS: [&str; 10] = [
+    "script", "iframe", "frame", "frameset", "object", "embed", "applet", "base", "meta",
+    "noscript",
+];
+
+/// Attributes containing an url.
+const URL_ATTRIBUTES: [&str; 9] = [
+    "href",
+    "src",
+    "action",
+    "formaction",
+    "background",
+    "poster",
+    "xlink:href",
+    "lowsrc",
+    "dynsrc",
+];
+
+fn is_forbidden_element(tag: &str) -> bool {
+    FORBIDDEN_ELEMENTS
+        .iter()
+        .any(|name| name.eq_ignore_ascii_case(tag))
+}
+
+/// Named references the browsers decode in the attributes, that can hide a
+/// scheme.
+fn decode_named_reference(name: &str) -> Option<char> {
+    match name {
+        "colon" => Some(':'),
+        "Tab" => Some('\t'),
+        "NewLine" => Some('\n'),
+        "lpar" => Some('('),
+        "rpar" => Some(')'),
+        "sol" => Some('/'),
+        "bsol" => Some('\\'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "nbsp" => Some(' '),
+        _ => None,
+    }
+}
+
+/// Decodes the character references of an attribute value, the way the
+/// browsers do before reading it. The numeric references don't need the
+/// final `;`.
+fn decode_character_references(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(index) = rest.find('&') {
+        result.push_str(&rest[..index]);
+        rest = &rest[index + 1..];
+        if let Some(code) = rest.strip_prefix('#') {
+            let (digits, radix, prefix) = match code.strip_prefix(|c| c == 'x' || c == 'X') {
+                Some(hex) => (hex, 16, 2),
+                None => (code, 10, 1),
+            };
+            let length = digits
+                .find(|c: char| !c.is_digit(radix))
+                .unwrap_or(digits.len());
+            let decoded = u32::from_str_radix(&digits[..length], radix)
+                .ok()
+                .map(|code| char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+            if let Some(c) = decoded {
+                result.push(c);
+                rest = &rest[prefix + length..];
+                rest = rest.strip_prefix(';').unwrap_or(rest);
+                continue;
+            }
+        } else if let Some(end) = rest.find(';').filter(|end| *end <= 8) {
+            if let Some(c) = decode_named_reference(&rest[..end]) {
+                result.push(c);
+                rest = &rest[end + 1..];
+                continue;
+            }
+        }
+        result.push('&');
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Decodes the escaped characters (`\6a` or `\:`) and removes the comments of
+/// a stylesheet, the way the browsers do before reading it.
+fn decode_css(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let mut code = String::new();
+                while code.len() < 6 && chars.peek().is_some_and(char::is_ascii_hexdigit) {
+                    code.extend(chars.next());
+                }
+                if code.is_empty() {
+                    result.extend(chars.next());
+                } else {
+                    // a whitespace ends the escaped character
+                    chars.next_if(|c| c.is_whitespace());
+                    let code = u32::from_str_radix(&code, 16).unwrap_or_default();
+                    result.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+                }
+            }
+            '/' if chars.next_if_eq(&'*').is_some() => {
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Removes the whitespaces and control characters the browsers ignore in a
+/// scheme or a function name, and lowers the case.
+fn normalize(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .collect::<String>()
+        .to_ascii_lowercase()
+}
+
+fn is_dangerous_url(value: &str) -> bool {
+    let normalized = normalize(&decode_character_references(value));
+    normalized.starts_with("javascript:")
+        || normalized.starts_with("vbscript:")
+        || (normalized.starts_with("data:") && !normalized.starts_with("data:image/"))
+}
+
+/// Checks the content of a `style` element or an `mj-style`.
+fn is_dangerous_style(value: &str) -> bool {
+    let normalized = normalize(&decode_css(value));
+    normalized.contains("expression(")
+        || normalized.contains("javascript:")
+        || normalized.contains("vbscript:")
+}
+
+fn is_dangerous_attribute(name: &str, value: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    if name.starts_with("on") || name == "srcdoc" {
+        return true;
+    }
+    if name == "style" {
+        return is_dangerous_style(&decode_character_references(value));
+    }
+    URL_ATTRIBUTES.contains(&name.as_str()) && is_dangerous_url(value)
+}
+
+fn sanitize_attributes(attributes: &mut Map<String, String>) {
+    attributes.retain(|name, value| !is_dangerous_attribute(name, value));
+}
+
+fn sanitize_children<C: Sanitize>(children: &mut Vec<C>) {
+    children.retain(|child| !child.is_forbidden());
+    children.iter_mut().for_each(Sanitize::sanitize);
+}
+
+pub(crate) trait Sanitize {
+    fn sanitize(&mut self);
+
+    /// When true, the element is removed with its content.
+    fn is_forbidden(&self) -> bool {
+        false
+    }
+
+    /// Content of the text nodes, checked when they are in a `style`
+    /// element.
+    fn as_text(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl<T, C: Sanitize> Sanitize for Component<PhantomData<T>, Map<String, String>, Vec<C>> {
+    fn sanitize(&mut self) {
+        sanitize_attributes(&mut self.attributes);
+        sanitize_children(&mut self.children);
+    }
+}
+
+impl<T> Sanitize for Component<PhantomData<T>, Map<String, String>, ()> {
+    fn sanitize(&mut self) {
+        sanitize_attributes(&mut self.attributes);
+    }
+}
+
+impl<T, C: Sanitize> Sanitize for Component<PhantomData<T>, (), Vec<C>> {
+    fn sanitize(&mut self) {
+        sanitize_children(&mut self.children);
+    }
+}
+
+impl<C: Sanitize> Sanitize for Node<C> {
+    fn sanitize(&mut self) {
+        sanitize_attributes(&mut self.attributes);
+        sanitize_children(&mut self.children);
+    }
+
+    fn is_forbidden(&self) -> bool {
+        is_forbidden_element(&self.tag)
+            || (self.tag.eq_ignore_ascii_case("style")
+                && self
+                    .children
+                    .iter()
+                    .filter_map(Sanitize::as_text)
+                    .any(is_dangerous_style))
+    }
+}
+
+impl Sanitize for Comment {
+    fn sanitize(&mut self) {}
+
+    /// The conditional comments are interpreted by some email clients.
+    fn is_forbidden(&self) -> bool {
+        let content = self.children.to_ascii_lowercase();
+        FORBIDDEN_ELEMENTS
+            .iter()
+            .any(|name| content.contains(&format!("<{name}")))
+    }
+}
+
+impl Sanitize for Text {
+    fn sanitize(&mut self) {}
+
+    fn as_text(&self) -> Option<&str> {
+        Some(self.inner_str())
+    }
+}
+
+impl Sanitize for MjAccordionElement {
+    fn sanitize(&mut self) {
+        sanitize_attributes(&mut self.attributes);
+        if let Some(ref mut title) = self.children.title {
+            title.sanitize();
+        }
+        if let Some(ref mut text) = self.children.text {
+            text.sanitize();
+        }
+    }
+}
+
+/// The code is escaped when rendered.
+impl Sanitize for MjCode {
+    fn sanitize(&mut self) {
+        sanitize_attributes(&mut self.attributes);
+    }
+}
+
+impl Sanitize for MjRaw {
+    fn sanitize(&mut self) {
+        sanitize_children(&mut self.children);
+    }
+}
+
+impl Sanitize for MjAttributes {
+    fn sanitize(&mut self) {
+        sanitize_children(&mut self.children);
+    }
+}
+
+impl Sanitize for MjAttributesClass {
+    fn sanitize(&mut self) {
+        sanitize_attributes(&mut self.attributes.others);
+    }
+}
+
+impl Sanitize for MjAttributesElement {
+    fn sanitize(&mut self) {
+        sanitize_attributes(&mut self.attributes);
+    }
+}
+
+impl Sanitize for MjFont {
+    fn sanitize(&mut self) {}
+
+    fn is_forbidden(&self) -> bool {
+        is_dangerous_url(&self.attributes.href)
+    }
+}
+
+impl Sanitize for MjStyle {
+    fn sanitize(&mut self) {}
+
+    fn is_forbidden(&self) -> bool {
+        is_dangerous_style(&self.children)
+    }
+}
+
+impl Sanitize for MjIncludeBody {
+    fn sanitize(&mut self) {
+        sanitize_children(&mut self.0.children);
+    }
+}
+
+macro_rules! sanitize_enum {
+    ($name:ident, $($variant:ident),+) => {
+        impl Sanitize for $name {
+            fn sanitize(&mut self) {
+                match self {
+                    $(Self::$variant(inner) => inner.sanitize(),)+
+                }
+            }
+
+            fn is_forbidden(&self) -> bool {
+                match self {
+                    $(Self::$variant(inner) => inner.is_forbidden(),)+
+                }
+            }
+
+            fn as_text(&self) -> Option<&str> {
+                match self {
+                    $(Self::$variant(inner) => inner.as_text(),)+
+                }
+            }
+        }
+    };
+}
+
+sanitize_enum!(
+    MjBodyChild,
+    Comment,
+    MjAccordion,
+    MjAvatar,
+    MjBadge,
+    MjButton,
+    MjCalendarButton,
+    MjCarousel,
+    MjCode,
+    MjColumn,
+    MjDivider,
+    MjFooter,
+    MjGroup,
+    MjHero,
+    MjInclude,
+    MjImage,
+    MjMap,
+    MjMsoOffice,
+    MjNavbar,
+    MjNotMso,
+    MjQuote,
+    MjRaw,
+    MjSection,
+    MjSocial,
+    MjSpacer,
+    MjStats,
+    MjTable,
+    MjTabs,
+    MjText,
+    MjWrapper,
+    Node,
+    Text
+);
+sanitize_enum!(
+    MjIncludeBodyChild,
+    Comment,
+    MjAccordion,
+    MjAvatar,
+    MjBadge,
+    MjButton,
+    MjCalendarButton,
+    MjCarousel,
+    MjCode,
+    MjColumn,
+    MjDivider,
+    MjFooter,
+    MjGroup,
+    MjHero,
+    MjImage,
+    MjMap,
+    MjMsoOffice,
+    MjNavbar,
+    MjNotMso,
+    MjQuote,
+    MjRaw,
+    MjSection,
+    MjSocial,
+    MjSpacer,
+    MjStats,
+    MjTable,
+    MjTabs,
+    MjText,
+    MjWrapper,
+    Node,
+    Text
+);
+sanitize_enum!(MjAccordionChild, Comment, MjAccordionElement);
+sanitize_enum!(
+    MjAttributesChild,
+    MjAttributesAll,
+    MjAttributesClass,
+    MjAttributesElement
+);
+sanitize_enum!(MjCarouselChild, Comment, MjCarouselImage);
+sanitize_enum!(MjNavbarChild, Comment, MjNavbarLink);
+sanitize_enum!(MjRawChild, Comment, Node, Text);
+sanitize_enum!(MjSocialChild, Comment, MjSocialElement);
+sanitize_enum!(MjStatsChild, Comment, MjStat);
+sanitize_enum!(MjTabsChild, Comment, MjTab);
+
+/// The other elements of the head only hold text.
+impl Sanitize for MjHeadChild {
+    fn sanitize(&mut self) {
+        match self {
+            Self::MjAttributes(inner) => inner.sanitize(),
+            Self::MjInclude(inner) => sanitize_children(&mut inner.0.children),
+            Self::MjRaw(inner) => inner.sanitize(),
+            _ => {}
+        }
+    }
+
+    fn is_forbidden(&self) -> bool {
+        match self {
+            Self::Comment(inner) => inner.is_forbidden(),
+            Self::MjFont(inner) => inner.is_forbidden(),
+            Self::MjStyle(inner) => inner.is_forbidden(),
+            _ => false,
+        }
+    }
+}
+
+impl Sanitize for MjIncludeHeadChild {
+    fn sanitize(&mut self) {
+        match self {
+            Self::MjAttributes(inner) => inner.sanitize(),
+            Self::MjRaw(inner) => inner.sanitize(),
+            _ => {}
+        }
+    }
+
+    fn is_forbidden(&self) -> bool {
+        match self {
+            Self::Comment(inner) => inner.is_forbidden(),
+            Self::MjFont(inner) => inner.is_forbidden(),
+            Self::MjStyle(inner) => inner.is_forbidden(),
+            _ => false,
+        }
+    }
+}
+
+impl Mjml {
+    /// Removes the dangerous html from the template, see the
+    /// [`sanitize`](crate::sanitize) module.
+    pub fn sanitize(&mut self) {
+        if let Some(ref mut head) = self.children.head {
+            sanitize_children(&mut head.children);
+        }
+        if let Some(ref mut body) = self.children.body {
+            body.sanitize();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "parse", feature = "render"))]
+mod tests {
+    use super::{is_dangerous_style, is_dangerous_url};
+    use crate::mjml::Mjml;
+
+    fn sanitize(template: &str) -> String {
+        let mut root = Mjml::parse(template).unwrap().element;
+        root.sanitize();
+        root.render(&Default::default()).unwrap()
+    }
+
+    #[test]
+    fn should_detect_dangerous_urls() {
+        assert!(is_dangerous_url("javascript:alert(1)"));
+        assert!(is_dangerous_url(" JavaScript\t:alert(1)"));
+        assert!(is_dangerous_url("&#106;avascript:alert(1)"));
+        assert!(is_dangerous_url("&#x6A&#x61vascript:alert(1)"));
+        assert!(is_dangerous_url("java&Tab;script&colon;alert(1)"));
+        assert!(is_dangerous_url("data:text/html;base64,PHNjcmlwdD4="));
+        assert!(!is_dangerous_url("data:image/png;base64,iVBORw0KGgo="));
+        assert!(!is_dangerous_url("https://example.com/javascript:"));
+    }
+
+    #[test]
+    fn should_remove_scripts() {
+        let result = sanitize(
+            r#"<mjml><mj-body><mj-text>Hello <script>alert("text")</script><b>World</b></mj-text><mj-raw><SCRIPT src="https://evil.com/raw.js"></SCRIPT><iframe src="https://evil.com"></iframe></mj-raw></mj-body></mjml>"#,
+        );
+        assert!(result.contains("<b>World</b>"));
+        assert!(!result.contains("alert"));
+        assert!(!result.contains("evil.com"));
+    }
+
+    #[test]
+    fn should_detect_dangerous_styles() {
+        assert!(is_dangerous_style(
+            "a { background: url(javascript:alert(1)) }"
+        ));
+        assert!(is_dangerous_style(
+            "a { background: url(java\\73 cript:alert(1)) }"
+        ));
+        assert!(is_dangerous_style("a { width: expr/**/ession(alert(1)) }"));
+        assert!(!is_dangerous_style(
+            "a { background: url(https://example.com/a.png) }"
+        ));
+    }
+
+    #[test]
+    fn should_remove_event_handlers_and_urls() {
+        let result = sanitize(
+            r#"<mjml><mj-body><mj-text><a href="javascript:alert('link')" onclick="alert('click')">Link</a><img src="https://example.com/image.png" onerror="alert('image')" /></mj-text><mj-button href="javascript:alert('button')">Button</mj-button></mj-body></mjml>"#,
+        );
+        assert!(result.contains("Link"));
+        assert!(result.contains("https://example.com/image.png"));
+        assert!(!result.contains("alert"));
+    }
+
+    #[test]
+    fn should_clean_head_raw() {
+        let result = sanitize(
+            r#"<mjml><mj-head><mj-raw><script>alert("head")</script><meta name="color-scheme" content="light" /></mj-raw></mj-head><mj-body></mj-body></mjml>"#,
+        );
+        assert!(!result.contains("alert"));
+    }
+
+    #[test]
+    fn should_remove_conditional_scripts() {
+        let result = sanitize(
+            r#"<mjml><mj-body><mj-raw><!--[if IE]><script>alert("comment")</script><![endif]--></mj-raw></mj-body></mjml>"#,
+        );
+        assert!(!result.contains("alert"));
+    }
+
+    #[test]
+    fn should_clean_head_attributes() {
+        let result = sanitize(
+            r#"<mjml><mj-head><mj-attributes><mj-all href="javascript:alert(0)" /><mj-button href="javascript:alert(1)" /></mj-attributes></mj-head><mj-body><mj-button>Button</mj-button></mj-body></mjml>"#,
+        );
+        assert!(result.contains("Button"));
+        assert!(!result.contains("alert"));
+    }
+
+    #[test]
+    fn should_clean_head_classes() {
+        let result = sanitize(
+            r#"<mjml><mj-head><mj-attributes><mj-class name="link" href="javascript:alert(2)" /></mj-attributes></mj-head><mj-body><mj-button mj-class="link">Button</mj-button></mj-body></mjml>"#,
+        );
+        assert!(result.contains("Button"));
+        assert!(!result.contains("alert"));
+    }
+
+    #[test]
+    fn should_clean_included_head_attributes() {
+        use crate::prelude::parser::memory_loader::MemoryIncludeLoader;
+        use crate::prelude::parser::ParserOptions;
+
+        let loader = MemoryIncludeLoader::from(vec![(
+            "attributes.mjml",
+            r#"<mj-attributes><mj-button href="javascript:alert(1)" /></mj-attributes>"#,
+        )]);
+        let opts = ParserOptions {
+            include_loader: Box::new(loader),
+            ..Default::default()
+        };
+        let mut root = Mjml::parse_with_options(
+            r#"<mjml><mj-head><mj-include path="attributes.mjml" /></mj-head><mj-body><mj-button>Button</mj-button></mj-body></mjml>"#,
+            &opts,
+        )
+        .unwrap()
+        .element;
+        root.sanitize();
+        let result = root.render(&Default::default()).unwrap();
+        assert!(result.contains("Button"));
+        assert!(!result.contains("alert"));
+    }
+
+    #[test]
+    fn should_decode_references_in_urls() {
+        let result = sanitize(
+            r#"<mjml><mj-body><mj-text><a href="&#106;avascript:alert(3)">Link</a></mj-text></mj-body></mjml>"#,
+        );
+        assert!(result.contains("Link"));
+        assert!(!result.contains("alert"));
+    }
+
+    #[test]
+    fn should_clean_styles() {
+        let result = sanitize(
+            r#"<mjml><mj-head><mj-style>.a { background: url(javascript:alert(4)) }</mj-style><mj-style>.b { color: red }</mj-style></mj-head><mj-body><mj-raw><style>.c { background: url(javascript:alert(5)) }</style></mj-raw><mj-text><p style="background: url(&#106;avascript:alert(6))">Text</p></mj-text></mj-body></mjml>"#,
+        );
+        assert!(result.contains(".b { color: red }"));
+        assert!(result.contains("Text"));
+        assert!(!result.contains("alert"));
+    }
+}
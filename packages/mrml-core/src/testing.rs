@@ -0,0 +1,171 @@
+//! Snapshot testing of the rendered templates, to make sure the html doesn't
+//! change unexpectedly, when upgrading MRML for example.
+//!
+//! The rendered html is compared to the content of the snapshot file,
+//! ignoring the differences that don't change the rendering (order of the
+//! attributes, whitespaces...). When the snapshot doesn't exist, it's
+//! created. When the `MRML_UPDATE_SNAPSHOTS` environment variable is set to
+//! `1`, the snapshots are overwritten with the new html.
+//!
+//! ```rust,no_run
+//! mrml::testing::assert_snapshot(
+//!     "<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>",
+//!     "tests/snapshots/hello.html",
+//! );
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use crate::prelude::render::RenderOptions;
+
+/// Name of the environment variable enabling the update of the snapshots.
+pub const UPDATE_ENV: &str = "MRML_UPDATE_SNAPSHOTS";
+
+#[derive(Debug, thiserror::Error)]
+pub enum SnapshotError {
+    #[error("unable to parse template")]
+    Parser(#[from] crate::prelude::parser::Error),
+    #[error("unable to render template")]
+    Render(#[from] crate::prelude::render::Error),
+    #[error("unable to access snapshot {path:?}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    /// The rendered html differs from the snapshot, the report describes the
+    /// first difference.
+    #[error("rendered html doesn't match snapshot {path:?}\n{report}")]
+    Mismatch { path: PathBuf, report: String },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SnapshotStatus {
+    Matched,
+    Created,
+    Updated,
+}
+
+#[derive(Debug)]
+pub struct Snapshot {
+    path: PathBuf,
+    options: RenderOptions,
+    update: bool,
+}
+
+impl Snapshot {
+    /// Creates a snapshot stored at `path`, updated when the
+    /// [`UPDATE_ENV`] environment variable is set to `1`.
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self {
+            path: path.into(),
+            options: RenderOptions::default(),
+            update: std::env::var(UPDATE_ENV).is_ok_and(|value| value == "1"),
+        }
+    }
+
+    pub fn with_options(mut self, options: RenderOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn with_update(mut self, update: bool) -> Self {
+        self.update = update;
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn io_error(&self, source: std::io::Error) -> SnapshotError {
+        SnapshotError::Io {
+            path: self.path.clone(),
+            source,
+        }
+    }
+
+    fn write(&self, html: &str) -> Result<(), SnapshotError> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(|err| self.io_error(err))?;
+        }
+        std::fs::write(&self.path, html).map_err(|err| self.io_error(err))
+    }
+
+    /// Renders the template and compares the result with the snapshot.
+    pub fn check(&self, template: &str) -> Result<SnapshotStatus, SnapshotError> {
+        let root = crate::parse(template)?;
+        let html = root.element.render(&self.options)?;
+        let expected = match std::fs::read_to_string(&self.path) {
+            Ok(value) => value,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                self.write(&html)?;
+                return Ok(SnapshotStatus::Created);
+            }
+            Err(err) => return Err(self.io_error(err)),
+        };
+        let result = html_compare::compare(&expected, &html);
+        match result {
+            Ok(_) => Ok(SnapshotStatus::Matched),
+            Err(_) if self.update => {
+                self.write(&html)?;
+                Ok(SnapshotStatus::Updated)
+            }
+            Err(error) => Err(SnapshotError::Mismatch {
+                path: self.path.clone(),
+                report: error.to_string(),
+            }),
+        }
+    }
+}
+
+/// Renders the template with the default options and panics when the html
+/// doesn't match the snapshot stored at `path`.
+pub fn assert_snapshot<P: Into<PathBuf>>(template: &str, path: P) {
+    if let Err(error) = Snapshot::new(path).check(template) {
+        panic!("{}", error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Snapshot, SnapshotError, SnapshotStatus};
+
+    const TEMPLATE: &str = "<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>";
+    const OTHER: &str = "<mjml><mj-body><mj-text>World</mj-text></mj-body></mjml>";
+
+    fn snapshot(name: &str) -> Snapshot {
+        let path = std::env::temp_dir()
+            .join(format!("mrml-testing-{}", std::process::id()))
+            .join(format!("{name}.html"));
+        let _ = std::fs::remove_file(&path);
+        Snapshot::new(path).with_update(false)
+    }
+
+    #[test]
+    fn should_create_and_match_snapshot() {
+        let snapshot = snapshot("match");
+        assert_eq!(snapshot.check(TEMPLATE).unwrap(), SnapshotStatus::Created);
+        assert!(snapshot.path().exists());
+        assert_eq!(snapshot.check(TEMPLATE).unwrap(), SnapshotStatus::Matched);
+    }
+
+    #[test]
+    fn should_detect_mismatch() {
+        let snapshot = snapshot("mismatch");
+        snapshot.check(TEMPLATE).unwrap();
+        match snapshot.check(OTHER) {
+            Err(SnapshotError::Mismatch { report, .. }) => assert!(report.contains("mismatch")),
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_update_snapshot() {
+        let snapshot = snapshot("update");
+        snapshot.check(TEMPLATE).unwrap();
+        let snapshot = snapshot.with_update(true);
+        assert_eq!(snapshot.check(OTHER).unwrap(), SnapshotStatus::Updated);
+        assert_eq!(snapshot.check(OTHER).unwrap(), SnapshotStatus::Matched);
+    }
+}
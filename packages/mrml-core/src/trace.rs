@@ -0,0 +1,72 @@
+//! Instrumentation of the parser and the renderer with the
+//! [`tracing`](https://docs.rs/tracing) crate, enabled with the `tracing`
+//! feature. Without it, the spans are replaced by a type doing nothing.
+//!
+//! The following spans are emitted, at the `INFO` level:
+//! - `mrml::parse`, with the `size` of the template,
+//! - `mrml::include`, with the `path` of the included template,
+//! - `mrml::render`, with the `size` of the generated html,
+//! - `mrml::render_element`, for each element of the body, with its `tag`, its
+//!   `index` and the `size` of the generated html.
+
+#[cfg(all(feature = "tracing", feature = "render"))]
+pub(crate) use tracing::field::Empty;
+#[cfg(all(
+    feature = "tracing",
+    feature = "async",
+    any(feature = "parse", feature = "render")
+))]
+pub(crate) use tracing::Span;
+
+#[cfg(all(not(feature = "tracing"), any(feature = "parse", feature = "render")))]
+#[derive(Clone, Debug)]
+pub(crate) struct Span;
+
+#[cfg(all(not(feature = "tracing"), any(feature = "parse", feature = "render")))]
+impl Span {
+    #[inline]
+    pub fn entered(self) -> Self {
+        self
+    }
+
+    #[cfg(feature = "render")]
+    #[inline]
+    pub fn record<V>(&self, _field: &str, _value: V) -> &Self {
+        self
+    }
+}
+
+#[cfg(any(feature = "parse", feature = "render"))]
+macro_rules! span {
+    ($name:literal $(, $key:ident = $value:expr)* $(,)?) => {{
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!($name $(, $key = $value)*);
+        #[cfg(not(feature = "tracing"))]
+        let span = $crate::trace::Span;
+        span
+    }};
+}
+
+#[cfg(any(feature = "parse", feature = "render"))]
+pub(crate) use span;
+
+#[cfg(all(
+    feature = "async",
+    feature = "tracing",
+    any(feature = "parse", feature = "render")
+))]
+pub(crate) fn instrument<F: std::future::Future>(
+    span: Span,
+    future: F,
+) -> tracing::instrument::Instrumented<F> {
+    tracing::Instrument::instrument(future, span)
+}
+
+#[cfg(all(
+    feature = "async",
+    not(feature = "tracing"),
+    any(feature = "parse", feature = "render")
+))]
+pub(crate) fn instrument<F: std::future::Future>(_span: Span, future: F) -> F {
+    future
+}
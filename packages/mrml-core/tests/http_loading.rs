@@ -18,6 +18,7 @@ async fn async_loading_include() {
     ]));
     let options = AsyncParserOptions {
         include_loader: Box::new(resolver),
+        ..Default::default()
     };
     let _ = mrml::async_parse_with_options(template, options.into())
         .await
@@ -38,6 +39,7 @@ fn sync_loading_include() {
     ]));
     let options = ParserOptions {
         include_loader: Box::new(resolver),
+        ..Default::default()
     };
     let _ = mrml::parse_with_options(template, &options).unwrap();
 }
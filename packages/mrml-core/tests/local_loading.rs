@@ -19,6 +19,7 @@ fn loading_include() {
     );
     let options = ParserOptions {
         include_loader: Box::new(resolver),
+        ..Default::default()
     };
     let parsed = mrml::parse_with_options(template, &options).unwrap();
     let output = parsed.element.render(&RenderOptions::default()).unwrap();
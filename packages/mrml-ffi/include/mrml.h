@@ -0,0 +1,105 @@
+#ifndef MRML_H
+#define MRML_H
+
+/* This file is generated by cbindgen, do not edit it manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Status returned by the functions of this library.
+typedef enum MrmlStatus {
+  MRML_STATUS_OK = 0,
+  // A required pointer was null.
+  MRML_STATUS_NULL_POINTER = 1,
+  // A provided string wasn't valid UTF-8.
+  MRML_STATUS_INVALID_UTF8 = 2,
+  // The template couldn't be parsed.
+  MRML_STATUS_PARSER_ERROR = 3,
+  // The template couldn't be rendered.
+  MRML_STATUS_RENDER_ERROR = 4,
+  // Something unexpected happened.
+  MRML_STATUS_PANIC = 5,
+} MrmlStatus;
+
+// Rendering options, created with [`mrml_render_options_new`] and released
+// with [`mrml_render_options_free`].
+typedef struct MrmlRenderOptions MrmlRenderOptions;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Creates rendering options with the default values.
+MrmlRenderOptions *mrml_render_options_new(void);
+
+// Releases rendering options created with [`mrml_render_options_new`].
+//
+// # Safety
+//
+// `options` must be null or a pointer returned by
+// [`mrml_render_options_new`] that hasn't been released yet.
+void mrml_render_options_free(MrmlRenderOptions *options);
+
+// Defines if the comments should be removed from the output.
+//
+// # Safety
+//
+// `options` must be a pointer returned by [`mrml_render_options_new`].
+MrmlStatus mrml_render_options_set_disable_comments(MrmlRenderOptions *options, bool value);
+
+// Defines the base url used to fetch the social icons. A null `value`
+// restores the default origin.
+//
+// # Safety
+//
+// `options` must be a pointer returned by [`mrml_render_options_new`] and
+// `value` must be null or a nul terminated string.
+MrmlStatus mrml_render_options_set_social_icon_origin(MrmlRenderOptions *options,
+                                                      const char *value);
+
+// Adds, or replaces, a font that can be imported when used in a template.
+//
+// # Safety
+//
+// `options` must be a pointer returned by [`mrml_render_options_new`],
+// `name` and `url` must be nul terminated strings.
+MrmlStatus mrml_render_options_add_font(MrmlRenderOptions *options,
+                                        const char *name,
+                                        const char *url);
+
+// Removes all the fonts, including the default ones.
+//
+// # Safety
+//
+// `options` must be a pointer returned by [`mrml_render_options_new`].
+MrmlStatus mrml_render_options_clear_fonts(MrmlRenderOptions *options);
+
+// Renders the mjml `input` into html.
+//
+// When the status is [`MrmlStatus::Ok`], `output` points to the rendered
+// html. Otherwise, when possible, it points to a message describing the
+// error. In both cases, the string must be released with
+// [`mrml_free_string`].
+//
+// # Safety
+//
+// `input` must be a nul terminated string, `options` must be null or a
+// pointer returned by [`mrml_render_options_new`] and `output` must be a
+// valid pointer.
+MrmlStatus mrml_render(const char *input, const MrmlRenderOptions *options, char **output);
+
+// Releases a string returned by this library.
+//
+// # Safety
+//
+// `value` must be null or a string returned by this library that hasn't
+// been released yet.
+void mrml_free_string(char *value);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* MRML_H */
@@ -0,0 +1,340 @@
+//! C bindings on top of MRML, to render mjml templates from any language
+//! able to call a C function (Go, PHP, C#...).
+//!
+//! The header matching these functions is available in `include/mrml.h`.
+//!
+//! ```c
+//! MrmlRenderOptions *options = mrml_render_options_new();
+//! mrml_render_options_set_disable_comments(options, true);
+//! char *output = NULL;
+//! MrmlStatus status = mrml_render("<mjml></mjml>", options, &output);
+//! if (status == MRML_STATUS_OK) {
+//!     printf("%s\n", output);
+//! } else {
+//!     fprintf(stderr, "unable to render: %s\n", output);
+//! }
+//! mrml_free_string(output);
+//! mrml_render_options_free(options);
+//! ```
+
+use std::borrow::Cow;
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+
+use mrml::prelude::render::RenderOptions;
+
+/// Status returned by the functions of this library.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MrmlStatus {
+    Ok = 0,
+    /// A required pointer was null.
+    NullPointer = 1,
+    /// A provided string wasn't valid UTF-8.
+    InvalidUtf8 = 2,
+    /// The template couldn't be parsed.
+    ParserError = 3,
+    /// The template couldn't be rendered.
+    RenderError = 4,
+    /// Something unexpected happened.
+    Panic = 5,
+}
+
+/// Rendering options, created with [`mrml_render_options_new`] and released
+/// with [`mrml_render_options_free`].
+#[derive(Debug, Default)]
+pub struct MrmlRenderOptions {
+    inner: RenderOptions,
+}
+
+unsafe fn read_str<'a>(value: *const c_char) -> Result<&'a str, MrmlStatus> {
+    if value.is_null() {
+        return Err(MrmlStatus::NullPointer);
+    }
+    CStr::from_ptr(value)
+        .to_str()
+        .map_err(|_| MrmlStatus::InvalidUtf8)
+}
+
+fn into_raw(value: String) -> *mut c_char {
+    // the rendered html cannot contain a nul byte, but an error message could
+    // contain one coming from the input.
+    CString::new(value.replace('\0', ""))
+        .map(CString::into_raw)
+        .unwrap_or(ptr::null_mut())
+}
+
+fn render(input: &str, options: &RenderOptions) -> Result<String, (MrmlStatus, String)> {
+    let parsed = mrml::parse(input).map_err(|err| (MrmlStatus::ParserError, err.to_string()))?;
+    parsed
+        .element
+        .render(options)
+        .map_err(|err| (MrmlStatus::RenderError, err.to_string()))
+}
+
+/// Creates rendering options with the default values.
+#[no_mangle]
+pub extern "C" fn mrml_render_options_new() -> *mut MrmlRenderOptions {
+    Box::into_raw(Box::default())
+}
+
+/// Releases rendering options created with [`mrml_render_options_new`].
+///
+/// # Safety
+///
+/// `options` must be null or a pointer returned by
+/// [`mrml_render_options_new`] that hasn't been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn mrml_render_options_free(options: *mut MrmlRenderOptions) {
+    if !options.is_null() {
+        drop(Box::from_raw(options));
+    }
+}
+
+/// Defines if the comments should be removed from the output.
+///
+/// # Safety
+///
+/// `options` must be a pointer returned by [`mrml_render_options_new`].
+#[no_mangle]
+pub unsafe extern "C" fn mrml_render_options_set_disable_comments(
+    options: *mut MrmlRenderOptions,
+    value: bool,
+) -> MrmlStatus {
+    let Some(options) = options.as_mut() else {
+        return MrmlStatus::NullPointer;
+    };
+    options.inner.disable_comments = value;
+    MrmlStatus::Ok
+}
+
+/// Defines the base url used to fetch the social icons. A null `value`
+/// restores the default origin.
+///
+/// # Safety
+///
+/// `options` must be a pointer returned by [`mrml_render_options_new`] and
+/// `value` must be null or a nul terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn mrml_render_options_set_social_icon_origin(
+    options: *mut MrmlRenderOptions,
+    value: *const c_char,
+) -> MrmlStatus {
+    let Some(options) = options.as_mut() else {
+        return MrmlStatus::NullPointer;
+    };
+    if value.is_null() {
+        options.inner.social_icon_origin = None;
+        return MrmlStatus::Ok;
+    }
+    match read_str(value) {
+        Ok(value) => {
+            options.inner.social_icon_origin = Some(Cow::Owned(value.to_string()));
+            MrmlStatus::Ok
+        }
+        Err(status) => status,
+    }
+}
+
+/// Adds, or replaces, a font that can be imported when used in a template.
+///
+/// # Safety
+///
+/// `options` must be a pointer returned by [`mrml_render_options_new`],
+/// `name` and `url` must be nul terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn mrml_render_options_add_font(
+    options: *mut MrmlRenderOptions,
+    name: *const c_char,
+    url: *const c_char,
+) -> MrmlStatus {
+    let Some(options) = options.as_mut() else {
+        return MrmlStatus::NullPointer;
+    };
+    match (read_str(name), read_str(url)) {
+        (Ok(name), Ok(url)) => {
+            options
+                .inner
+                .fonts
+                .insert(name.to_string(), Cow::Owned(url.to_string()));
+            MrmlStatus::Ok
+        }
+        (Err(status), _) | (_, Err(status)) => status,
+    }
+}
+
+/// Removes all the fonts, including the default ones.
+///
+/// # Safety
+///
+/// `options` must be a pointer returned by [`mrml_render_options_new`].
+#[no_mangle]
+pub unsafe extern "C" fn mrml_render_options_clear_fonts(
+    options: *mut MrmlRenderOptions,
+) -> MrmlStatus {
+    let Some(options) = options.as_mut() else {
+        return MrmlStatus::NullPointer;
+    };
+    options.inner.fonts.clear();
+    MrmlStatus::Ok
+}
+
+/// Renders the mjml `input` into html.
+///
+/// When the status is [`MrmlStatus::Ok`], `output` points to the rendered
+/// html. Otherwise, when possible, it points to a message describing the
+/// error. In both cases, the string must be released with
+/// [`mrml_free_string`].
+///
+/// # Safety
+///
+/// `input` must be a nul terminated string, `options` must be null or a
+/// pointer returned by [`mrml_render_options_new`] and `output` must be a
+/// valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn mrml_render(
+    input: *const c_char,
+    options: *const MrmlRenderOptions,
+    output: *mut *mut c_char,
+) -> MrmlStatus {
+    if output.is_null() {
+        return MrmlStatus::NullPointer;
+    }
+    *output = ptr::null_mut();
+    let input = match read_str(input) {
+        Ok(value) => value,
+        Err(status) => return status,
+    };
+    let default_options;
+    let options = match options.as_ref() {
+        Some(options) => &options.inner,
+        None => {
+            default_options = RenderOptions::default();
+            &default_options
+        }
+    };
+    let result = catch_unwind(AssertUnwindSafe(|| render(input, options)))
+        .unwrap_or_else(|_| Err((MrmlStatus::Panic, String::from("unexpected panic"))));
+    let (status, content) = match result {
+        Ok(html) => (MrmlStatus::Ok, html),
+        Err(error) => error,
+    };
+    *output = into_raw(content);
+    status
+}
+
+/// Releases a string returned by this library.
+///
+/// # Safety
+///
+/// `value` must be null or a string returned by this library that hasn't
+/// been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn mrml_free_string(value: *mut c_char) {
+    if !value.is_null() {
+        drop(CString::from_raw(value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::{c_char, CStr, CString};
+    use std::ptr;
+
+    use super::*;
+
+    unsafe fn call(input: &str, options: *const MrmlRenderOptions) -> (MrmlStatus, String) {
+        let input = CString::new(input).unwrap();
+        let mut output: *mut c_char = ptr::null_mut();
+        let status = mrml_render(input.as_ptr(), options, &mut output);
+        let content = CStr::from_ptr(output).to_string_lossy().into_owned();
+        mrml_free_string(output);
+        (status, content)
+    }
+
+    #[test]
+    fn should_render() {
+        let (status, content) = unsafe { call("<mjml></mjml>", ptr::null()) };
+        assert_eq!(status, MrmlStatus::Ok);
+        assert!(content.starts_with("<!doctype html>"));
+    }
+
+    #[test]
+    fn should_render_with_options() {
+        unsafe {
+            let options = mrml_render_options_new();
+            assert_eq!(
+                mrml_render_options_set_disable_comments(options, true),
+                MrmlStatus::Ok
+            );
+            let (status, content) = call(
+                "<mjml><mj-body><!-- Goodbye --><mj-text>Hello</mj-text></mj-body></mjml>",
+                options,
+            );
+            mrml_render_options_free(options);
+            assert_eq!(status, MrmlStatus::Ok);
+            assert!(!content.contains("Goodbye"));
+        }
+    }
+
+    #[test]
+    fn should_add_fonts() {
+        unsafe {
+            let options = mrml_render_options_new();
+            let name = CString::new("Comic").unwrap();
+            let url = CString::new("https://example.com/comic.css").unwrap();
+            assert_eq!(
+                mrml_render_options_add_font(options, name.as_ptr(), url.as_ptr()),
+                MrmlStatus::Ok
+            );
+            assert_eq!(
+                mrml_render_options_add_font(options, ptr::null(), url.as_ptr()),
+                MrmlStatus::NullPointer
+            );
+            let (status, content) = call(
+                r#"<mjml><mj-body><mj-text font-family="Comic">Hello</mj-text></mj-body></mjml>"#,
+                options,
+            );
+            mrml_render_options_free(options);
+            assert_eq!(status, MrmlStatus::Ok);
+            assert!(content.contains("https://example.com/comic.css"));
+        }
+    }
+
+    #[test]
+    fn should_return_parser_error() {
+        let (status, content) = unsafe { call("<mjml><mj-body>", ptr::null()) };
+        assert_eq!(status, MrmlStatus::ParserError);
+        assert!(!content.is_empty());
+    }
+
+    #[test]
+    fn should_reject_null_pointers() {
+        unsafe {
+            let mut output: *mut c_char = ptr::null_mut();
+            assert_eq!(
+                mrml_render(ptr::null(), ptr::null(), &mut output),
+                MrmlStatus::NullPointer
+            );
+            assert!(output.is_null());
+            let input = CString::new("<mjml></mjml>").unwrap();
+            assert_eq!(
+                mrml_render(input.as_ptr(), ptr::null(), ptr::null_mut()),
+                MrmlStatus::NullPointer
+            );
+        }
+    }
+
+    #[test]
+    fn should_reject_invalid_utf8() {
+        unsafe {
+            let input = [0xffu8, 0xfe, 0x00];
+            let mut output: *mut c_char = ptr::null_mut();
+            assert_eq!(
+                mrml_render(input.as_ptr() as *const c_char, ptr::null(), &mut output),
+                MrmlStatus::InvalidUtf8
+            );
+        }
+    }
+}
@@ -0,0 +1,55 @@
+const assert = require("assert");
+const path = require("path");
+const { describe, it } = require("node:test");
+const { mjml2html } = require("../index.js");
+
+describe("mjml2html", function () {
+  it("should render to html", function () {
+    const result = mjml2html(
+      "<mjml><mj-body><mj-text>Hello world</mj-text></mj-body></mjml>",
+    );
+    assert.match(result.html, /^<!doctype html>/);
+    assert.deepEqual(result.errors, []);
+  });
+
+  it("should remove the comments", function () {
+    const result = mjml2html(
+      "<mjml><mj-body><!-- Goodbye --><mj-text>Hello world</mj-text></mj-body></mjml>",
+      { keepComments: false },
+    );
+    assert.doesNotMatch(result.html, /Goodbye/);
+  });
+
+  it("should report the validation errors", function () {
+    const result = mjml2html(`<mjml>
+  <mj-body>
+    <mj-text foo="bar">Hello world</mj-text>
+  </mj-body>
+</mjml>`);
+    assert.equal(result.errors.length, 1);
+    assert.equal(result.errors[0].line, 3);
+    assert.equal(result.errors[0].tagName, "mj-text");
+  });
+
+  it("should throw with the strict validation level", function () {
+    assert.throws(() =>
+      mjml2html('<mjml foo="bar"></mjml>', { validationLevel: "strict" }),
+    );
+    const result = mjml2html('<mjml foo="bar"></mjml>', {
+      validationLevel: "skip",
+    });
+    assert.deepEqual(result.errors, []);
+  });
+
+  it("should resolve the includes from the file path", function () {
+    const result = mjml2html(
+      '<mjml><mj-body><mj-include path="./partials/hello-world" /></mj-body></mjml>',
+      { filePath: path.join(__dirname, "template.mjml") },
+    );
+    assert.match(result.html, /Hello World/);
+  });
+
+  it("should throw when the template is invalid", function () {
+    assert.throws(() => mjml2html("<mjml><mj-body>"));
+  });
+});
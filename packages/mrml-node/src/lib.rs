@@ -0,0 +1,200 @@
+//! Node.js binding exposing a `mjml2html` function compatible with the one
+//! provided by the `mjml` package.
+
+#![deny(clippy::all)]
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use mrml::prelude::parser::loader::{IncludeLoader, IncludeLoaderError};
+use mrml::prelude::parser::{Origin, ParserOptions, Warning};
+use mrml::prelude::render::RenderOptions;
+use napi::{Error, Result};
+use napi_derive::napi;
+
+/// Options of the `mjml2html` function, following the ones of the `mjml`
+/// package. The options that are not listed here are ignored.
+#[napi(object)]
+#[derive(Debug, Default)]
+pub struct Mjml2HtmlOptions {
+    /// Fonts that can be imported when used in the template. Replaces the
+    /// default fonts when provided.
+    pub fonts: Option<HashMap<String, String>>,
+    /// Keeps the comments in the html output. Enabled by default.
+    pub keep_comments: Option<bool>,
+    /// `strict`, `soft` or `skip`. With `strict`, an error is thrown when the
+    /// template isn't valid, with `skip` the errors are not reported.
+    pub validation_level: Option<String>,
+    /// Path of the template, used to resolve the paths of the `mj-include`
+    /// elements.
+    pub file_path: Option<String>,
+    /// Base url of the server to fetch the social icons.
+    pub social_icon_origin: Option<String>,
+}
+
+#[napi(object)]
+#[derive(Debug)]
+pub struct Mjml2HtmlError {
+    pub line: u32,
+    pub message: String,
+    pub tag_name: String,
+    pub formatted_message: String,
+}
+
+#[napi(object)]
+#[derive(Debug)]
+pub struct Mjml2HtmlResult {
+    pub html: String,
+    pub errors: Vec<Mjml2HtmlError>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ValidationLevel {
+    Strict,
+    Soft,
+    Skip,
+}
+
+impl ValidationLevel {
+    fn parse(value: Option<&str>) -> Result<Self> {
+        match value {
+            None | Some("soft") => Ok(Self::Soft),
+            Some("strict") => Ok(Self::Strict),
+            Some("skip") => Ok(Self::Skip),
+            Some(other) => Err(Error::from_reason(format!(
+                "invalid validationLevel {other:?}, expected strict, soft or skip"
+            ))),
+        }
+    }
+}
+
+/// Resolves the `mj-include` paths relatively to the directory of the
+/// template, like the `mjml` package does.
+#[derive(Debug)]
+struct FileIncludeLoader {
+    directory: PathBuf,
+}
+
+impl IncludeLoader for FileIncludeLoader {
+    fn resolve(&self, path: &str) -> std::result::Result<String, IncludeLoaderError> {
+        let mut target = self.directory.join(path);
+        if target.extension().is_none() {
+            target.set_extension("mjml");
+        }
+        std::fs::read_to_string(target).map_err(|err| {
+            IncludeLoaderError::new(path, ErrorKind::NotFound)
+                .with_message("unable to load the included template")
+                .with_cause(Arc::new(err))
+        })
+    }
+}
+
+fn include_directory(file_path: Option<&str>) -> PathBuf {
+    let path = Path::new(file_path.unwrap_or("."));
+    if path.is_dir() {
+        path.to_path_buf()
+    } else {
+        path.parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."))
+    }
+}
+
+fn line_of(input: &str, offset: usize) -> u32 {
+    let offset = offset.min(input.len());
+    let count = input.as_bytes()[..offset]
+        .iter()
+        .filter(|c| **c == b'\n')
+        .count();
+    count as u32 + 1
+}
+
+fn tag_name_at(input: &str, offset: usize) -> String {
+    input
+        .get(..offset)
+        .and_then(|before| before.rfind('<'))
+        .map(|start| {
+            input[start + 1..]
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '-')
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn build_error(input: &str, file_path: &str, warning: &Warning) -> Mjml2HtmlError {
+    let message = warning.kind.to_string();
+    let (line, tag_name, file) = match warning.origin {
+        Origin::Root => (
+            line_of(input, warning.span.start),
+            tag_name_at(input, warning.span.start),
+            file_path,
+        ),
+        Origin::Include { ref path } => (0, String::new(), path.as_str()),
+    };
+    Mjml2HtmlError {
+        formatted_message: format!("Line {line} of {file} ({tag_name}) — {message}"),
+        line,
+        message,
+        tag_name,
+    }
+}
+
+/// Renders the mjml template to html, with the same signature as the
+/// `mjml2html` function of the `mjml` package.
+#[napi(js_name = "mjml2html")]
+pub fn mjml2html(input: String, options: Option<Mjml2HtmlOptions>) -> Result<Mjml2HtmlResult> {
+    let options = options.unwrap_or_default();
+    let level = ValidationLevel::parse(options.validation_level.as_deref())?;
+
+    let parser_options = ParserOptions {
+        include_loader: Box::new(FileIncludeLoader {
+            directory: include_directory(options.file_path.as_deref()),
+        }),
+        ..Default::default()
+    };
+    let parsed = mrml::parse_with_options(&input, &parser_options)
+        .map_err(|err| Error::from_reason(err.to_string()))?;
+
+    let file_path = options.file_path.as_deref().unwrap_or(".");
+    let errors = match level {
+        ValidationLevel::Skip => Vec::new(),
+        _ => parsed
+            .warnings
+            .iter()
+            .map(|warning| build_error(&input, file_path, warning))
+            .collect::<Vec<_>>(),
+    };
+    if level == ValidationLevel::Strict && !errors.is_empty() {
+        let details = errors
+            .iter()
+            .map(|item| item.formatted_message.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        return Err(Error::from_reason(format!(
+            "ValidationError: Found {} validation errors\n{details}",
+            errors.len()
+        )));
+    }
+
+    let mut render_options = RenderOptions {
+        disable_comments: !options.keep_comments.unwrap_or(true),
+        social_icon_origin: options.social_icon_origin.map(Cow::Owned),
+        ..Default::default()
+    };
+    if let Some(fonts) = options.fonts {
+        render_options.fonts = fonts
+            .into_iter()
+            .map(|(name, url)| (name, Cow::Owned(url)))
+            .collect();
+    }
+    let html = parsed
+        .element
+        .render(&render_options)
+        .map_err(|err| Error::from_reason(err.to_string()))?;
+
+    Ok(Mjml2HtmlResult { html, errors })
+}
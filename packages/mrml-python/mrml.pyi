@@ -95,3 +95,22 @@ def to_html(
 ) -> Output:
     """Function to convert input a MJML string to HTML using optional parser and render configurations."""
     ...
+
+def to_json(
+    input: str,
+    parser_options: Optional[ParserOptions] = None,
+    pretty: bool = False,
+) -> str:
+    """Function to parse a MJML string and convert its structure to JSON."""
+    ...
+
+def from_json(input: str, pretty: bool = False) -> str:
+    """Function to convert a JSON structure, generated by to_json, back to a MJML string."""
+    ...
+
+def json_to_html(
+    input: str,
+    render_options: Optional[RenderOptions] = None,
+) -> Output:
+    """Function to render a JSON structure, generated by to_json, to HTML."""
+    ...
@@ -7,7 +7,8 @@ use mrml::prelude::parser::loader::IncludeLoader;
 use mrml::prelude::parser::local_loader::LocalIncludeLoader;
 use mrml::prelude::parser::memory_loader::MemoryIncludeLoader;
 use mrml::prelude::parser::noop_loader::NoopIncludeLoader;
-use pyo3::exceptions::PyIOError;
+use mrml::prelude::print::Printable;
+use pyo3::exceptions::{PyIOError, PyValueError};
 use pyo3::prelude::*;
 
 #[pyclass]
@@ -151,7 +152,10 @@ impl ParserOptions {
 impl From<ParserOptions> for mrml::prelude::parser::ParserOptions {
     fn from(value: ParserOptions) -> Self {
         let include_loader = value.include_loader.build();
-        mrml::prelude::parser::ParserOptions { include_loader }
+        mrml::prelude::parser::ParserOptions {
+            include_loader,
+            ..Default::default()
+        }
     }
 }
 
@@ -169,8 +173,17 @@ pub struct RenderOptions {
 #[pymethods]
 impl RenderOptions {
     #[new]
-    pub fn new() -> Self {
-        Self::default()
+    #[pyo3(signature = (disable_comments=false, social_icon_origin=None, fonts=None))]
+    pub fn new(
+        disable_comments: bool,
+        social_icon_origin: Option<String>,
+        fonts: Option<HashMap<String, String>>,
+    ) -> Self {
+        Self {
+            disable_comments,
+            social_icon_origin,
+            fonts,
+        }
     }
 }
 
@@ -245,7 +258,13 @@ fn to_html(
     let parser_options = parser_options.unwrap_or_default().into();
     let parsed = mrml::parse_with_options(input, &parser_options)
         .map_err(|err| PyIOError::new_err(err.to_string()))?;
+    render(parsed, render_options)
+}
 
+fn render(
+    parsed: mrml::prelude::parser::ParseOutput<mrml::mjml::Mjml>,
+    render_options: Option<RenderOptions>,
+) -> PyResult<Output> {
     let render_options = render_options.unwrap_or_default().into();
     let content = parsed
         .element
@@ -258,6 +277,52 @@ fn to_html(
     })
 }
 
+fn parse_json(input: &str) -> PyResult<mrml::mjml::Mjml> {
+    serde_json::from_str(input).map_err(|err| PyValueError::new_err(err.to_string()))
+}
+
+/// Renders a template that was previously converted to JSON with `to_json`.
+#[pyfunction]
+#[pyo3(name = "json_to_html", signature = (input, render_options=None))]
+fn json_to_html(input: String, render_options: Option<RenderOptions>) -> PyResult<Output> {
+    let element = parse_json(&input)?;
+    render(
+        mrml::prelude::parser::ParseOutput {
+            element,
+            warnings: Vec::new(),
+        },
+        render_options,
+    )
+}
+
+/// Parses a mjml template and converts its AST to JSON.
+#[pyfunction]
+#[pyo3(name = "to_json", signature = (input, parser_options=None, pretty=false))]
+fn to_json(input: String, parser_options: Option<ParserOptions>, pretty: bool) -> PyResult<String> {
+    let parser_options = parser_options.unwrap_or_default().into();
+    let parsed = mrml::parse_with_options(input, &parser_options)
+        .map_err(|err| PyIOError::new_err(err.to_string()))?;
+    let result = if pretty {
+        serde_json::to_string_pretty(&parsed.element)
+    } else {
+        serde_json::to_string(&parsed.element)
+    };
+    result.map_err(|err| PyValueError::new_err(err.to_string()))
+}
+
+/// Converts a JSON AST back to a mjml template.
+#[pyfunction]
+#[pyo3(name = "from_json", signature = (input, pretty=false))]
+fn from_json(input: String, pretty: bool) -> PyResult<String> {
+    let element = parse_json(&input)?;
+    let result = if pretty {
+        element.print_pretty()
+    } else {
+        element.print_dense()
+    };
+    result.map_err(|err| PyValueError::new_err(err.to_string()))
+}
+
 #[pymodule]
 #[pyo3(name = "mrml")]
 fn register(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -271,6 +336,9 @@ fn register(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Output>()?;
     m.add_class::<Warning>()?;
     m.add_function(wrap_pyfunction!(to_html, m)?)?;
+    m.add_function(wrap_pyfunction!(to_json, m)?)?;
+    m.add_function(wrap_pyfunction!(from_json, m)?)?;
+    m.add_function(wrap_pyfunction!(json_to_html, m)?)?;
     m.add_function(wrap_pyfunction!(noop_loader, m)?)?;
     m.add_function(wrap_pyfunction!(local_loader, m)?)?;
     m.add_function(wrap_pyfunction!(http_loader, m)?)?;
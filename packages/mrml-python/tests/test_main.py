@@ -1,3 +1,4 @@
+import pytest
 import mrml
 
 def test_simple_template():
@@ -19,3 +20,22 @@ def test_template_with_options():
     }))
     result = mrml.to_html("<mjml><mj-body><mj-include path=\"hello-world.mjml\" /></mj-body></mjml>", parser_options = parser_options)
     assert result.content.startswith("<!doctype html>")
+
+def test_template_with_render_options():
+    render_options = mrml.RenderOptions(disable_comments = True, fonts = {})
+    result = mrml.to_html("<mjml><mj-body><!-- Goodbye --><mj-text>Hello World!</mj-text></mj-body></mjml>", render_options = render_options)
+    assert "Goodbye" not in result.content
+    assert "Hello World!" in result.content
+
+def test_json_round_trip():
+    template = "<mjml><mj-body><mj-text>Hello World!</mj-text></mj-body></mjml>"
+    json = mrml.to_json(template)
+    assert json.startswith("{")
+    assert "<mj-text>Hello World!</mj-text>" in mrml.from_json(json)
+    result = mrml.json_to_html(json)
+    assert result.content.startswith("<!doctype html>")
+    assert "Hello World!" in result.content
+
+def test_invalid_json():
+    with pytest.raises(ValueError):
+        mrml.from_json("{")
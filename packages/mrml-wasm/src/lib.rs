@@ -96,6 +96,60 @@ impl Engine {
     }
 }
 
+/// Parsing and rendering options used by the [`to_html_with_options`]
+/// function.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, tsify::Tsify)]
+#[serde(default, rename_all = "camelCase")]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct Options {
+    #[tsify(optional)]
+    pub parser: ParserOptions,
+    #[tsify(optional)]
+    pub render: RenderOptions,
+}
+
+/// Parsing and rendering options used by the
+/// [`to_html_with_options_async`] function.
+#[cfg(feature = "async")]
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, tsify::Tsify)]
+#[serde(default, rename_all = "camelCase")]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct AsyncOptions {
+    #[tsify(optional)]
+    pub parser: AsyncParserOptions,
+    #[tsify(optional)]
+    pub render: RenderOptions,
+}
+
+/// Renders the mjml input into html, without having to build an [`Engine`].
+#[wasm_bindgen(js_name = "toHtml")]
+pub fn to_html_with_options(input: &str, options: Option<Options>) -> ToHtmlResult {
+    let options = options.unwrap_or_default();
+    let parser = options.parser.into();
+    let render = options.render.into();
+    match to_html(input, &parser, &render) {
+        Ok((content, warnings)) => ToHtmlResult::Success { content, warnings },
+        Err(error) => ToHtmlResult::Error(error),
+    }
+}
+
+/// Renders the mjml input into html, without having to build an [`Engine`].
+#[cfg(feature = "async")]
+#[allow(clippy::arc_with_non_send_sync)]
+#[wasm_bindgen(js_name = "toHtmlAsync")]
+pub async fn to_html_with_options_async(
+    input: &str,
+    options: Option<AsyncOptions>,
+) -> ToHtmlResult {
+    let options = options.unwrap_or_default();
+    let parser = std::sync::Arc::new(options.parser.into());
+    let render = options.render.into();
+    match to_html_async(input, parser, &render).await {
+        Ok((content, warnings)) => ToHtmlResult::Success { content, warnings },
+        Err(error) => ToHtmlResult::Error(error),
+    }
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize, tsify::Tsify)]
 #[serde(rename_all = "camelCase", tag = "origin")]
 #[tsify(into_wasm_abi)]
@@ -159,7 +213,10 @@ mod tests {
 
     use wasm_bindgen_test::wasm_bindgen_test;
 
-    use crate::{Engine, ToHtmlResult};
+    use crate::{
+        to_html_with_options, Engine, Options, ParserError, RenderOptions, ToHtmlError,
+        ToHtmlResult,
+    };
 
     #[wasm_bindgen_test]
     fn it_should_render() {
@@ -177,6 +234,42 @@ mod tests {
         assert!(matches!(result, ToHtmlResult::Error(_)));
     }
 
+    #[wasm_bindgen_test]
+    fn it_should_render_with_options() {
+        let template = r#"<mjml><mj-head><mj-font name="Comic" href="https://example.com/comic.css" /></mj-head><mj-body><!-- Hello --><mj-text font-family="Comic">Hello World</mj-text></mj-body></mjml>"#;
+        let result = to_html_with_options(
+            template,
+            Some(Options {
+                render: RenderOptions {
+                    disable_comments: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        )
+        .into_success();
+        assert!(!result.contains("<!-- Hello -->"));
+        assert!(result.contains("https://example.com/comic.css"));
+    }
+
+    #[wasm_bindgen_test]
+    fn it_should_render_without_options() {
+        let template = "<mjml><mj-body><mj-text>Hello World</mj-text></mj-body></mjml>";
+        let result = to_html_with_options(template, None);
+        assert!(matches!(result, ToHtmlResult::Success { .. }));
+    }
+
+    #[wasm_bindgen_test]
+    fn it_should_return_structured_error() {
+        let template = "<mjml><mj-body><mj-text>Hello World";
+        let result = to_html_with_options(template, None);
+        let ToHtmlResult::Error(ToHtmlError::Parser { message, details }) = result else {
+            panic!("expected a parser error");
+        };
+        assert!(!message.is_empty());
+        assert!(!matches!(details, ParserError::NoRootNode));
+    }
+
     #[wasm_bindgen_test]
     fn it_should_render_with_include() {
         let template = "<mjml><mj-body><mj-include path=\"/hello-world.mjml\" /></mj-body></mjml>";
@@ -203,7 +296,7 @@ mod async_tests {
 
     use wasm_bindgen_test::wasm_bindgen_test;
 
-    use crate::{Engine, ToHtmlResult};
+    use crate::{to_html_with_options_async, Engine, ToHtmlResult};
 
     #[wasm_bindgen_test]
     async fn it_should_render() {
@@ -221,6 +314,13 @@ mod async_tests {
         assert!(matches!(result, ToHtmlResult::Error(_)));
     }
 
+    #[wasm_bindgen_test]
+    async fn it_should_render_without_options() {
+        let template = "<mjml><mj-body><mj-text>Hello World</mj-text></mj-body></mjml>";
+        let result = to_html_with_options_async(template, None).await;
+        assert!(matches!(result, ToHtmlResult::Success { .. }));
+    }
+
     #[wasm_bindgen_test]
     async fn it_should_render_with_include() {
         let template = "<mjml><mj-body><mj-include path=\"/hello-world.mjml\" /></mj-body></mjml>";
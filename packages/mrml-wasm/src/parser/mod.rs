@@ -31,10 +31,11 @@ impl IncludeLoaderOptions {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, tsify::Tsify)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, tsify::Tsify)]
+#[serde(default, rename_all = "camelCase")]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct ParserOptions {
+    #[tsify(optional)]
     pub include_loader: IncludeLoaderOptions,
 }
 
@@ -42,6 +43,7 @@ impl From<ParserOptions> for mrml::prelude::parser::ParserOptions {
     fn from(value: ParserOptions) -> Self {
         mrml::prelude::parser::ParserOptions {
             include_loader: value.include_loader.build(),
+            ..Default::default()
         }
     }
 }
@@ -80,10 +82,11 @@ impl AsyncIncludeLoaderOptions {
 }
 
 #[cfg(feature = "async")]
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, tsify::Tsify)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, tsify::Tsify)]
+#[serde(default, rename_all = "camelCase")]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct AsyncParserOptions {
+    #[tsify(optional)]
     pub include_loader: AsyncIncludeLoaderOptions,
 }
 
@@ -92,6 +95,7 @@ impl From<AsyncParserOptions> for mrml::prelude::parser::AsyncParserOptions {
     fn from(value: AsyncParserOptions) -> Self {
         mrml::prelude::parser::AsyncParserOptions {
             include_loader: value.include_loader.build_async(),
+            ..Default::default()
         }
     }
 }
@@ -140,6 +144,12 @@ pub enum ParserError {
         position: super::Span,
         source: String,
     },
+    /// One of the limits defined in the options was reached.
+    LimitExceeded {
+        origin: super::Origin,
+        kind: LimitKind,
+        max: usize,
+    },
 }
 
 impl From<mrml::prelude::parser::Error> for ParserError {
@@ -192,6 +202,11 @@ impl From<mrml::prelude::parser::Error> for ParserError {
                 origin: origin.into(),
                 position: position.into(),
             },
+            Error::LimitExceeded { origin, kind, max } => Self::LimitExceeded {
+                origin: origin.into(),
+                kind: kind.into(),
+                max,
+            },
         }
     }
 }
@@ -229,17 +244,40 @@ impl From<mrml::prelude::parser::Span> for Span {
     }
 }
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, tsify::Tsify)]
+#[serde(rename_all = "kebab-case")]
+#[tsify(into_wasm_abi)]
+pub enum LimitKind {
+    InputSize,
+    Depth,
+    Elements,
+    Attributes,
+}
+
+impl From<mrml::prelude::parser::LimitKind> for LimitKind {
+    fn from(value: mrml::prelude::parser::LimitKind) -> Self {
+        match value {
+            mrml::prelude::parser::LimitKind::InputSize => Self::InputSize,
+            mrml::prelude::parser::LimitKind::Depth => Self::Depth,
+            mrml::prelude::parser::LimitKind::Elements => Self::Elements,
+            mrml::prelude::parser::LimitKind::Attributes => Self::Attributes,
+        }
+    }
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize, tsify::Tsify)]
 #[serde(rename_all = "kebab-case")]
 #[tsify(into_wasm_abi)]
 pub enum WarningKind {
     UnexpectedAttributes,
+    UnbalancedHtml,
 }
 
 impl From<mrml::prelude::parser::WarningKind> for WarningKind {
     fn from(value: mrml::prelude::parser::WarningKind) -> Self {
         match value {
             mrml::prelude::parser::WarningKind::UnexpectedAttribute => Self::UnexpectedAttributes,
+            mrml::prelude::parser::WarningKind::UnbalancedHtml => Self::UnbalancedHtml,
         }
     }
 }
@@ -3,20 +3,37 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 
-#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, tsify::Tsify)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, tsify::Tsify)]
+#[serde(default, rename_all = "camelCase")]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 /// Rendering options
 pub struct RenderOptions {
     /// If disabled, the comments won't be kept in the result. Disabled by
     /// default.
+    #[tsify(optional)]
     pub disable_comments: bool,
     /// Base url of the server to fetch the social icons.
+    #[tsify(optional)]
     pub social_icon_origin: Option<String>,
-    /// Map of fonts that can be used.
+    /// Map of fonts that can be used. When not provided, the default fonts
+    /// (Open Sans, Droid Sans, Lato, Roboto and Ubuntu) are available.
+    #[tsify(optional)]
     pub fonts: HashMap<String, String>,
 }
 
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            disable_comments: false,
+            social_icon_origin: None,
+            fonts: mrml::prelude::render::default_fonts()
+                .into_iter()
+                .map(|(key, value)| (key, value.into_owned()))
+                .collect(),
+        }
+    }
+}
+
 impl From<RenderOptions> for mrml::prelude::render::RenderOptions {
     fn from(value: RenderOptions) -> Self {
         Self {
@@ -27,6 +44,32 @@ impl From<RenderOptions> for mrml::prelude::render::RenderOptions {
                 .into_iter()
                 .map(|(key, value)| (key, Cow::Owned(value)))
                 .collect(),
+            ..Default::default()
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::RenderOptions;
+
+    #[wasm_bindgen_test]
+    fn should_keep_default_fonts() {
+        let opts: RenderOptions = serde_json::from_str(r#"{"disableComments":true}"#).unwrap();
+        assert!(opts.disable_comments);
+        assert!(opts.fonts.contains_key("Roboto"));
+        let opts: mrml::prelude::render::RenderOptions = opts.into();
+        assert_eq!(opts.fonts, mrml::prelude::render::default_fonts());
+    }
+
+    #[wasm_bindgen_test]
+    fn should_replace_fonts() {
+        let opts: RenderOptions =
+            serde_json::from_str(r#"{"fonts":{"Comic":"https://example.com/comic.css"}}"#).unwrap();
+        assert_eq!(opts.fonts.len(), 1);
+        assert!(!opts.disable_comments);
+        assert!(opts.social_icon_origin.is_none());
+    }
+}
@@ -4,6 +4,8 @@ const fsp = require('fs/promises');
 
 const COMPARE_DIRECTORY = 'resources/compare/success';
 const TEMPLATE_DIRECTORY = 'resources/template';
+// components that only exist in mrml, their html is rendered by mrml
+const MRML_COMPONENTS = ['mj-avatar', 'mj-badge', 'mj-calendar-button', 'mj-code', 'mj-footer', 'mj-map', 'mj-quote', 'mj-stats'];
 
 const iterate = (list, callback) =>
   list.reduce((res, item) => res.then(() => callback(item)), Promise.resolve());
@@ -61,6 +63,7 @@ const handleDirectory = (dir) => {
   return fsp.readdir(dir)
     .then((files) => files
       .filter((fname) => fname.endsWith('.mjml'))
+      .filter((fname) => !MRML_COMPONENTS.some((name) => fname.startsWith(name)))
       .map((fname) => fname.substring(0, fname.length - 5)))
     .then((files) => iterate(files, (fname) => handleFile(dir, fname)))
     .then(() => console.log(`🎉 done with ${dir}`));